@@ -0,0 +1,17 @@
+CREATE TABLE login_attempts
+(
+    id         BIGSERIAL PRIMARY KEY,
+    email      VARCHAR     NOT NULL,
+    user_id    BIGINT      NULL,
+    ip         VARCHAR     NOT NULL,
+    successful BOOLEAN     NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT fk_login_attempts_user
+        FOREIGN KEY (user_id)
+            REFERENCES users (id)
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_login_attempts_email_created_at ON login_attempts (email, created_at);
+CREATE INDEX idx_login_attempts_ip_created_at ON login_attempts (ip, created_at);
@@ -0,0 +1,25 @@
+CREATE TABLE account_lockouts
+(
+    id              BIGSERIAL PRIMARY KEY,
+    email           VARCHAR     NOT NULL,
+    user_id         BIGINT      NULL,
+    ip              VARCHAR     NOT NULL,
+    failed_attempts BIGINT      NOT NULL,
+    locked_at       TIMESTAMPTZ NOT NULL DEFAULT now(),
+    locked_until    TIMESTAMPTZ NOT NULL,
+    unlocked_at     TIMESTAMPTZ NULL,
+    unlocked_by     BIGINT      NULL,
+
+    CONSTRAINT fk_account_lockouts_user
+        FOREIGN KEY (user_id)
+            REFERENCES users (id)
+            ON DELETE CASCADE,
+
+    CONSTRAINT fk_account_lockouts_unlocked_by
+        FOREIGN KEY (unlocked_by)
+            REFERENCES users (id)
+            ON DELETE SET NULL
+);
+
+CREATE INDEX idx_account_lockouts_email ON account_lockouts (email);
+CREATE INDEX idx_account_lockouts_user_id ON account_lockouts (user_id);
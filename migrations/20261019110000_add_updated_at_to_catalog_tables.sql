@@ -0,0 +1,58 @@
+ALTER TABLE products ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
+ALTER TABLE product_images ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
+ALTER TABLE product_videos ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
+ALTER TABLE categories ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+-- row version, bumped on every update
+CREATE OR REPLACE FUNCTION set_updated_at() RETURNS TRIGGER AS
+$$
+BEGIN
+    NEW.updated_at = clock_timestamp();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_products_updated_at
+    BEFORE UPDATE ON products
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER trg_product_images_updated_at
+    BEFORE UPDATE ON product_images
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER trg_product_videos_updated_at
+    BEFORE UPDATE ON product_videos
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER trg_categories_updated_at
+    BEFORE UPDATE ON categories
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+-- product pages embed images, videos and reviews, so any change to them
+-- bumps the parent product version as well
+CREATE OR REPLACE FUNCTION touch_parent_product() RETURNS TRIGGER AS
+$$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') THEN
+        UPDATE products SET updated_at = clock_timestamp() WHERE id = OLD.product_id;
+    END IF;
+
+    IF TG_OP IN ('INSERT', 'UPDATE') THEN
+        UPDATE products SET updated_at = clock_timestamp() WHERE id = NEW.product_id;
+    END IF;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_product_images_touch_product
+    AFTER INSERT OR UPDATE OR DELETE ON product_images
+    FOR EACH ROW EXECUTE FUNCTION touch_parent_product();
+
+CREATE TRIGGER trg_product_videos_touch_product
+    AFTER INSERT OR UPDATE OR DELETE ON product_videos
+    FOR EACH ROW EXECUTE FUNCTION touch_parent_product();
+
+CREATE TRIGGER trg_product_reviews_touch_product
+    AFTER INSERT OR UPDATE OR DELETE ON product_reviews
+    FOR EACH ROW EXECUTE FUNCTION touch_parent_product();
@@ -0,0 +1,2 @@
+CREATE INDEX idx_products_created_at_id ON products (created_at DESC, id DESC);
+CREATE INDEX idx_product_reviews_created_at_id ON product_reviews (created_at DESC, id DESC);
@@ -0,0 +1,13 @@
+ALTER TABLE products ADD COLUMN deleted_at TIMESTAMPTZ NULL;
+ALTER TABLE categories ADD COLUMN deleted_at TIMESTAMPTZ NULL;
+
+-- a trashed row must not block its slug, uniqueness only applies to live rows.
+-- the indexes keep the `<table>_<column>_key` names so conflicts still map to the column
+ALTER TABLE products DROP CONSTRAINT products_slug_key;
+CREATE UNIQUE INDEX products_slug_key ON products (slug) WHERE deleted_at IS NULL;
+
+ALTER TABLE categories DROP CONSTRAINT categories_slug_key;
+CREATE UNIQUE INDEX categories_slug_key ON categories (slug) WHERE deleted_at IS NULL;
+
+CREATE INDEX idx_products_deleted_at ON products (deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_categories_deleted_at ON categories (deleted_at) WHERE deleted_at IS NOT NULL;
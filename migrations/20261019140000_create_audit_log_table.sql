@@ -0,0 +1,20 @@
+CREATE TABLE audit_log
+(
+    id         BIGSERIAL PRIMARY KEY,
+    actor_id   BIGINT      NULL,
+    entity     VARCHAR     NOT NULL,
+    entity_id  BIGINT      NOT NULL,
+    action     VARCHAR     NOT NULL,
+    changes    JSONB       NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    -- the trail outlives the admin who made the change
+    CONSTRAINT fk_audit_log_actor
+        FOREIGN KEY (actor_id)
+            REFERENCES users (id)
+            ON DELETE SET NULL
+);
+
+CREATE INDEX idx_audit_log_entity ON audit_log (entity, entity_id, created_at DESC);
+CREATE INDEX idx_audit_log_actor ON audit_log (actor_id, created_at DESC);
+CREATE INDEX idx_audit_log_created_at ON audit_log (created_at DESC);
@@ -0,0 +1,5 @@
+ALTER TABLE products
+    ADD COLUMN publish_at TIMESTAMPTZ NULL,
+    ADD COLUMN unpublish_at TIMESTAMPTZ NULL,
+    ADD CONSTRAINT products_publish_window_check
+        CHECK (publish_at IS NULL OR unpublish_at IS NULL OR publish_at < unpublish_at);
@@ -0,0 +1,24 @@
+ALTER TABLE products
+    ADD COLUMN sale_price DOUBLE PRECISION NULL,
+    ADD COLUMN sale_starts_at TIMESTAMPTZ NULL,
+    ADD COLUMN sale_ends_at TIMESTAMPTZ NULL,
+    ADD CONSTRAINT products_sale_price_check
+        CHECK (sale_price IS NULL OR (sale_price >= 0 AND sale_price < price)),
+    ADD CONSTRAINT products_sale_window_check
+        CHECK (sale_starts_at IS NULL OR sale_ends_at IS NULL OR sale_starts_at < sale_ends_at);
+
+-- The price a product sells for right now: the sale price while its schedule
+-- runs, the regular price otherwise. Every query pricing a product calls it.
+CREATE FUNCTION product_effective_price(product products) RETURNS DOUBLE PRECISION
+    LANGUAGE sql
+    STABLE
+AS
+$$
+SELECT CASE
+           WHEN product.sale_price IS NOT NULL
+               AND (product.sale_starts_at IS NULL OR product.sale_starts_at <= now())
+               AND (product.sale_ends_at IS NULL OR product.sale_ends_at > now())
+               THEN product.sale_price
+           ELSE product.price
+           END;
+$$;
@@ -0,0 +1,33 @@
+-- the effective price follows the column type, it is recreated below
+DROP FUNCTION product_effective_price(products);
+
+ALTER TABLE products
+    ALTER COLUMN price TYPE NUMERIC(12, 2) USING round(price::numeric, 2),
+    ALTER COLUMN sale_price TYPE NUMERIC(12, 2) USING round(sale_price::numeric, 2);
+
+CREATE FUNCTION product_effective_price(product products) RETURNS NUMERIC(12, 2)
+    LANGUAGE sql
+    STABLE
+AS
+$$
+SELECT CASE
+           WHEN product.sale_price IS NOT NULL
+               AND (product.sale_starts_at IS NULL OR product.sale_starts_at <= now())
+               AND (product.sale_ends_at IS NULL OR product.sale_ends_at > now())
+               THEN product.sale_price
+           ELSE product.price
+           END;
+$$;
+
+ALTER TABLE cart_items
+    ALTER COLUMN price TYPE NUMERIC(12, 2) USING round(price::numeric, 2);
+
+ALTER TABLE cart
+    ALTER COLUMN total TYPE NUMERIC(12, 2) USING round(total::numeric, 2);
+
+-- totals were never kept up to date, start from the current lines
+UPDATE cart
+SET total = COALESCE(
+    (SELECT SUM(cart_items.price * cart_items.quantity) FROM cart_items WHERE cart_items.cart_id = cart.id),
+    0
+);
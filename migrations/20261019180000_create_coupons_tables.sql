@@ -0,0 +1,113 @@
+CREATE TYPE coupon_kind AS ENUM ('percentage', 'fixed_amount', 'free_item');
+
+CREATE TABLE coupons
+(
+    id                   BIGSERIAL PRIMARY KEY,
+    code                 VARCHAR        NOT NULL UNIQUE,
+    kind                 coupon_kind    NOT NULL,
+    value                NUMERIC(12, 2) NULL,
+    free_product_id      BIGINT         NULL,
+    free_quantity        INTEGER        NOT NULL DEFAULT 1,
+    min_cart_value       NUMERIC(12, 2) NULL,
+    usage_limit          INTEGER        NULL,
+    usage_limit_per_user INTEGER        NULL,
+    starts_at            TIMESTAMPTZ    NULL,
+    ends_at              TIMESTAMPTZ    NULL,
+    is_active            BOOLEAN        NOT NULL DEFAULT TRUE,
+    created_at           TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    updated_at           TIMESTAMPTZ    NOT NULL DEFAULT now(),
+
+    CONSTRAINT fk_coupons_free_product
+        FOREIGN KEY (free_product_id)
+            REFERENCES products (id)
+            ON DELETE SET NULL,
+
+    CONSTRAINT coupons_value_check CHECK (
+        (kind = 'percentage' AND value > 0 AND value <= 100)
+            OR (kind = 'fixed_amount' AND value > 0)
+            OR (kind = 'free_item' AND value IS NULL)
+        ),
+    CONSTRAINT coupons_free_quantity_check CHECK (free_quantity >= 1),
+    CONSTRAINT coupons_usage_limits_check CHECK (
+        (usage_limit IS NULL OR usage_limit >= 1)
+            AND (usage_limit_per_user IS NULL OR usage_limit_per_user >= 1)
+        ),
+    CONSTRAINT coupons_window_check CHECK (starts_at IS NULL OR ends_at IS NULL OR starts_at < ends_at)
+);
+
+CREATE TABLE coupon_has_products
+(
+    id         BIGSERIAL PRIMARY KEY,
+    coupon_id  BIGINT NOT NULL,
+    product_id BIGINT NOT NULL,
+
+    CONSTRAINT fk_coupon_has_products_coupon_id
+        FOREIGN KEY (coupon_id)
+            REFERENCES coupons (id)
+            ON DELETE CASCADE,
+
+    CONSTRAINT fk_coupon_has_products_product_id
+        FOREIGN KEY (product_id)
+            REFERENCES products (id)
+            ON DELETE CASCADE
+);
+
+CREATE TABLE coupon_has_categories
+(
+    id          BIGSERIAL PRIMARY KEY,
+    coupon_id   BIGINT NOT NULL,
+    category_id BIGINT NOT NULL,
+
+    CONSTRAINT fk_coupon_has_categories_coupon_id
+        FOREIGN KEY (coupon_id)
+            REFERENCES coupons (id)
+            ON DELETE CASCADE,
+
+    CONSTRAINT fk_coupon_has_categories_category_id
+        FOREIGN KEY (category_id)
+            REFERENCES categories (id)
+            ON DELETE CASCADE
+);
+
+ALTER TABLE cart
+    ADD COLUMN coupon_id BIGINT NULL,
+    ADD CONSTRAINT fk_cart_coupon
+        FOREIGN KEY (coupon_id)
+            REFERENCES coupons (id)
+            ON DELETE SET NULL;
+
+-- one row per cart holding a coupon, usage limits count these. The row
+-- outlives its cart, a cart that is gone has spent the coupon.
+CREATE TABLE coupon_redemptions
+(
+    id           BIGSERIAL PRIMARY KEY,
+    coupon_id    BIGINT      NOT NULL,
+    cart_id      BIGINT      NULL UNIQUE,
+    user_id      BIGINT      NULL,
+    user_hash_id BIGINT      NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT fk_coupon_redemptions_coupon
+        FOREIGN KEY (coupon_id)
+            REFERENCES coupons (id)
+            ON DELETE CASCADE,
+
+    CONSTRAINT fk_coupon_redemptions_cart
+        FOREIGN KEY (cart_id)
+            REFERENCES cart (id)
+            ON DELETE SET NULL,
+
+    CONSTRAINT fk_coupon_redemptions_user
+        FOREIGN KEY (user_id)
+            REFERENCES users (id)
+            ON DELETE CASCADE,
+
+    CONSTRAINT fk_coupon_redemptions_user_hash
+        FOREIGN KEY (user_hash_id)
+            REFERENCES user_hashes (id)
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_coupon_has_products_coupon ON coupon_has_products (coupon_id);
+CREATE INDEX idx_coupon_has_categories_coupon ON coupon_has_categories (coupon_id);
+CREATE INDEX idx_coupon_redemptions_coupon ON coupon_redemptions (coupon_id);
@@ -0,0 +1,18 @@
+CREATE TABLE promotions
+(
+    id         BIGSERIAL PRIMARY KEY,
+    name       VARCHAR     NOT NULL,
+    rule       JSONB       NOT NULL,
+    priority   INTEGER     NOT NULL DEFAULT 0,
+    stackable  BOOLEAN     NOT NULL DEFAULT TRUE,
+    starts_at  TIMESTAMPTZ NULL,
+    ends_at    TIMESTAMPTZ NULL,
+    is_active  BOOLEAN     NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT promotions_rule_check CHECK (jsonb_typeof(rule) = 'object' AND rule ? 'type'),
+    CONSTRAINT promotions_window_check CHECK (starts_at IS NULL OR ends_at IS NULL OR starts_at < ends_at)
+);
+
+CREATE INDEX idx_promotions_running ON promotions (priority DESC, id) WHERE is_active;
@@ -0,0 +1,21 @@
+ALTER TABLE products
+    ADD COLUMN tax_class VARCHAR NOT NULL DEFAULT 'standard';
+
+CREATE TABLE tax_rates
+(
+    id         BIGSERIAL PRIMARY KEY,
+    name       VARCHAR       NOT NULL,
+    tax_class  VARCHAR       NOT NULL DEFAULT 'standard',
+    country    CHAR(2)       NOT NULL,
+    region     VARCHAR       NULL,
+    postcode   VARCHAR       NULL,
+    rate       NUMERIC(7, 4) NOT NULL,
+    priority   INTEGER       NOT NULL DEFAULT 0,
+    is_active  BOOLEAN       NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ   NOT NULL DEFAULT now(),
+
+    CONSTRAINT tax_rates_rate_check CHECK (rate >= 0 AND rate <= 100)
+);
+
+CREATE INDEX idx_tax_rates_lookup ON tax_rates (tax_class, country) WHERE is_active;
@@ -0,0 +1,32 @@
+CREATE TABLE user_addresses
+(
+    id                  BIGSERIAL PRIMARY KEY,
+    user_id             BIGINT      NOT NULL,
+    label               VARCHAR     NULL,
+    full_name           VARCHAR     NOT NULL,
+    company             VARCHAR     NULL,
+    line1               VARCHAR     NOT NULL,
+    line2               VARCHAR     NULL,
+    city                VARCHAR     NOT NULL,
+    region              VARCHAR     NULL,
+    postcode            VARCHAR     NULL,
+    country             CHAR(2)     NOT NULL,
+    phone               VARCHAR     NULL,
+    is_default_shipping BOOLEAN     NOT NULL DEFAULT FALSE,
+    is_default_billing  BOOLEAN     NOT NULL DEFAULT FALSE,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT fk_user_addresses_user_id
+        FOREIGN KEY (user_id)
+            REFERENCES users (id)
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_user_addresses_user_id ON user_addresses (user_id);
+
+-- a user has at most one default address of each kind
+CREATE UNIQUE INDEX idx_user_addresses_default_shipping
+    ON user_addresses (user_id) WHERE is_default_shipping;
+CREATE UNIQUE INDEX idx_user_addresses_default_billing
+    ON user_addresses (user_id) WHERE is_default_billing;
@@ -0,0 +1,47 @@
+ALTER TABLE products
+    ADD COLUMN weight_grams INTEGER NULL,
+    ADD COLUMN length_mm    INTEGER NULL,
+    ADD COLUMN width_mm     INTEGER NULL,
+    ADD COLUMN height_mm    INTEGER NULL,
+    ADD CONSTRAINT products_weight_check CHECK (weight_grams IS NULL OR weight_grams >= 0),
+    ADD CONSTRAINT products_dimensions_check CHECK (
+        (length_mm IS NULL OR length_mm > 0)
+            AND (width_mm IS NULL OR width_mm > 0)
+            AND (height_mm IS NULL OR height_mm > 0)
+        );
+
+CREATE TABLE shipping_zones
+(
+    id         BIGSERIAL PRIMARY KEY,
+    name       VARCHAR     NOT NULL,
+    countries  TEXT[]      NOT NULL,
+    postcodes  TEXT[]      NOT NULL DEFAULT '{}',
+    priority   INTEGER     NOT NULL DEFAULT 0,
+    is_active  BOOLEAN     NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT shipping_zones_countries_check CHECK (cardinality(countries) > 0)
+);
+
+CREATE INDEX idx_shipping_zones_countries ON shipping_zones USING GIN (countries) WHERE is_active;
+
+CREATE TABLE shipping_methods
+(
+    id         BIGSERIAL PRIMARY KEY,
+    zone_id    BIGINT      NOT NULL,
+    name       VARCHAR     NOT NULL,
+    rule       JSONB       NOT NULL,
+    is_active  BOOLEAN     NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT shipping_methods_rule_check CHECK (jsonb_typeof(rule) = 'object' AND rule ? 'type'),
+
+    CONSTRAINT fk_shipping_methods_zone_id
+        FOREIGN KEY (zone_id)
+            REFERENCES shipping_zones (id)
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_shipping_methods_zone_id ON shipping_methods (zone_id);
@@ -0,0 +1,43 @@
+CREATE TABLE wishlists
+(
+    id          BIGSERIAL PRIMARY KEY,
+    user_id     BIGINT      NOT NULL,
+    name        VARCHAR     NOT NULL,
+    share_token VARCHAR     NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT fk_wishlists_user_id
+        FOREIGN KEY (user_id)
+            REFERENCES users (id)
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_wishlists_user_id ON wishlists (user_id);
+CREATE UNIQUE INDEX idx_wishlists_user_id_name ON wishlists (user_id, lower(name));
+CREATE UNIQUE INDEX idx_wishlists_share_token ON wishlists (share_token) WHERE share_token IS NOT NULL;
+
+CREATE TABLE wishlist_items
+(
+    id          BIGSERIAL PRIMARY KEY,
+    wishlist_id BIGINT        NOT NULL,
+    product_id  BIGINT        NOT NULL,
+    -- what the product cost when it was added
+    added_price NUMERIC(12,2) NOT NULL,
+    created_at  TIMESTAMPTZ   NOT NULL DEFAULT now(),
+
+    CONSTRAINT fk_wishlist_items_wishlist_id
+        FOREIGN KEY (wishlist_id)
+            REFERENCES wishlists (id)
+            ON DELETE CASCADE,
+
+    CONSTRAINT fk_wishlist_items_product_id
+        FOREIGN KEY (product_id)
+            REFERENCES products (id)
+            ON DELETE CASCADE,
+
+    CONSTRAINT uq_wishlist_items_wishlist_id_product_id UNIQUE (wishlist_id, product_id)
+);
+
+-- who to tell when a product changes
+CREATE INDEX idx_wishlist_items_product_id ON wishlist_items (product_id);
@@ -0,0 +1,53 @@
+CREATE TABLE stock_movements
+(
+    id         BIGSERIAL PRIMARY KEY,
+    product_id BIGINT      NOT NULL,
+    kind       VARCHAR     NOT NULL,
+    -- signed change, receipts and returns add stock, sales take it away
+    quantity   INTEGER     NOT NULL,
+    -- stock of the product right after the movement
+    balance    INTEGER     NOT NULL,
+    reason     VARCHAR     NULL,
+    actor_id   BIGINT      NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT chk_stock_movements_kind
+        CHECK (kind IN ('receipt', 'sale', 'adjustment', 'return')),
+
+    CONSTRAINT chk_stock_movements_quantity CHECK (quantity <> 0),
+
+    CONSTRAINT fk_stock_movements_product_id
+        FOREIGN KEY (product_id)
+            REFERENCES products (id)
+            ON DELETE CASCADE,
+
+    -- the ledger outlives the admin who posted to it
+    CONSTRAINT fk_stock_movements_actor
+        FOREIGN KEY (actor_id)
+            REFERENCES users (id)
+            ON DELETE SET NULL
+);
+
+CREATE INDEX idx_stock_movements_product ON stock_movements (product_id, created_at DESC, id DESC);
+CREATE INDEX idx_stock_movements_created_at ON stock_movements (created_at DESC);
+
+-- movements are never edited, a correction is a new movement
+CREATE FUNCTION reject_stock_movement_update() RETURNS trigger AS
+$$
+BEGIN
+    RAISE EXCEPTION 'stock movements are append only';
+END;
+$$ LANGUAGE plpgsql;
+
+-- actor_id is left out so deleting an admin can still clear it
+CREATE TRIGGER trg_stock_movements_append_only
+    BEFORE UPDATE OF product_id, kind, quantity, balance, reason, created_at
+    ON stock_movements
+    FOR EACH ROW
+EXECUTE FUNCTION reject_stock_movement_update();
+
+-- opening balance, so the ledger sums up to the current stock
+INSERT INTO stock_movements (product_id, kind, quantity, balance, reason)
+SELECT id, 'adjustment', quantity, quantity, 'opening balance'
+FROM products
+WHERE quantity <> 0;
@@ -0,0 +1,78 @@
+CREATE TABLE warehouses
+(
+    id         BIGSERIAL PRIMARY KEY,
+    name       VARCHAR     NOT NULL,
+    code       VARCHAR     NOT NULL UNIQUE,
+    -- allocation order, lower goes first
+    priority   INTEGER     NOT NULL DEFAULT 0,
+    -- stock of an inactive warehouse is kept but not sold
+    is_active  BOOLEAN     NOT NULL DEFAULT true,
+    -- receives stock set on the product itself
+    is_default BOOLEAN     NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT chk_warehouses_default_active CHECK (is_active OR NOT is_default)
+);
+
+CREATE UNIQUE INDEX uq_warehouses_default ON warehouses (is_default) WHERE is_default;
+
+INSERT INTO warehouses (name, code, is_default)
+VALUES ('Main warehouse', 'MAIN', true);
+
+CREATE TABLE warehouse_stock
+(
+    warehouse_id BIGINT      NOT NULL,
+    product_id   BIGINT      NOT NULL,
+    quantity     INTEGER     NOT NULL DEFAULT 0,
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    PRIMARY KEY (warehouse_id, product_id),
+
+    CONSTRAINT chk_warehouse_stock_quantity CHECK (quantity >= 0),
+
+    CONSTRAINT fk_warehouse_stock_warehouse_id
+        FOREIGN KEY (warehouse_id)
+            REFERENCES warehouses (id)
+            ON DELETE RESTRICT,
+
+    CONSTRAINT fk_warehouse_stock_product_id
+        FOREIGN KEY (product_id)
+            REFERENCES products (id)
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_warehouse_stock_product ON warehouse_stock (product_id);
+
+-- everything in stock so far sits in the default warehouse
+INSERT INTO warehouse_stock (warehouse_id, product_id, quantity)
+SELECT warehouses.id, products.id, products.quantity
+FROM products
+CROSS JOIN warehouses
+WHERE warehouses.is_default AND products.quantity > 0;
+
+-- every movement now happens at a warehouse, transfers move stock between them
+ALTER TABLE stock_movements ADD COLUMN warehouse_id BIGINT NULL;
+
+UPDATE stock_movements
+SET warehouse_id = (SELECT id FROM warehouses WHERE is_default);
+
+ALTER TABLE stock_movements
+    ALTER COLUMN warehouse_id SET NOT NULL,
+    ADD CONSTRAINT fk_stock_movements_warehouse_id
+        FOREIGN KEY (warehouse_id)
+            REFERENCES warehouses (id)
+            ON DELETE RESTRICT,
+    DROP CONSTRAINT chk_stock_movements_kind,
+    ADD CONSTRAINT chk_stock_movements_kind
+        CHECK (kind IN ('receipt', 'sale', 'adjustment', 'return', 'transfer'));
+
+CREATE INDEX idx_stock_movements_warehouse ON stock_movements (warehouse_id);
+
+DROP TRIGGER trg_stock_movements_append_only ON stock_movements;
+
+CREATE TRIGGER trg_stock_movements_append_only
+    BEFORE UPDATE OF product_id, warehouse_id, kind, quantity, balance, reason, created_at
+    ON stock_movements
+    FOR EACH ROW
+EXECUTE FUNCTION reject_stock_movement_update();
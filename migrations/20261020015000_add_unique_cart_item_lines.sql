@@ -0,0 +1,19 @@
+-- one line per product and cart, adding the product again raises its quantity
+UPDATE cart_items
+SET quantity = lines.quantity
+FROM (
+    SELECT MIN(id) AS id, SUM(quantity)::INTEGER AS quantity
+    FROM cart_items
+    GROUP BY cart_id, product_id
+    HAVING COUNT(*) > 1
+) AS lines
+WHERE cart_items.id = lines.id;
+
+DELETE FROM cart_items
+USING cart_items AS kept
+WHERE cart_items.cart_id = kept.cart_id
+  AND cart_items.product_id = kept.product_id
+  AND cart_items.id > kept.id;
+
+ALTER TABLE cart_items
+    ADD CONSTRAINT uq_cart_items_cart_id_product_id UNIQUE (cart_id, product_id);
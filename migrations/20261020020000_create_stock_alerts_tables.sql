@@ -0,0 +1,31 @@
+-- stock at or below it shows on the low stock report, the configured default when empty
+ALTER TABLE products
+    ADD COLUMN low_stock_threshold INTEGER NULL,
+    ADD CONSTRAINT products_low_stock_threshold_check
+        CHECK (low_stock_threshold IS NULL OR low_stock_threshold >= 0);
+
+CREATE TABLE stock_subscriptions
+(
+    id         BIGSERIAL PRIMARY KEY,
+    product_id BIGINT      NOT NULL,
+    -- empty for guests, who only leave an email
+    user_id    BIGINT      NULL,
+    email      VARCHAR     NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT fk_stock_subscriptions_product_id
+        FOREIGN KEY (product_id)
+            REFERENCES products (id)
+            ON DELETE CASCADE,
+
+    CONSTRAINT fk_stock_subscriptions_user_id
+        FOREIGN KEY (user_id)
+            REFERENCES users (id)
+            ON DELETE CASCADE
+);
+
+-- one subscription per address and product, whoever made it
+CREATE UNIQUE INDEX uq_stock_subscriptions_product_id_email
+    ON stock_subscriptions (product_id, lower(email));
+
+CREATE INDEX idx_stock_subscriptions_user_id ON stock_subscriptions (user_id);
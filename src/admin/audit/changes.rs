@@ -0,0 +1,48 @@
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+/**
+ * Bookkeeping columns that change on every write and say nothing about what
+ * the admin did.
+ */
+const IGNORED_FIELDS: [&str; 1] = ["updated_at"];
+
+/**
+ * Field level diff of two json snapshots of the same row, as
+ * `{ "<field>": { "before": .., "after": .. } }`. A missing snapshot (create or
+ * delete) reads as `null` on that side, unchanged fields are left out.
+ */
+pub fn diff(before: Option<Value>, after: Option<Value>) -> Value {
+    let before = into_object(before);
+    let after = into_object(after);
+
+    let fields: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+
+    let mut changes = Map::new();
+
+    for field in fields {
+        if IGNORED_FIELDS.contains(&field.as_str()) {
+            continue;
+        }
+
+        let old = before.get(field).unwrap_or(&Value::Null);
+        let new = after.get(field).unwrap_or(&Value::Null);
+
+        if old != new {
+            let mut change = Map::new();
+            change.insert("before".to_string(), old.clone());
+            change.insert("after".to_string(), new.clone());
+
+            changes.insert(field.clone(), Value::Object(change));
+        }
+    }
+
+    Value::Object(changes)
+}
+
+fn into_object(snapshot: Option<Value>) -> Map<String, Value> {
+    match snapshot {
+        Some(Value::Object(map)) => map,
+        _ => Map::new(),
+    }
+}
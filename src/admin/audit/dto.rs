@@ -0,0 +1,264 @@
+use crate::admin::audit::changes::diff;
+use crate::admin::audit::filters::AuditLogFilters;
+use crate::admin::audit::model::AuditLogModel;
+use crate::errors::error::AppError;
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::str::FromStr;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEntity {
+    Product,
+    Category,
+    ProductImage,
+    ProductVideo,
+    Review,
+    Coupon,
+    Promotion,
+    TaxRate,
+    ShippingZone,
+    ShippingMethod,
+    Warehouse,
+}
+
+impl AuditEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditEntity::Product => "product",
+            AuditEntity::Category => "category",
+            AuditEntity::ProductImage => "product_image",
+            AuditEntity::ProductVideo => "product_video",
+            AuditEntity::Review => "review",
+            AuditEntity::Coupon => "coupon",
+            AuditEntity::Promotion => "promotion",
+            AuditEntity::TaxRate => "tax_rate",
+            AuditEntity::ShippingZone => "shipping_zone",
+            AuditEntity::ShippingMethod => "shipping_method",
+            AuditEntity::Warehouse => "warehouse",
+        }
+    }
+}
+
+impl FromStr for AuditEntity {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "product" => Ok(AuditEntity::Product),
+            "category" => Ok(AuditEntity::Category),
+            "product_image" => Ok(AuditEntity::ProductImage),
+            "product_video" => Ok(AuditEntity::ProductVideo),
+            "review" => Ok(AuditEntity::Review),
+            "coupon" => Ok(AuditEntity::Coupon),
+            "promotion" => Ok(AuditEntity::Promotion),
+            "tax_rate" => Ok(AuditEntity::TaxRate),
+            "shipping_zone" => Ok(AuditEntity::ShippingZone),
+            "shipping_method" => Ok(AuditEntity::ShippingMethod),
+            "warehouse" => Ok(AuditEntity::Warehouse),
+            _ => Err(AppError::field(
+                "entity",
+                "invalid_choice",
+                "must be one of product, category, product_image, product_video, review, coupon, promotion, tax_rate, shipping_zone, shipping_method, warehouse",
+            )),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Restore,
+    Purge,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Create => "create",
+            AuditAction::Update => "update",
+            AuditAction::Delete => "delete",
+            AuditAction::Restore => "restore",
+            AuditAction::Purge => "purge",
+        }
+    }
+}
+
+/**
+ * One admin mutation. `changes` holds the field level diff between the row
+ * snapshots taken before and after it.
+ */
+pub struct CreateAuditEntryCommand {
+    pub actor_id: i64,
+    pub entity: AuditEntity,
+    pub entity_id: i64,
+    pub action: AuditAction,
+    pub changes: Value,
+}
+
+impl CreateAuditEntryCommand {
+    pub fn new<T: Serialize>(
+        actor_id: i64,
+        entity: AuditEntity,
+        entity_id: i64,
+        action: AuditAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Self {
+        let snapshot = |row: &T| serde_json::to_value(row).ok();
+
+        Self {
+            actor_id,
+            entity,
+            entity_id,
+            action,
+            changes: diff(before.and_then(snapshot), after.and_then(snapshot)),
+        }
+    }
+
+    pub fn created<T: Serialize>(
+        actor_id: i64,
+        entity: AuditEntity,
+        entity_id: i64,
+        after: &T,
+    ) -> Self {
+        Self::new(
+            actor_id,
+            entity,
+            entity_id,
+            AuditAction::Create,
+            None,
+            Some(after),
+        )
+    }
+
+    pub fn updated<T: Serialize>(
+        actor_id: i64,
+        entity: AuditEntity,
+        entity_id: i64,
+        before: &T,
+        after: &T,
+    ) -> Self {
+        Self::new(
+            actor_id,
+            entity,
+            entity_id,
+            AuditAction::Update,
+            Some(before),
+            Some(after),
+        )
+    }
+
+    pub fn deleted<T: Serialize>(
+        actor_id: i64,
+        entity: AuditEntity,
+        entity_id: i64,
+        before: &T,
+    ) -> Self {
+        Self::new(
+            actor_id,
+            entity,
+            entity_id,
+            AuditAction::Delete,
+            Some(before),
+            None,
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicAuditEntry {
+    pub id: i64,
+    pub actor_id: Option<i64>,
+    pub entity: String,
+    pub entity_id: i64,
+    pub action: String,
+    /// `{ "<field>": { "before": .., "after": .. } }` for every changed field.
+    #[schema(value_type = Object)]
+    pub changes: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPublicAuditEntry {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl From<AuditLogModel> for AdminPublicAuditEntry {
+    fn from(entry: AuditLogModel) -> Self {
+        Self {
+            id: entry.id,
+            actor_id: entry.actor_id,
+            entity: entry.entity,
+            entity_id: entry.entity_id,
+            action: entry.action,
+            changes: entry.changes,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexAuditLogDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    /// `product`, `category`, `product_image`, `product_video` or `review`.
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub entity: Option<String>,
+
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
+    pub entity_id: Option<i64>,
+
+    /// Id of the admin who made the change.
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
+    pub actor_id: Option<i64>,
+
+    /// Inclusive lower bound, RFC 3339.
+    pub from: Option<DateTime<Utc>>,
+
+    /// Exclusive upper bound, RFC 3339.
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<IndexAuditLogDTO> for AuditLogFilters {
+    type Error = AppError;
+
+    fn try_from(dto: IndexAuditLogDTO) -> Result<Self, Self::Error> {
+        let entity = match dto.entity {
+            Some(entity) => Some(AuditEntity::from_str(&entity)?),
+            None => None,
+        };
+
+        if let (Some(from), Some(to)) = (dto.from, dto.to)
+            && from >= to
+        {
+            return Err(AppError::field("to", "invalid_range", "must be after from"));
+        }
+
+        Ok(Self {
+            entity,
+            entity_id: dto.entity_id,
+            actor_id: dto.actor_id,
+            from: dto.from,
+            to: dto.to,
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use crate::admin::audit::dto::AuditEntity;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditLogFilters {
+    pub entity: Option<AuditEntity>,
+    pub entity_id: Option<i64>,
+    pub actor_id: Option<i64>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,45 @@
+use crate::admin::audit::dto::{AdminPublicAuditEntry, IndexAuditLogDTO};
+use crate::admin::audit::filters::AuditLogFilters;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::pagination::Paginate;
+use actix_web::{HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin/audit/list",
+    tag = "admin-audit",
+    params(IndexAuditLogDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicAuditEntry>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["audit:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    state: web::Data<AppState>,
+    body: web::Query<IndexAuditLogDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = AuditLogFilters::try_from(body.clone().into_inner())?;
+
+    let entries = state
+        .admin_audit_service
+        .get_all_paginated_public(&pagination, &filters)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        entries.data,
+        pagination,
+    )))
+}
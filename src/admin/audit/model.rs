@@ -0,0 +1,21 @@
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct AuditLogModel {
+    pub id: i64,
+    pub actor_id: Option<i64>,
+    pub entity: String,
+    pub entity_id: i64,
+    pub action: String,
+    pub changes: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl HasId for AuditLogModel {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
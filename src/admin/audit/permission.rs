@@ -0,0 +1,17 @@
+use crate::auth::traits::Scope;
+
+pub enum AuditScope {
+    List,
+}
+
+impl Scope for AuditScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AuditScope::List => "audit:list",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![AuditScope::List]
+    }
+}
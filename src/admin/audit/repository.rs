@@ -0,0 +1,127 @@
+use crate::admin::audit::dto::CreateAuditEntryCommand;
+use crate::admin::audit::filters::AuditLogFilters;
+use crate::admin::audit::model::AuditLogModel;
+use crate::errors::error::AppError;
+use crate::utils::pagination::Paginate;
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+pub struct AdminAuditRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AdminAuditRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminAuditRepository {
+    /**
+     * Newest first, every filter is optional.
+     */
+    #[instrument(skip_all)]
+    pub async fn index_paginated(
+        &self,
+        pagination: &Paginate,
+        filters: &AuditLogFilters,
+    ) -> Result<Vec<AuditLogModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT
+                id,
+                actor_id,
+                entity,
+                entity_id,
+                action,
+                changes,
+                created_at
+            FROM audit_log
+        "#,
+        );
+
+        let mut has_where = false;
+
+        let mut push_condition = |qb: &mut QueryBuilder<Postgres>, condition: &str| {
+            if has_where {
+                qb.push(" AND ");
+            } else {
+                qb.push(" WHERE ");
+                has_where = true;
+            }
+
+            qb.push(condition);
+        };
+
+        if let Some(entity) = filters.entity {
+            push_condition(&mut qb, " entity = ");
+            qb.push_bind(entity.as_str());
+        }
+
+        if let Some(entity_id) = filters.entity_id {
+            push_condition(&mut qb, " entity_id = ");
+            qb.push_bind(entity_id);
+        }
+
+        if let Some(actor_id) = filters.actor_id {
+            push_condition(&mut qb, " actor_id = ");
+            qb.push_bind(actor_id);
+        }
+
+        if let Some(from) = filters.from {
+            push_condition(&mut qb, " created_at >= ");
+            qb.push_bind(from);
+        }
+
+        if let Some(to) = filters.to {
+            push_condition(&mut qb, " created_at < ");
+            qb.push_bind(to);
+        }
+
+        qb.push(" ORDER BY created_at DESC, id DESC ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        let query = qb.build_query_as::<AuditLogModel>();
+
+        query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &CreateAuditEntryCommand,
+    ) -> Result<(), AppError> {
+        sqlx::query! {
+            r#"
+        INSERT INTO audit_log (actor_id, entity, entity_id, action, changes)
+        VALUES ($1, $2, $3, $4, $5);
+        "#,
+            cmd.actor_id,
+            cmd.entity.as_str(),
+            cmd.entity_id,
+            cmd.action.as_str(),
+            cmd.changes
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use super::handler;
+use crate::admin::audit::permission::AuditScope;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{get, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/audit").service(
+            resource("/list")
+                .wrap(AuthMiddleware::new(Some(Arc::new(AuditScope::List))))
+                .route(get().to(handler::index)),
+        ),
+    );
+}
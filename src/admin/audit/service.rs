@@ -0,0 +1,39 @@
+use crate::admin::audit::dto::AdminPublicAuditEntry;
+use crate::admin::audit::filters::AuditLogFilters;
+use crate::admin::audit::model::AuditLogModel;
+use crate::admin::audit::repository::AdminAuditRepository;
+use crate::admin::audit::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct AdminAuditService {
+    repository: AdminAuditRepository,
+}
+
+impl AdminAuditService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: AdminAuditRepository::new(pool),
+        }
+    }
+
+    pub async fn get_all_paginated(
+        &self,
+        pagination: &Paginate,
+        filters: &AuditLogFilters,
+    ) -> Result<PaginatedDataCollection<AuditLogModel>, AppError> {
+        let data = self.repository.index_paginated(pagination, filters).await?;
+        Ok(PaginatedDataCollection::new(data, pagination.clone()))
+    }
+
+    pub async fn get_all_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &AuditLogFilters,
+    ) -> Result<PaginatedDataCollection<AdminPublicAuditEntry>, AppError> {
+        let data = self.get_all_paginated(pagination, filters).await?;
+        Ok(data.into_public())
+    }
+}
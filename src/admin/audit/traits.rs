@@ -0,0 +1,21 @@
+use crate::admin::audit::dto::AdminPublicAuditEntry;
+use crate::admin::audit::model::AuditLogModel;
+use crate::utils::pagination::PaginatedDataCollection;
+
+pub trait IntoPublic<T> {
+    fn into_public(self) -> T;
+}
+
+impl IntoPublic<PaginatedDataCollection<AdminPublicAuditEntry>>
+    for PaginatedDataCollection<AuditLogModel>
+{
+    fn into_public(self) -> PaginatedDataCollection<AdminPublicAuditEntry> {
+        PaginatedDataCollection::new(
+            self.data
+                .into_iter()
+                .map(AdminPublicAuditEntry::from)
+                .collect(),
+            self.pagination,
+        )
+    }
+}
@@ -2,15 +2,19 @@ use crate::admin::categories::filters::CategoryFilters;
 use crate::admin::categories::model::AdminCategoryModel;
 use crate::errors::error::AppError;
 use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct AdminPublicCategory {
     pub id: i64,
     pub name: String,
     pub slug: String,
     pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl HasId for AdminPublicCategory {
@@ -26,19 +30,25 @@ impl From<AdminCategoryModel> for AdminPublicCategory {
             name: category.name,
             slug: category.slug,
             is_active: category.is_active,
+            updated_at: category.updated_at,
+            deleted_at: category.deleted_at,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Validate, Clone)]
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct IndexCategoryDTO {
     #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
     pub page: Option<i64>,
 
     #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
     pub limit: Option<i64>,
 
     #[validate(length(min = 1))]
+    #[param(min_length = 1)]
     pub search: Option<String>,
 
     pub is_active: Option<bool>,
@@ -50,16 +60,19 @@ impl TryFrom<IndexCategoryDTO> for CategoryFilters {
     fn try_from(dto: IndexCategoryDTO) -> Result<Self, Self::Error> {
         Ok(Self {
             is_active: dto.is_active,
+            trashed: false,
         })
     }
 }
 
-#[derive(Serialize, Deserialize, Validate)]
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateCategoryDTO {
     #[validate(required, length(min = 3))]
+    #[schema(required = true, min_length = 3)]
     pub name: Option<String>,
 
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub slug: Option<String>,
 
     pub is_active: Option<bool>,
@@ -83,12 +96,14 @@ impl TryFrom<CreateCategoryDTO> for CreateCategoryCommand {
     }
 }
 
-#[derive(Serialize, Deserialize, Validate)]
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateCategoryDTO {
     #[validate(required, length(min = 3))]
+    #[schema(required = true, min_length = 3)]
     pub name: Option<String>,
 
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub slug: Option<String>,
 
     pub is_active: Option<bool>,
@@ -1,16 +1,33 @@
 use crate::admin::categories::dto::{
-    CreateCategoryCommand, CreateCategoryDTO, IndexCategoryDTO, UpdateCategoryCommand,
-    UpdateCategoryDTO,
+    AdminPublicCategory, CreateCategoryCommand, CreateCategoryDTO, IndexCategoryDTO,
+    UpdateCategoryCommand, UpdateCategoryDTO,
 };
 use crate::admin::categories::filters::CategoryFilters;
 use crate::admin::categories::traits::IntoPublic;
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
 use crate::utils::pagination::Paginate;
-use actix_web::{HttpResponse, Responder, web};
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
 use validator::Validate;
 
+#[utoipa::path(
+    get,
+    path = "/admin/categories/list",
+    tag = "admin-categories",
+    params(IndexCategoryDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicCategory>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["categories:list"])),
+)]
+#[instrument(skip_all)]
 pub async fn index(
     state: web::Data<AppState>,
     body: web::Query<IndexCategoryDTO>,
@@ -32,6 +49,21 @@ pub async fn index(
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/admin/categories/get/{id}",
+    tag = "admin-categories",
+    params(("id" = i64, Path, description = "Category id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicCategory>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["categories:read"])),
+)]
+#[instrument(skip_all)]
 pub async fn show(
     state: web::Data<AppState>,
     id: web::Path<i64>,
@@ -44,38 +76,199 @@ pub async fn show(
     Ok(HttpResponse::Ok().json(SuccessResponse::ok(category)))
 }
 
+#[utoipa::path(
+    post,
+    path = "/admin/categories/create",
+    tag = "admin-categories",
+    request_body = CreateCategoryDTO,
+    responses(
+        (status = 201, description = "Category created", body = SuccessResponse<AdminPublicCategory>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Name or slug already taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["categories:create"])),
+)]
+#[instrument(skip_all)]
 pub async fn create(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Json<CreateCategoryDTO>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     body.validate()?;
 
     let command = CreateCategoryCommand::try_from(body.into_inner())?;
-    let category = state.admin_category_service.create(command).await?;
+    let category = state
+        .admin_category_service
+        .create(command, actor_id)
+        .await?;
 
     Ok(HttpResponse::Created().json(SuccessResponse::ok(category.into_public())))
 }
 
+#[utoipa::path(
+    put,
+    path = "/admin/categories/update/{id}",
+    tag = "admin-categories",
+    params(("id" = i64, Path, description = "Category id")),
+    request_body = UpdateCategoryDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Name or slug already taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["categories:update"])),
+)]
+#[instrument(skip_all)]
 pub async fn update(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Json<UpdateCategoryDTO>,
     id: web::Path<i64>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     body.validate()?;
 
     let command = UpdateCategoryCommand::try_from(body.into_inner())?;
     state
         .admin_category_service
-        .update(command, id.into_inner())
+        .update(command, id.into_inner(), actor_id)
         .await?;
 
     Ok(HttpResponse::NoContent().finish())
 }
 
+#[utoipa::path(
+    delete,
+    path = "/admin/categories/delete/{id}",
+    tag = "admin-categories",
+    params(("id" = i64, Path, description = "Category id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["categories:delete"])),
+)]
+#[instrument(skip_all)]
 pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_category_service
+        .delete(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/categories/trash",
+    tag = "admin-categories",
+    params(IndexCategoryDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicCategory>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["categories:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn trash(
+    state: web::Data<AppState>,
+    body: web::Query<IndexCategoryDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = CategoryFilters::try_from(body.clone().into_inner())?;
+
+    let categories = state
+        .admin_category_service
+        .get_trashed_paginated_public(&pagination, &filters, &body.search)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        categories.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/categories/restore/{id}",
+    tag = "admin-categories",
+    params(("id" = i64, Path, description = "Category id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Slug already taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["categories:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn restore(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_category_service
+        .restore(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/categories/purge/{id}",
+    tag = "admin-categories",
+    params(("id" = i64, Path, description = "Category id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["categories:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn purge(
+    request: HttpRequest,
     state: web::Data<AppState>,
     id: web::Path<i64>,
 ) -> Result<impl Responder, AppError> {
-    state.admin_category_service.delete(id.into_inner()).await?;
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_category_service
+        .purge(id.into_inner(), actor_id)
+        .await?;
     Ok(HttpResponse::NoContent().finish())
 }
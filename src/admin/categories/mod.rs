@@ -1,6 +1,6 @@
 pub mod dto;
 pub mod filters;
-mod handler;
+pub(crate) mod handler;
 mod model;
 pub mod permission;
 pub mod repository;
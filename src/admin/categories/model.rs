@@ -12,6 +12,8 @@ pub struct AdminCategoryModel {
     pub slug: String,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl HasId for AdminCategoryModel {
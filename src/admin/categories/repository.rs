@@ -3,17 +3,28 @@ use crate::admin::categories::filters::CategoryFilters;
 use crate::admin::categories::model::AdminCategoryModel;
 use crate::errors::error::AppError;
 use crate::utils::pagination::Paginate;
-use sqlx::{PgPool, Postgres, QueryBuilder};
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
 
 pub struct AdminCategoryRepository {
     pool: PgPool,
 }
 
-impl AdminCategoryRepository {
-    pub fn new(pool: PgPool) -> Self {
+impl IsRepository for AdminCategoryRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
         Self { pool }
     }
 
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminCategoryRepository {
+    #[instrument(skip_all)]
     pub async fn index(&self) -> Result<Vec<AdminCategoryModel>, AppError> {
         sqlx::query_as! {
             AdminCategoryModel,
@@ -23,8 +34,11 @@ impl AdminCategoryRepository {
             name,
             slug,
             is_active,
-            created_at
-        FROM categories;
+            created_at,
+            updated_at,
+            deleted_at
+        FROM categories
+        WHERE deleted_at IS NULL;
         "#,
         }
         .fetch_all(&self.pool)
@@ -32,6 +46,7 @@ impl AdminCategoryRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn index_paginated(
         &self,
         pagination: &Paginate,
@@ -45,27 +60,29 @@ impl AdminCategoryRepository {
                 name,
                 slug,
                 is_active,
-                created_at
+                created_at,
+                updated_at,
+                deleted_at
             FROM categories
         "#,
         );
 
-        let mut has_where = false;
+        // trash
+        if filters.trashed {
+            qb.push(" WHERE deleted_at IS NOT NULL ");
+        } else {
+            qb.push(" WHERE deleted_at IS NULL ");
+        }
 
         // handle search
         if let Some(search) = search {
-            qb.push(" WHERE name ILIKE ");
+            qb.push(" AND name ILIKE ");
             qb.push_bind(format!("%{}%", search));
         }
 
         // is active
         if let Some(is_active) = filters.is_active {
-            if has_where {
-                qb.push(" AND ");
-            } else {
-                qb.push(" WHERE ");
-                has_where = true;
-            }
+            qb.push(" AND ");
 
             if is_active {
                 qb.push(" is_active IS TRUE ");
@@ -88,6 +105,7 @@ impl AdminCategoryRepository {
             .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn show(&self, id: i64) -> Result<Option<AdminCategoryModel>, AppError> {
         sqlx::query_as! {
             AdminCategoryModel,
@@ -97,9 +115,60 @@ impl AdminCategoryRepository {
             name,
             slug,
             is_active,
-            created_at
+            created_at,
+            updated_at,
+            deleted_at
+        FROM categories
+        WHERE id = $1 AND deleted_at IS NULL;
+        "#,
+            id,
+        }
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn get_by_slugs(
+        &self,
+        slugs: &[String],
+    ) -> Result<Vec<AdminCategoryModel>, AppError> {
+        sqlx::query_as! {
+            AdminCategoryModel,
+            r#"
+        SELECT
+            id,
+            name,
+            slug,
+            is_active,
+            created_at,
+            updated_at,
+            deleted_at
+        FROM categories
+        WHERE slug = ANY($1) AND deleted_at IS NULL;
+        "#,
+            slugs,
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn show_trashed(&self, id: i64) -> Result<Option<AdminCategoryModel>, AppError> {
+        sqlx::query_as! {
+            AdminCategoryModel,
+            r#"
+        SELECT
+            id,
+            name,
+            slug,
+            is_active,
+            created_at,
+            updated_at,
+            deleted_at
         FROM categories
-        WHERE id = $1;
+        WHERE id = $1 AND deleted_at IS NOT NULL;
         "#,
             id,
         }
@@ -108,50 +177,104 @@ impl AdminCategoryRepository {
         .map_err(AppError::Database)
     }
 
-    pub async fn create(&self, cmd: CreateCategoryCommand) -> Result<AdminCategoryModel, AppError> {
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: CreateCategoryCommand,
+    ) -> Result<AdminCategoryModel, AppError> {
         sqlx::query_as! {
             AdminCategoryModel,
             r#"
         INSERT INTO categories (name, slug, is_active)
         VALUES ($1, $2, $3)
-        RETURNING *;
+        RETURNING id, name, slug, is_active, created_at, updated_at, deleted_at;
         "#,
             cmd.name, cmd.slug, cmd.is_active
         }
-        .fetch_one(&self.pool)
+        .fetch_one(executor)
         .await
         .map_err(AppError::Database)
     }
 
-    pub async fn update(&self, cmd: UpdateCategoryCommand, id: i64) -> Result<u64, AppError> {
-        let result = sqlx::query_as! {
+    #[instrument(skip_all)]
+    pub async fn update(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: UpdateCategoryCommand,
+        id: i64,
+    ) -> Result<AdminCategoryModel, AppError> {
+        sqlx::query_as! {
             AdminCategoryModel,
             r#"
         UPDATE categories
         SET (name, slug, is_active) = ($1, $2, $3)
-        WHERE id = $4;
+        WHERE id = $4 AND deleted_at IS NULL
+        RETURNING id, name, slug, is_active, created_at, updated_at, deleted_at;
         "#,
             cmd.name, cmd.slug, cmd.is_active, id
         }
-        .execute(&self.pool)
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)?
+        .ok_or_else(|| AppError::NotFound("Category not found".to_string()))
+    }
+
+    /**
+     * Moves a category to the trash, products stay attached to it.
+     */
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "UPDATE categories SET deleted_at = now() WHERE id = $1 AND deleted_at IS NULL;",
+            id
+        }
+        .execute(executor)
         .await
         .map_err(AppError::Database)?;
 
         Ok(result.rows_affected())
     }
 
-    pub async fn delete(&self, id: i64) -> Result<u64, AppError> {
+    #[instrument(skip_all)]
+    pub async fn restore(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
         let result = sqlx::query! {
-            "DELETE FROM categories WHERE id = $1;",
+            "UPDATE categories SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL;",
             id
         }
-        .execute(&self.pool)
+        .execute(executor)
         .await
         .map_err(AppError::Database)?;
 
         Ok(result.rows_affected())
     }
 
+    #[instrument(skip_all)]
+    pub async fn purge(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM categories WHERE id = $1 AND deleted_at IS NOT NULL;",
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
     pub async fn get_categories_by_product(
         &self,
         product_id: i64,
@@ -159,10 +282,17 @@ impl AdminCategoryRepository {
         sqlx::query_as! {
             AdminCategoryModel,
             r#"
-            SELECT categories.id, categories.name, categories.slug, categories.is_active, categories.created_at
+            SELECT
+                categories.id,
+                categories.name,
+                categories.slug,
+                categories.is_active,
+                categories.created_at,
+                categories.updated_at,
+                categories.deleted_at
             FROM categories
             INNER JOIN product_has_categories ON categories.id = product_has_categories.category_id
-            WHERE product_has_categories.product_id = $1;
+            WHERE product_has_categories.product_id = $1 AND categories.deleted_at IS NULL;
             "#,
             product_id
         }
@@ -171,11 +301,12 @@ impl AdminCategoryRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn check_existence_by_id(&self, id: i64) -> Result<bool, AppError> {
         sqlx::query_scalar! {
             r#"
             SELECT EXISTS (
-                SELECT 1 FROM categories WHERE id = $1
+                SELECT 1 FROM categories WHERE id = $1 AND deleted_at IS NULL
             ) AS "exists!";
             "#,
             id,
@@ -185,11 +316,12 @@ impl AdminCategoryRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn check_existence_by_name(&self, name: &str) -> Result<bool, AppError> {
         sqlx::query_scalar! {
             r#"
             SELECT EXISTS (
-                SELECT 1 FROM categories WHERE name = $1
+                SELECT 1 FROM categories WHERE name = $1 AND deleted_at IS NULL
             ) AS "exists!";
             "#,
             name,
@@ -199,11 +331,12 @@ impl AdminCategoryRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn check_existence_by_slug(&self, slug: &str) -> Result<bool, AppError> {
         sqlx::query_scalar! {
             r#"
             SELECT EXISTS (
-                SELECT 1 FROM categories WHERE slug = $1
+                SELECT 1 FROM categories WHERE slug = $1 AND deleted_at IS NULL
             ) AS "exists!";
             "#,
             slug,
@@ -32,6 +32,21 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
                 resource("/delete/{id}")
                     .wrap(AuthMiddleware::new(Some(Arc::new(CategoryScope::Delete))))
                     .route(delete().to(handler::delete)),
+            )
+            .service(
+                resource("/trash")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(CategoryScope::List))))
+                    .route(get().to(handler::trash)),
+            )
+            .service(
+                resource("/restore/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(CategoryScope::Delete))))
+                    .route(put().to(handler::restore)),
+            )
+            .service(
+                resource("/purge/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(CategoryScope::Delete))))
+                    .route(delete().to(handler::purge)),
             ),
     );
 }
@@ -1,3 +1,5 @@
+use crate::admin::audit::dto::{AuditAction, AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
 use crate::admin::categories::dto::{
     AdminPublicCategory, CreateCategoryCommand, UpdateCategoryCommand,
 };
@@ -7,17 +9,20 @@ use crate::admin::categories::repository::AdminCategoryRepository;
 use crate::admin::categories::traits::IntoPublic;
 use crate::errors::error::AppError;
 use crate::utils::pagination::{DataCollection, Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
 use crate::utils::validation_utils::validate_slug;
 use sqlx::PgPool;
 
 pub struct AdminCategoryService {
     repository: AdminCategoryRepository,
+    audit_repository: AdminAuditRepository,
 }
 
 impl AdminCategoryService {
     pub fn new(pool: PgPool) -> Self {
         Self {
-            repository: AdminCategoryRepository::new(pool),
+            repository: AdminCategoryRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool),
         }
     }
 
@@ -69,7 +74,11 @@ impl AdminCategoryService {
         Ok(category.into_public())
     }
 
-    pub async fn create(&self, cmd: CreateCategoryCommand) -> Result<AdminCategoryModel, AppError> {
+    pub async fn create(
+        &self,
+        cmd: CreateCategoryCommand,
+        actor_id: i64,
+    ) -> Result<AdminCategoryModel, AppError> {
         validate_slug(&cmd.slug)?;
 
         let category_already_exists = self.check_exist_with_same_name(&cmd.name).await?;
@@ -87,11 +96,34 @@ impl AdminCategoryService {
             ));
         }
 
-        self.repository.create(cmd).await
+        let mut tx = self.repository.start_transaction().await?;
+
+        let category = self.repository.create(&mut *tx, cmd).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(
+                    actor_id,
+                    AuditEntity::Category,
+                    category.id,
+                    &category,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(category)
     }
 
-    pub async fn update(&self, cmd: UpdateCategoryCommand, id: i64) -> Result<u64, AppError> {
-        self.get_one(id).await?;
+    pub async fn update(
+        &self,
+        cmd: UpdateCategoryCommand,
+        id: i64,
+        actor_id: i64,
+    ) -> Result<AdminCategoryModel, AppError> {
+        let before = self.get_one(id).await?;
         validate_slug(&cmd.slug)?;
 
         let category_already_exists = self.check_exist_with_same_name(&cmd.name).await?;
@@ -109,12 +141,140 @@ impl AdminCategoryService {
             ));
         }
 
-        self.repository.update(cmd, id).await
+        let mut tx = self.repository.start_transaction().await?;
+
+        let after = self.repository.update(&mut *tx, cmd, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::Category,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(after)
+    }
+
+    pub async fn delete(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let category = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(actor_id, AuditEntity::Category, id, &category),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(deleted)
     }
 
-    pub async fn delete(&self, id: i64) -> Result<u64, AppError> {
-        self.get_one(id).await?;
-        self.repository.delete(id).await
+    pub async fn get_trashed_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &CategoryFilters,
+        search: &Option<String>,
+    ) -> Result<PaginatedDataCollection<AdminPublicCategory>, AppError> {
+        let filters = CategoryFilters {
+            trashed: true,
+            ..filters.clone()
+        };
+
+        self.get_all_paginated_public(pagination, &filters, search)
+            .await
+    }
+
+    pub async fn get_one_trashed(&self, id: i64) -> Result<AdminCategoryModel, AppError> {
+        let category = self.repository.show_trashed(id).await?;
+
+        match category {
+            Some(category) => Ok(category),
+            None => Err(AppError::NotFound("Trashed category not found".to_string())),
+        }
+    }
+
+    /**
+     * Brings a category back from the trash. Fails with a conflict when its
+     * name or slug was taken by another category in the meantime.
+     */
+    pub async fn restore(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let category = self.get_one_trashed(id).await?;
+
+        if self.check_exist_with_same_name(&category.name).await? {
+            return Err(AppError::Conflict(
+                "Category with the same name already exists".to_string(),
+            ));
+        }
+
+        if self.check_exist_with_same_slug(&category.slug).await? {
+            return Err(AppError::Conflict(
+                "Category with the same slug already exists".to_string(),
+            ));
+        }
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let restored = self.repository.restore(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::new(
+                    actor_id,
+                    AuditEntity::Category,
+                    id,
+                    AuditAction::Restore,
+                    Some(&category),
+                    Some(&AdminCategoryModel {
+                        deleted_at: None,
+                        ..category.clone()
+                    }),
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(restored)
+    }
+
+    pub async fn purge(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let category = self.get_one_trashed(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let purged = self.repository.purge(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::new(
+                    actor_id,
+                    AuditEntity::Category,
+                    id,
+                    AuditAction::Purge,
+                    Some(&category),
+                    None,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(purged)
     }
 
     pub async fn check_exist_with_same_name(&self, name: &str) -> Result<bool, AppError> {
@@ -0,0 +1,392 @@
+use crate::admin::coupons::filters::CouponFilters;
+use crate::admin::coupons::model::AdminCouponModel;
+use crate::errors::error::AppError;
+use crate::utils::money::{Money, deserialize_amount, validate_amount};
+use crate::utils::traits::HasId;
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, sqlx::Type, PartialEq, Eq, ToSchema)]
+#[sqlx(type_name = "coupon_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CouponKind {
+    /// `value` percent off the eligible items.
+    Percentage,
+    /// `value` off the eligible items, at most their total.
+    FixedAmount,
+    /// `free_quantity` units of `free_product_id` for free, it has to be in the cart.
+    FreeItem,
+}
+
+impl FromStr for CouponKind {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "percentage" => Ok(CouponKind::Percentage),
+            "fixed_amount" => Ok(CouponKind::FixedAmount),
+            "free_item" => Ok(CouponKind::FreeItem),
+            _ => Err(AppError::field(
+                "kind",
+                "invalid_choice",
+                "must be one of percentage, fixed_amount, free_item",
+            )),
+        }
+    }
+}
+
+/** Codes are matched case-insensitively, they're stored upper case. */
+pub fn normalize_code(code: &str) -> String {
+    code.trim().to_uppercase()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicCoupon {
+    pub id: i64,
+    pub code: String,
+    pub kind: CouponKind,
+    /// Percent for `percentage`, amount for `fixed_amount`, empty for `free_item`.
+    #[schema(value_type = Option<String>, example = "15.00")]
+    pub value: Option<BigDecimal>,
+    pub free_product_id: Option<i64>,
+    pub free_quantity: i32,
+    pub min_cart_value: Option<Money>,
+    pub usage_limit: Option<i32>,
+    pub usage_limit_per_user: Option<i32>,
+    pub times_used: i64,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub products: Vec<i64>,
+    pub categories: Vec<i64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPublicCoupon {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl From<AdminCouponModel> for AdminPublicCoupon {
+    fn from(coupon: AdminCouponModel) -> Self {
+        Self {
+            id: coupon.id,
+            code: coupon.code,
+            kind: coupon.kind,
+            value: coupon.value,
+            free_product_id: coupon.free_product_id,
+            free_quantity: coupon.free_quantity,
+            min_cart_value: coupon.min_cart_value.map(Money::new),
+            usage_limit: coupon.usage_limit,
+            usage_limit_per_user: coupon.usage_limit_per_user,
+            times_used: coupon.times_used,
+            starts_at: coupon.starts_at,
+            ends_at: coupon.ends_at,
+            is_active: coupon.is_active,
+            products: coupon.products,
+            categories: coupon.categories,
+            updated_at: coupon.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexCouponDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub search: Option<String>,
+
+    pub is_active: Option<bool>,
+}
+
+impl TryFrom<IndexCouponDTO> for CouponFilters {
+    type Error = AppError;
+
+    fn try_from(dto: IndexCouponDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            is_active: dto.is_active,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateCouponDTO {
+    #[validate(required, length(min = 3, max = 32))]
+    #[schema(required = true, min_length = 3, max_length = 32)]
+    pub code: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true, example = "percentage")]
+    pub kind: Option<String>,
+
+    /// Percent off for `percentage` (up to 100), amount off for `fixed_amount`.
+    #[validate(custom(function = "validate_amount"))]
+    #[schema(value_type = Option<String>, example = "15")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub value: Option<BigDecimal>,
+
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub free_product_id: Option<i64>,
+
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub free_quantity: Option<i32>,
+
+    /// Cart subtotal needed before the coupon applies.
+    #[validate(custom(function = "validate_amount"))]
+    #[schema(value_type = Option<String>, example = "50.00")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub min_cart_value: Option<BigDecimal>,
+
+    /// How many carts can hold the coupon overall, unlimited when empty.
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub usage_limit: Option<i32>,
+
+    /// How many carts of one customer can hold the coupon, unlimited when empty.
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub usage_limit_per_user: Option<i32>,
+
+    pub starts_at: Option<DateTime<Utc>>,
+
+    pub ends_at: Option<DateTime<Utc>>,
+
+    pub is_active: Option<bool>,
+
+    /// Restricts the discount to these products, together with `categories`.
+    pub products: Option<Vec<i64>>,
+
+    /// Restricts the discount to products in these categories, together with `products`.
+    pub categories: Option<Vec<i64>>,
+}
+
+pub struct CreateCouponCommand {
+    pub code: String,
+    pub kind: CouponKind,
+    pub value: Option<BigDecimal>,
+    pub free_product_id: Option<i64>,
+    pub free_quantity: i32,
+    pub min_cart_value: Option<BigDecimal>,
+    pub usage_limit: Option<i32>,
+    pub usage_limit_per_user: Option<i32>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub products: Vec<i64>,
+    pub categories: Vec<i64>,
+}
+
+impl TryFrom<CreateCouponDTO> for CreateCouponCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreateCouponDTO) -> Result<Self, Self::Error> {
+        let code = normalize_code(&dto.code.unwrap());
+        let kind = CouponKind::from_str(&dto.kind.unwrap())?;
+
+        validate_code(&code)?;
+        validate_offer(kind, dto.value.as_ref(), dto.free_product_id)?;
+        validate_window(dto.starts_at, dto.ends_at)?;
+
+        Ok(Self {
+            code,
+            kind,
+            value: dto.value,
+            free_product_id: dto.free_product_id,
+            free_quantity: dto.free_quantity.unwrap_or(1),
+            min_cart_value: dto.min_cart_value,
+            usage_limit: dto.usage_limit,
+            usage_limit_per_user: dto.usage_limit_per_user,
+            starts_at: dto.starts_at,
+            ends_at: dto.ends_at,
+            is_active: dto.is_active.unwrap_or(true),
+            products: dto.products.unwrap_or_default(),
+            categories: dto.categories.unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateCouponDTO {
+    #[validate(required, length(min = 3, max = 32))]
+    #[schema(required = true, min_length = 3, max_length = 32)]
+    pub code: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true, example = "percentage")]
+    pub kind: Option<String>,
+
+    /// Percent off for `percentage` (up to 100), amount off for `fixed_amount`.
+    #[validate(custom(function = "validate_amount"))]
+    #[schema(value_type = Option<String>, example = "15")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub value: Option<BigDecimal>,
+
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub free_product_id: Option<i64>,
+
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub free_quantity: Option<i32>,
+
+    /// Cart subtotal needed before the coupon applies.
+    #[validate(custom(function = "validate_amount"))]
+    #[schema(value_type = Option<String>, example = "50.00")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub min_cart_value: Option<BigDecimal>,
+
+    /// How many carts can hold the coupon overall, unlimited when empty.
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub usage_limit: Option<i32>,
+
+    /// How many carts of one customer can hold the coupon, unlimited when empty.
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub usage_limit_per_user: Option<i32>,
+
+    pub starts_at: Option<DateTime<Utc>>,
+
+    pub ends_at: Option<DateTime<Utc>>,
+
+    pub is_active: Option<bool>,
+
+    /// Restricts the discount to these products, together with `categories`.
+    pub products: Option<Vec<i64>>,
+
+    /// Restricts the discount to products in these categories, together with `products`.
+    pub categories: Option<Vec<i64>>,
+}
+
+pub struct UpdateCouponCommand {
+    pub code: String,
+    pub kind: CouponKind,
+    pub value: Option<BigDecimal>,
+    pub free_product_id: Option<i64>,
+    pub free_quantity: i32,
+    pub min_cart_value: Option<BigDecimal>,
+    pub usage_limit: Option<i32>,
+    pub usage_limit_per_user: Option<i32>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub products: Vec<i64>,
+    pub categories: Vec<i64>,
+}
+
+impl TryFrom<UpdateCouponDTO> for UpdateCouponCommand {
+    type Error = AppError;
+
+    fn try_from(dto: UpdateCouponDTO) -> Result<Self, Self::Error> {
+        let code = normalize_code(&dto.code.unwrap());
+        let kind = CouponKind::from_str(&dto.kind.unwrap())?;
+
+        validate_code(&code)?;
+        validate_offer(kind, dto.value.as_ref(), dto.free_product_id)?;
+        validate_window(dto.starts_at, dto.ends_at)?;
+
+        Ok(Self {
+            code,
+            kind,
+            value: dto.value,
+            free_product_id: dto.free_product_id,
+            free_quantity: dto.free_quantity.unwrap_or(1),
+            min_cart_value: dto.min_cart_value,
+            usage_limit: dto.usage_limit,
+            usage_limit_per_user: dto.usage_limit_per_user,
+            starts_at: dto.starts_at,
+            ends_at: dto.ends_at,
+            is_active: dto.is_active.unwrap_or(true),
+            products: dto.products.unwrap_or_default(),
+            categories: dto.categories.unwrap_or_default(),
+        })
+    }
+}
+
+fn validate_code(code: &str) -> Result<(), AppError> {
+    let valid = code
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if !valid {
+        return Err(AppError::field(
+            "code",
+            "code_format",
+            "may only contain letters, digits, - and _",
+        ));
+    }
+
+    Ok(())
+}
+
+/**
+ * What a coupon gives has to match its kind: a percentage up to 100, a
+ * positive fixed amount, or a product to hand out for free.
+ */
+fn validate_offer(
+    kind: CouponKind,
+    value: Option<&BigDecimal>,
+    free_product_id: Option<i64>,
+) -> Result<(), AppError> {
+    match (kind, value) {
+        (CouponKind::Percentage | CouponKind::FixedAmount, None) => {
+            Err(AppError::field("value", "required", "field is required"))
+        }
+        (CouponKind::Percentage, Some(value))
+            if value.is_zero() || value > &BigDecimal::from(100) =>
+        {
+            Err(AppError::field(
+                "value",
+                "range",
+                "must be greater than 0 and at most 100",
+            ))
+        }
+        (CouponKind::FixedAmount, Some(value)) if value.is_zero() => {
+            Err(AppError::field("value", "range", "must be greater than 0"))
+        }
+        (CouponKind::FreeItem, Some(_)) => Err(AppError::field(
+            "value",
+            "not_allowed",
+            "free_item coupons take no value",
+        )),
+        (CouponKind::FreeItem, None) if free_product_id.is_none() => Err(AppError::field(
+            "free_product_id",
+            "required",
+            "field is required",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_window(
+    starts_at: Option<DateTime<Utc>>,
+    ends_at: Option<DateTime<Utc>>,
+) -> Result<(), AppError> {
+    if let (Some(starts_at), Some(ends_at)) = (starts_at, ends_at)
+        && starts_at >= ends_at
+    {
+        return Err(AppError::field(
+            "ends_at",
+            "invalid_range",
+            "must be after starts_at",
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use crate::admin::coupons::dto::{
+    AdminPublicCoupon, CreateCouponCommand, CreateCouponDTO, IndexCouponDTO, UpdateCouponCommand,
+    UpdateCouponDTO,
+};
+use crate::admin::coupons::filters::CouponFilters;
+use crate::admin::coupons::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use crate::utils::pagination::Paginate;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin/coupons/list",
+    tag = "admin-coupons",
+    params(IndexCouponDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicCoupon>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["coupons:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    state: web::Data<AppState>,
+    body: web::Query<IndexCouponDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = CouponFilters::try_from(body.clone().into_inner())?;
+
+    let coupons = state
+        .admin_coupon_service
+        .get_all_paginated_public(&pagination, &filters, &body.search)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        coupons.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/coupons/get/{id}",
+    tag = "admin-coupons",
+    params(("id" = i64, Path, description = "Coupon id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicCoupon>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["coupons:read"])),
+)]
+#[instrument(skip_all)]
+pub async fn show(
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let coupon = state
+        .admin_coupon_service
+        .get_one_public(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(coupon)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/coupons/create",
+    tag = "admin-coupons",
+    request_body = CreateCouponDTO,
+    responses(
+        (status = 201, description = "Coupon created", body = SuccessResponse<AdminPublicCoupon>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Product or category not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Code already taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["coupons:create"])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreateCouponDTO>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreateCouponCommand::try_from(body.into_inner())?;
+    let coupon = state.admin_coupon_service.create(command, actor_id).await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(coupon.into_public())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/coupons/update/{id}",
+    tag = "admin-coupons",
+    params(("id" = i64, Path, description = "Coupon id")),
+    request_body = UpdateCouponDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Code already taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["coupons:update"])),
+)]
+#[instrument(skip_all)]
+pub async fn update(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdateCouponDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = UpdateCouponCommand::try_from(body.into_inner())?;
+    state
+        .admin_coupon_service
+        .update(command, id.into_inner(), actor_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/coupons/delete/{id}",
+    tag = "admin-coupons",
+    params(("id" = i64, Path, description = "Coupon id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["coupons:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_coupon_service
+        .delete(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,35 @@
+use crate::admin::coupons::dto::CouponKind;
+use crate::utils::money::serialize_optional_amount;
+use crate::utils::traits::HasId;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct AdminCouponModel {
+    pub id: i64,
+    pub code: String,
+    pub kind: CouponKind,
+    #[serde(serialize_with = "serialize_optional_amount")]
+    pub value: Option<BigDecimal>,
+    pub free_product_id: Option<i64>,
+    pub free_quantity: i32,
+    #[serde(serialize_with = "serialize_optional_amount")]
+    pub min_cart_value: Option<BigDecimal>,
+    pub usage_limit: Option<i32>,
+    pub usage_limit_per_user: Option<i32>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub products: Vec<i64>,
+    pub categories: Vec<i64>,
+    pub times_used: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminCouponModel {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
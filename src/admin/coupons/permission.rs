@@ -0,0 +1,31 @@
+use crate::auth::traits::Scope;
+
+pub enum CouponScope {
+    Create,
+    Read,
+    Update,
+    Delete,
+    List,
+}
+
+impl Scope for CouponScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CouponScope::Create => "coupons:create",
+            CouponScope::Read => "coupons:read",
+            CouponScope::Update => "coupons:update",
+            CouponScope::Delete => "coupons:delete",
+            CouponScope::List => "coupons:list",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![
+            CouponScope::Create,
+            CouponScope::Read,
+            CouponScope::Update,
+            CouponScope::Delete,
+            CouponScope::List,
+        ]
+    }
+}
@@ -0,0 +1,298 @@
+use crate::admin::coupons::dto::{CreateCouponCommand, UpdateCouponCommand};
+use crate::admin::coupons::filters::CouponFilters;
+use crate::admin::coupons::model::AdminCouponModel;
+use crate::errors::error::AppError;
+use crate::utils::pagination::Paginate;
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+/** Coupon columns plus its restrictions and how often it was used. */
+const COUPON_COLUMNS: &str = r#"
+    SELECT
+        coupons.id,
+        coupons.code,
+        coupons.kind,
+        coupons.value,
+        coupons.free_product_id,
+        coupons.free_quantity,
+        coupons.min_cart_value,
+        coupons.usage_limit,
+        coupons.usage_limit_per_user,
+        coupons.starts_at,
+        coupons.ends_at,
+        coupons.is_active,
+        ARRAY(
+            SELECT product_id FROM coupon_has_products
+            WHERE coupon_id = coupons.id ORDER BY product_id
+        ) AS products,
+        ARRAY(
+            SELECT category_id FROM coupon_has_categories
+            WHERE coupon_id = coupons.id ORDER BY category_id
+        ) AS categories,
+        (SELECT COUNT(*) FROM coupon_redemptions WHERE coupon_id = coupons.id) AS times_used,
+        coupons.created_at,
+        coupons.updated_at
+    FROM coupons
+"#;
+
+pub struct AdminCouponRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AdminCouponRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminCouponRepository {
+    #[instrument(skip_all)]
+    pub async fn index_paginated(
+        &self,
+        pagination: &Paginate,
+        search: &Option<String>,
+        filters: &CouponFilters,
+    ) -> Result<Vec<AdminCouponModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(COUPON_COLUMNS);
+
+        qb.push(" WHERE TRUE ");
+
+        // handle search
+        if let Some(search) = search {
+            qb.push(" AND coupons.code ILIKE ");
+            qb.push_bind(format!("%{}%", search));
+        }
+
+        // is active
+        if let Some(is_active) = filters.is_active {
+            qb.push(" AND coupons.is_active = ");
+            qb.push_bind(is_active);
+        }
+
+        qb.push(" ORDER BY coupons.id DESC ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        qb.build_query_as::<AdminCouponModel>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn show(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<Option<AdminCouponModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(COUPON_COLUMNS);
+
+        qb.push(" WHERE coupons.id = ");
+        qb.push_bind(id);
+
+        qb.build_query_as::<AdminCouponModel>()
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &CreateCouponCommand,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar(
+            r#"
+        INSERT INTO coupons (
+            code, kind, value, free_product_id, free_quantity, min_cart_value,
+            usage_limit, usage_limit_per_user, starts_at, ends_at, is_active
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        RETURNING id;
+        "#,
+        )
+        .bind(&cmd.code)
+        .bind(cmd.kind)
+        .bind(&cmd.value)
+        .bind(cmd.free_product_id)
+        .bind(cmd.free_quantity)
+        .bind(&cmd.min_cart_value)
+        .bind(cmd.usage_limit)
+        .bind(cmd.usage_limit_per_user)
+        .bind(cmd.starts_at)
+        .bind(cmd.ends_at)
+        .bind(cmd.is_active)
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn update(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &UpdateCouponCommand,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+        UPDATE coupons
+        SET (
+            code, kind, value, free_product_id, free_quantity, min_cart_value,
+            usage_limit, usage_limit_per_user, starts_at, ends_at, is_active, updated_at
+        ) = ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, now())
+        WHERE id = $12;
+        "#,
+        )
+        .bind(&cmd.code)
+        .bind(cmd.kind)
+        .bind(&cmd.value)
+        .bind(cmd.free_product_id)
+        .bind(cmd.free_quantity)
+        .bind(&cmd.min_cart_value)
+        .bind(cmd.usage_limit)
+        .bind(cmd.usage_limit_per_user)
+        .bind(cmd.starts_at)
+        .bind(cmd.ends_at)
+        .bind(cmd.is_active)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /**
+     * Deletes a coupon for good, carts holding it lose the discount and its
+     * redemptions go with it.
+     */
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM coupons WHERE id = $1;",
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn detach_all_products(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        coupon_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM coupon_has_products WHERE coupon_id = $1;",
+            coupon_id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn detach_all_categories(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        coupon_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM coupon_has_categories WHERE coupon_id = $1;",
+            coupon_id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn attach_product(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        coupon_id: i64,
+        product_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        INSERT INTO coupon_has_products (coupon_id, product_id)
+        VALUES ($1, $2);
+        "#,
+            coupon_id, product_id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn attach_category(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        coupon_id: i64,
+        category_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        INSERT INTO coupon_has_categories (coupon_id, category_id)
+        VALUES ($1, $2);
+        "#,
+            coupon_id, category_id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /**
+     * Whether another coupon already uses `code`, `except` leaves out the
+     * coupon being updated.
+     */
+    #[instrument(skip_all)]
+    pub async fn check_existence_by_code(
+        &self,
+        code: &str,
+        except: Option<i64>,
+    ) -> Result<bool, AppError> {
+        sqlx::query_scalar! {
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM coupons WHERE code = $1 AND id IS DISTINCT FROM $2
+            ) AS "exists!";
+            "#,
+            code,
+            except,
+        }
+        .fetch_one(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+}
@@ -0,0 +1,37 @@
+use super::handler;
+use crate::admin::coupons::permission::CouponScope;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{delete, get, post, put, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/coupons")
+            .service(
+                resource("/list")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(CouponScope::List))))
+                    .route(get().to(handler::index)),
+            )
+            .service(
+                resource("/get/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(CouponScope::Read))))
+                    .route(get().to(handler::show)),
+            )
+            .service(
+                resource("/create")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(CouponScope::Create))))
+                    .route(post().to(handler::create)),
+            )
+            .service(
+                resource("/update/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(CouponScope::Update))))
+                    .route(put().to(handler::update)),
+            )
+            .service(
+                resource("/delete/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(CouponScope::Delete))))
+                    .route(delete().to(handler::delete)),
+            ),
+    );
+}
@@ -0,0 +1,230 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
+use crate::admin::categories::repository::AdminCategoryRepository;
+use crate::admin::coupons::dto::{AdminPublicCoupon, CreateCouponCommand, UpdateCouponCommand};
+use crate::admin::coupons::filters::CouponFilters;
+use crate::admin::coupons::model::AdminCouponModel;
+use crate::admin::coupons::repository::AdminCouponRepository;
+use crate::admin::coupons::traits::IntoPublic;
+use crate::admin::products::repository::AdminProductRepository;
+use crate::errors::error::AppError;
+use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct AdminCouponService {
+    repository: AdminCouponRepository,
+    product_repository: AdminProductRepository,
+    category_repository: AdminCategoryRepository,
+    audit_repository: AdminAuditRepository,
+}
+
+impl AdminCouponService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: AdminCouponRepository::new(pool.clone()),
+            product_repository: AdminProductRepository::new(pool.clone()),
+            category_repository: AdminCategoryRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool),
+        }
+    }
+
+    pub async fn get_all_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &CouponFilters,
+        search: &Option<String>,
+    ) -> Result<PaginatedDataCollection<AdminPublicCoupon>, AppError> {
+        let data = self
+            .repository
+            .index_paginated(pagination, search, filters)
+            .await?;
+
+        Ok(PaginatedDataCollection::new(data, pagination.clone()).into_public())
+    }
+
+    pub async fn get_one(&self, id: i64) -> Result<AdminCouponModel, AppError> {
+        let coupon = self.repository.show(self.repository.get_pool(), id).await?;
+
+        match coupon {
+            Some(coupon) => Ok(coupon),
+            None => Err(AppError::NotFound("Coupon not found".to_string())),
+        }
+    }
+
+    pub async fn get_one_public(&self, id: i64) -> Result<AdminPublicCoupon, AppError> {
+        let coupon = self.get_one(id).await?;
+
+        Ok(coupon.into_public())
+    }
+
+    pub async fn create(
+        &self,
+        cmd: CreateCouponCommand,
+        actor_id: i64,
+    ) -> Result<AdminCouponModel, AppError> {
+        if self
+            .repository
+            .check_existence_by_code(&cmd.code, None)
+            .await?
+        {
+            return Err(AppError::Conflict(
+                "Coupon with the same code already exists".to_string(),
+            ));
+        }
+
+        self.check_targets_exist(cmd.free_product_id, &cmd.products, &cmd.categories)
+            .await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let id = self.repository.create(&mut *tx, &cmd).await?;
+
+        for product_id in &cmd.products {
+            self.repository
+                .attach_product(&mut *tx, id, *product_id)
+                .await?;
+        }
+
+        for category_id in &cmd.categories {
+            self.repository
+                .attach_category(&mut *tx, id, *category_id)
+                .await?;
+        }
+
+        let coupon = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Coupon not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(actor_id, AuditEntity::Coupon, id, &coupon),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(coupon)
+    }
+
+    pub async fn update(
+        &self,
+        cmd: UpdateCouponCommand,
+        id: i64,
+        actor_id: i64,
+    ) -> Result<AdminCouponModel, AppError> {
+        let before = self.get_one(id).await?;
+
+        if self
+            .repository
+            .check_existence_by_code(&cmd.code, Some(id))
+            .await?
+        {
+            return Err(AppError::Conflict(
+                "Coupon with the same code already exists".to_string(),
+            ));
+        }
+
+        self.check_targets_exist(cmd.free_product_id, &cmd.products, &cmd.categories)
+            .await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.update(&mut *tx, &cmd, id).await?;
+
+        self.repository.detach_all_products(&mut *tx, id).await?;
+        self.repository.detach_all_categories(&mut *tx, id).await?;
+
+        for product_id in &cmd.products {
+            self.repository
+                .attach_product(&mut *tx, id, *product_id)
+                .await?;
+        }
+
+        for category_id in &cmd.categories {
+            self.repository
+                .attach_category(&mut *tx, id, *category_id)
+                .await?;
+        }
+
+        let after = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Coupon not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::Coupon,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(after)
+    }
+
+    pub async fn delete(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let coupon = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(actor_id, AuditEntity::Coupon, id, &coupon),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(deleted)
+    }
+
+    async fn check_targets_exist(
+        &self,
+        free_product_id: Option<i64>,
+        products: &[i64],
+        categories: &[i64],
+    ) -> Result<(), AppError> {
+        for product_id in free_product_id.iter().chain(products) {
+            if !self
+                .product_repository
+                .check_existence_by_id(*product_id)
+                .await?
+            {
+                return Err(AppError::NotFound(format!(
+                    "Product with id {} not found",
+                    product_id
+                )));
+            }
+        }
+
+        for category_id in categories {
+            if !self
+                .category_repository
+                .check_existence_by_id(*category_id)
+                .await?
+            {
+                return Err(AppError::NotFound(format!(
+                    "Category with id {} not found",
+                    category_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
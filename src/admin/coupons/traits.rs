@@ -0,0 +1,24 @@
+use crate::admin::coupons::dto::AdminPublicCoupon;
+use crate::admin::coupons::model::AdminCouponModel;
+use crate::utils::pagination::PaginatedDataCollection;
+
+pub trait IntoPublic<T> {
+    fn into_public(self) -> T;
+}
+
+impl IntoPublic<AdminPublicCoupon> for AdminCouponModel {
+    fn into_public(self) -> AdminPublicCoupon {
+        AdminPublicCoupon::from(self)
+    }
+}
+
+impl IntoPublic<PaginatedDataCollection<AdminPublicCoupon>>
+    for PaginatedDataCollection<AdminCouponModel>
+{
+    fn into_public(self) -> PaginatedDataCollection<AdminPublicCoupon> {
+        PaginatedDataCollection::new(
+            self.data.into_iter().map(AdminPublicCoupon::from).collect(),
+            self.pagination,
+        )
+    }
+}
@@ -1,5 +1,13 @@
+pub mod audit;
 pub mod categories;
+pub mod coupons;
 pub mod products;
+pub mod promotions;
 pub mod reviews;
 pub mod routes;
+pub mod shipping_methods;
+pub mod shipping_zones;
+pub mod stock;
+pub mod tax_rates;
 pub mod users;
+pub mod warehouses;
@@ -1,20 +1,67 @@
-use crate::admin::products::filters::ProductFilters;
+use crate::admin::products::filters::{LowStockFilters, ProductFilters};
 use crate::admin::products::images::dto::AdminPublicProductImage;
-use crate::admin::products::model::AdminProductModel;
+use crate::admin::products::model::{AdminProductModel, LowStockProductModel};
 use crate::admin::products::videos::dto::AdminPublicProductVideo;
+use crate::admin::tax_rates::dto::normalize_tax_class;
 use crate::errors::error::AppError;
+use crate::utils::money::{Money, deserialize_amount, validate_amount};
 use crate::utils::traits::HasId;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/**
+ * Whether a product is visible on the storefront right now, derived from
+ * `is_active` and its publish window.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ProductStatus {
+    Inactive,
+    Scheduled,
+    Live,
+    Expired,
+}
+
+impl ProductStatus {
+    pub fn at(product: &AdminProductModel, now: DateTime<Utc>) -> Self {
+        if !product.is_active {
+            return ProductStatus::Inactive;
+        }
+
+        match (product.publish_at, product.unpublish_at) {
+            (Some(publish_at), _) if publish_at > now => ProductStatus::Scheduled,
+            (_, Some(unpublish_at)) if unpublish_at <= now => ProductStatus::Expired,
+            _ => ProductStatus::Live,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct AdminPublicProduct {
     pub id: i64,
     pub name: String,
-    pub price: f64,
+    pub price: Money,
     pub quantity: i32,
     pub configurable: bool,
     pub is_active: bool,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub unpublish_at: Option<DateTime<Utc>>,
+    pub status: ProductStatus,
+    pub sale_price: Option<Money>,
+    pub sale_starts_at: Option<DateTime<Utc>>,
+    pub sale_ends_at: Option<DateTime<Utc>>,
+    pub tax_class: String,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
+    /// Stock at or below it counts as low, the configured default when empty.
+    pub low_stock_threshold: Option<i32>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
     pub images: Vec<AdminPublicProductImage>,
     pub videos: Vec<AdminPublicProductVideo>,
 }
@@ -27,13 +74,29 @@ impl HasId for AdminPublicProduct {
 
 impl From<AdminProductModel> for AdminPublicProduct {
     fn from(product: AdminProductModel) -> Self {
+        let status = ProductStatus::at(&product, Utc::now());
+
         Self {
             id: product.id,
             name: product.name,
-            price: product.price,
+            price: Money::new(product.price),
             quantity: product.quantity,
             configurable: product.configurable,
             is_active: product.is_active,
+            publish_at: product.publish_at,
+            unpublish_at: product.unpublish_at,
+            status,
+            sale_price: product.sale_price.map(Money::new),
+            sale_starts_at: product.sale_starts_at,
+            sale_ends_at: product.sale_ends_at,
+            tax_class: product.tax_class,
+            weight_grams: product.weight_grams,
+            length_mm: product.length_mm,
+            width_mm: product.width_mm,
+            height_mm: product.height_mm,
+            low_stock_threshold: product.low_stock_threshold,
+            updated_at: product.updated_at,
+            deleted_at: product.deleted_at,
             images: Vec::new(),
             videos: Vec::new(),
         }
@@ -46,38 +109,61 @@ impl AdminPublicProduct {
         images: Vec<AdminPublicProductImage>,
         videos: Vec<AdminPublicProductVideo>,
     ) -> Self {
+        let status = ProductStatus::at(&product, Utc::now());
+
         Self {
             id: product.id,
             name: product.name,
-            price: product.price,
+            price: Money::new(product.price),
             quantity: product.quantity,
             configurable: product.configurable,
             is_active: product.is_active,
+            publish_at: product.publish_at,
+            unpublish_at: product.unpublish_at,
+            status,
+            sale_price: product.sale_price.map(Money::new),
+            sale_starts_at: product.sale_starts_at,
+            sale_ends_at: product.sale_ends_at,
+            tax_class: product.tax_class,
+            weight_grams: product.weight_grams,
+            length_mm: product.length_mm,
+            width_mm: product.width_mm,
+            height_mm: product.height_mm,
+            low_stock_threshold: product.low_stock_threshold,
+            updated_at: product.updated_at,
+            deleted_at: product.deleted_at,
             images,
             videos,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Validate, Clone)]
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct IndexProductDTO {
     #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
     pub page: Option<i64>,
 
     #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
     pub limit: Option<i64>,
 
     #[validate(length(min = 1))]
+    #[param(min_length = 1)]
     pub search: Option<String>,
 
     #[validate(range(min = 1))]
+    #[param(minimum = 1)]
     pub category: Option<i64>,
 
-    #[validate(range(min = 0.0))]
-    pub price_min: Option<f64>,
+    #[validate(custom(function = "validate_amount"))]
+    #[param(value_type = Option<String>)]
+    pub price_min: Option<BigDecimal>,
 
-    #[validate(range(min = 0.0))]
-    pub price_max: Option<f64>,
+    #[validate(custom(function = "validate_amount"))]
+    #[param(value_type = Option<String>)]
+    pub price_max: Option<BigDecimal>,
 
     pub in_stock: Option<bool>,
 
@@ -94,46 +180,112 @@ impl TryFrom<IndexProductDTO> for ProductFilters {
             price_max: dto.price_max,
             in_stock: dto.in_stock,
             is_active: dto.is_active,
+            trashed: false,
         })
     }
 }
 
-#[derive(Serialize, Deserialize, Validate)]
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateProductDTO {
     #[validate(required, length(min = 3))]
+    #[schema(required = true, min_length = 3)]
     pub name: Option<String>,
 
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub slug: Option<String>,
 
     #[validate(length(min = 1))]
+    #[schema(min_items = 1)]
     pub categories: Option<Vec<i64>>,
 
-    #[validate(required, range(min = 0.0))]
-    pub price: Option<f64>,
+    #[validate(required, custom(function = "validate_amount"))]
+    #[schema(required = true, value_type = String, example = "10.99")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub price: Option<BigDecimal>,
 
     #[validate(range(min = 0))]
+    #[schema(minimum = 0)]
     pub quantity: Option<i32>,
 
     pub configurable: Option<bool>,
 
     pub is_active: Option<bool>,
+
+    /// Storefront visibility starts here, immediately when empty.
+    pub publish_at: Option<DateTime<Utc>>,
+
+    /// Storefront visibility ends here, never when empty.
+    pub unpublish_at: Option<DateTime<Utc>>,
+
+    /// Discounted price, `price` becomes the compare-at price while it runs.
+    #[validate(custom(function = "validate_amount"))]
+    #[schema(value_type = Option<String>, example = "7.99")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub sale_price: Option<BigDecimal>,
+
+    /// The sale starts here, immediately when empty.
+    pub sale_starts_at: Option<DateTime<Utc>>,
+
+    /// The sale ends here, never when empty.
+    pub sale_ends_at: Option<DateTime<Utc>>,
+
+    /// Tax class the product is taxed under, `standard` when empty.
+    #[validate(length(min = 1, max = 32))]
+    #[schema(min_length = 1, max_length = 32, example = "standard")]
+    pub tax_class: Option<String>,
+
+    /// Shipping weight in grams.
+    #[validate(range(min = 0))]
+    #[schema(minimum = 0, example = 250)]
+    pub weight_grams: Option<i32>,
+
+    /// Package length in millimetres, with width and height it gives the volumetric weight.
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub length_mm: Option<i32>,
+
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub width_mm: Option<i32>,
+
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub height_mm: Option<i32>,
 }
 
 pub struct CreateProductCommand {
     pub name: String,
     pub slug: String,
     pub categories: Option<Vec<i64>>,
-    pub price: f64,
+    pub price: BigDecimal,
     pub quantity: i32,
     pub configurable: bool,
     pub is_active: bool,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub unpublish_at: Option<DateTime<Utc>>,
+    pub sale_price: Option<BigDecimal>,
+    pub sale_starts_at: Option<DateTime<Utc>>,
+    pub sale_ends_at: Option<DateTime<Utc>>,
+    pub tax_class: String,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
 }
 
 impl TryFrom<CreateProductDTO> for CreateProductCommand {
     type Error = AppError;
 
     fn try_from(dto: CreateProductDTO) -> Result<Self, Self::Error> {
+        validate_publish_window(dto.publish_at, dto.unpublish_at)?;
+        validate_sale(
+            dto.price.as_ref(),
+            dto.sale_price.as_ref(),
+            dto.sale_starts_at,
+            dto.sale_ends_at,
+        )?;
+
         Ok(Self {
             name: dto.name.unwrap(),
             slug: dto.slug.unwrap(),
@@ -142,46 +294,121 @@ impl TryFrom<CreateProductDTO> for CreateProductCommand {
             quantity: dto.quantity.unwrap_or(0),
             configurable: dto.configurable.unwrap_or(false),
             is_active: dto.is_active.unwrap_or(true),
+            publish_at: dto.publish_at,
+            unpublish_at: dto.unpublish_at,
+            sale_price: dto.sale_price,
+            sale_starts_at: dto.sale_starts_at,
+            sale_ends_at: dto.sale_ends_at,
+            tax_class: normalize_tax_class(dto.tax_class)?,
+            weight_grams: dto.weight_grams,
+            length_mm: dto.length_mm,
+            width_mm: dto.width_mm,
+            height_mm: dto.height_mm,
         })
     }
 }
 
-#[derive(Serialize, Deserialize, Validate)]
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateProductDTO {
     #[validate(required, length(min = 3))]
+    #[schema(required = true, min_length = 3)]
     pub name: Option<String>,
 
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub slug: Option<String>,
 
     #[validate(length(min = 1))]
+    #[schema(min_items = 1)]
     pub categories: Option<Vec<i64>>,
 
-    #[validate(required, range(min = 0.0))]
-    pub price: Option<f64>,
+    #[validate(required, custom(function = "validate_amount"))]
+    #[schema(required = true, value_type = String, example = "10.99")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub price: Option<BigDecimal>,
 
     #[validate(range(min = 0))]
+    #[schema(minimum = 0)]
     pub quantity: Option<i32>,
 
     pub configurable: Option<bool>,
 
     pub is_active: Option<bool>,
+
+    /// Storefront visibility starts here, immediately when empty.
+    pub publish_at: Option<DateTime<Utc>>,
+
+    /// Storefront visibility ends here, never when empty.
+    pub unpublish_at: Option<DateTime<Utc>>,
+
+    /// Discounted price, `price` becomes the compare-at price while it runs.
+    #[validate(custom(function = "validate_amount"))]
+    #[schema(value_type = Option<String>, example = "7.99")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub sale_price: Option<BigDecimal>,
+
+    /// The sale starts here, immediately when empty.
+    pub sale_starts_at: Option<DateTime<Utc>>,
+
+    /// The sale ends here, never when empty.
+    pub sale_ends_at: Option<DateTime<Utc>>,
+
+    /// Tax class the product is taxed under, `standard` when empty.
+    #[validate(length(min = 1, max = 32))]
+    #[schema(min_length = 1, max_length = 32, example = "standard")]
+    pub tax_class: Option<String>,
+
+    /// Shipping weight in grams.
+    #[validate(range(min = 0))]
+    #[schema(minimum = 0, example = 250)]
+    pub weight_grams: Option<i32>,
+
+    /// Package length in millimetres, with width and height it gives the volumetric weight.
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub length_mm: Option<i32>,
+
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub width_mm: Option<i32>,
+
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub height_mm: Option<i32>,
 }
 
 pub struct UpdateProductCommand {
     pub name: String,
     pub slug: String,
     pub categories: Option<Vec<i64>>,
-    pub price: f64,
+    pub price: BigDecimal,
     pub quantity: i32,
     pub configurable: bool,
     pub is_active: bool,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub unpublish_at: Option<DateTime<Utc>>,
+    pub sale_price: Option<BigDecimal>,
+    pub sale_starts_at: Option<DateTime<Utc>>,
+    pub sale_ends_at: Option<DateTime<Utc>>,
+    pub tax_class: String,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
 }
 
 impl TryFrom<UpdateProductDTO> for UpdateProductCommand {
     type Error = AppError;
 
     fn try_from(dto: UpdateProductDTO) -> Result<Self, Self::Error> {
+        validate_publish_window(dto.publish_at, dto.unpublish_at)?;
+        validate_sale(
+            dto.price.as_ref(),
+            dto.sale_price.as_ref(),
+            dto.sale_starts_at,
+            dto.sale_ends_at,
+        )?;
+
         Ok(Self {
             name: dto.name.unwrap(),
             slug: dto.slug.unwrap(),
@@ -190,6 +417,126 @@ impl TryFrom<UpdateProductDTO> for UpdateProductCommand {
             quantity: dto.quantity.unwrap_or(0),
             configurable: dto.configurable.unwrap_or(false),
             is_active: dto.is_active.unwrap_or(true),
+            publish_at: dto.publish_at,
+            unpublish_at: dto.unpublish_at,
+            sale_price: dto.sale_price,
+            sale_starts_at: dto.sale_starts_at,
+            sale_ends_at: dto.sale_ends_at,
+            tax_class: normalize_tax_class(dto.tax_class)?,
+            weight_grams: dto.weight_grams,
+            length_mm: dto.length_mm,
+            width_mm: dto.width_mm,
+            height_mm: dto.height_mm,
         })
     }
 }
+
+/** A product at or below its low stock threshold. */
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicLowStockProduct {
+    pub id: i64,
+    pub name: String,
+    pub slug: String,
+    pub quantity: i32,
+    /// The product's own threshold, or the configured default when it has none.
+    pub threshold: i32,
+    pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<LowStockProductModel> for AdminPublicLowStockProduct {
+    fn from(product: LowStockProductModel) -> Self {
+        Self {
+            id: product.id,
+            name: product.name,
+            slug: product.slug,
+            quantity: product.quantity,
+            threshold: product.threshold,
+            is_active: product.is_active,
+            updated_at: product.updated_at,
+        }
+    }
+}
+
+impl HasId for AdminPublicLowStockProduct {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexLowStockDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    /// Only the products that ran out, or only the ones that haven't yet.
+    pub out_of_stock: Option<bool>,
+}
+
+impl From<IndexLowStockDTO> for LowStockFilters {
+    fn from(dto: IndexLowStockDTO) -> Self {
+        Self {
+            out_of_stock: dto.out_of_stock,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct LowStockThresholdDTO {
+    /// Stock at or below it counts as low, `null` falls back to the configured default.
+    #[validate(range(min = 0))]
+    #[schema(minimum = 0, example = 5)]
+    pub threshold: Option<i32>,
+}
+
+fn validate_publish_window(
+    publish_at: Option<DateTime<Utc>>,
+    unpublish_at: Option<DateTime<Utc>>,
+) -> Result<(), AppError> {
+    if let (Some(publish_at), Some(unpublish_at)) = (publish_at, unpublish_at)
+        && publish_at >= unpublish_at
+    {
+        return Err(AppError::field(
+            "unpublish_at",
+            "invalid_range",
+            "must be after publish_at",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_sale(
+    price: Option<&BigDecimal>,
+    sale_price: Option<&BigDecimal>,
+    sale_starts_at: Option<DateTime<Utc>>,
+    sale_ends_at: Option<DateTime<Utc>>,
+) -> Result<(), AppError> {
+    if let (Some(price), Some(sale_price)) = (price, sale_price)
+        && sale_price >= price
+    {
+        return Err(AppError::field(
+            "sale_price",
+            "invalid_range",
+            "must be lower than price",
+        ));
+    }
+
+    if let (Some(starts_at), Some(ends_at)) = (sale_starts_at, sale_ends_at)
+        && starts_at >= ends_at
+    {
+        return Err(AppError::field(
+            "sale_ends_at",
+            "invalid_range",
+            "must be after sale_starts_at",
+        ));
+    }
+
+    Ok(())
+}
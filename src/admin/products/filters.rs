@@ -1,10 +1,17 @@
+use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductFilters {
     pub category: Option<i64>,
-    pub price_min: Option<f64>,
-    pub price_max: Option<f64>,
+    pub price_min: Option<BigDecimal>,
+    pub price_max: Option<BigDecimal>,
     pub in_stock: Option<bool>,
     pub is_active: Option<bool>,
+    pub trashed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LowStockFilters {
+    pub out_of_stock: Option<bool>,
 }
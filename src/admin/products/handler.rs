@@ -1,15 +1,34 @@
 use crate::admin::products::dto::{
-    CreateProductCommand, CreateProductDTO, IndexProductDTO, UpdateProductCommand, UpdateProductDTO,
+    AdminPublicLowStockProduct, AdminPublicProduct, CreateProductCommand, CreateProductDTO,
+    IndexLowStockDTO, IndexProductDTO, LowStockThresholdDTO, UpdateProductCommand,
+    UpdateProductDTO,
 };
-use crate::admin::products::filters::ProductFilters;
+use crate::admin::products::filters::{LowStockFilters, ProductFilters};
 use crate::admin::products::traits::IntoPublic;
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
 use crate::utils::pagination::Paginate;
-use actix_web::{HttpResponse, Responder, web};
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
 use validator::Validate;
 
+#[utoipa::path(
+    get,
+    path = "/admin/products/list",
+    tag = "admin-products",
+    params(IndexProductDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicProduct>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:list"])),
+)]
+#[instrument(skip_all)]
 pub async fn index(
     state: web::Data<AppState>,
     body: web::Query<IndexProductDTO>,
@@ -31,6 +50,21 @@ pub async fn index(
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/admin/products/get/{id}",
+    tag = "admin-products",
+    params(("id" = i64, Path, description = "Product id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicProduct>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:read"])),
+)]
+#[instrument(skip_all)]
 pub async fn show(
     state: web::Data<AppState>,
     id: web::Path<i64>,
@@ -43,38 +77,280 @@ pub async fn show(
     Ok(HttpResponse::Ok().json(SuccessResponse::ok(product)))
 }
 
+#[utoipa::path(
+    post,
+    path = "/admin/products/create",
+    tag = "admin-products",
+    request_body = CreateProductDTO,
+    responses(
+        (status = 201, description = "Product created", body = SuccessResponse<AdminPublicProduct>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Slug already taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:create"])),
+)]
+#[instrument(skip_all)]
 pub async fn create(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Json<CreateProductDTO>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     body.validate()?;
 
     let command = CreateProductCommand::try_from(body.into_inner())?;
-    let product = state.admin_product_service.create(command).await?;
+    let product = state
+        .admin_product_service
+        .create(command, actor_id)
+        .await?;
 
     Ok(HttpResponse::Created().json(SuccessResponse::ok(product.into_public())))
 }
 
+#[utoipa::path(
+    put,
+    path = "/admin/products/update/{id}",
+    tag = "admin-products",
+    params(("id" = i64, Path, description = "Product id")),
+    request_body = UpdateProductDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Slug already taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:update"])),
+)]
+#[instrument(skip_all)]
 pub async fn update(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Json<UpdateProductDTO>,
     id: web::Path<i64>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     body.validate()?;
 
     let command = UpdateProductCommand::try_from(body.into_inner())?;
     state
         .admin_product_service
-        .update(command, id.into_inner())
+        .update(command, id.into_inner(), actor_id)
         .await?;
 
     Ok(HttpResponse::NoContent().finish())
 }
 
+#[utoipa::path(
+    delete,
+    path = "/admin/products/delete/{id}",
+    tag = "admin-products",
+    params(("id" = i64, Path, description = "Product id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:delete"])),
+)]
+#[instrument(skip_all)]
 pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_product_service
+        .delete(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/products/trash",
+    tag = "admin-products",
+    params(IndexProductDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicProduct>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn trash(
+    state: web::Data<AppState>,
+    body: web::Query<IndexProductDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = ProductFilters::try_from(body.clone().into_inner())?;
+
+    let products = state
+        .admin_product_service
+        .get_trashed_paginated_public(&pagination, &filters, &body.search)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        products.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/products/restore/{id}",
+    tag = "admin-products",
+    params(("id" = i64, Path, description = "Product id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Slug already taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn restore(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_product_service
+        .restore(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/products/purge/{id}",
+    tag = "admin-products",
+    params(("id" = i64, Path, description = "Product id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn purge(
+    request: HttpRequest,
     state: web::Data<AppState>,
     id: web::Path<i64>,
 ) -> Result<impl Responder, AppError> {
-    state.admin_product_service.delete(id.into_inner()).await?;
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_product_service
+        .purge(id.into_inner(), &state.local_storage, actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/**
+ * Products at or below their low stock threshold, the ones that ran out
+ * first. Products without a threshold of their own use
+ * `inventory.low_stock_threshold`.
+ */
+#[utoipa::path(
+    get,
+    path = "/admin/products/low-stock",
+    tag = "admin-products",
+    params(IndexLowStockDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicLowStockProduct>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn low_stock(
+    state: web::Data<AppState>,
+    body: web::Query<IndexLowStockDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = LowStockFilters::from(body.clone().into_inner());
+
+    let products = state
+        .admin_product_service
+        .get_low_stock_public(
+            &pagination,
+            &filters,
+            state.config.inventory.low_stock_threshold,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        products.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/products/low-stock/{id}",
+    tag = "admin-products",
+    params(("id" = i64, Path, description = "Product id")),
+    request_body = LowStockThresholdDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:update"])),
+)]
+#[instrument(skip_all)]
+pub async fn low_stock_threshold(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<LowStockThresholdDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    state
+        .admin_product_service
+        .set_low_stock_threshold(id.into_inner(), body.threshold, actor_id)
+        .await?;
+
     Ok(HttpResponse::NoContent().finish())
 }
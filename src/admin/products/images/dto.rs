@@ -8,14 +8,16 @@ use actix_multipart::form::tempfile::TempFile;
 use actix_multipart::form::text::Text;
 use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct AdminPublicProductImage {
     pub id: i64,
     pub product_id: i64,
     pub url: String,
     pub alt: String,
     pub is_main: bool,
+    #[schema(value_type = String)]
     pub sort: BigDecimal,
 }
 
@@ -38,11 +40,15 @@ impl From<AdminProductImageModel> for AdminPublicProductImage {
     }
 }
 
-#[derive(MultipartForm)]
+#[derive(MultipartForm, ToSchema)]
 pub struct CreateProductImageDTO {
+    #[schema(value_type = i64)]
     pub product_id: Text<i64>,
+    #[schema(value_type = String, format = Binary)]
     pub file: TempFile,
+    #[schema(value_type = String)]
     pub alt: Text<String>,
+    #[schema(value_type = bool)]
     pub is_main: Text<bool>,
 }
 
@@ -109,7 +115,7 @@ impl CreateProductImageCommand {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UpdateProductImageSortDTO {
     pub target_index: Option<i32>,
 }
@@ -3,16 +3,37 @@ use crate::admin::products::images::dto::{
     UpdateProductImageSortDTO,
 };
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
 use actix_multipart::form::MultipartForm;
-use actix_web::{HttpResponse, Responder, web};
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
 use tokio::fs;
+use tracing::instrument;
 
+#[utoipa::path(
+    post,
+    path = "/admin/products/images/upload",
+    tag = "admin-products",
+    request_body(content = CreateProductImageDTO, content_type = "multipart/form-data"),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:create"])),
+)]
+#[instrument(skip_all)]
 pub async fn upload(
+    request: HttpRequest,
     state: web::Data<AppState>,
     form: MultipartForm<CreateProductImageDTO>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     let temp_file = &form.file;
 
     let bytes = fs::read(temp_file.file.path())
@@ -30,34 +51,78 @@ pub async fn upload(
 
     state
         .admin_product_images_service
-        .upload(command, &state.local_storage, bytes, extension.as_str())
+        .upload(
+            command,
+            &state.local_storage,
+            bytes,
+            extension.as_str(),
+            actor_id,
+        )
         .await?;
 
     Ok(HttpResponse::NoContent().finish())
 }
 
+#[utoipa::path(
+    put,
+    path = "/admin/products/images/{id}/update-sort",
+    tag = "admin-products",
+    params(("id" = i64, Path, description = "Image id")),
+    request_body = UpdateProductImageSortDTO,
+    responses(
+        (status = 200, description = "New sort value", body = SuccessResponse<String>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:update"])),
+)]
+#[instrument(skip_all)]
 pub async fn update_sort(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Json<UpdateProductImageSortDTO>,
     id: web::Path<i64>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     let command = UpdateProductImageSortCommand::new_from_dto(&body.into_inner())?;
 
     let new_sort = state
         .admin_product_images_service
-        .update_sort(id.into_inner(), command)
+        .update_sort(id.into_inner(), command, actor_id)
         .await?;
 
     Ok(HttpResponse::Ok().json(SuccessResponse::ok(new_sort)))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/admin/products/images/delete/{id}",
+    tag = "admin-products",
+    params(("id" = i64, Path, description = "Image id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:delete"])),
+)]
+#[instrument(skip_all)]
 pub async fn delete(
+    request: HttpRequest,
     state: web::Data<AppState>,
     id: web::Path<i64>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     state
         .admin_product_images_service
-        .delete(id.into_inner(), &state.local_storage)
+        .delete(id.into_inner(), &state.local_storage, actor_id)
         .await?;
 
     Ok(HttpResponse::NoContent().finish())
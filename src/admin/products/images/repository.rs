@@ -6,6 +6,7 @@ use crate::errors::error::AppError;
 use crate::utils::traits::IsRepository;
 use bigdecimal::BigDecimal;
 use sqlx::{Executor, PgPool, Postgres};
+use tracing::instrument;
 
 pub struct AdminProductImageRepository {
     pool: PgPool,
@@ -24,6 +25,7 @@ impl IsRepository for AdminProductImageRepository {
 }
 
 impl AdminProductImageRepository {
+    #[instrument(skip_all)]
     pub async fn get_all_by_product(
         &self,
         product_id: i64,
@@ -50,6 +52,7 @@ impl AdminProductImageRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_all_for_multiple_products(
         &self,
         product_ids: Vec<i64>,
@@ -77,6 +80,7 @@ impl AdminProductImageRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn show(&self, id: i64) -> Result<Option<AdminProductImageModel>, AppError> {
         sqlx::query_as! {
             AdminProductImageModel,
@@ -100,6 +104,7 @@ impl AdminProductImageRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn create(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
@@ -119,7 +124,13 @@ impl AdminProductImageRepository {
         .map_err(AppError::Database)
     }
 
-    pub async fn update_sort(&self, id: i64, sort: BigDecimal) -> Result<u64, AppError> {
+    #[instrument(skip_all)]
+    pub async fn update_sort(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+        sort: BigDecimal,
+    ) -> Result<u64, AppError> {
         let result = sqlx::query_as! {
             AdminProductImageModel,
             r#"
@@ -130,13 +141,14 @@ impl AdminProductImageRepository {
             sort,
             id,
         }
-        .execute(&self.pool)
+        .execute(executor)
         .await
         .map_err(AppError::Database)?;
 
         Ok(result.rows_affected())
     }
 
+    #[instrument(skip_all)]
     pub async fn delete(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
@@ -153,6 +165,7 @@ impl AdminProductImageRepository {
         Ok(result.rows_affected())
     }
 
+    #[instrument(skip_all)]
     pub async fn get_total_count(&self, product_id: i64) -> Result<i64, AppError> {
         sqlx::query_scalar! {
             r#"
@@ -168,6 +181,7 @@ impl AdminProductImageRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_images_only_sort(
         &self,
         product_id: i64,
@@ -187,6 +201,7 @@ impl AdminProductImageRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_last_sort(&self, product_id: i64) -> Result<Option<BigDecimal>, AppError> {
         sqlx::query_scalar! {
             r#"
@@ -204,6 +219,7 @@ impl AdminProductImageRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn reset_is_main(&self, product_id: i64) -> Result<u64, AppError> {
         let result = sqlx::query_as! {
             AdminProductImageModel,
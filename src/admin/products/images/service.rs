@@ -1,9 +1,12 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
 use crate::admin::products::images::dto::{
     CreateProductImageCommand, UpdateProductImageSortCommand,
 };
 use crate::admin::products::images::model::AdminProductImageModel;
 use crate::admin::products::images::repository::AdminProductImageRepository;
 use crate::admin::products::service::AdminProductService;
+use crate::app::products::cache::ProductCache;
 use crate::errors::error::AppError;
 use crate::utils::storage::LocalStorage;
 use crate::utils::traits::{IsRepository, UseStorage};
@@ -15,13 +18,15 @@ use uuid::Uuid;
 pub struct AdminProductImageService {
     repository: AdminProductImageRepository,
     product_service: AdminProductService,
+    audit_repository: AdminAuditRepository,
 }
 
 impl AdminProductImageService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, product_cache: ProductCache) -> Self {
         Self {
             repository: AdminProductImageRepository::new(pool.clone()),
-            product_service: AdminProductService::new(pool),
+            product_service: AdminProductService::new(pool.clone(), product_cache),
+            audit_repository: AdminAuditRepository::new(pool),
         }
     }
 
@@ -40,6 +45,7 @@ impl AdminProductImageService {
         storage: &LocalStorage,
         file_bytes: Vec<u8>,
         extension: &str,
+        actor_id: i64,
     ) -> Result<i64, AppError> {
         self.product_service.get_one(cmd.product_id).await?;
 
@@ -53,11 +59,26 @@ impl AdminProductImageService {
         cmd.handle_main(&self.repository).await?;
         cmd.handle_sort(&self.repository).await?;
 
-        let image_model = self
-            .repository
-            .create(self.repository.get_pool(), &cmd)
+        let mut tx = self.repository.start_transaction().await?;
+
+        let image_model = self.repository.create(&mut *tx, &cmd).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(
+                    actor_id,
+                    AuditEntity::ProductImage,
+                    image_model.id,
+                    &image_model,
+                ),
+            )
             .await?;
 
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_service.invalidate_cached_pages(cmd.product_id);
+
         Ok(image_model.id)
     }
 
@@ -65,6 +86,7 @@ impl AdminProductImageService {
         &self,
         id: i64,
         cmd: UpdateProductImageSortCommand,
+        actor_id: i64,
     ) -> Result<BigDecimal, AppError> {
         let image = self.get_one(id).await?;
 
@@ -94,14 +116,63 @@ impl AdminProductImageService {
             (prev.sort.clone() + next.sort.clone()) / divider
         };
 
-        self.repository.update_sort(id, new_sort.clone()).await?;
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository
+            .update_sort(&mut *tx, id, new_sort.clone())
+            .await?;
+
+        let after = AdminProductImageModel {
+            sort: new_sort.clone(),
+            ..image.clone()
+        };
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::ProductImage,
+                    id,
+                    &image,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_service
+            .invalidate_cached_pages(image.product_id);
 
         Ok(new_sort)
     }
 
-    pub async fn delete(&self, id: i64, storage: &LocalStorage) -> Result<u64, AppError> {
+    pub async fn delete(
+        &self,
+        id: i64,
+        storage: &LocalStorage,
+        actor_id: i64,
+    ) -> Result<u64, AppError> {
         let image = self.get_one(id).await?;
         storage.delete(image.url.as_str()).await?;
-        self.repository.delete(self.repository.get_pool(), id).await
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(actor_id, AuditEntity::ProductImage, id, &image),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_service
+            .invalidate_cached_pages(image.product_id);
+
+        Ok(deleted)
     }
 }
@@ -1,11 +1,12 @@
 pub mod dto;
 pub mod filters;
-mod handler;
+pub(crate) mod handler;
 pub mod images;
 pub mod model;
 pub mod permission;
-mod repository;
+pub(crate) mod repository;
 pub mod routes;
 pub mod service;
 pub mod traits;
+pub mod transfer;
 pub mod videos;
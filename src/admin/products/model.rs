@@ -1,4 +1,6 @@
+use crate::utils::money::{serialize_amount, serialize_optional_amount};
 use crate::utils::traits::HasId;
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use fake::{Dummy, Fake, Faker};
 use serde::{Deserialize, Serialize};
@@ -9,11 +11,26 @@ pub struct AdminProductModel {
     pub id: i64,
     pub name: String,
     pub slug: String,
-    pub price: f64,
+    #[serde(serialize_with = "serialize_amount")]
+    pub price: BigDecimal,
     pub quantity: i32,
     pub configurable: bool,
     pub is_active: bool,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub unpublish_at: Option<DateTime<Utc>>,
+    #[serde(serialize_with = "serialize_optional_amount")]
+    pub sale_price: Option<BigDecimal>,
+    pub sale_starts_at: Option<DateTime<Utc>>,
+    pub sale_ends_at: Option<DateTime<Utc>>,
+    pub tax_class: String,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
+    pub low_stock_threshold: Option<i32>,
     pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl HasId for AdminProductModel {
@@ -22,12 +39,46 @@ impl HasId for AdminProductModel {
     }
 }
 
+/** A row of the low stock report, `threshold` already falls back to the default. */
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct LowStockProductModel {
+    pub id: i64,
+    pub name: String,
+    pub slug: String,
+    pub quantity: i32,
+    pub threshold: i32,
+    pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProductExportModel {
+    pub id: i64,
+    pub name: String,
+    pub slug: String,
+    pub price: BigDecimal,
+    pub quantity: i32,
+    pub configurable: bool,
+    pub is_active: bool,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub unpublish_at: Option<DateTime<Utc>>,
+    pub sale_price: Option<BigDecimal>,
+    pub sale_starts_at: Option<DateTime<Utc>>,
+    pub sale_ends_at: Option<DateTime<Utc>>,
+    pub tax_class: String,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
+    pub categories: String,
+}
+
 pub struct AdminProductDummy {
     pub name: String,
 
     pub slug: String,
 
-    pub price: f64,
+    pub price: BigDecimal,
 
     pub quantity: i32,
 
@@ -45,7 +96,7 @@ impl Dummy<Faker> for AdminProductDummy {
         Self {
             name,
             slug: format!("slug-{}", Uuid::new_v4()),
-            price: rng.random_range(10.0..200.0),
+            price: BigDecimal::new(rng.random_range(1000..20000i64).into(), 2),
             quantity: rng.random_range(12..200),
             configurable: false,
             is_active: true,
@@ -1,10 +1,11 @@
 use crate::admin::products::dto::{CreateProductCommand, UpdateProductCommand};
-use crate::admin::products::filters::ProductFilters;
-use crate::admin::products::model::AdminProductModel;
+use crate::admin::products::filters::{LowStockFilters, ProductFilters};
+use crate::admin::products::model::{AdminProductModel, LowStockProductModel, ProductExportModel};
 use crate::errors::error::AppError;
 use crate::utils::pagination::Paginate;
 use crate::utils::traits::IsRepository;
 use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
 
 pub struct AdminProductRepository {
     pool: PgPool,
@@ -23,6 +24,7 @@ impl IsRepository for AdminProductRepository {
 }
 
 impl AdminProductRepository {
+    #[instrument(skip_all)]
     pub async fn index(&self) -> Result<Vec<AdminProductModel>, AppError> {
         sqlx::query_as! {
             AdminProductModel,
@@ -35,8 +37,22 @@ impl AdminProductRepository {
             quantity,
             configurable,
             is_active,
-            created_at
-        FROM products;
+            publish_at,
+            unpublish_at,
+            sale_price,
+            sale_starts_at,
+            sale_ends_at,
+            tax_class,
+            weight_grams,
+            length_mm,
+            width_mm,
+            height_mm,
+            low_stock_threshold,
+            created_at,
+            updated_at,
+            deleted_at
+        FROM products
+        WHERE deleted_at IS NULL;
         "#,
         }
         .fetch_all(&self.pool)
@@ -44,6 +60,7 @@ impl AdminProductRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn index_paginated(
         &self,
         pagination: &Paginate,
@@ -60,11 +77,161 @@ impl AdminProductRepository {
                 products.quantity,
                 products.configurable,
                 products.is_active,
-                products.created_at
+                products.publish_at,
+                products.unpublish_at,
+                products.sale_price,
+                products.sale_starts_at,
+                products.sale_ends_at,
+                products.tax_class,
+                products.weight_grams,
+                products.length_mm,
+                products.width_mm,
+                products.height_mm,
+                products.low_stock_threshold,
+                products.created_at,
+                products.updated_at,
+                products.deleted_at
+            FROM products
+        "#,
+        );
+
+        Self::push_filters(&mut qb, search, filters);
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        let query = qb.build_query_as::<AdminProductModel>();
+
+        query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    /**
+     * Products whose stock is at or below their threshold, emptiest first.
+     * Products without a threshold of their own use `default_threshold`.
+     */
+    #[instrument(skip_all)]
+    pub async fn low_stock_paginated(
+        &self,
+        pagination: &Paginate,
+        filters: &LowStockFilters,
+        default_threshold: i32,
+    ) -> Result<Vec<LowStockProductModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT
+                products.id,
+                products.name,
+                products.slug,
+                products.quantity,
+                COALESCE(products.low_stock_threshold, "#,
+        );
+        qb.push_bind(default_threshold);
+        qb.push(
+            r#") AS threshold,
+                products.is_active,
+                products.updated_at
+            FROM products
+            WHERE products.deleted_at IS NULL
+                AND products.quantity <= COALESCE(products.low_stock_threshold, "#,
+        );
+        qb.push_bind(default_threshold);
+        qb.push(") ");
+
+        // out of stock
+        match filters.out_of_stock {
+            Some(true) => {
+                qb.push(" AND products.quantity <= 0 ");
+            }
+            Some(false) => {
+                qb.push(" AND products.quantity > 0 ");
+            }
+            None => {}
+        }
+
+        qb.push(" ORDER BY products.quantity, products.id ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        qb.build_query_as::<LowStockProductModel>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    /**
+     * One page of the filtered listing for export, walked by id so the whole
+     * catalog is never held in memory. Categories come as `|` separated slugs.
+     */
+    #[instrument(skip_all)]
+    pub async fn export_batch(
+        &self,
+        after_id: i64,
+        limit: i64,
+        search: &Option<String>,
+        filters: &ProductFilters,
+    ) -> Result<Vec<ProductExportModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT
+                products.id,
+                products.name,
+                products.slug,
+                products.price,
+                products.quantity,
+                products.configurable,
+                products.is_active,
+                products.publish_at,
+                products.unpublish_at,
+                products.sale_price,
+                products.sale_starts_at,
+                products.sale_ends_at,
+                products.tax_class,
+                products.weight_grams,
+                products.length_mm,
+                products.width_mm,
+                products.height_mm,
+                COALESCE((
+                    SELECT string_agg(categories.slug, '|' ORDER BY categories.slug)
+                    FROM product_has_categories AS assigned
+                    INNER JOIN categories ON categories.id = assigned.category_id
+                    WHERE assigned.product_id = products.id AND categories.deleted_at IS NULL
+                ), '') AS categories
             FROM products
         "#,
         );
 
+        Self::push_filters(&mut qb, search, filters);
+
+        qb.push(" AND products.id > ");
+        qb.push_bind(after_id);
+        qb.push(" ORDER BY products.id LIMIT ");
+        qb.push_bind(limit);
+
+        qb.build_query_as::<ProductExportModel>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    /**
+     * Shared `WHERE` of the admin listing and export. Always filters on the
+     * trash, so the clause is never empty.
+     */
+    fn push_filters(
+        qb: &mut QueryBuilder<'_, Postgres>,
+        search: &Option<String>,
+        filters: &ProductFilters,
+    ) {
         let mut has_where = false;
 
         // category
@@ -84,6 +251,20 @@ impl AdminProductRepository {
             qb.push_bind(category);
         }
 
+        // trash
+        if has_where {
+            qb.push(" AND ");
+        } else {
+            qb.push(" WHERE ");
+            has_where = true;
+        }
+
+        if filters.trashed {
+            qb.push(" products.deleted_at IS NOT NULL ");
+        } else {
+            qb.push(" products.deleted_at IS NULL ");
+        }
+
         // handle search
         if let Some(search) = search {
             if has_where {
@@ -130,7 +311,7 @@ impl AdminProductRepository {
         }
 
         // min price
-        if let Some(min_price) = filters.price_min {
+        if let Some(min_price) = &filters.price_min {
             if has_where {
                 qb.push(" AND ");
             } else {
@@ -139,11 +320,11 @@ impl AdminProductRepository {
             }
 
             qb.push(" products.price >= ");
-            qb.push_bind(min_price);
+            qb.push_bind(min_price.clone());
         }
 
         // max price
-        if let Some(max_price) = filters.price_max {
+        if let Some(max_price) = &filters.price_max {
             if has_where {
                 qb.push(" AND ");
             } else {
@@ -152,24 +333,86 @@ impl AdminProductRepository {
             }
 
             qb.push(" products.price <= ");
-            qb.push_bind(max_price);
+            qb.push_bind(max_price.clone());
         }
+    }
 
-        // handle pagination
-        qb.push(" LIMIT ");
-        qb.push_bind(pagination.limit);
-        qb.push(" OFFSET ");
-        qb.push_bind(pagination.get_offset());
-
-        let query = qb.build_query_as::<AdminProductModel>();
+    #[instrument(skip_all)]
+    pub async fn show(&self, id: i64) -> Result<Option<AdminProductModel>, AppError> {
+        sqlx::query_as! {
+            AdminProductModel,
+            r#"
+        SELECT
+            id,
+            name,
+            slug,
+            price,
+            quantity,
+            configurable,
+            is_active,
+            publish_at,
+            unpublish_at,
+            sale_price,
+            sale_starts_at,
+            sale_ends_at,
+            tax_class,
+            weight_grams,
+            length_mm,
+            width_mm,
+            height_mm,
+            low_stock_threshold,
+            created_at,
+            updated_at,
+            deleted_at
+        FROM products
+        WHERE id = $1 AND deleted_at IS NULL;
+        "#,
+            id,
+        }
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
 
-        query
-            .fetch_all(&self.pool)
-            .await
-            .map_err(AppError::Database)
+    #[instrument(skip_all)]
+    pub async fn get_by_slugs(&self, slugs: &[String]) -> Result<Vec<AdminProductModel>, AppError> {
+        sqlx::query_as! {
+            AdminProductModel,
+            r#"
+        SELECT
+            id,
+            name,
+            slug,
+            price,
+            quantity,
+            configurable,
+            is_active,
+            publish_at,
+            unpublish_at,
+            sale_price,
+            sale_starts_at,
+            sale_ends_at,
+            tax_class,
+            weight_grams,
+            length_mm,
+            width_mm,
+            height_mm,
+            low_stock_threshold,
+            created_at,
+            updated_at,
+            deleted_at
+        FROM products
+        WHERE slug = ANY($1) AND deleted_at IS NULL;
+        "#,
+            slugs,
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
     }
 
-    pub async fn show(&self, id: i64) -> Result<Option<AdminProductModel>, AppError> {
+    #[instrument(skip_all)]
+    pub async fn show_trashed(&self, id: i64) -> Result<Option<AdminProductModel>, AppError> {
         sqlx::query_as! {
             AdminProductModel,
             r#"
@@ -181,9 +424,22 @@ impl AdminProductRepository {
             quantity,
             configurable,
             is_active,
-            created_at
+            publish_at,
+            unpublish_at,
+            sale_price,
+            sale_starts_at,
+            sale_ends_at,
+            tax_class,
+            weight_grams,
+            length_mm,
+            width_mm,
+            height_mm,
+            low_stock_threshold,
+            created_at,
+            updated_at,
+            deleted_at
         FROM products
-        WHERE id = $1;
+        WHERE id = $1 AND deleted_at IS NOT NULL;
         "#,
             id,
         }
@@ -192,6 +448,11 @@ impl AdminProductRepository {
         .map_err(AppError::Database)
     }
 
+    /**
+     * Inserts a product without stock, `cmd.quantity` goes in through the
+     * stock ledger.
+     */
+    #[instrument(skip_all)]
     pub async fn create(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
@@ -200,31 +461,81 @@ impl AdminProductRepository {
         sqlx::query_as! {
             AdminProductModel,
             r#"
-        INSERT INTO products (name, slug, price, quantity, configurable, is_active)
-        VALUES ($1, $2, $3, $4, $5, $6)
-        RETURNING *;
+        INSERT INTO products (name, slug, price, configurable, is_active, publish_at, unpublish_at, sale_price, sale_starts_at, sale_ends_at, tax_class, weight_grams, length_mm, width_mm, height_mm)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        RETURNING id, name, slug, price, quantity, configurable, is_active, publish_at, unpublish_at, sale_price, sale_starts_at, sale_ends_at, tax_class, weight_grams, length_mm, width_mm, height_mm, low_stock_threshold, created_at, updated_at, deleted_at;
         "#,
-            cmd.name, cmd.slug, cmd.price, cmd.quantity, cmd.configurable, cmd.is_active
+            cmd.name, cmd.slug, cmd.price, cmd.configurable, cmd.is_active, cmd.publish_at, cmd.unpublish_at, cmd.sale_price, cmd.sale_starts_at, cmd.sale_ends_at, cmd.tax_class, cmd.weight_grams, cmd.length_mm, cmd.width_mm, cmd.height_mm
         }
         .fetch_one(executor)
         .await
         .map_err(AppError::Database)
     }
 
+    /**
+     * Updates everything but the stock, `cmd.quantity` goes in through the
+     * stock ledger.
+     */
+    #[instrument(skip_all)]
     pub async fn update(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
-        cmd: UpdateProductCommand,
+        cmd: &UpdateProductCommand,
         id: i64,
-    ) -> Result<u64, AppError> {
-        let result = sqlx::query_as! {
+    ) -> Result<AdminProductModel, AppError> {
+        sqlx::query_as! {
+            AdminProductModel,
+            r#"
+        UPDATE products
+        SET (name, slug, price, configurable, is_active, publish_at, unpublish_at, sale_price, sale_starts_at, sale_ends_at, tax_class, weight_grams, length_mm, width_mm, height_mm)
+            = ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        WHERE id = $16 AND deleted_at IS NULL
+        RETURNING id, name, slug, price, quantity, configurable, is_active, publish_at, unpublish_at, sale_price, sale_starts_at, sale_ends_at, tax_class, weight_grams, length_mm, width_mm, height_mm, low_stock_threshold, created_at, updated_at, deleted_at;
+        "#,
+            cmd.name, cmd.slug, cmd.price, cmd.configurable, cmd.is_active, cmd.publish_at, cmd.unpublish_at, cmd.sale_price, cmd.sale_starts_at, cmd.sale_ends_at, cmd.tax_class, cmd.weight_grams, cmd.length_mm, cmd.width_mm, cmd.height_mm, id
+        }
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)?
+        .ok_or_else(|| AppError::NotFound("Product not found".to_string()))
+    }
+
+    /** Sets the low stock threshold alone, `None` falls back to the default. */
+    #[instrument(skip_all)]
+    pub async fn set_low_stock_threshold(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        threshold: Option<i32>,
+        id: i64,
+    ) -> Result<AdminProductModel, AppError> {
+        sqlx::query_as! {
             AdminProductModel,
             r#"
         UPDATE products
-        SET (name, slug, price, quantity, configurable, is_active) = ($1, $2, $3, $4, $5, $6)
-        WHERE id = $7;
+        SET low_stock_threshold = $1
+        WHERE id = $2 AND deleted_at IS NULL
+        RETURNING id, name, slug, price, quantity, configurable, is_active, publish_at, unpublish_at, sale_price, sale_starts_at, sale_ends_at, tax_class, weight_grams, length_mm, width_mm, height_mm, low_stock_threshold, created_at, updated_at, deleted_at;
         "#,
-            cmd.name, cmd.slug, cmd.price, cmd.quantity, cmd.configurable, cmd.is_active, id
+            threshold, id
+        }
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)?
+        .ok_or_else(|| AppError::NotFound("Product not found".to_string()))
+    }
+
+    /**
+     * Moves a product to the trash. Its carts, reviews and media are kept.
+     */
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "UPDATE products SET deleted_at = now() WHERE id = $1 AND deleted_at IS NULL;",
+            id
         }
         .execute(executor)
         .await
@@ -233,13 +544,14 @@ impl AdminProductRepository {
         Ok(result.rows_affected())
     }
 
-    pub async fn delete(
+    #[instrument(skip_all)]
+    pub async fn restore(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
         id: i64,
     ) -> Result<u64, AppError> {
         let result = sqlx::query! {
-            "DELETE FROM products WHERE id = $1;",
+            "UPDATE products SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL;",
             id
         }
         .execute(executor)
@@ -249,11 +561,48 @@ impl AdminProductRepository {
         Ok(result.rows_affected())
     }
 
+    /**
+     * Permanently deletes a trashed product, cascading to everything that
+     * references it.
+     */
+    #[instrument(skip_all)]
+    pub async fn purge(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM products WHERE id = $1 AND deleted_at IS NOT NULL;",
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn check_existence_by_id(&self, id: i64) -> Result<bool, AppError> {
+        sqlx::query_scalar! {
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM products WHERE id = $1 AND deleted_at IS NULL
+            ) AS "exists!";
+            "#,
+            id,
+        }
+        .fetch_one(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
     pub async fn check_existence_by_slug(&self, slug: &str) -> Result<bool, AppError> {
         sqlx::query_scalar! {
             r#"
             SELECT EXISTS (
-                SELECT 1 FROM products WHERE slug = $1
+                SELECT 1 FROM products WHERE slug = $1 AND deleted_at IS NULL
             ) AS "exists!";
             "#,
             slug,
@@ -263,6 +612,7 @@ impl AdminProductRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn detach_product_from_all_categories(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
@@ -281,6 +631,7 @@ impl AdminProductRepository {
         Ok(result.rows_affected())
     }
 
+    #[instrument(skip_all)]
     pub async fn attach_product_to_category(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
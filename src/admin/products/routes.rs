@@ -1,6 +1,7 @@
 use super::handler;
 use crate::admin::products::images::routes::routes as images_routes;
 use crate::admin::products::permission::ProductScope;
+use crate::admin::products::transfer::routes::routes as transfer_routes;
 use crate::admin::products::videos::routes::routes as videos_routes;
 use crate::middlewares::auth::AuthMiddleware;
 use actix_web::web;
@@ -12,6 +13,7 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
         web::scope("/products")
             .configure(images_routes)
             .configure(videos_routes)
+            .configure(transfer_routes)
             .service(
                 resource("/list")
                     .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::List))))
@@ -36,6 +38,31 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
                 resource("/delete/{id}")
                     .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::Delete))))
                     .route(delete().to(handler::delete)),
+            )
+            .service(
+                resource("/trash")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::List))))
+                    .route(get().to(handler::trash)),
+            )
+            .service(
+                resource("/restore/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::Delete))))
+                    .route(put().to(handler::restore)),
+            )
+            .service(
+                resource("/purge/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::Delete))))
+                    .route(delete().to(handler::purge)),
+            )
+            .service(
+                resource("/low-stock")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::List))))
+                    .route(get().to(handler::low_stock)),
+            )
+            .service(
+                resource("/low-stock/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::Update))))
+                    .route(put().to(handler::low_stock_threshold)),
             ),
     );
 }
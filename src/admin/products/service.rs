@@ -1,36 +1,74 @@
 use super::model::AdminProductModel;
+use crate::admin::audit::dto::{AuditAction, AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
 use crate::admin::categories::repository::AdminCategoryRepository;
-use crate::admin::products::dto::{AdminPublicProduct, CreateProductCommand, UpdateProductCommand};
-use crate::admin::products::filters::ProductFilters;
+use crate::admin::products::dto::{
+    AdminPublicLowStockProduct, AdminPublicProduct, CreateProductCommand, UpdateProductCommand,
+};
+use crate::admin::products::filters::{LowStockFilters, ProductFilters};
 use crate::admin::products::images::repository::AdminProductImageRepository;
 use crate::admin::products::images::traits::IntoPublic as ProductImageIntoPublic;
 use crate::admin::products::repository::AdminProductRepository;
 use crate::admin::products::traits::IntoPublic;
 use crate::admin::products::videos::repository::AdminProductVideoRepository;
 use crate::admin::products::videos::traits::IntoPublic as ProductVideoIntoPublic;
+use crate::admin::stock::repository::AdminStockRepository;
+use crate::app::products::cache::ProductCache;
+use crate::app::stock_subscriptions::alerts::StockSubscriptionAlerts;
+use crate::app::wishlists::alerts::WishlistAlerts;
 use crate::errors::error::AppError;
+use crate::notifications::notifier::{LogNotifier, Notifier};
 use crate::utils::pagination::{Paginate, PaginatedDataCollection};
-use crate::utils::traits::IsRepository;
+use crate::utils::storage::LocalStorage;
+use crate::utils::traits::{IsRepository, UseStorage};
 use crate::utils::validation_utils::validate_slug;
 use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::warn;
 
 pub struct AdminProductService {
     repository: AdminProductRepository,
     category_repository: AdminCategoryRepository,
     product_image_repository: AdminProductImageRepository,
     product_video_repository: AdminProductVideoRepository,
+    audit_repository: AdminAuditRepository,
+    stock_repository: AdminStockRepository,
+    product_cache: ProductCache,
+    wishlist_alerts: WishlistAlerts,
+    stock_subscription_alerts: StockSubscriptionAlerts,
 }
 
 impl AdminProductService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, product_cache: ProductCache) -> Self {
+        Self::with_notifier(pool, product_cache, Arc::new(LogNotifier))
+    }
+
+    pub fn with_notifier(
+        pool: PgPool,
+        product_cache: ProductCache,
+        notifier: Arc<dyn Notifier>,
+    ) -> Self {
         Self {
             repository: AdminProductRepository::new(pool.clone()),
             category_repository: AdminCategoryRepository::new(pool.clone()),
             product_image_repository: AdminProductImageRepository::new(pool.clone()),
-            product_video_repository: AdminProductVideoRepository::new(pool),
+            product_video_repository: AdminProductVideoRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool.clone()),
+            stock_repository: AdminStockRepository::new(pool.clone()),
+            product_cache,
+            wishlist_alerts: WishlistAlerts::with_notifier(pool.clone(), notifier.clone()),
+            stock_subscription_alerts: StockSubscriptionAlerts::with_notifier(pool, notifier),
         }
     }
 
+    /**
+     * Drops the cached public pages of a product, called after anything shown
+     * on them changes.
+     */
+    pub fn invalidate_cached_pages(&self, id: i64) {
+        self.product_cache.invalidate_product(id);
+    }
+
     pub async fn get_all_paginated(
         &self,
         pagination: &Paginate,
@@ -100,7 +138,11 @@ impl AdminProductService {
         Ok(product.into_public_with_media(images, videos))
     }
 
-    pub async fn create(&self, cmd: CreateProductCommand) -> Result<AdminProductModel, AppError> {
+    pub async fn create(
+        &self,
+        cmd: CreateProductCommand,
+        actor_id: i64,
+    ) -> Result<AdminProductModel, AppError> {
         validate_slug(&cmd.slug)?;
 
         let product_already_exists = self.check_exist_with_same_slug(&cmd.slug).await?;
@@ -113,7 +155,19 @@ impl AdminProductService {
 
         let mut tx = self.repository.start_transaction().await?;
 
-        let product = self.repository.create(&mut *tx, &cmd).await?;
+        let mut product = self.repository.create(&mut *tx, &cmd).await?;
+
+        product.quantity = self
+            .stock_repository
+            .adjust_to(
+                &mut *tx,
+                product.id,
+                0,
+                cmd.quantity,
+                "initial stock",
+                Some(actor_id),
+            )
+            .await?;
 
         if let Some(categories) = &cmd.categories {
             for category_id in categories {
@@ -135,15 +189,32 @@ impl AdminProductService {
             }
         }
 
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(
+                    actor_id,
+                    AuditEntity::Product,
+                    product.id,
+                    &product,
+                ),
+            )
+            .await?;
+
         self.repository.commit_transaction(tx).await?;
 
         Ok(product)
     }
 
-    pub async fn update(&self, cmd: UpdateProductCommand, id: i64) -> Result<(), AppError> {
+    pub async fn update(
+        &self,
+        cmd: UpdateProductCommand,
+        id: i64,
+        actor_id: i64,
+    ) -> Result<(), AppError> {
         validate_slug(&cmd.slug)?;
 
-        self.get_one(id).await?;
+        let before = self.get_one(id).await?;
 
         let product_already_exists = self.check_exist_with_same_slug(&cmd.slug).await?;
 
@@ -155,6 +226,8 @@ impl AdminProductService {
 
         let mut tx = self.repository.start_transaction().await?;
 
+        let snapshot_before = self.wishlist_alerts.snapshot(&mut *tx, id).await?;
+
         if let Some(categories) = &cmd.categories {
             self.repository
                 .detach_product_from_all_categories(&mut *tx, id)
@@ -179,14 +252,253 @@ impl AdminProductService {
             }
         }
 
-        self.repository.update(&mut *tx, cmd, id).await?;
+        let mut after = self.repository.update(&mut *tx, &cmd, id).await?;
+
+        after.quantity = self
+            .stock_repository
+            .adjust_to(
+                &mut *tx,
+                id,
+                after.quantity,
+                cmd.quantity,
+                "product update",
+                Some(actor_id),
+            )
+            .await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::Product,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        let snapshot_after = self.wishlist_alerts.snapshot(&mut *tx, id).await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.invalidate_cached_pages(id);
+
+        self.wishlist_alerts
+            .product_changed_or_warn(&snapshot_before, &snapshot_after)
+            .await;
+
+        self.stock_subscription_alerts
+            .product_changed_or_warn(&snapshot_before, &snapshot_after)
+            .await;
+
+        Ok(())
+    }
+
+    /** Sets or clears the product's own low stock threshold. */
+    pub async fn set_low_stock_threshold(
+        &self,
+        id: i64,
+        threshold: Option<i32>,
+        actor_id: i64,
+    ) -> Result<AdminProductModel, AppError> {
+        let before = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let after = self
+            .repository
+            .set_low_stock_threshold(&mut *tx, threshold, id)
+            .await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::Product,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
 
-        self.repository.commit_transaction(tx).await
+        Ok(after)
     }
 
-    pub async fn delete(&self, id: i64) -> Result<u64, AppError> {
-        self.get_one(id).await?;
-        self.repository.delete(self.repository.get_pool(), id).await
+    /** The low stock report, see `AdminProductRepository::low_stock_paginated`. */
+    pub async fn get_low_stock_public(
+        &self,
+        pagination: &Paginate,
+        filters: &LowStockFilters,
+        default_threshold: i32,
+    ) -> Result<PaginatedDataCollection<AdminPublicLowStockProduct>, AppError> {
+        let data = self
+            .repository
+            .low_stock_paginated(pagination, filters, default_threshold)
+            .await?
+            .into_iter()
+            .map(AdminPublicLowStockProduct::from)
+            .collect();
+
+        Ok(PaginatedDataCollection::new(data, pagination.clone()))
+    }
+
+    pub async fn delete(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let product = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(actor_id, AuditEntity::Product, id, &product),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.invalidate_cached_pages(id);
+
+        Ok(deleted)
+    }
+
+    /**
+     * Trashed products, listed with the same filters and media as the live ones.
+     */
+    pub async fn get_trashed_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &ProductFilters,
+        search: &Option<String>,
+    ) -> Result<PaginatedDataCollection<AdminPublicProduct>, AppError> {
+        let filters = ProductFilters {
+            trashed: true,
+            ..filters.clone()
+        };
+
+        self.get_all_paginated_public(pagination, &filters, search)
+            .await
+    }
+
+    pub async fn get_one_trashed(&self, id: i64) -> Result<AdminProductModel, AppError> {
+        let product = self.repository.show_trashed(id).await?;
+
+        match product {
+            Some(product) => Ok(product),
+            None => Err(AppError::NotFound("Trashed product not found".to_string())),
+        }
+    }
+
+    /**
+     * Brings a product back from the trash. Fails with a conflict when its
+     * slug was taken by another product in the meantime. A product that comes
+     * back with stock is a restock for the alerts.
+     */
+    pub async fn restore(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let product = self.get_one_trashed(id).await?;
+
+        if self.check_exist_with_same_slug(&product.slug).await? {
+            return Err(AppError::Conflict(
+                "Product with the same slug already exists".to_string(),
+            ));
+        }
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let snapshot_before = self.wishlist_alerts.snapshot(&mut *tx, id).await?;
+
+        let restored = self.repository.restore(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::new(
+                    actor_id,
+                    AuditEntity::Product,
+                    id,
+                    AuditAction::Restore,
+                    Some(&product),
+                    Some(&AdminProductModel {
+                        deleted_at: None,
+                        ..product.clone()
+                    }),
+                ),
+            )
+            .await?;
+
+        let snapshot_after = self.wishlist_alerts.snapshot(&mut *tx, id).await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.invalidate_cached_pages(id);
+
+        self.wishlist_alerts
+            .product_changed_or_warn(&snapshot_before, &snapshot_after)
+            .await;
+
+        self.stock_subscription_alerts
+            .product_changed_or_warn(&snapshot_before, &snapshot_after)
+            .await;
+
+        Ok(restored)
+    }
+
+    /**
+     * Permanently deletes a trashed product and its media files. Files that
+     * can't be removed are logged and left behind, the rows go regardless.
+     */
+    pub async fn purge(
+        &self,
+        id: i64,
+        storage: &LocalStorage,
+        actor_id: i64,
+    ) -> Result<u64, AppError> {
+        let product = self.get_one_trashed(id).await?;
+
+        let images = self.product_image_repository.get_all_by_product(id).await?;
+        let videos = self.product_video_repository.get_all_by_product(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let purged = self.repository.purge(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::new(
+                    actor_id,
+                    AuditEntity::Product,
+                    id,
+                    AuditAction::Purge,
+                    Some(&product),
+                    None,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        let urls = images
+            .iter()
+            .map(|image| image.url.as_str())
+            .chain(videos.iter().map(|video| video.url.as_str()));
+
+        for url in urls {
+            if let Err(error) = storage.delete(url).await {
+                warn!(product_id = id, url, %error, "failed to delete media of purged product");
+            }
+        }
+
+        self.invalidate_cached_pages(id);
+
+        Ok(purged)
     }
 
     pub async fn check_exist_with_same_slug(&self, name: &str) -> Result<bool, AppError> {
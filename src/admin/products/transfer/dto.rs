@@ -0,0 +1,274 @@
+use crate::admin::products::filters::ProductFilters;
+use crate::admin::products::model::ProductExportModel;
+use crate::errors::error::AppError;
+use crate::utils::money::{deserialize_amount, validate_amount};
+use actix_multipart::form::MultipartForm;
+use actix_multipart::form::tempfile::TempFile;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferFormat {
+    Csv,
+    Json,
+}
+
+impl TransferFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            TransferFormat::Csv => "text/csv; charset=utf-8",
+            TransferFormat::Json => "application/json",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TransferFormat::Csv => "csv",
+            TransferFormat::Json => "json",
+        }
+    }
+}
+
+/**
+ * `create` rejects rows whose slug already exists, `upsert` updates them.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportMode {
+    #[default]
+    Create,
+    Upsert,
+}
+
+/**
+ * One product in an import or export file. Categories are referenced by slug;
+ * leaving them out on an upsert keeps the current assignment.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ProductTransferRow {
+    pub name: Option<String>,
+    pub slug: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    #[schema(value_type = Option<String>)]
+    pub price: Option<BigDecimal>,
+    pub quantity: Option<i32>,
+    pub configurable: Option<bool>,
+    pub is_active: Option<bool>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub unpublish_at: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    #[schema(value_type = Option<String>)]
+    pub sale_price: Option<BigDecimal>,
+    pub sale_starts_at: Option<DateTime<Utc>>,
+    pub sale_ends_at: Option<DateTime<Utc>>,
+    pub tax_class: Option<String>,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
+    pub categories: Option<Vec<String>>,
+}
+
+impl From<ProductExportModel> for ProductTransferRow {
+    fn from(product: ProductExportModel) -> Self {
+        Self {
+            name: Some(product.name),
+            slug: Some(product.slug),
+            price: Some(product.price),
+            quantity: Some(product.quantity),
+            configurable: Some(product.configurable),
+            is_active: Some(product.is_active),
+            publish_at: product.publish_at,
+            unpublish_at: product.unpublish_at,
+            sale_price: product.sale_price,
+            sale_starts_at: product.sale_starts_at,
+            sale_ends_at: product.sale_ends_at,
+            tax_class: Some(product.tax_class),
+            weight_grams: product.weight_grams,
+            length_mm: product.length_mm,
+            width_mm: product.width_mm,
+            height_mm: product.height_mm,
+            categories: Some(split_categories(&product.categories)),
+        }
+    }
+}
+
+/**
+ * Csv has no lists, categories are a single `|` separated column there.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProductCsvRow {
+    pub name: Option<String>,
+    pub slug: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub price: Option<BigDecimal>,
+    pub quantity: Option<i32>,
+    pub configurable: Option<bool>,
+    pub is_active: Option<bool>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub unpublish_at: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub sale_price: Option<BigDecimal>,
+    pub sale_starts_at: Option<DateTime<Utc>>,
+    pub sale_ends_at: Option<DateTime<Utc>>,
+    pub tax_class: Option<String>,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
+    pub categories: Option<String>,
+}
+
+impl ProductCsvRow {
+    pub const HEADERS: [&'static str; 17] = [
+        "name",
+        "slug",
+        "price",
+        "quantity",
+        "configurable",
+        "is_active",
+        "publish_at",
+        "unpublish_at",
+        "sale_price",
+        "sale_starts_at",
+        "sale_ends_at",
+        "tax_class",
+        "weight_grams",
+        "length_mm",
+        "width_mm",
+        "height_mm",
+        "categories",
+    ];
+}
+
+impl From<ProductCsvRow> for ProductTransferRow {
+    fn from(row: ProductCsvRow) -> Self {
+        Self {
+            name: row.name,
+            slug: row.slug,
+            price: row.price,
+            quantity: row.quantity,
+            configurable: row.configurable,
+            is_active: row.is_active,
+            publish_at: row.publish_at,
+            unpublish_at: row.unpublish_at,
+            sale_price: row.sale_price,
+            sale_starts_at: row.sale_starts_at,
+            sale_ends_at: row.sale_ends_at,
+            tax_class: row.tax_class,
+            weight_grams: row.weight_grams,
+            length_mm: row.length_mm,
+            width_mm: row.width_mm,
+            height_mm: row.height_mm,
+            categories: row.categories.as_deref().map(split_categories),
+        }
+    }
+}
+
+impl From<ProductExportModel> for ProductCsvRow {
+    fn from(product: ProductExportModel) -> Self {
+        Self {
+            name: Some(product.name),
+            slug: Some(product.slug),
+            price: Some(product.price),
+            quantity: Some(product.quantity),
+            configurable: Some(product.configurable),
+            is_active: Some(product.is_active),
+            publish_at: product.publish_at,
+            unpublish_at: product.unpublish_at,
+            sale_price: product.sale_price,
+            sale_starts_at: product.sale_starts_at,
+            sale_ends_at: product.sale_ends_at,
+            tax_class: Some(product.tax_class),
+            weight_grams: product.weight_grams,
+            length_mm: product.length_mm,
+            width_mm: product.width_mm,
+            height_mm: product.height_mm,
+            categories: Some(product.categories),
+        }
+    }
+}
+
+fn split_categories(categories: &str) -> Vec<String> {
+    categories
+        .split('|')
+        .map(str::trim)
+        .filter(|slug| !slug.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[derive(MultipartForm, ToSchema)]
+pub struct ImportProductsDTO {
+    #[schema(value_type = String, format = Binary)]
+    pub file: TempFile,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportProductsQuery {
+    #[validate(required)]
+    #[param(required = true)]
+    pub format: Option<TransferFormat>,
+
+    /// `create` (default) or `upsert`.
+    pub mode: Option<ImportMode>,
+
+    /// Validate and run the import without keeping any of it.
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub mode: ImportMode,
+    pub created: usize,
+    pub updated: usize,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportProductsDTO {
+    #[validate(required)]
+    #[param(required = true)]
+    pub format: Option<TransferFormat>,
+
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub search: Option<String>,
+
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
+    pub category: Option<i64>,
+
+    #[validate(custom(function = "validate_amount"))]
+    #[param(value_type = Option<String>)]
+    pub price_min: Option<BigDecimal>,
+
+    #[validate(custom(function = "validate_amount"))]
+    #[param(value_type = Option<String>)]
+    pub price_max: Option<BigDecimal>,
+
+    pub in_stock: Option<bool>,
+
+    pub is_active: Option<bool>,
+}
+
+impl TryFrom<ExportProductsDTO> for ProductFilters {
+    type Error = AppError;
+
+    fn try_from(dto: ExportProductsDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            category: dto.category,
+            price_min: dto.price_min,
+            price_max: dto.price_max,
+            in_stock: dto.in_stock,
+            is_active: dto.is_active,
+            trashed: false,
+        })
+    }
+}
@@ -0,0 +1,113 @@
+use crate::admin::products::model::ProductExportModel;
+use crate::admin::products::transfer::dto::{ProductCsvRow, ProductTransferRow, TransferFormat};
+use crate::errors::error::AppError;
+use serde_json::Value;
+
+/**
+ * Splits an uploaded file into rows. A file that can't be read at all is a
+ * `file` error, a single malformed row only fails that row.
+ */
+pub fn parse_rows(
+    format: TransferFormat,
+    file: &[u8],
+) -> Result<Vec<Result<ProductTransferRow, String>>, AppError> {
+    match format {
+        TransferFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(file);
+
+            let headers = reader
+                .headers()
+                .map_err(|err| AppError::field("file", "invalid_file", &err.to_string()))?;
+
+            if !headers.iter().any(|header| header == "slug") {
+                return Err(AppError::field(
+                    "file",
+                    "invalid_file",
+                    "must have a header row with a slug column",
+                ));
+            }
+
+            Ok(reader
+                .deserialize::<ProductCsvRow>()
+                .map(|row| row.map(Into::into).map_err(|err| err.to_string()))
+                .collect())
+        }
+        TransferFormat::Json => {
+            let rows: Vec<Value> = serde_json::from_slice(file).map_err(|_| {
+                AppError::field("file", "invalid_file", "must be a json array of products")
+            })?;
+
+            Ok(rows
+                .into_iter()
+                .map(|row| serde_json::from_value(row).map_err(|err| err.to_string()))
+                .collect())
+        }
+    }
+}
+
+pub fn header(format: TransferFormat) -> Result<Vec<u8>, AppError> {
+    match format {
+        TransferFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer
+                .write_record(ProductCsvRow::HEADERS)
+                .map_err(|err| AppError::Internal(err.to_string()))?;
+
+            writer
+                .into_inner()
+                .map_err(|err| AppError::Internal(err.to_string()))
+        }
+        TransferFormat::Json => Ok(b"[".to_vec()),
+    }
+}
+
+/**
+ * Encodes one batch of the export. `written` is the number of rows already
+ * sent, json needs it to know where the separators go.
+ */
+pub fn rows(
+    format: TransferFormat,
+    products: Vec<ProductExportModel>,
+    written: usize,
+) -> Result<Vec<u8>, AppError> {
+    match format {
+        TransferFormat::Csv => {
+            let mut writer = csv::WriterBuilder::new()
+                .has_headers(false)
+                .from_writer(Vec::new());
+
+            for product in products {
+                writer
+                    .serialize(ProductCsvRow::from(product))
+                    .map_err(|err| AppError::Internal(err.to_string()))?;
+            }
+
+            writer
+                .into_inner()
+                .map_err(|err| AppError::Internal(err.to_string()))
+        }
+        TransferFormat::Json => {
+            let mut out = Vec::new();
+
+            for (index, product) in products.into_iter().enumerate() {
+                if written + index > 0 {
+                    out.push(b',');
+                }
+
+                serde_json::to_writer(&mut out, &ProductTransferRow::from(product))
+                    .map_err(|err| AppError::Internal(err.to_string()))?;
+            }
+
+            Ok(out)
+        }
+    }
+}
+
+pub fn footer(format: TransferFormat) -> Vec<u8> {
+    match format {
+        TransferFormat::Csv => Vec::new(),
+        TransferFormat::Json => b"]".to_vec(),
+    }
+}
@@ -0,0 +1,104 @@
+use crate::admin::products::filters::ProductFilters;
+use crate::admin::products::transfer::dto::{
+    ExportProductsDTO, ImportProductsDTO, ImportProductsQuery, ImportReport, ProductTransferRow,
+};
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use actix_multipart::form::MultipartForm;
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tokio::fs;
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/admin/products/import",
+    tag = "admin-products",
+    params(ImportProductsQuery),
+    request_body(content = ImportProductsDTO, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<ImportReport>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed, errors are keyed by row", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:create"])),
+)]
+#[instrument(skip_all)]
+pub async fn import(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    query: web::Query<ImportProductsQuery>,
+    form: MultipartForm<ImportProductsDTO>,
+) -> Result<impl Responder, AppError> {
+    query.validate()?;
+
+    let actor_id = extract_auth_user_id(&request)?;
+
+    let bytes = fs::read(form.file.file.path())
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+
+    let report = state
+        .admin_product_transfer_service
+        .import(
+            &bytes,
+            query.format.unwrap(),
+            query.mode.unwrap_or_default(),
+            query.dry_run.unwrap_or(false),
+            actor_id,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(report)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/products/export",
+    tag = "admin-products",
+    params(ExportProductsDTO),
+    responses(
+        (status = 200, description = "Products file", content(
+            (String = "text/csv"),
+            (Vec<ProductTransferRow> = "application/json"),
+        )),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn export(
+    state: web::Data<AppState>,
+    query: web::Query<ExportProductsDTO>,
+) -> Result<impl Responder, AppError> {
+    query.validate()?;
+
+    let query = query.into_inner();
+    let format = query.format.unwrap();
+    let search = query.search.clone();
+    let filters = ProductFilters::try_from(query)?;
+
+    let body = state
+        .admin_product_transfer_service
+        .export(format, search, filters);
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!(
+                "products.{}",
+                format.extension()
+            ))],
+        })
+        .streaming(body))
+}
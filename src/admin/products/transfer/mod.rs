@@ -0,0 +1,5 @@
+pub mod dto;
+mod format;
+pub mod handler;
+pub mod routes;
+pub mod service;
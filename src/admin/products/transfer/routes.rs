@@ -0,0 +1,19 @@
+use crate::admin::products::permission::ProductScope;
+use crate::admin::products::transfer::handler;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{get, post, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        resource("/import")
+            .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::Create))))
+            .route(post().to(handler::import)),
+    )
+    .service(
+        resource("/export")
+            .wrap(AuthMiddleware::new(Some(Arc::new(ProductScope::List))))
+            .route(get().to(handler::export)),
+    );
+}
@@ -0,0 +1,452 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
+use crate::admin::categories::repository::AdminCategoryRepository;
+use crate::admin::products::dto::{CreateProductCommand, CreateProductDTO, UpdateProductCommand};
+use crate::admin::products::filters::ProductFilters;
+use crate::admin::products::model::AdminProductModel;
+use crate::admin::products::repository::AdminProductRepository;
+use crate::admin::products::transfer::dto::{
+    ImportMode, ImportReport, ProductTransferRow, TransferFormat,
+};
+use crate::admin::products::transfer::format;
+use crate::admin::stock::repository::AdminStockRepository;
+use crate::app::products::cache::ProductCache;
+use crate::app::stock_subscriptions::alerts::StockSubscriptionAlerts;
+use crate::app::wishlists::alerts::WishlistAlerts;
+use crate::errors::error::AppError;
+use crate::notifications::notifier::Notifier;
+use crate::responses::error_responses::FieldError;
+use crate::utils::traits::IsRepository;
+use crate::utils::validation_utils::validate_slug;
+use actix_web::web::Bytes;
+use futures_util::Stream;
+use futures_util::stream;
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use validator::Validate;
+
+pub const MAX_IMPORT_ROWS: usize = 5000;
+
+const EXPORT_BATCH_SIZE: i64 = 500;
+
+/**
+ * A row that passed validation, waiting for its categories and slug to be
+ * checked against the database.
+ */
+struct ImportRow {
+    index: usize,
+    cmd: CreateProductCommand,
+    categories: Option<Vec<String>>,
+}
+
+enum ExportStage {
+    Header,
+    Rows,
+    Done,
+}
+
+struct ExportCursor {
+    repository: AdminProductRepository,
+    format: TransferFormat,
+    search: Option<String>,
+    filters: ProductFilters,
+    after_id: i64,
+    written: usize,
+    stage: ExportStage,
+}
+
+pub struct AdminProductTransferService {
+    pool: PgPool,
+    repository: AdminProductRepository,
+    category_repository: AdminCategoryRepository,
+    audit_repository: AdminAuditRepository,
+    stock_repository: AdminStockRepository,
+    product_cache: ProductCache,
+    wishlist_alerts: WishlistAlerts,
+    stock_subscription_alerts: StockSubscriptionAlerts,
+}
+
+impl AdminProductTransferService {
+    pub fn with_notifier(
+        pool: PgPool,
+        product_cache: ProductCache,
+        notifier: Arc<dyn Notifier>,
+    ) -> Self {
+        Self {
+            repository: AdminProductRepository::new(pool.clone()),
+            category_repository: AdminCategoryRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool.clone()),
+            stock_repository: AdminStockRepository::new(pool.clone()),
+            wishlist_alerts: WishlistAlerts::with_notifier(pool.clone(), notifier.clone()),
+            stock_subscription_alerts: StockSubscriptionAlerts::with_notifier(
+                pool.clone(),
+                notifier,
+            ),
+            pool,
+            product_cache,
+        }
+    }
+
+    /**
+     * All or nothing: every row is validated first and any error rejects the
+     * whole file with one error per offending field, keyed `rows[<index>]`.
+     * A dry run goes through the same writes and rolls them back.
+     */
+    pub async fn import(
+        &self,
+        file: &[u8],
+        format: TransferFormat,
+        mode: ImportMode,
+        dry_run: bool,
+        actor_id: i64,
+    ) -> Result<ImportReport, AppError> {
+        let rows = format::parse_rows(format, file)?;
+
+        if rows.is_empty() {
+            return Err(AppError::field(
+                "file",
+                "empty",
+                "must contain at least one row",
+            ));
+        }
+
+        if rows.len() > MAX_IMPORT_ROWS {
+            return Err(AppError::field(
+                "file",
+                "too_many_rows",
+                &format!("must contain at most {} rows", MAX_IMPORT_ROWS),
+            ));
+        }
+
+        let mut errors = Vec::new();
+        let mut valid = Vec::new();
+        let mut seen_slugs = HashSet::new();
+
+        for (index, row) in rows.into_iter().enumerate() {
+            let prefix = format!("rows[{}]", index);
+
+            let row = match row {
+                Ok(row) => row,
+                Err(message) => {
+                    errors.push(FieldError::new(&prefix, "invalid_row", &message));
+                    continue;
+                }
+            };
+
+            match Self::validate_row(index, row) {
+                Ok(row) => {
+                    if !seen_slugs.insert(row.cmd.slug.clone()) {
+                        errors.push(FieldError::new(
+                            &format!("{}.slug", prefix),
+                            "duplicate",
+                            "appears more than once in the file",
+                        ));
+                        continue;
+                    }
+
+                    valid.push(row);
+                }
+                Err(err) => errors.extend(err.field_errors_at(&prefix)),
+            }
+        }
+
+        let category_slugs: Vec<String> = valid
+            .iter()
+            .flat_map(|row| row.categories.iter().flatten().cloned())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        let categories: HashMap<String, i64> = self
+            .category_repository
+            .get_by_slugs(&category_slugs)
+            .await?
+            .into_iter()
+            .map(|category| (category.slug, category.id))
+            .collect();
+
+        let product_slugs: Vec<String> = seen_slugs.into_iter().collect();
+
+        let mut existing: HashMap<String, AdminProductModel> = self
+            .repository
+            .get_by_slugs(&product_slugs)
+            .await?
+            .into_iter()
+            .map(|product| (product.slug.clone(), product))
+            .collect();
+
+        for row in &valid {
+            let prefix = format!("rows[{}]", row.index);
+
+            for slug in row.categories.iter().flatten() {
+                if !categories.contains_key(slug) {
+                    errors.push(FieldError::new(
+                        &format!("{}.categories", prefix),
+                        "not_found",
+                        &format!("category {} does not exist", slug),
+                    ));
+                }
+            }
+
+            if mode == ImportMode::Create && existing.contains_key(&row.cmd.slug) {
+                errors.push(FieldError::new(
+                    &format!("{}.slug", prefix),
+                    "unique",
+                    "slug is already taken",
+                ));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(AppError::ValidationSingle(errors));
+        }
+
+        let mut report = ImportReport {
+            dry_run,
+            mode,
+            created: 0,
+            updated: 0,
+        };
+        let mut changes = Vec::new();
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        for row in valid {
+            let category_ids = row.categories.map(|slugs| {
+                slugs
+                    .iter()
+                    .map(|slug| categories[slug])
+                    .collect::<HashSet<_>>()
+            });
+
+            let (product, audit) = match existing.remove(&row.cmd.slug) {
+                Some(before) => {
+                    let cmd = UpdateProductCommand {
+                        name: row.cmd.name,
+                        slug: row.cmd.slug,
+                        categories: None,
+                        price: row.cmd.price,
+                        quantity: row.cmd.quantity,
+                        configurable: row.cmd.configurable,
+                        is_active: row.cmd.is_active,
+                        publish_at: row.cmd.publish_at,
+                        unpublish_at: row.cmd.unpublish_at,
+                        sale_price: row.cmd.sale_price,
+                        sale_starts_at: row.cmd.sale_starts_at,
+                        sale_ends_at: row.cmd.sale_ends_at,
+                        tax_class: row.cmd.tax_class,
+                        weight_grams: row.cmd.weight_grams,
+                        length_mm: row.cmd.length_mm,
+                        width_mm: row.cmd.width_mm,
+                        height_mm: row.cmd.height_mm,
+                    };
+
+                    let snapshot_before =
+                        self.wishlist_alerts.snapshot(&mut *tx, before.id).await?;
+
+                    if category_ids.is_some() {
+                        self.repository
+                            .detach_product_from_all_categories(&mut *tx, before.id)
+                            .await?;
+                    }
+
+                    let mut after = self.repository.update(&mut *tx, &cmd, before.id).await?;
+
+                    after.quantity = self
+                        .stock_repository
+                        .adjust_to(
+                            &mut *tx,
+                            after.id,
+                            after.quantity,
+                            cmd.quantity,
+                            "product import",
+                            Some(actor_id),
+                        )
+                        .await?;
+
+                    let snapshot_after = self.wishlist_alerts.snapshot(&mut *tx, after.id).await?;
+
+                    let audit = CreateAuditEntryCommand::updated(
+                        actor_id,
+                        AuditEntity::Product,
+                        after.id,
+                        &before,
+                        &after,
+                    );
+
+                    report.updated += 1;
+                    changes.push((snapshot_before, snapshot_after));
+
+                    (after, audit)
+                }
+                None => {
+                    let mut product = self.repository.create(&mut *tx, &row.cmd).await?;
+
+                    product.quantity = self
+                        .stock_repository
+                        .adjust_to(
+                            &mut *tx,
+                            product.id,
+                            0,
+                            row.cmd.quantity,
+                            "product import",
+                            Some(actor_id),
+                        )
+                        .await?;
+
+                    let audit = CreateAuditEntryCommand::created(
+                        actor_id,
+                        AuditEntity::Product,
+                        product.id,
+                        &product,
+                    );
+
+                    report.created += 1;
+
+                    (product, audit)
+                }
+            };
+
+            for category_id in category_ids.into_iter().flatten() {
+                self.repository
+                    .attach_product_to_category(&mut *tx, product.id, category_id)
+                    .await?;
+            }
+
+            self.audit_repository.create(&mut *tx, &audit).await?;
+        }
+
+        if dry_run {
+            tx.rollback().await.map_err(AppError::Database)?;
+
+            return Ok(report);
+        }
+
+        self.repository.commit_transaction(tx).await?;
+
+        for (before, after) in changes {
+            self.product_cache.invalidate_product(after.id);
+
+            self.wishlist_alerts
+                .product_changed_or_warn(&before, &after)
+                .await;
+
+            self.stock_subscription_alerts
+                .product_changed_or_warn(&before, &after)
+                .await;
+        }
+
+        Ok(report)
+    }
+
+    /**
+     * Streams the filtered product list, walking it by id in batches so the
+     * whole catalog is never held in memory.
+     */
+    pub fn export(
+        &self,
+        format: TransferFormat,
+        search: Option<String>,
+        filters: ProductFilters,
+    ) -> impl Stream<Item = Result<Bytes, AppError>> + 'static {
+        let cursor = ExportCursor {
+            repository: AdminProductRepository::new(self.pool.clone()),
+            format,
+            search,
+            filters,
+            after_id: 0,
+            written: 0,
+            stage: ExportStage::Header,
+        };
+
+        stream::unfold(cursor, |mut cursor| async move {
+            match cursor.stage {
+                ExportStage::Header => {
+                    cursor.stage = ExportStage::Rows;
+
+                    Some((format::header(cursor.format).map(Bytes::from), cursor))
+                }
+                ExportStage::Rows => {
+                    let batch = cursor
+                        .repository
+                        .export_batch(
+                            cursor.after_id,
+                            EXPORT_BATCH_SIZE,
+                            &cursor.search,
+                            &cursor.filters,
+                        )
+                        .await;
+
+                    let products = match batch {
+                        Ok(products) => products,
+                        Err(err) => {
+                            cursor.stage = ExportStage::Done;
+
+                            return Some((Err(err), cursor));
+                        }
+                    };
+
+                    let Some(last) = products.last() else {
+                        cursor.stage = ExportStage::Done;
+
+                        return Some((Ok(Bytes::from(format::footer(cursor.format))), cursor));
+                    };
+
+                    let count = products.len();
+                    cursor.after_id = last.id;
+
+                    let chunk = format::rows(cursor.format, products, cursor.written);
+                    cursor.written += count;
+
+                    Some((chunk.map(Bytes::from), cursor))
+                }
+                ExportStage::Done => None,
+            }
+        })
+    }
+
+    fn validate_row(index: usize, row: ProductTransferRow) -> Result<ImportRow, AppError> {
+        let dto = CreateProductDTO {
+            name: row.name,
+            slug: row.slug,
+            categories: None,
+            price: row.price,
+            quantity: row.quantity,
+            configurable: row.configurable,
+            is_active: row.is_active,
+            publish_at: row.publish_at,
+            unpublish_at: row.unpublish_at,
+            sale_price: row.sale_price,
+            sale_starts_at: row.sale_starts_at,
+            sale_ends_at: row.sale_ends_at,
+            tax_class: row.tax_class,
+            weight_grams: row.weight_grams,
+            length_mm: row.length_mm,
+            width_mm: row.width_mm,
+            height_mm: row.height_mm,
+        };
+
+        dto.validate()?;
+
+        if let Some(categories) = &row.categories
+            && categories.is_empty()
+        {
+            return Err(AppError::field(
+                "categories",
+                "length",
+                "must contain at least 1 category",
+            ));
+        }
+
+        let cmd = CreateProductCommand::try_from(dto)?;
+
+        validate_slug(&cmd.slug)?;
+
+        Ok(ImportRow {
+            index,
+            cmd,
+            categories: row.categories,
+        })
+    }
+}
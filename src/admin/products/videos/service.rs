@@ -1,9 +1,12 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
 use crate::admin::products::service::AdminProductService;
 use crate::admin::products::videos::dto::{
     CreateProductVideoCommand, UpdateProductVideoSortCommand,
 };
 use crate::admin::products::videos::model::AdminProductVideoModel;
 use crate::admin::products::videos::repository::AdminProductVideoRepository;
+use crate::app::products::cache::ProductCache;
 use crate::errors::error::AppError;
 use crate::utils::storage::LocalStorage;
 use crate::utils::traits::{IsRepository, UseStorage};
@@ -17,13 +20,15 @@ use uuid::Uuid;
 pub struct AdminProductVideoService {
     repository: AdminProductVideoRepository,
     product_service: AdminProductService,
+    audit_repository: AdminAuditRepository,
 }
 
 impl AdminProductVideoService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, product_cache: ProductCache) -> Self {
         Self {
             repository: AdminProductVideoRepository::new(pool.clone()),
-            product_service: AdminProductService::new(pool),
+            product_service: AdminProductService::new(pool.clone(), product_cache),
+            audit_repository: AdminAuditRepository::new(pool),
         }
     }
 
@@ -42,6 +47,7 @@ impl AdminProductVideoService {
         storage: &LocalStorage,
         file_bytes: Vec<u8>,
         extension: &str,
+        actor_id: i64,
     ) -> Result<i64, AppError> {
         self.product_service.get_one(cmd.product_id).await?;
 
@@ -55,11 +61,26 @@ impl AdminProductVideoService {
         cmd.handle_main(&self.repository).await?;
         cmd.handle_sort(&self.repository).await?;
 
-        let video_model = self
-            .repository
-            .create(self.repository.get_pool(), &cmd)
+        let mut tx = self.repository.start_transaction().await?;
+
+        let video_model = self.repository.create(&mut *tx, &cmd).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(
+                    actor_id,
+                    AuditEntity::ProductVideo,
+                    video_model.id,
+                    &video_model,
+                ),
+            )
             .await?;
 
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_service.invalidate_cached_pages(cmd.product_id);
+
         Ok(video_model.id)
     }
 
@@ -67,6 +88,7 @@ impl AdminProductVideoService {
         &self,
         id: i64,
         cmd: UpdateProductVideoSortCommand,
+        actor_id: i64,
     ) -> Result<BigDecimal, AppError> {
         let video = self.get_one(id).await?;
 
@@ -96,7 +118,34 @@ impl AdminProductVideoService {
             (prev.sort.clone() + next.sort.clone()) / divider
         };
 
-        self.repository.update_sort(id, new_sort.clone()).await?;
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository
+            .update_sort(&mut *tx, id, new_sort.clone())
+            .await?;
+
+        let after = AdminProductVideoModel {
+            sort: new_sort.clone(),
+            ..video.clone()
+        };
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::ProductVideo,
+                    id,
+                    &video,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_service
+            .invalidate_cached_pages(video.product_id);
 
         Ok(new_sort)
     }
@@ -109,9 +158,31 @@ impl AdminProductVideoService {
         NamedFile::open(full_path).map_err(|e| AppError::Internal(e.to_string()))
     }
 
-    pub async fn delete(&self, id: i64, storage: &LocalStorage) -> Result<u64, AppError> {
+    pub async fn delete(
+        &self,
+        id: i64,
+        storage: &LocalStorage,
+        actor_id: i64,
+    ) -> Result<u64, AppError> {
         let video = self.get_one(id).await?;
         storage.delete(video.url.as_str()).await?;
-        self.repository.delete(self.repository.get_pool(), id).await
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(actor_id, AuditEntity::ProductVideo, id, &video),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_service
+            .invalidate_cached_pages(video.product_id);
+
+        Ok(deleted)
     }
 }
@@ -0,0 +1,373 @@
+use crate::admin::promotions::filters::PromotionFilters;
+use crate::admin::promotions::model::AdminPromotionModel;
+use crate::errors::error::AppError;
+use crate::utils::money::validate_amount;
+use crate::utils::traits::HasId;
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PromotionKind {
+    BuyXGetY,
+    TieredVolume,
+    CategoryPercentage,
+    Bundle,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct VolumeTier {
+    /// Units of the product the cart needs for this tier.
+    pub min_quantity: i32,
+    #[schema(value_type = String, example = "10")]
+    pub percent: BigDecimal,
+}
+
+/**
+ * What a promotion gives, stored as the `rule` json of the promotion and told
+ * apart by its `type`.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PromotionRule {
+    /// Every `buy_quantity` units of `product_id` give `get_quantity` units of
+    /// `get_product_id` for free, the same product when it's empty.
+    BuyXGetY {
+        product_id: i64,
+        buy_quantity: i32,
+        get_product_id: Option<i64>,
+        get_quantity: i32,
+    },
+    /// Percent off a product, from the highest tier its quantity reaches.
+    TieredVolume {
+        product_id: i64,
+        tiers: Vec<VolumeTier>,
+    },
+    /// `percent` off every product in `category_id`.
+    CategoryPercentage {
+        category_id: i64,
+        #[schema(value_type = String, example = "15")]
+        percent: BigDecimal,
+    },
+    /// One unit of each of `product_ids` together for `price`.
+    Bundle {
+        product_ids: Vec<i64>,
+        #[schema(value_type = String, example = "25.00")]
+        price: BigDecimal,
+    },
+}
+
+impl PromotionRule {
+    pub fn kind(&self) -> PromotionKind {
+        match self {
+            PromotionRule::BuyXGetY { .. } => PromotionKind::BuyXGetY,
+            PromotionRule::TieredVolume { .. } => PromotionKind::TieredVolume,
+            PromotionRule::CategoryPercentage { .. } => PromotionKind::CategoryPercentage,
+            PromotionRule::Bundle { .. } => PromotionKind::Bundle,
+        }
+    }
+
+    /** Products the rule refers to. */
+    pub fn product_ids(&self) -> Vec<i64> {
+        match self {
+            PromotionRule::BuyXGetY {
+                product_id,
+                get_product_id,
+                ..
+            } => std::iter::once(*product_id)
+                .chain(*get_product_id)
+                .collect(),
+            PromotionRule::TieredVolume { product_id, .. } => vec![*product_id],
+            PromotionRule::CategoryPercentage { .. } => Vec::new(),
+            PromotionRule::Bundle { product_ids, .. } => product_ids.clone(),
+        }
+    }
+
+    /** Categories the rule refers to. */
+    pub fn category_ids(&self) -> Vec<i64> {
+        match self {
+            PromotionRule::CategoryPercentage { category_id, .. } => vec![*category_id],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicPromotion {
+    pub id: i64,
+    pub name: String,
+    pub kind: PromotionKind,
+    pub rule: PromotionRule,
+    pub priority: i32,
+    pub stackable: bool,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPublicPromotion {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl From<AdminPromotionModel> for AdminPublicPromotion {
+    fn from(promotion: AdminPromotionModel) -> Self {
+        Self {
+            id: promotion.id,
+            name: promotion.name,
+            kind: promotion.rule.kind(),
+            rule: promotion.rule.0,
+            priority: promotion.priority,
+            stackable: promotion.stackable,
+            starts_at: promotion.starts_at,
+            ends_at: promotion.ends_at,
+            is_active: promotion.is_active,
+            updated_at: promotion.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexPromotionDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub search: Option<String>,
+
+    pub is_active: Option<bool>,
+}
+
+impl TryFrom<IndexPromotionDTO> for PromotionFilters {
+    type Error = AppError;
+
+    fn try_from(dto: IndexPromotionDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            is_active: dto.is_active,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreatePromotionDTO {
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true)]
+    pub rule: Option<PromotionRule>,
+
+    /// Higher goes first, ties go by id.
+    pub priority: Option<i32>,
+
+    /// A promotion that doesn't stack only applies when no other one did, and
+    /// keeps later ones out.
+    pub stackable: Option<bool>,
+
+    pub starts_at: Option<DateTime<Utc>>,
+
+    pub ends_at: Option<DateTime<Utc>>,
+
+    pub is_active: Option<bool>,
+}
+
+pub struct CreatePromotionCommand {
+    pub name: String,
+    pub rule: PromotionRule,
+    pub priority: i32,
+    pub stackable: bool,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+}
+
+impl TryFrom<CreatePromotionDTO> for CreatePromotionCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreatePromotionDTO) -> Result<Self, Self::Error> {
+        let rule = dto.rule.unwrap();
+
+        validate_rule(&rule)?;
+        validate_window(dto.starts_at, dto.ends_at)?;
+
+        Ok(Self {
+            name: dto.name.unwrap(),
+            rule,
+            priority: dto.priority.unwrap_or(0),
+            stackable: dto.stackable.unwrap_or(true),
+            starts_at: dto.starts_at,
+            ends_at: dto.ends_at,
+            is_active: dto.is_active.unwrap_or(true),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdatePromotionDTO {
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true)]
+    pub rule: Option<PromotionRule>,
+
+    /// Higher goes first, ties go by id.
+    pub priority: Option<i32>,
+
+    /// A promotion that doesn't stack only applies when no other one did, and
+    /// keeps later ones out.
+    pub stackable: Option<bool>,
+
+    pub starts_at: Option<DateTime<Utc>>,
+
+    pub ends_at: Option<DateTime<Utc>>,
+
+    pub is_active: Option<bool>,
+}
+
+pub struct UpdatePromotionCommand {
+    pub name: String,
+    pub rule: PromotionRule,
+    pub priority: i32,
+    pub stackable: bool,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+}
+
+impl TryFrom<UpdatePromotionDTO> for UpdatePromotionCommand {
+    type Error = AppError;
+
+    fn try_from(dto: UpdatePromotionDTO) -> Result<Self, Self::Error> {
+        let rule = dto.rule.unwrap();
+
+        validate_rule(&rule)?;
+        validate_window(dto.starts_at, dto.ends_at)?;
+
+        Ok(Self {
+            name: dto.name.unwrap(),
+            rule,
+            priority: dto.priority.unwrap_or(0),
+            stackable: dto.stackable.unwrap_or(true),
+            starts_at: dto.starts_at,
+            ends_at: dto.ends_at,
+            is_active: dto.is_active.unwrap_or(true),
+        })
+    }
+}
+
+fn validate_rule(rule: &PromotionRule) -> Result<(), AppError> {
+    match rule {
+        PromotionRule::BuyXGetY {
+            buy_quantity,
+            get_quantity,
+            ..
+        } => {
+            validate_quantity("rule.buy_quantity", *buy_quantity)?;
+            validate_quantity("rule.get_quantity", *get_quantity)
+        }
+        PromotionRule::TieredVolume { tiers, .. } => {
+            if tiers.is_empty() {
+                return Err(AppError::field(
+                    "rule.tiers",
+                    "required",
+                    "field is required",
+                ));
+            }
+
+            let mut seen = HashSet::new();
+
+            for (index, tier) in tiers.iter().enumerate() {
+                validate_quantity(
+                    &format!("rule.tiers[{}].min_quantity", index),
+                    tier.min_quantity,
+                )?;
+                validate_percent(&format!("rule.tiers[{}].percent", index), &tier.percent)?;
+
+                if !seen.insert(tier.min_quantity) {
+                    return Err(AppError::field(
+                        &format!("rule.tiers[{}].min_quantity", index),
+                        "duplicate",
+                        "every tier needs its own min_quantity",
+                    ));
+                }
+            }
+
+            Ok(())
+        }
+        PromotionRule::CategoryPercentage { percent, .. } => {
+            validate_percent("rule.percent", percent)
+        }
+        PromotionRule::Bundle { product_ids, price } => {
+            let distinct: HashSet<&i64> = product_ids.iter().collect();
+
+            if distinct.len() < 2 || distinct.len() != product_ids.len() {
+                return Err(AppError::field(
+                    "rule.product_ids",
+                    "length",
+                    "must hold at least 2 different products",
+                ));
+            }
+
+            validate_price("rule.price", price)
+        }
+    }
+}
+
+fn validate_quantity(field: &str, quantity: i32) -> Result<(), AppError> {
+    if quantity < 1 {
+        return Err(AppError::field(field, "range", "must be at least 1"));
+    }
+
+    Ok(())
+}
+
+fn validate_price(field: &str, price: &BigDecimal) -> Result<(), AppError> {
+    validate_amount(price).map_err(|error| AppError::field(field, &error.code, &error.to_string()))
+}
+
+fn validate_percent(field: &str, percent: &BigDecimal) -> Result<(), AppError> {
+    validate_price(field, percent)?;
+
+    if percent.is_zero() || percent > &BigDecimal::from(100) {
+        return Err(AppError::field(
+            field,
+            "range",
+            "must be greater than 0 and at most 100",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_window(
+    starts_at: Option<DateTime<Utc>>,
+    ends_at: Option<DateTime<Utc>>,
+) -> Result<(), AppError> {
+    if let (Some(starts_at), Some(ends_at)) = (starts_at, ends_at)
+        && starts_at >= ends_at
+    {
+        return Err(AppError::field(
+            "ends_at",
+            "invalid_range",
+            "must be after starts_at",
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use crate::admin::promotions::dto::{
+    AdminPublicPromotion, CreatePromotionCommand, CreatePromotionDTO, IndexPromotionDTO,
+    UpdatePromotionCommand, UpdatePromotionDTO,
+};
+use crate::admin::promotions::filters::PromotionFilters;
+use crate::admin::promotions::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use crate::utils::pagination::Paginate;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin/promotions/list",
+    tag = "admin-promotions",
+    params(IndexPromotionDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicPromotion>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["promotions:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    state: web::Data<AppState>,
+    body: web::Query<IndexPromotionDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = PromotionFilters::try_from(body.clone().into_inner())?;
+
+    let promotions = state
+        .admin_promotion_service
+        .get_all_paginated_public(&pagination, &filters, &body.search)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        promotions.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/promotions/get/{id}",
+    tag = "admin-promotions",
+    params(("id" = i64, Path, description = "Promotion id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicPromotion>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["promotions:read"])),
+)]
+#[instrument(skip_all)]
+pub async fn show(
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let promotion = state
+        .admin_promotion_service
+        .get_one_public(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(promotion)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/promotions/create",
+    tag = "admin-promotions",
+    request_body = CreatePromotionDTO,
+    responses(
+        (status = 201, description = "Promotion created", body = SuccessResponse<AdminPublicPromotion>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Product or category not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["promotions:create"])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreatePromotionDTO>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreatePromotionCommand::try_from(body.into_inner())?;
+    let promotion = state
+        .admin_promotion_service
+        .create(command, actor_id)
+        .await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(promotion.into_public())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/promotions/update/{id}",
+    tag = "admin-promotions",
+    params(("id" = i64, Path, description = "Promotion id")),
+    request_body = UpdatePromotionDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["promotions:update"])),
+)]
+#[instrument(skip_all)]
+pub async fn update(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdatePromotionDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = UpdatePromotionCommand::try_from(body.into_inner())?;
+    state
+        .admin_promotion_service
+        .update(command, id.into_inner(), actor_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/promotions/delete/{id}",
+    tag = "admin-promotions",
+    params(("id" = i64, Path, description = "Promotion id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["promotions:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_promotion_service
+        .delete(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,25 @@
+use crate::admin::promotions::dto::PromotionRule;
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct AdminPromotionModel {
+    pub id: i64,
+    pub name: String,
+    pub rule: Json<PromotionRule>,
+    pub priority: i32,
+    pub stackable: bool,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPromotionModel {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
@@ -0,0 +1,31 @@
+use crate::auth::traits::Scope;
+
+pub enum PromotionScope {
+    Create,
+    Read,
+    Update,
+    Delete,
+    List,
+}
+
+impl Scope for PromotionScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PromotionScope::Create => "promotions:create",
+            PromotionScope::Read => "promotions:read",
+            PromotionScope::Update => "promotions:update",
+            PromotionScope::Delete => "promotions:delete",
+            PromotionScope::List => "promotions:list",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![
+            PromotionScope::Create,
+            PromotionScope::Read,
+            PromotionScope::Update,
+            PromotionScope::Delete,
+            PromotionScope::List,
+        ]
+    }
+}
@@ -0,0 +1,168 @@
+use crate::admin::promotions::dto::{CreatePromotionCommand, UpdatePromotionCommand};
+use crate::admin::promotions::filters::PromotionFilters;
+use crate::admin::promotions::model::AdminPromotionModel;
+use crate::errors::error::AppError;
+use crate::utils::pagination::Paginate;
+use crate::utils::traits::IsRepository;
+use sqlx::types::Json;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+const PROMOTION_COLUMNS: &str = r#"
+    SELECT
+        promotions.id,
+        promotions.name,
+        promotions.rule,
+        promotions.priority,
+        promotions.stackable,
+        promotions.starts_at,
+        promotions.ends_at,
+        promotions.is_active,
+        promotions.created_at,
+        promotions.updated_at
+    FROM promotions
+"#;
+
+pub struct AdminPromotionRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AdminPromotionRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminPromotionRepository {
+    #[instrument(skip_all)]
+    pub async fn index_paginated(
+        &self,
+        pagination: &Paginate,
+        search: &Option<String>,
+        filters: &PromotionFilters,
+    ) -> Result<Vec<AdminPromotionModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(PROMOTION_COLUMNS);
+
+        qb.push(" WHERE TRUE ");
+
+        // handle search
+        if let Some(search) = search {
+            qb.push(" AND promotions.name ILIKE ");
+            qb.push_bind(format!("%{}%", search));
+        }
+
+        // is active
+        if let Some(is_active) = filters.is_active {
+            qb.push(" AND promotions.is_active = ");
+            qb.push_bind(is_active);
+        }
+
+        qb.push(" ORDER BY promotions.priority DESC, promotions.id ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        qb.build_query_as::<AdminPromotionModel>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn show(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<Option<AdminPromotionModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(PROMOTION_COLUMNS);
+
+        qb.push(" WHERE promotions.id = ");
+        qb.push_bind(id);
+
+        qb.build_query_as::<AdminPromotionModel>()
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &CreatePromotionCommand,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar(
+            r#"
+        INSERT INTO promotions (name, rule, priority, stackable, starts_at, ends_at, is_active)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id;
+        "#,
+        )
+        .bind(&cmd.name)
+        .bind(Json(&cmd.rule))
+        .bind(cmd.priority)
+        .bind(cmd.stackable)
+        .bind(cmd.starts_at)
+        .bind(cmd.ends_at)
+        .bind(cmd.is_active)
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn update(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &UpdatePromotionCommand,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+        UPDATE promotions
+        SET (name, rule, priority, stackable, starts_at, ends_at, is_active, updated_at)
+            = ($1, $2, $3, $4, $5, $6, $7, now())
+        WHERE id = $8;
+        "#,
+        )
+        .bind(&cmd.name)
+        .bind(Json(&cmd.rule))
+        .bind(cmd.priority)
+        .bind(cmd.stackable)
+        .bind(cmd.starts_at)
+        .bind(cmd.ends_at)
+        .bind(cmd.is_active)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM promotions WHERE id = $1;",
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,178 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
+use crate::admin::categories::repository::AdminCategoryRepository;
+use crate::admin::products::repository::AdminProductRepository;
+use crate::admin::promotions::dto::{
+    AdminPublicPromotion, CreatePromotionCommand, PromotionRule, UpdatePromotionCommand,
+};
+use crate::admin::promotions::filters::PromotionFilters;
+use crate::admin::promotions::model::AdminPromotionModel;
+use crate::admin::promotions::repository::AdminPromotionRepository;
+use crate::admin::promotions::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct AdminPromotionService {
+    repository: AdminPromotionRepository,
+    product_repository: AdminProductRepository,
+    category_repository: AdminCategoryRepository,
+    audit_repository: AdminAuditRepository,
+}
+
+impl AdminPromotionService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: AdminPromotionRepository::new(pool.clone()),
+            product_repository: AdminProductRepository::new(pool.clone()),
+            category_repository: AdminCategoryRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool),
+        }
+    }
+
+    pub async fn get_all_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &PromotionFilters,
+        search: &Option<String>,
+    ) -> Result<PaginatedDataCollection<AdminPublicPromotion>, AppError> {
+        let data = self
+            .repository
+            .index_paginated(pagination, search, filters)
+            .await?;
+
+        Ok(PaginatedDataCollection::new(data, pagination.clone()).into_public())
+    }
+
+    pub async fn get_one(&self, id: i64) -> Result<AdminPromotionModel, AppError> {
+        let promotion = self.repository.show(self.repository.get_pool(), id).await?;
+
+        match promotion {
+            Some(promotion) => Ok(promotion),
+            None => Err(AppError::NotFound("Promotion not found".to_string())),
+        }
+    }
+
+    pub async fn get_one_public(&self, id: i64) -> Result<AdminPublicPromotion, AppError> {
+        let promotion = self.get_one(id).await?;
+
+        Ok(promotion.into_public())
+    }
+
+    pub async fn create(
+        &self,
+        cmd: CreatePromotionCommand,
+        actor_id: i64,
+    ) -> Result<AdminPromotionModel, AppError> {
+        self.check_targets_exist(&cmd.rule).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let id = self.repository.create(&mut *tx, &cmd).await?;
+
+        let promotion = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Promotion not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(actor_id, AuditEntity::Promotion, id, &promotion),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(promotion)
+    }
+
+    pub async fn update(
+        &self,
+        cmd: UpdatePromotionCommand,
+        id: i64,
+        actor_id: i64,
+    ) -> Result<AdminPromotionModel, AppError> {
+        let before = self.get_one(id).await?;
+
+        self.check_targets_exist(&cmd.rule).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.update(&mut *tx, &cmd, id).await?;
+
+        let after = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Promotion not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::Promotion,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(after)
+    }
+
+    pub async fn delete(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let promotion = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(actor_id, AuditEntity::Promotion, id, &promotion),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(deleted)
+    }
+
+    async fn check_targets_exist(&self, rule: &PromotionRule) -> Result<(), AppError> {
+        for product_id in rule.product_ids() {
+            if !self
+                .product_repository
+                .check_existence_by_id(product_id)
+                .await?
+            {
+                return Err(AppError::NotFound(format!(
+                    "Product with id {} not found",
+                    product_id
+                )));
+            }
+        }
+
+        for category_id in rule.category_ids() {
+            if !self
+                .category_repository
+                .check_existence_by_id(category_id)
+                .await?
+            {
+                return Err(AppError::NotFound(format!(
+                    "Category with id {} not found",
+                    category_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
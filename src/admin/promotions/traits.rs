@@ -0,0 +1,27 @@
+use crate::admin::promotions::dto::AdminPublicPromotion;
+use crate::admin::promotions::model::AdminPromotionModel;
+use crate::utils::pagination::PaginatedDataCollection;
+
+pub trait IntoPublic<T> {
+    fn into_public(self) -> T;
+}
+
+impl IntoPublic<AdminPublicPromotion> for AdminPromotionModel {
+    fn into_public(self) -> AdminPublicPromotion {
+        AdminPublicPromotion::from(self)
+    }
+}
+
+impl IntoPublic<PaginatedDataCollection<AdminPublicPromotion>>
+    for PaginatedDataCollection<AdminPromotionModel>
+{
+    fn into_public(self) -> PaginatedDataCollection<AdminPublicPromotion> {
+        PaginatedDataCollection::new(
+            self.data
+                .into_iter()
+                .map(AdminPublicPromotion::from)
+                .collect(),
+            self.pagination,
+        )
+    }
+}
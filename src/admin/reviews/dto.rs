@@ -1,13 +1,14 @@
 use crate::admin::reviews::filters::AdminReviewFilters;
 use crate::admin::reviews::model::AdminReviewModel;
 use crate::errors::error::AppError;
+use crate::utils::pagination::PaginationMode;
 use crate::utils::traits::HasId;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::str::FromStr;
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Clone, sqlx::Type, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::Type, PartialEq, ToSchema)]
 #[sqlx(type_name = "review_status", rename_all = "lowercase")]
 pub enum ReviewApprovalStatus {
     Pending,
@@ -23,16 +24,16 @@ impl FromStr for ReviewApprovalStatus {
             "pending" => Ok(ReviewApprovalStatus::Pending),
             "approved" => Ok(ReviewApprovalStatus::Approved),
             "rejected" => Ok(ReviewApprovalStatus::Rejected),
-            _ => {
-                let mut error = HashMap::new();
-                error.insert("status".to_string(), vec!["invalid status".to_string()]);
-                Err(AppError::ValidationSingle(error))
-            }
+            _ => Err(AppError::field(
+                "status",
+                "invalid_choice",
+                "must be one of pending, approved, rejected",
+            )),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct AdminPublicReview {
     pub id: i64,
     pub user_id: Option<i64>,
@@ -63,27 +64,44 @@ impl From<AdminReviewModel> for AdminPublicReview {
     }
 }
 
-#[derive(Serialize, Deserialize, Validate, Clone)]
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct IndexReviewDTO {
-    #[validate(required, range(min = 1))]
+    /// Required in `page` mode, not allowed in `cursor` mode.
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
     pub page: Option<i64>,
 
     #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
     pub limit: Option<i64>,
 
+    /// `page` (default) or `cursor`.
+    pub pagination: Option<PaginationMode>,
+
+    /// `next_cursor` or `prev_cursor` from a previous response.
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub cursor: Option<String>,
+
     #[validate(length(min = 1))]
+    #[param(min_length = 1)]
     pub search: Option<String>,
 
     #[validate(range(min = 1))]
+    #[param(minimum = 1)]
     pub user_id: Option<i64>,
 
     #[validate(range(min = 1))]
+    #[param(minimum = 1)]
     pub product_id: Option<i64>,
 
     #[validate(range(min = 0))]
+    #[param(minimum = 0)]
     pub rating: Option<i16>,
 
     #[validate(length(min = 1))]
+    #[param(min_length = 1)]
     pub status: Option<String>,
 }
 
@@ -105,9 +123,10 @@ impl TryFrom<IndexReviewDTO> for AdminReviewFilters {
     }
 }
 
-#[derive(Serialize, Deserialize, Validate, Clone)]
+#[derive(Serialize, Deserialize, Validate, Clone, ToSchema)]
 pub struct UpdateReviewStatusDTO {
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub status: Option<String>,
 }
 
@@ -1,35 +1,87 @@
 use crate::admin::reviews::dto::{
-    IndexReviewDTO, UpdateReviewStatusCommand, UpdateReviewStatusDTO,
+    AdminPublicReview, IndexReviewDTO, UpdateReviewStatusCommand, UpdateReviewStatusDTO,
 };
 use crate::admin::reviews::filters::AdminReviewFilters;
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
-use crate::utils::pagination::Paginate;
-use actix_web::{HttpResponse, Responder, web};
+use crate::utils::extractors::extract_auth_user_id;
+use crate::utils::pagination::{CursorMeta, PageRequest};
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
 use validator::Validate;
 
+#[utoipa::path(
+    get,
+    path = "/admin/reviews/list",
+    tag = "admin-reviews",
+    params(IndexReviewDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicReview>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:list"])),
+)]
+#[instrument(skip_all)]
 pub async fn index(
     state: web::Data<AppState>,
     body: web::Query<IndexReviewDTO>,
 ) -> Result<impl Responder, AppError> {
     body.validate()?;
 
-    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+    let page_request = PageRequest::new(
+        body.pagination,
+        body.limit.unwrap(),
+        body.page,
+        body.cursor.as_deref(),
+        &state.cursor_codec,
+    )?;
 
     let filters = AdminReviewFilters::try_from(body.clone().into_inner())?;
 
-    let reviews = state
-        .admin_reviews_service
-        .get_all_paginated_public(&pagination, &filters, &body.search)
-        .await?;
+    match page_request {
+        PageRequest::Page(pagination) => {
+            let reviews = state
+                .admin_reviews_service
+                .get_all_paginated_public(&pagination, &filters, &body.search)
+                .await?;
+
+            Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+                reviews.data,
+                pagination,
+            )))
+        }
+        PageRequest::Cursor(pagination) => {
+            let reviews = state
+                .admin_reviews_service
+                .get_all_cursor_public(&pagination, &filters, &body.search)
+                .await?;
 
-    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
-        reviews.data,
-        pagination,
-    )))
+            let meta = CursorMeta::new(&reviews, &state.cursor_codec);
+
+            Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_cursor(reviews.data, meta)))
+        }
+    }
 }
 
+#[utoipa::path(
+    get,
+    path = "/admin/reviews/{review_id}/get",
+    tag = "admin-reviews",
+    params(("review_id" = i64, Path, description = "Review id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicReview>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:read"])),
+)]
+#[instrument(skip_all)]
 pub async fn show(
     state: web::Data<AppState>,
     review_id: web::Path<i64>,
@@ -42,30 +94,68 @@ pub async fn show(
     Ok(HttpResponse::Ok().json(SuccessResponse::ok(review)))
 }
 
+#[utoipa::path(
+    put,
+    path = "/admin/reviews/{review_id}/update-status",
+    tag = "admin-reviews",
+    params(("review_id" = i64, Path, description = "Review id")),
+    request_body = UpdateReviewStatusDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:update"])),
+)]
+#[instrument(skip_all)]
 pub async fn update_status(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Json<UpdateReviewStatusDTO>,
     review_id: web::Path<i64>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     body.validate()?;
 
     let command = UpdateReviewStatusCommand::try_from(body.into_inner())?;
 
     state
         .admin_reviews_service
-        .update_status(command, review_id.into_inner())
+        .update_status(command, review_id.into_inner(), actor_id)
         .await?;
 
     Ok(HttpResponse::NoContent().finish())
 }
 
+#[utoipa::path(
+    delete,
+    path = "/admin/reviews/{review_id}/delete",
+    tag = "admin-reviews",
+    params(("review_id" = i64, Path, description = "Review id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["products:delete"])),
+)]
+#[instrument(skip_all)]
 pub async fn delete(
+    request: HttpRequest,
     state: web::Data<AppState>,
     review_id: web::Path<i64>,
 ) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
     state
         .admin_reviews_service
-        .delete(review_id.into_inner())
+        .delete(review_id.into_inner(), actor_id)
         .await?;
     Ok(HttpResponse::NoContent().finish())
 }
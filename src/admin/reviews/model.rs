@@ -1,4 +1,5 @@
 use crate::admin::reviews::dto::ReviewApprovalStatus;
+use crate::utils::cursor::HasCursorKey;
 use crate::utils::traits::HasId;
 use chrono::{DateTime, Utc};
 use fake::{Dummy, Fake, Faker};
@@ -23,6 +24,12 @@ impl HasId for AdminReviewModel {
     }
 }
 
+impl HasCursorKey for AdminReviewModel {
+    fn cursor_key(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
 pub struct AdminReviewDummy {
     pub user_id: Option<i64>,
     pub product_id: i64,
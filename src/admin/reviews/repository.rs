@@ -2,9 +2,11 @@ use crate::admin::reviews::dto::ReviewApprovalStatus;
 use crate::admin::reviews::filters::AdminReviewFilters;
 use crate::admin::reviews::model::AdminReviewModel;
 use crate::errors::error::AppError;
-use crate::utils::pagination::Paginate;
+use crate::utils::cursor::CursorDirection;
+use crate::utils::pagination::{CursorPaginate, Paginate};
 use crate::utils::traits::IsRepository;
 use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
 
 pub struct AdminReviewRepository {
     pool: PgPool,
@@ -23,6 +25,7 @@ impl IsRepository for AdminReviewRepository {
 }
 
 impl AdminReviewRepository {
+    #[instrument(skip_all)]
     pub async fn index_paginated(
         &self,
         pagination: &Paginate,
@@ -44,6 +47,98 @@ impl AdminReviewRepository {
         "#,
         );
 
+        Self::push_filters(&mut qb, search, filters);
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        let query = qb.build_query_as::<AdminReviewModel>();
+
+        query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    /**
+     * Keyset variant of `index_paginated`, newest first. Rows before a `Prev`
+     * cursor come back in ascending order.
+     */
+    #[instrument(skip_all)]
+    pub async fn index_cursor(
+        &self,
+        pagination: &CursorPaginate,
+        search: &Option<String>,
+        filters: &AdminReviewFilters,
+    ) -> Result<Vec<AdminReviewModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT
+                product_reviews.id,
+                product_reviews.user_id,
+                product_reviews.product_id,
+                product_reviews.title,
+                product_reviews.content,
+                product_reviews.rating,
+                product_reviews.approval_status,
+                product_reviews.created_at
+            FROM product_reviews
+        "#,
+        );
+
+        let has_where = Self::push_filters(&mut qb, search, filters);
+
+        // handle cursor
+        if let Some(cursor) = &pagination.cursor {
+            qb.push(if has_where { " AND " } else { " WHERE " });
+
+            match cursor.direction {
+                CursorDirection::Next => {
+                    qb.push(" (product_reviews.created_at, product_reviews.id) < (")
+                }
+                CursorDirection::Prev => {
+                    qb.push(" (product_reviews.created_at, product_reviews.id) > (")
+                }
+            };
+
+            qb.push_bind(cursor.sort_key);
+            qb.push(", ");
+            qb.push_bind(cursor.id);
+            qb.push(")");
+        }
+
+        match pagination.direction() {
+            CursorDirection::Next => {
+                qb.push(" ORDER BY product_reviews.created_at DESC, product_reviews.id DESC")
+            }
+            CursorDirection::Prev => {
+                qb.push(" ORDER BY product_reviews.created_at ASC, product_reviews.id ASC")
+            }
+        };
+
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.fetch_limit());
+
+        let query = qb.build_query_as::<AdminReviewModel>();
+
+        query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    /**
+     * Pushes the search and filter conditions, returns whether a `WHERE` was
+     * added.
+     */
+    fn push_filters<'args>(
+        qb: &mut QueryBuilder<'args, Postgres>,
+        search: &Option<String>,
+        filters: &'args AdminReviewFilters,
+    ) -> bool {
         let mut has_where = false;
 
         // handle search
@@ -116,20 +211,10 @@ impl AdminReviewRepository {
             qb.push_bind(status);
         }
 
-        // handle pagination
-        qb.push(" LIMIT ");
-        qb.push_bind(pagination.limit);
-        qb.push(" OFFSET ");
-        qb.push_bind(pagination.get_offset());
-
-        let query = qb.build_query_as::<AdminReviewModel>();
-
-        query
-            .fetch_all(&self.pool)
-            .await
-            .map_err(AppError::Database)
+        has_where
     }
 
+    #[instrument(skip_all)]
     pub async fn show(&self, id: i64) -> Result<Option<AdminReviewModel>, AppError> {
         sqlx::query_as! {
             AdminReviewModel,
@@ -153,6 +238,7 @@ impl AdminReviewRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn update_status(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
@@ -175,6 +261,7 @@ impl AdminReviewRepository {
         Ok(result.rows_affected())
     }
 
+    #[instrument(skip_all)]
     pub async fn delete(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
@@ -1,22 +1,31 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
 use crate::admin::reviews::dto::UpdateReviewStatusCommand;
 use crate::admin::reviews::dto::{AdminPublicReview, ReviewApprovalStatus};
 use crate::admin::reviews::filters::AdminReviewFilters;
 use crate::admin::reviews::model::AdminReviewModel;
 use crate::admin::reviews::repository::AdminReviewRepository;
 use crate::admin::reviews::traits::IntoPublic;
+use crate::app::products::cache::ProductCache;
 use crate::errors::error::AppError;
-use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::pagination::{
+    CursorPaginate, CursorPaginatedDataCollection, Paginate, PaginatedDataCollection,
+};
 use crate::utils::traits::IsRepository;
 use sqlx::PgPool;
 
 pub struct AdminReviewService {
     repository: AdminReviewRepository,
+    audit_repository: AdminAuditRepository,
+    product_cache: ProductCache,
 }
 
 impl AdminReviewService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, product_cache: ProductCache) -> Self {
         Self {
-            repository: AdminReviewRepository::new(pool),
+            repository: AdminReviewRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool),
+            product_cache,
         }
     }
 
@@ -46,6 +55,23 @@ impl AdminReviewService {
         Ok(reviews.into_public())
     }
 
+    /**
+     * Cursor paginated reviews, newest first (public version).
+     */
+    pub async fn get_all_cursor_public(
+        &self,
+        pagination: &CursorPaginate,
+        filters: &AdminReviewFilters,
+        search: &Option<String>,
+    ) -> Result<CursorPaginatedDataCollection<AdminPublicReview>, AppError> {
+        let rows = self
+            .repository
+            .index_cursor(pagination, search, filters)
+            .await?;
+
+        Ok(CursorPaginatedDataCollection::new(rows, pagination).into_public())
+    }
+
     pub async fn get_one(&self, id: i64) -> Result<AdminReviewModel, AppError> {
         let review = self.repository.show(id).await?;
 
@@ -68,18 +94,59 @@ impl AdminReviewService {
         &self,
         cmd: UpdateReviewStatusCommand,
         id: i64,
+        actor_id: i64,
     ) -> Result<ReviewApprovalStatus, AppError> {
-        self.get_one(id).await?;
+        let review = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
 
         self.repository
-            .update_status(self.repository.get_pool(), &cmd.status, id)
+            .update_status(&mut *tx, &cmd.status, id)
             .await?;
 
+        let after = AdminReviewModel {
+            approval_status: cmd.status.clone(),
+            ..review.clone()
+        };
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::Review,
+                    id,
+                    &review,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_cache.invalidate_product(review.product_id);
+
         Ok(cmd.status)
     }
 
-    pub async fn delete(&self, id: i64) -> Result<u64, AppError> {
-        self.get_one(id).await?;
-        self.repository.delete(self.repository.get_pool(), id).await
+    pub async fn delete(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let review = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(actor_id, AuditEntity::Review, id, &review),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_cache.invalidate_product(review.product_id);
+
+        Ok(deleted)
     }
 }
@@ -1,6 +1,6 @@
 use crate::admin::reviews::dto::AdminPublicReview;
 use crate::admin::reviews::model::AdminReviewModel;
-use crate::utils::pagination::PaginatedDataCollection;
+use crate::utils::pagination::{CursorPaginatedDataCollection, PaginatedDataCollection};
 
 pub trait IntoPublic<T> {
     fn into_public(self) -> T;
@@ -22,3 +22,11 @@ impl IntoPublic<PaginatedDataCollection<AdminPublicReview>>
         )
     }
 }
+
+impl IntoPublic<CursorPaginatedDataCollection<AdminPublicReview>>
+    for CursorPaginatedDataCollection<AdminReviewModel>
+{
+    fn into_public(self) -> CursorPaginatedDataCollection<AdminPublicReview> {
+        self.map(AdminPublicReview::from)
+    }
+}
@@ -1,6 +1,15 @@
+use crate::admin::audit::routes as audit_routes;
 use crate::admin::categories::routes as categories_routes;
+use crate::admin::coupons::routes as coupons_routes;
 use crate::admin::products::routes as products_routes;
+use crate::admin::promotions::routes as promotions_routes;
 use crate::admin::reviews::routes as reviews_routes;
+use crate::admin::shipping_methods::routes as shipping_methods_routes;
+use crate::admin::shipping_zones::routes as shipping_zones_routes;
+use crate::admin::stock::routes as stock_routes;
+use crate::admin::tax_rates::routes as tax_rates_routes;
+use crate::admin::users::routes as users_routes;
+use crate::admin::warehouses::routes as warehouses_routes;
 use actix_web::web;
 
 pub fn routes(cfg: &mut web::ServiceConfig) {
@@ -9,6 +18,15 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
             // .wrap(AuthMiddleware::new(pool))
             .configure(products_routes::routes)
             .configure(categories_routes::routes)
-            .configure(reviews_routes::routes),
+            .configure(reviews_routes::routes)
+            .configure(coupons_routes::routes)
+            .configure(promotions_routes::routes)
+            .configure(tax_rates_routes::routes)
+            .configure(shipping_zones_routes::routes)
+            .configure(shipping_methods_routes::routes)
+            .configure(stock_routes::routes)
+            .configure(warehouses_routes::routes)
+            .configure(users_routes::routes)
+            .configure(audit_routes::routes),
     );
 }
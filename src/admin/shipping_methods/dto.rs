@@ -0,0 +1,260 @@
+use crate::admin::shipping_methods::filters::ShippingMethodFilters;
+use crate::admin::shipping_methods::model::AdminShippingMethodModel;
+use crate::errors::error::AppError;
+use crate::utils::money::validate_amount;
+use crate::utils::traits::HasId;
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ShippingKind {
+    FlatRate,
+    WeightTiers,
+    FreeOver,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct WeightTier {
+    /// Heaviest cart, in grams, this tier ships.
+    pub max_weight_grams: i32,
+    #[schema(value_type = String, example = "4.99")]
+    pub price: BigDecimal,
+}
+
+/**
+ * What a shipping method costs, stored as the `rule` json of the method and
+ * told apart by its `type`.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ShippingRule {
+    /// The same `price` for every cart.
+    FlatRate {
+        #[schema(value_type = String, example = "4.99")]
+        price: BigDecimal,
+    },
+    /// The price of the lightest tier the cart's weight fits in, carts heavier
+    /// than every tier can't use the method.
+    WeightTiers { tiers: Vec<WeightTier> },
+    /// Free for carts worth `threshold` or more after discounts, `price`
+    /// otherwise.
+    FreeOver {
+        #[schema(value_type = String, example = "50.00")]
+        threshold: BigDecimal,
+        #[schema(value_type = String, example = "4.99")]
+        price: BigDecimal,
+    },
+}
+
+impl ShippingRule {
+    pub fn kind(&self) -> ShippingKind {
+        match self {
+            ShippingRule::FlatRate { .. } => ShippingKind::FlatRate,
+            ShippingRule::WeightTiers { .. } => ShippingKind::WeightTiers,
+            ShippingRule::FreeOver { .. } => ShippingKind::FreeOver,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicShippingMethod {
+    pub id: i64,
+    pub zone_id: i64,
+    pub name: String,
+    pub kind: ShippingKind,
+    pub rule: ShippingRule,
+    pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPublicShippingMethod {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl From<AdminShippingMethodModel> for AdminPublicShippingMethod {
+    fn from(method: AdminShippingMethodModel) -> Self {
+        Self {
+            id: method.id,
+            zone_id: method.zone_id,
+            name: method.name,
+            kind: method.rule.kind(),
+            rule: method.rule.0,
+            is_active: method.is_active,
+            updated_at: method.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexShippingMethodDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub search: Option<String>,
+
+    pub zone_id: Option<i64>,
+
+    pub is_active: Option<bool>,
+}
+
+impl TryFrom<IndexShippingMethodDTO> for ShippingMethodFilters {
+    type Error = AppError;
+
+    fn try_from(dto: IndexShippingMethodDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            zone_id: dto.zone_id,
+            is_active: dto.is_active,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateShippingMethodDTO {
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub zone_id: Option<i64>,
+
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true)]
+    pub rule: Option<ShippingRule>,
+
+    pub is_active: Option<bool>,
+}
+
+pub struct CreateShippingMethodCommand {
+    pub zone_id: i64,
+    pub name: String,
+    pub rule: ShippingRule,
+    pub is_active: bool,
+}
+
+impl TryFrom<CreateShippingMethodDTO> for CreateShippingMethodCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreateShippingMethodDTO) -> Result<Self, Self::Error> {
+        let rule = dto.rule.unwrap();
+
+        validate_rule(&rule)?;
+
+        Ok(Self {
+            zone_id: dto.zone_id.unwrap(),
+            name: dto.name.unwrap(),
+            rule,
+            is_active: dto.is_active.unwrap_or(true),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateShippingMethodDTO {
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub zone_id: Option<i64>,
+
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true)]
+    pub rule: Option<ShippingRule>,
+
+    pub is_active: Option<bool>,
+}
+
+pub struct UpdateShippingMethodCommand {
+    pub zone_id: i64,
+    pub name: String,
+    pub rule: ShippingRule,
+    pub is_active: bool,
+}
+
+impl TryFrom<UpdateShippingMethodDTO> for UpdateShippingMethodCommand {
+    type Error = AppError;
+
+    fn try_from(dto: UpdateShippingMethodDTO) -> Result<Self, Self::Error> {
+        let rule = dto.rule.unwrap();
+
+        validate_rule(&rule)?;
+
+        Ok(Self {
+            zone_id: dto.zone_id.unwrap(),
+            name: dto.name.unwrap(),
+            rule,
+            is_active: dto.is_active.unwrap_or(true),
+        })
+    }
+}
+
+fn validate_rule(rule: &ShippingRule) -> Result<(), AppError> {
+    match rule {
+        ShippingRule::FlatRate { price } => validate_price("rule.price", price),
+        ShippingRule::WeightTiers { tiers } => {
+            if tiers.is_empty() {
+                return Err(AppError::field(
+                    "rule.tiers",
+                    "required",
+                    "field is required",
+                ));
+            }
+
+            let mut seen = HashSet::new();
+
+            for (index, tier) in tiers.iter().enumerate() {
+                let field = format!("rule.tiers[{}].max_weight_grams", index);
+
+                if tier.max_weight_grams < 1 {
+                    return Err(AppError::field(&field, "range", "must be at least 1"));
+                }
+
+                if !seen.insert(tier.max_weight_grams) {
+                    return Err(AppError::field(
+                        &field,
+                        "duplicate",
+                        "every tier needs its own max_weight_grams",
+                    ));
+                }
+
+                validate_price(&format!("rule.tiers[{}].price", index), &tier.price)?;
+            }
+
+            Ok(())
+        }
+        ShippingRule::FreeOver { threshold, price } => {
+            validate_price("rule.threshold", threshold)?;
+
+            if threshold.is_zero() {
+                return Err(AppError::field(
+                    "rule.threshold",
+                    "range",
+                    "must be greater than 0",
+                ));
+            }
+
+            validate_price("rule.price", price)
+        }
+    }
+}
+
+fn validate_price(field: &str, price: &BigDecimal) -> Result<(), AppError> {
+    validate_amount(price).map_err(|error| AppError::field(field, &error.code, &error.to_string()))
+}
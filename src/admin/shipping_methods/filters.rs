@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShippingMethodFilters {
+    pub zone_id: Option<i64>,
+    pub is_active: Option<bool>,
+}
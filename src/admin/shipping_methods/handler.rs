@@ -0,0 +1,177 @@
+use crate::admin::shipping_methods::dto::{
+    AdminPublicShippingMethod, CreateShippingMethodCommand, CreateShippingMethodDTO,
+    IndexShippingMethodDTO, UpdateShippingMethodCommand, UpdateShippingMethodDTO,
+};
+use crate::admin::shipping_methods::filters::ShippingMethodFilters;
+use crate::admin::shipping_methods::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use crate::utils::pagination::Paginate;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin/shipping-methods/list",
+    tag = "admin-shipping-methods",
+    params(IndexShippingMethodDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicShippingMethod>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_methods:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    state: web::Data<AppState>,
+    body: web::Query<IndexShippingMethodDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = ShippingMethodFilters::try_from(body.clone().into_inner())?;
+
+    let shipping_methods = state
+        .admin_shipping_method_service
+        .get_all_paginated_public(&pagination, &filters, &body.search)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        shipping_methods.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/shipping-methods/get/{id}",
+    tag = "admin-shipping-methods",
+    params(("id" = i64, Path, description = "Shipping method id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicShippingMethod>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_methods:read"])),
+)]
+#[instrument(skip_all)]
+pub async fn show(
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let shipping_method = state
+        .admin_shipping_method_service
+        .get_one_public(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(shipping_method)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/shipping-methods/create",
+    tag = "admin-shipping-methods",
+    request_body = CreateShippingMethodDTO,
+    responses(
+        (status = 201, description = "Shipping method created", body = SuccessResponse<AdminPublicShippingMethod>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_methods:create"])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreateShippingMethodDTO>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreateShippingMethodCommand::try_from(body.into_inner())?;
+    let shipping_method = state
+        .admin_shipping_method_service
+        .create(command, actor_id)
+        .await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(shipping_method.into_public())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/shipping-methods/update/{id}",
+    tag = "admin-shipping-methods",
+    params(("id" = i64, Path, description = "Shipping method id")),
+    request_body = UpdateShippingMethodDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_methods:update"])),
+)]
+#[instrument(skip_all)]
+pub async fn update(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdateShippingMethodDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = UpdateShippingMethodCommand::try_from(body.into_inner())?;
+    state
+        .admin_shipping_method_service
+        .update(command, id.into_inner(), actor_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/shipping-methods/delete/{id}",
+    tag = "admin-shipping-methods",
+    params(("id" = i64, Path, description = "Shipping method id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_methods:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_shipping_method_service
+        .delete(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,22 @@
+use crate::admin::shipping_methods::dto::ShippingRule;
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct AdminShippingMethodModel {
+    pub id: i64,
+    pub zone_id: i64,
+    pub name: String,
+    pub rule: Json<ShippingRule>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminShippingMethodModel {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
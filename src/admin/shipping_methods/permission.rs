@@ -0,0 +1,31 @@
+use crate::auth::traits::Scope;
+
+pub enum ShippingMethodScope {
+    Create,
+    Read,
+    Update,
+    Delete,
+    List,
+}
+
+impl Scope for ShippingMethodScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ShippingMethodScope::Create => "shipping_methods:create",
+            ShippingMethodScope::Read => "shipping_methods:read",
+            ShippingMethodScope::Update => "shipping_methods:update",
+            ShippingMethodScope::Delete => "shipping_methods:delete",
+            ShippingMethodScope::List => "shipping_methods:list",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![
+            ShippingMethodScope::Create,
+            ShippingMethodScope::Read,
+            ShippingMethodScope::Update,
+            ShippingMethodScope::Delete,
+            ShippingMethodScope::List,
+        ]
+    }
+}
@@ -0,0 +1,166 @@
+use crate::admin::shipping_methods::dto::{
+    CreateShippingMethodCommand, UpdateShippingMethodCommand,
+};
+use crate::admin::shipping_methods::filters::ShippingMethodFilters;
+use crate::admin::shipping_methods::model::AdminShippingMethodModel;
+use crate::errors::error::AppError;
+use crate::utils::pagination::Paginate;
+use crate::utils::traits::IsRepository;
+use sqlx::types::Json;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+const TAX_RATE_COLUMNS: &str = r#"
+    SELECT
+        shipping_methods.id,
+        shipping_methods.zone_id,
+        shipping_methods.name,
+        shipping_methods.rule,
+        shipping_methods.is_active,
+        shipping_methods.created_at,
+        shipping_methods.updated_at
+    FROM shipping_methods
+"#;
+
+pub struct AdminShippingMethodRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AdminShippingMethodRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminShippingMethodRepository {
+    #[instrument(skip_all)]
+    pub async fn index_paginated(
+        &self,
+        pagination: &Paginate,
+        search: &Option<String>,
+        filters: &ShippingMethodFilters,
+    ) -> Result<Vec<AdminShippingMethodModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(TAX_RATE_COLUMNS);
+
+        qb.push(" WHERE TRUE ");
+
+        // handle search
+        if let Some(search) = search {
+            qb.push(" AND shipping_methods.name ILIKE ");
+            qb.push_bind(format!("%{}%", search));
+        }
+
+        // zone
+        if let Some(zone_id) = filters.zone_id {
+            qb.push(" AND shipping_methods.zone_id = ");
+            qb.push_bind(zone_id);
+        }
+
+        // is active
+        if let Some(is_active) = filters.is_active {
+            qb.push(" AND shipping_methods.is_active = ");
+            qb.push_bind(is_active);
+        }
+
+        qb.push(" ORDER BY shipping_methods.zone_id, shipping_methods.id ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        qb.build_query_as::<AdminShippingMethodModel>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn show(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<Option<AdminShippingMethodModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(TAX_RATE_COLUMNS);
+
+        qb.push(" WHERE shipping_methods.id = ");
+        qb.push_bind(id);
+
+        qb.build_query_as::<AdminShippingMethodModel>()
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &CreateShippingMethodCommand,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar(
+            r#"
+        INSERT INTO shipping_methods (zone_id, name, rule, is_active)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id;
+        "#,
+        )
+        .bind(cmd.zone_id)
+        .bind(&cmd.name)
+        .bind(Json(&cmd.rule))
+        .bind(cmd.is_active)
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn update(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &UpdateShippingMethodCommand,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+        UPDATE shipping_methods
+        SET (zone_id, name, rule, is_active, updated_at) = ($1, $2, $3, $4, now())
+        WHERE id = $5;
+        "#,
+        )
+        .bind(cmd.zone_id)
+        .bind(&cmd.name)
+        .bind(Json(&cmd.rule))
+        .bind(cmd.is_active)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM shipping_methods WHERE id = $1;",
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,47 @@
+use super::handler;
+use crate::admin::shipping_methods::permission::ShippingMethodScope;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{delete, get, post, put, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/shipping-methods")
+            .service(
+                resource("/list")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        ShippingMethodScope::List,
+                    ))))
+                    .route(get().to(handler::index)),
+            )
+            .service(
+                resource("/get/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        ShippingMethodScope::Read,
+                    ))))
+                    .route(get().to(handler::show)),
+            )
+            .service(
+                resource("/create")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        ShippingMethodScope::Create,
+                    ))))
+                    .route(post().to(handler::create)),
+            )
+            .service(
+                resource("/update/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        ShippingMethodScope::Update,
+                    ))))
+                    .route(put().to(handler::update)),
+            )
+            .service(
+                resource("/delete/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        ShippingMethodScope::Delete,
+                    ))))
+                    .route(delete().to(handler::delete)),
+            ),
+    );
+}
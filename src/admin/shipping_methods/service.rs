@@ -0,0 +1,171 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
+use crate::admin::shipping_methods::dto::{
+    AdminPublicShippingMethod, CreateShippingMethodCommand, UpdateShippingMethodCommand,
+};
+use crate::admin::shipping_methods::filters::ShippingMethodFilters;
+use crate::admin::shipping_methods::model::AdminShippingMethodModel;
+use crate::admin::shipping_methods::repository::AdminShippingMethodRepository;
+use crate::admin::shipping_methods::traits::IntoPublic;
+use crate::admin::shipping_zones::repository::AdminShippingZoneRepository;
+use crate::errors::error::AppError;
+use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct AdminShippingMethodService {
+    repository: AdminShippingMethodRepository,
+    zone_repository: AdminShippingZoneRepository,
+    audit_repository: AdminAuditRepository,
+}
+
+impl AdminShippingMethodService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: AdminShippingMethodRepository::new(pool.clone()),
+            zone_repository: AdminShippingZoneRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool),
+        }
+    }
+
+    pub async fn get_all_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &ShippingMethodFilters,
+        search: &Option<String>,
+    ) -> Result<PaginatedDataCollection<AdminPublicShippingMethod>, AppError> {
+        let data = self
+            .repository
+            .index_paginated(pagination, search, filters)
+            .await?;
+
+        Ok(PaginatedDataCollection::new(data, pagination.clone()).into_public())
+    }
+
+    pub async fn get_one(&self, id: i64) -> Result<AdminShippingMethodModel, AppError> {
+        let shipping_method = self.repository.show(self.repository.get_pool(), id).await?;
+
+        match shipping_method {
+            Some(shipping_method) => Ok(shipping_method),
+            None => Err(AppError::NotFound("Shipping method not found".to_string())),
+        }
+    }
+
+    pub async fn get_one_public(&self, id: i64) -> Result<AdminPublicShippingMethod, AppError> {
+        let shipping_method = self.get_one(id).await?;
+
+        Ok(shipping_method.into_public())
+    }
+
+    pub async fn create(
+        &self,
+        cmd: CreateShippingMethodCommand,
+        actor_id: i64,
+    ) -> Result<AdminShippingMethodModel, AppError> {
+        self.check_zone_exists(cmd.zone_id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let id = self.repository.create(&mut *tx, &cmd).await?;
+
+        let shipping_method = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Shipping method not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(
+                    actor_id,
+                    AuditEntity::ShippingMethod,
+                    id,
+                    &shipping_method,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(shipping_method)
+    }
+
+    pub async fn update(
+        &self,
+        cmd: UpdateShippingMethodCommand,
+        id: i64,
+        actor_id: i64,
+    ) -> Result<AdminShippingMethodModel, AppError> {
+        let before = self.get_one(id).await?;
+
+        self.check_zone_exists(cmd.zone_id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.update(&mut *tx, &cmd, id).await?;
+
+        let after = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Shipping method not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::ShippingMethod,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(after)
+    }
+
+    pub async fn delete(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let shipping_method = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(
+                    actor_id,
+                    AuditEntity::ShippingMethod,
+                    id,
+                    &shipping_method,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(deleted)
+    }
+
+    async fn check_zone_exists(&self, zone_id: i64) -> Result<(), AppError> {
+        if self
+            .zone_repository
+            .show(self.zone_repository.get_pool(), zone_id)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::NotFound(format!(
+                "Shipping zone with id {} not found",
+                zone_id
+            )));
+        }
+
+        Ok(())
+    }
+}
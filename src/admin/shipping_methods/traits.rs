@@ -0,0 +1,27 @@
+use crate::admin::shipping_methods::dto::AdminPublicShippingMethod;
+use crate::admin::shipping_methods::model::AdminShippingMethodModel;
+use crate::utils::pagination::PaginatedDataCollection;
+
+pub trait IntoPublic<T> {
+    fn into_public(self) -> T;
+}
+
+impl IntoPublic<AdminPublicShippingMethod> for AdminShippingMethodModel {
+    fn into_public(self) -> AdminPublicShippingMethod {
+        AdminPublicShippingMethod::from(self)
+    }
+}
+
+impl IntoPublic<PaginatedDataCollection<AdminPublicShippingMethod>>
+    for PaginatedDataCollection<AdminShippingMethodModel>
+{
+    fn into_public(self) -> PaginatedDataCollection<AdminPublicShippingMethod> {
+        PaginatedDataCollection::new(
+            self.data
+                .into_iter()
+                .map(AdminPublicShippingMethod::from)
+                .collect(),
+            self.pagination,
+        )
+    }
+}
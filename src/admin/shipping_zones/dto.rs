@@ -0,0 +1,202 @@
+use crate::admin::shipping_zones::filters::ShippingZoneFilters;
+use crate::admin::shipping_zones::model::AdminShippingZoneModel;
+use crate::admin::tax_rates::dto::{normalize_country, normalize_postcode};
+use crate::errors::error::AppError;
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+/** Country codes upper case, each listed once, in the order given. */
+fn normalize_countries(countries: Vec<String>) -> Result<Vec<String>, AppError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(countries.len());
+
+    for (index, country) in countries.iter().enumerate() {
+        let country = normalize_country(country).map_err(|_| {
+            AppError::field(
+                &format!("countries[{}]", index),
+                "country_format",
+                "must be a two letter country code",
+            )
+        })?;
+
+        if !normalized.contains(&country) {
+            normalized.push(country);
+        }
+    }
+
+    Ok(normalized)
+}
+
+/** Postcode patterns normalized like the ones of tax rates, each listed once. */
+fn normalize_postcodes(postcodes: Vec<String>) -> Result<Vec<String>, AppError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(postcodes.len());
+
+    for (index, postcode) in postcodes.iter().enumerate() {
+        let postcode = normalize_postcode(postcode, true).map_err(|_| {
+            AppError::field(
+                &format!("postcodes[{}]", index),
+                "postcode_format",
+                "may only contain letters, digits, - and *",
+            )
+        })?;
+
+        if !normalized.contains(&postcode) {
+            normalized.push(postcode);
+        }
+    }
+
+    Ok(normalized)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicShippingZone {
+    pub id: i64,
+    pub name: String,
+    pub countries: Vec<String>,
+    pub postcodes: Vec<String>,
+    pub priority: i32,
+    pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPublicShippingZone {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl From<AdminShippingZoneModel> for AdminPublicShippingZone {
+    fn from(zone: AdminShippingZoneModel) -> Self {
+        Self {
+            id: zone.id,
+            name: zone.name,
+            countries: zone.countries,
+            postcodes: zone.postcodes,
+            priority: zone.priority,
+            is_active: zone.is_active,
+            updated_at: zone.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexShippingZoneDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub search: Option<String>,
+
+    /// Only zones covering this country.
+    pub country: Option<String>,
+
+    pub is_active: Option<bool>,
+}
+
+impl TryFrom<IndexShippingZoneDTO> for ShippingZoneFilters {
+    type Error = AppError;
+
+    fn try_from(dto: IndexShippingZoneDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            country: dto.country.as_deref().map(normalize_country).transpose()?,
+            is_active: dto.is_active,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateShippingZoneDTO {
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    /// Two letter codes of the countries the zone covers.
+    #[validate(required, length(min = 1, max = 250))]
+    #[schema(required = true, min_items = 1, max_items = 250)]
+    pub countries: Option<Vec<String>>,
+
+    /// Limits the zone to matching postcodes, `*` matches any run of characters.
+    #[validate(length(max = 500))]
+    #[schema(max_items = 500)]
+    pub postcodes: Option<Vec<String>>,
+
+    /// Breaks ties between equally specific zones, higher wins.
+    pub priority: Option<i32>,
+
+    pub is_active: Option<bool>,
+}
+
+pub struct CreateShippingZoneCommand {
+    pub name: String,
+    pub countries: Vec<String>,
+    pub postcodes: Vec<String>,
+    pub priority: i32,
+    pub is_active: bool,
+}
+
+impl TryFrom<CreateShippingZoneDTO> for CreateShippingZoneCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreateShippingZoneDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: dto.name.unwrap(),
+            countries: normalize_countries(dto.countries.unwrap())?,
+            postcodes: normalize_postcodes(dto.postcodes.unwrap_or_default())?,
+            priority: dto.priority.unwrap_or(0),
+            is_active: dto.is_active.unwrap_or(true),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateShippingZoneDTO {
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    /// Two letter codes of the countries the zone covers.
+    #[validate(required, length(min = 1, max = 250))]
+    #[schema(required = true, min_items = 1, max_items = 250)]
+    pub countries: Option<Vec<String>>,
+
+    /// Limits the zone to matching postcodes, `*` matches any run of characters.
+    #[validate(length(max = 500))]
+    #[schema(max_items = 500)]
+    pub postcodes: Option<Vec<String>>,
+
+    /// Breaks ties between equally specific zones, higher wins.
+    pub priority: Option<i32>,
+
+    pub is_active: Option<bool>,
+}
+
+pub struct UpdateShippingZoneCommand {
+    pub name: String,
+    pub countries: Vec<String>,
+    pub postcodes: Vec<String>,
+    pub priority: i32,
+    pub is_active: bool,
+}
+
+impl TryFrom<UpdateShippingZoneDTO> for UpdateShippingZoneCommand {
+    type Error = AppError;
+
+    fn try_from(dto: UpdateShippingZoneDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: dto.name.unwrap(),
+            countries: normalize_countries(dto.countries.unwrap())?,
+            postcodes: normalize_postcodes(dto.postcodes.unwrap_or_default())?,
+            priority: dto.priority.unwrap_or(0),
+            is_active: dto.is_active.unwrap_or(true),
+        })
+    }
+}
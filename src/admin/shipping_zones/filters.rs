@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShippingZoneFilters {
+    pub country: Option<String>,
+    pub is_active: Option<bool>,
+}
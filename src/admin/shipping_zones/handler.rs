@@ -0,0 +1,177 @@
+use crate::admin::shipping_zones::dto::{
+    AdminPublicShippingZone, CreateShippingZoneCommand, CreateShippingZoneDTO,
+    IndexShippingZoneDTO, UpdateShippingZoneCommand, UpdateShippingZoneDTO,
+};
+use crate::admin::shipping_zones::filters::ShippingZoneFilters;
+use crate::admin::shipping_zones::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use crate::utils::pagination::Paginate;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin/shipping-zones/list",
+    tag = "admin-shipping-zones",
+    params(IndexShippingZoneDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicShippingZone>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_zones:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    state: web::Data<AppState>,
+    body: web::Query<IndexShippingZoneDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = ShippingZoneFilters::try_from(body.clone().into_inner())?;
+
+    let shipping_zones = state
+        .admin_shipping_zone_service
+        .get_all_paginated_public(&pagination, &filters, &body.search)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        shipping_zones.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/shipping-zones/get/{id}",
+    tag = "admin-shipping-zones",
+    params(("id" = i64, Path, description = "Shipping zone id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicShippingZone>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_zones:read"])),
+)]
+#[instrument(skip_all)]
+pub async fn show(
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let shipping_zone = state
+        .admin_shipping_zone_service
+        .get_one_public(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(shipping_zone)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/shipping-zones/create",
+    tag = "admin-shipping-zones",
+    request_body = CreateShippingZoneDTO,
+    responses(
+        (status = 201, description = "Shipping zone created", body = SuccessResponse<AdminPublicShippingZone>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_zones:create"])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreateShippingZoneDTO>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreateShippingZoneCommand::try_from(body.into_inner())?;
+    let shipping_zone = state
+        .admin_shipping_zone_service
+        .create(command, actor_id)
+        .await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(shipping_zone.into_public())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/shipping-zones/update/{id}",
+    tag = "admin-shipping-zones",
+    params(("id" = i64, Path, description = "Shipping zone id")),
+    request_body = UpdateShippingZoneDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_zones:update"])),
+)]
+#[instrument(skip_all)]
+pub async fn update(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdateShippingZoneDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = UpdateShippingZoneCommand::try_from(body.into_inner())?;
+    state
+        .admin_shipping_zone_service
+        .update(command, id.into_inner(), actor_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/shipping-zones/delete/{id}",
+    tag = "admin-shipping-zones",
+    params(("id" = i64, Path, description = "Shipping zone id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["shipping_zones:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_shipping_zone_service
+        .delete(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
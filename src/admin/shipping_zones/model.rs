@@ -0,0 +1,21 @@
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct AdminShippingZoneModel {
+    pub id: i64,
+    pub name: String,
+    pub countries: Vec<String>,
+    pub postcodes: Vec<String>,
+    pub priority: i32,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminShippingZoneModel {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
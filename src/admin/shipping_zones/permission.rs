@@ -0,0 +1,31 @@
+use crate::auth::traits::Scope;
+
+pub enum ShippingZoneScope {
+    Create,
+    Read,
+    Update,
+    Delete,
+    List,
+}
+
+impl Scope for ShippingZoneScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ShippingZoneScope::Create => "shipping_zones:create",
+            ShippingZoneScope::Read => "shipping_zones:read",
+            ShippingZoneScope::Update => "shipping_zones:update",
+            ShippingZoneScope::Delete => "shipping_zones:delete",
+            ShippingZoneScope::List => "shipping_zones:list",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![
+            ShippingZoneScope::Create,
+            ShippingZoneScope::Read,
+            ShippingZoneScope::Update,
+            ShippingZoneScope::Delete,
+            ShippingZoneScope::List,
+        ]
+    }
+}
@@ -0,0 +1,168 @@
+use crate::admin::shipping_zones::dto::{CreateShippingZoneCommand, UpdateShippingZoneCommand};
+use crate::admin::shipping_zones::filters::ShippingZoneFilters;
+use crate::admin::shipping_zones::model::AdminShippingZoneModel;
+use crate::errors::error::AppError;
+use crate::utils::pagination::Paginate;
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+const TAX_RATE_COLUMNS: &str = r#"
+    SELECT
+        shipping_zones.id,
+        shipping_zones.name,
+        shipping_zones.countries,
+        shipping_zones.postcodes,
+        shipping_zones.priority,
+        shipping_zones.is_active,
+        shipping_zones.created_at,
+        shipping_zones.updated_at
+    FROM shipping_zones
+"#;
+
+pub struct AdminShippingZoneRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AdminShippingZoneRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminShippingZoneRepository {
+    #[instrument(skip_all)]
+    pub async fn index_paginated(
+        &self,
+        pagination: &Paginate,
+        search: &Option<String>,
+        filters: &ShippingZoneFilters,
+    ) -> Result<Vec<AdminShippingZoneModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(TAX_RATE_COLUMNS);
+
+        qb.push(" WHERE TRUE ");
+
+        // handle search
+        if let Some(search) = search {
+            qb.push(" AND shipping_zones.name ILIKE ");
+            qb.push_bind(format!("%{}%", search));
+        }
+
+        // country
+        if let Some(country) = &filters.country {
+            qb.push(" AND ");
+            qb.push_bind(country);
+            qb.push(" = ANY(shipping_zones.countries) ");
+        }
+
+        // is active
+        if let Some(is_active) = filters.is_active {
+            qb.push(" AND shipping_zones.is_active = ");
+            qb.push_bind(is_active);
+        }
+
+        qb.push(" ORDER BY shipping_zones.priority DESC, shipping_zones.id ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        qb.build_query_as::<AdminShippingZoneModel>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn show(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<Option<AdminShippingZoneModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(TAX_RATE_COLUMNS);
+
+        qb.push(" WHERE shipping_zones.id = ");
+        qb.push_bind(id);
+
+        qb.build_query_as::<AdminShippingZoneModel>()
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &CreateShippingZoneCommand,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar(
+            r#"
+        INSERT INTO shipping_zones (name, countries, postcodes, priority, is_active)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id;
+        "#,
+        )
+        .bind(&cmd.name)
+        .bind(&cmd.countries)
+        .bind(&cmd.postcodes)
+        .bind(cmd.priority)
+        .bind(cmd.is_active)
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn update(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &UpdateShippingZoneCommand,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+        UPDATE shipping_zones
+        SET (name, countries, postcodes, priority, is_active, updated_at)
+            = ($1, $2, $3, $4, $5, now())
+        WHERE id = $6;
+        "#,
+        )
+        .bind(&cmd.name)
+        .bind(&cmd.countries)
+        .bind(&cmd.postcodes)
+        .bind(cmd.priority)
+        .bind(cmd.is_active)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM shipping_zones WHERE id = $1;",
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,43 @@
+use super::handler;
+use crate::admin::shipping_zones::permission::ShippingZoneScope;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{delete, get, post, put, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/shipping-zones")
+            .service(
+                resource("/list")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(ShippingZoneScope::List))))
+                    .route(get().to(handler::index)),
+            )
+            .service(
+                resource("/get/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(ShippingZoneScope::Read))))
+                    .route(get().to(handler::show)),
+            )
+            .service(
+                resource("/create")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        ShippingZoneScope::Create,
+                    ))))
+                    .route(post().to(handler::create)),
+            )
+            .service(
+                resource("/update/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        ShippingZoneScope::Update,
+                    ))))
+                    .route(put().to(handler::update)),
+            )
+            .service(
+                resource("/delete/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        ShippingZoneScope::Delete,
+                    ))))
+                    .route(delete().to(handler::delete)),
+            ),
+    );
+}
@@ -0,0 +1,148 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
+use crate::admin::shipping_zones::dto::{
+    AdminPublicShippingZone, CreateShippingZoneCommand, UpdateShippingZoneCommand,
+};
+use crate::admin::shipping_zones::filters::ShippingZoneFilters;
+use crate::admin::shipping_zones::model::AdminShippingZoneModel;
+use crate::admin::shipping_zones::repository::AdminShippingZoneRepository;
+use crate::admin::shipping_zones::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct AdminShippingZoneService {
+    repository: AdminShippingZoneRepository,
+    audit_repository: AdminAuditRepository,
+}
+
+impl AdminShippingZoneService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: AdminShippingZoneRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool),
+        }
+    }
+
+    pub async fn get_all_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &ShippingZoneFilters,
+        search: &Option<String>,
+    ) -> Result<PaginatedDataCollection<AdminPublicShippingZone>, AppError> {
+        let data = self
+            .repository
+            .index_paginated(pagination, search, filters)
+            .await?;
+
+        Ok(PaginatedDataCollection::new(data, pagination.clone()).into_public())
+    }
+
+    pub async fn get_one(&self, id: i64) -> Result<AdminShippingZoneModel, AppError> {
+        let shipping_zone = self.repository.show(self.repository.get_pool(), id).await?;
+
+        match shipping_zone {
+            Some(shipping_zone) => Ok(shipping_zone),
+            None => Err(AppError::NotFound("Shipping zone not found".to_string())),
+        }
+    }
+
+    pub async fn get_one_public(&self, id: i64) -> Result<AdminPublicShippingZone, AppError> {
+        let shipping_zone = self.get_one(id).await?;
+
+        Ok(shipping_zone.into_public())
+    }
+
+    pub async fn create(
+        &self,
+        cmd: CreateShippingZoneCommand,
+        actor_id: i64,
+    ) -> Result<AdminShippingZoneModel, AppError> {
+        let mut tx = self.repository.start_transaction().await?;
+
+        let id = self.repository.create(&mut *tx, &cmd).await?;
+
+        let shipping_zone = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Shipping zone not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(
+                    actor_id,
+                    AuditEntity::ShippingZone,
+                    id,
+                    &shipping_zone,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(shipping_zone)
+    }
+
+    pub async fn update(
+        &self,
+        cmd: UpdateShippingZoneCommand,
+        id: i64,
+        actor_id: i64,
+    ) -> Result<AdminShippingZoneModel, AppError> {
+        let before = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.update(&mut *tx, &cmd, id).await?;
+
+        let after = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Shipping zone not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::ShippingZone,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(after)
+    }
+
+    pub async fn delete(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let shipping_zone = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(
+                    actor_id,
+                    AuditEntity::ShippingZone,
+                    id,
+                    &shipping_zone,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(deleted)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::admin::shipping_zones::dto::AdminPublicShippingZone;
+use crate::admin::shipping_zones::model::AdminShippingZoneModel;
+use crate::utils::pagination::PaginatedDataCollection;
+
+pub trait IntoPublic<T> {
+    fn into_public(self) -> T;
+}
+
+impl IntoPublic<AdminPublicShippingZone> for AdminShippingZoneModel {
+    fn into_public(self) -> AdminPublicShippingZone {
+        AdminPublicShippingZone::from(self)
+    }
+}
+
+impl IntoPublic<PaginatedDataCollection<AdminPublicShippingZone>>
+    for PaginatedDataCollection<AdminShippingZoneModel>
+{
+    fn into_public(self) -> PaginatedDataCollection<AdminPublicShippingZone> {
+        PaginatedDataCollection::new(
+            self.data
+                .into_iter()
+                .map(AdminPublicShippingZone::from)
+                .collect(),
+            self.pagination,
+        )
+    }
+}
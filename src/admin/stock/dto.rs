@@ -0,0 +1,339 @@
+use crate::admin::stock::filters::StockMovementFilters;
+use crate::admin::stock::model::{StockDriftModel, StockMovementModel};
+use crate::errors::error::AppError;
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StockMovementKind {
+    Receipt,
+    Sale,
+    Adjustment,
+    Return,
+    Transfer,
+}
+
+impl StockMovementKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockMovementKind::Receipt => "receipt",
+            StockMovementKind::Sale => "sale",
+            StockMovementKind::Adjustment => "adjustment",
+            StockMovementKind::Return => "return",
+            StockMovementKind::Transfer => "transfer",
+        }
+    }
+
+    /**
+     * Receipts and returns add stock, sales take it away, adjustments and
+     * transfers go either way. None of them can be zero.
+     */
+    pub fn check_quantity(&self, quantity: i32) -> Result<(), AppError> {
+        let valid = match self {
+            StockMovementKind::Receipt | StockMovementKind::Return => quantity > 0,
+            StockMovementKind::Sale => quantity < 0,
+            StockMovementKind::Adjustment | StockMovementKind::Transfer => quantity != 0,
+        };
+
+        if valid {
+            return Ok(());
+        }
+
+        let message = match self {
+            StockMovementKind::Receipt => "must be positive for a receipt",
+            StockMovementKind::Return => "must be positive for a return",
+            StockMovementKind::Sale => "must be negative for a sale",
+            StockMovementKind::Adjustment | StockMovementKind::Transfer => "must not be 0",
+        };
+
+        Err(AppError::field("quantity", "invalid_sign", message))
+    }
+}
+
+impl FromStr for StockMovementKind {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "receipt" => Ok(StockMovementKind::Receipt),
+            "sale" => Ok(StockMovementKind::Sale),
+            "adjustment" => Ok(StockMovementKind::Adjustment),
+            "return" => Ok(StockMovementKind::Return),
+            "transfer" => Ok(StockMovementKind::Transfer),
+            _ => Err(AppError::field(
+                "kind",
+                "invalid_choice",
+                "must be one of receipt, sale, adjustment, return, transfer",
+            )),
+        }
+    }
+}
+
+/**
+ * One entry to append to the ledger, `quantity` is the signed change. It
+ * lands in the default warehouse when `warehouse_id` is empty.
+ */
+pub struct RecordStockMovementCommand {
+    pub product_id: i64,
+    pub warehouse_id: Option<i64>,
+    pub kind: StockMovementKind,
+    pub quantity: i32,
+    pub reason: Option<String>,
+    pub actor_id: Option<i64>,
+}
+
+impl RecordStockMovementCommand {
+    /**
+     * The adjustment that takes stock from `from` to `to`, for writes that
+     * set the stock instead of changing it. The difference goes to the
+     * default warehouse. `None` when nothing changes.
+     */
+    pub fn adjustment(
+        product_id: i64,
+        from: i32,
+        to: i32,
+        reason: &str,
+        actor_id: Option<i64>,
+    ) -> Option<Self> {
+        (from != to).then(|| Self {
+            product_id,
+            warehouse_id: None,
+            kind: StockMovementKind::Adjustment,
+            quantity: to - from,
+            reason: Some(reason.to_string()),
+            actor_id,
+        })
+    }
+
+    /**
+     * What the movement does to the product's stock across warehouses. Each
+     * leg of a transfer moves units between warehouses, the total stays.
+     */
+    pub fn product_change(&self) -> i32 {
+        if self.kind == StockMovementKind::Transfer {
+            0
+        } else {
+            self.quantity
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicStockMovement {
+    pub id: i64,
+    pub product_id: i64,
+    pub warehouse_id: i64,
+    pub kind: String,
+    /// Signed change, negative for stock taken away.
+    pub quantity: i32,
+    /// Stock of the product across warehouses right after the movement.
+    pub balance: i32,
+    pub reason: Option<String>,
+    /// Id of the admin who posted it, empty for system movements.
+    pub actor_id: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPublicStockMovement {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl From<StockMovementModel> for AdminPublicStockMovement {
+    fn from(movement: StockMovementModel) -> Self {
+        Self {
+            id: movement.id,
+            product_id: movement.product_id,
+            warehouse_id: movement.warehouse_id,
+            kind: movement.kind,
+            quantity: movement.quantity,
+            balance: movement.balance,
+            reason: movement.reason,
+            actor_id: movement.actor_id,
+            created_at: movement.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicStockDrift {
+    pub product_id: i64,
+    pub name: String,
+    /// Stock stored on the product.
+    pub quantity: i32,
+    /// Sum of the product's ledger.
+    pub ledger_quantity: i64,
+    /// Sum of the product's stock in each warehouse.
+    pub warehouse_quantity: i64,
+    /// `quantity - ledger_quantity`.
+    pub drift: i64,
+}
+
+impl From<StockDriftModel> for AdminPublicStockDrift {
+    fn from(drift: StockDriftModel) -> Self {
+        Self {
+            product_id: drift.product_id,
+            name: drift.name,
+            quantity: drift.quantity,
+            ledger_quantity: drift.ledger_quantity,
+            warehouse_quantity: drift.warehouse_quantity,
+            drift: i64::from(drift.quantity) - drift.ledger_quantity,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateStockMovementDTO {
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub product_id: Option<i64>,
+
+    /// The default warehouse when empty.
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub warehouse_id: Option<i64>,
+
+    /// `receipt`, `sale`, `adjustment` or `return`.
+    #[validate(required, length(min = 1))]
+    #[schema(required = true, example = "receipt")]
+    pub kind: Option<String>,
+
+    /// Signed change: positive for receipts and returns, negative for sales.
+    #[validate(required)]
+    #[schema(required = true, example = 12)]
+    pub quantity: Option<i32>,
+
+    /// Required for adjustments.
+    #[validate(length(min = 1, max = 255))]
+    #[schema(min_length = 1, max_length = 255, example = "Delivery PO-1042")]
+    pub reason: Option<String>,
+}
+
+pub struct CreateStockMovementCommand {
+    pub product_id: i64,
+    pub warehouse_id: Option<i64>,
+    pub kind: StockMovementKind,
+    pub quantity: i32,
+    pub reason: Option<String>,
+}
+
+impl TryFrom<CreateStockMovementDTO> for CreateStockMovementCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreateStockMovementDTO) -> Result<Self, Self::Error> {
+        let kind = StockMovementKind::from_str(&dto.kind.unwrap())?;
+        let quantity = dto.quantity.unwrap();
+
+        if kind == StockMovementKind::Transfer {
+            return Err(AppError::field(
+                "kind",
+                "invalid_choice",
+                "transfers go through /admin/warehouses/transfer",
+            ));
+        }
+
+        kind.check_quantity(quantity)?;
+
+        let reason = dto
+            .reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+
+        if kind == StockMovementKind::Adjustment && reason.is_none() {
+            return Err(AppError::field(
+                "reason",
+                "required",
+                "is required for an adjustment",
+            ));
+        }
+
+        Ok(Self {
+            product_id: dto.product_id.unwrap(),
+            warehouse_id: dto.warehouse_id,
+            kind,
+            quantity,
+            reason,
+        })
+    }
+}
+
+impl CreateStockMovementCommand {
+    pub fn into_record(self, warehouse_id: i64, actor_id: i64) -> RecordStockMovementCommand {
+        RecordStockMovementCommand {
+            product_id: self.product_id,
+            warehouse_id: Some(warehouse_id),
+            kind: self.kind,
+            quantity: self.quantity,
+            reason: self.reason,
+            actor_id: Some(actor_id),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexStockMovementsDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
+    pub product_id: Option<i64>,
+
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
+    pub warehouse_id: Option<i64>,
+
+    /// `receipt`, `sale`, `adjustment`, `return` or `transfer`.
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub kind: Option<String>,
+
+    /// Id of the admin who posted the movement.
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
+    pub actor_id: Option<i64>,
+
+    /// Inclusive lower bound, RFC 3339.
+    pub from: Option<DateTime<Utc>>,
+
+    /// Exclusive upper bound, RFC 3339.
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<IndexStockMovementsDTO> for StockMovementFilters {
+    type Error = AppError;
+
+    fn try_from(dto: IndexStockMovementsDTO) -> Result<Self, Self::Error> {
+        let kind = match dto.kind {
+            Some(kind) => Some(StockMovementKind::from_str(&kind)?),
+            None => None,
+        };
+
+        if let (Some(from), Some(to)) = (dto.from, dto.to)
+            && from >= to
+        {
+            return Err(AppError::field("to", "invalid_range", "must be after from"));
+        }
+
+        Ok(Self {
+            product_id: dto.product_id,
+            warehouse_id: dto.warehouse_id,
+            kind,
+            actor_id: dto.actor_id,
+            from: dto.from,
+            to: dto.to,
+        })
+    }
+}
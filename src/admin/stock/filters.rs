@@ -0,0 +1,13 @@
+use crate::admin::stock::dto::StockMovementKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StockMovementFilters {
+    pub product_id: Option<i64>,
+    pub warehouse_id: Option<i64>,
+    pub kind: Option<StockMovementKind>,
+    pub actor_id: Option<i64>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
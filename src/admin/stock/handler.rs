@@ -0,0 +1,101 @@
+use crate::admin::stock::dto::{
+    AdminPublicStockDrift, AdminPublicStockMovement, CreateStockMovementCommand,
+    CreateStockMovementDTO, IndexStockMovementsDTO,
+};
+use crate::admin::stock::filters::StockMovementFilters;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use crate::utils::pagination::Paginate;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin/stock/list",
+    tag = "admin-stock",
+    params(IndexStockMovementsDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicStockMovement>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["stock:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    state: web::Data<AppState>,
+    body: web::Query<IndexStockMovementsDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = StockMovementFilters::try_from(body.clone().into_inner())?;
+
+    let movements = state
+        .admin_stock_service
+        .get_all_paginated_public(&pagination, &filters)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        movements.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/stock/create",
+    tag = "admin-stock",
+    request_body = CreateStockMovementDTO,
+    responses(
+        (status = 201, description = "Movement posted", body = SuccessResponse<AdminPublicStockMovement>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Product or warehouse not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Not enough stock", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["stock:create"])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreateStockMovementDTO>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreateStockMovementCommand::try_from(body.into_inner())?;
+    let movement = state.admin_stock_service.create(command, actor_id).await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(movement)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/stock/reconcile",
+    tag = "admin-stock",
+    responses(
+        (status = 200, description = "Products whose stock drifted from their ledger or warehouse stock", body = SuccessResponse<Vec<AdminPublicStockDrift>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["stock:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn reconcile(state: web::Data<AppState>) -> Result<impl Responder, AppError> {
+    let drift = state.admin_stock_service.get_drift().await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(drift)))
+}
@@ -0,0 +1,9 @@
+pub mod dto;
+mod filters;
+pub mod handler;
+pub mod model;
+pub mod permission;
+pub mod repository;
+pub mod routes;
+pub mod service;
+mod traits;
@@ -0,0 +1,35 @@
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct StockMovementModel {
+    pub id: i64,
+    pub product_id: i64,
+    pub warehouse_id: i64,
+    pub kind: String,
+    pub quantity: i32,
+    pub balance: i32,
+    pub reason: Option<String>,
+    pub actor_id: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl HasId for StockMovementModel {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+/**
+ * A product whose stock doesn't match the sum of its ledger or of its
+ * warehouse stock.
+ */
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct StockDriftModel {
+    pub product_id: i64,
+    pub name: String,
+    pub quantity: i32,
+    pub ledger_quantity: i64,
+    pub warehouse_quantity: i64,
+}
@@ -0,0 +1,19 @@
+use crate::auth::traits::Scope;
+
+pub enum StockScope {
+    Create,
+    List,
+}
+
+impl Scope for StockScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StockScope::Create => "stock:create",
+            StockScope::List => "stock:list",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![StockScope::Create, StockScope::List]
+    }
+}
@@ -0,0 +1,270 @@
+use crate::admin::stock::dto::RecordStockMovementCommand;
+use crate::admin::stock::filters::StockMovementFilters;
+use crate::admin::stock::model::{StockDriftModel, StockMovementModel};
+use crate::errors::error::AppError;
+use crate::utils::pagination::Paginate;
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+pub struct AdminStockRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AdminStockRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminStockRepository {
+    /**
+     * Newest first, every filter is optional.
+     */
+    #[instrument(skip_all)]
+    pub async fn index_paginated(
+        &self,
+        pagination: &Paginate,
+        filters: &StockMovementFilters,
+    ) -> Result<Vec<StockMovementModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT
+                id,
+                product_id,
+                warehouse_id,
+                kind,
+                quantity,
+                balance,
+                reason,
+                actor_id,
+                created_at
+            FROM stock_movements
+        "#,
+        );
+
+        let mut has_where = false;
+
+        let mut push_condition = |qb: &mut QueryBuilder<Postgres>, condition: &str| {
+            if has_where {
+                qb.push(" AND ");
+            } else {
+                qb.push(" WHERE ");
+                has_where = true;
+            }
+
+            qb.push(condition);
+        };
+
+        if let Some(product_id) = filters.product_id {
+            push_condition(&mut qb, " product_id = ");
+            qb.push_bind(product_id);
+        }
+
+        if let Some(warehouse_id) = filters.warehouse_id {
+            push_condition(&mut qb, " warehouse_id = ");
+            qb.push_bind(warehouse_id);
+        }
+
+        if let Some(kind) = filters.kind {
+            push_condition(&mut qb, " kind = ");
+            qb.push_bind(kind.as_str());
+        }
+
+        if let Some(actor_id) = filters.actor_id {
+            push_condition(&mut qb, " actor_id = ");
+            qb.push_bind(actor_id);
+        }
+
+        if let Some(from) = filters.from {
+            push_condition(&mut qb, " created_at >= ");
+            qb.push_bind(from);
+        }
+
+        if let Some(to) = filters.to {
+            push_condition(&mut qb, " created_at < ");
+            qb.push_bind(to);
+        }
+
+        qb.push(" ORDER BY created_at DESC, id DESC ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        let query = qb.build_query_as::<StockMovementModel>();
+
+        query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    /**
+     * Current stock of a product, locking the row so concurrent movements
+     * are checked against each other. `None` when the product is gone.
+     */
+    #[instrument(skip_all)]
+    pub async fn lock_stock(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        product_id: i64,
+    ) -> Result<Option<i32>, AppError> {
+        sqlx::query_scalar! {
+            r#"
+        SELECT quantity FROM products
+        WHERE id = $1 AND deleted_at IS NULL
+        FOR UPDATE;
+        "#,
+            product_id
+        }
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Appends a movement and applies it to the warehouse's stock and to
+     * `products.quantity` in the same statement, the only place stock is
+     * written. `None` when the product doesn't exist, Conflict when the
+     * warehouse would go below zero.
+     */
+    #[instrument(skip_all)]
+    pub async fn record(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &RecordStockMovementCommand,
+    ) -> Result<Option<StockMovementModel>, AppError> {
+        sqlx::query_as! {
+            StockMovementModel,
+            r#"
+        WITH target AS (
+            SELECT COALESCE($6, (SELECT id FROM warehouses WHERE is_default)) AS warehouse_id
+        ),
+        updated AS (
+            UPDATE warehouse_stock
+            SET quantity = warehouse_stock.quantity + $2, updated_at = now()
+            FROM target
+            WHERE warehouse_stock.warehouse_id = target.warehouse_id
+                AND warehouse_stock.product_id = $1
+            RETURNING warehouse_stock.warehouse_id
+        ),
+        inserted AS (
+            INSERT INTO warehouse_stock (warehouse_id, product_id, quantity)
+            SELECT target.warehouse_id, $1, $2
+            FROM target
+            WHERE NOT EXISTS (SELECT 1 FROM updated)
+            RETURNING warehouse_id
+        ),
+        product AS (
+            UPDATE products
+            SET quantity = quantity + $7, updated_at = now()
+            WHERE id = $1
+            RETURNING id, quantity
+        )
+        INSERT INTO stock_movements
+            (product_id, warehouse_id, kind, quantity, balance, reason, actor_id)
+        SELECT product.id, target.warehouse_id, $3, $2, product.quantity, $4, $5
+        FROM product, target
+        RETURNING id, product_id, warehouse_id, kind, quantity, balance, reason, actor_id, created_at;
+        "#,
+            cmd.product_id,
+            cmd.quantity,
+            cmd.kind.as_str(),
+            cmd.reason,
+            cmd.actor_id,
+            cmd.warehouse_id,
+            cmd.product_change()
+        }
+        .fetch_optional(executor)
+        .await
+        .map_err(|err| {
+            // services check the stock first, this catches writes that set it
+            if let sqlx::Error::Database(db_err) = &err
+                && db_err.constraint() == Some("chk_warehouse_stock_quantity")
+            {
+                return AppError::Conflict("Not enough stock in the warehouse".to_string());
+            }
+
+            AppError::Database(err)
+        })
+    }
+
+    /**
+     * Records the adjustment taking a product from `from` to `to` units, for
+     * writes that set the stock instead of changing it. Returns the stock
+     * after it.
+     */
+    #[instrument(skip_all)]
+    pub async fn adjust_to(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        product_id: i64,
+        from: i32,
+        to: i32,
+        reason: &str,
+        actor_id: Option<i64>,
+    ) -> Result<i32, AppError> {
+        let Some(cmd) =
+            RecordStockMovementCommand::adjustment(product_id, from, to, reason, actor_id)
+        else {
+            return Ok(from);
+        };
+
+        let movement = self
+            .record(executor, &cmd)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Product not found".to_string()))?;
+
+        Ok(movement.balance)
+    }
+
+    /**
+     * Products whose stock differs from the sum of their movements or of
+     * their warehouse stock.
+     */
+    #[instrument(skip_all)]
+    pub async fn drift(&self) -> Result<Vec<StockDriftModel>, AppError> {
+        sqlx::query_as! {
+            StockDriftModel,
+            r#"
+        SELECT
+            product_id AS "product_id!",
+            name AS "name!",
+            quantity AS "quantity!",
+            ledger_quantity AS "ledger_quantity!",
+            warehouse_quantity AS "warehouse_quantity!"
+        FROM (
+            SELECT
+                products.id AS product_id,
+                products.name,
+                products.quantity,
+                (
+                    SELECT COALESCE(SUM(stock_movements.quantity), 0)
+                    FROM stock_movements
+                    WHERE stock_movements.product_id = products.id
+                ) AS ledger_quantity,
+                (
+                    SELECT COALESCE(SUM(warehouse_stock.quantity), 0)
+                    FROM warehouse_stock
+                    WHERE warehouse_stock.product_id = products.id
+                ) AS warehouse_quantity
+            FROM products
+        ) totals
+        WHERE quantity <> ledger_quantity OR quantity <> warehouse_quantity
+        ORDER BY product_id;
+        "#
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+}
@@ -0,0 +1,27 @@
+use super::handler;
+use crate::admin::stock::permission::StockScope;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{get, post, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/stock")
+            .service(
+                resource("/list")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(StockScope::List))))
+                    .route(get().to(handler::index)),
+            )
+            .service(
+                resource("/create")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(StockScope::Create))))
+                    .route(post().to(handler::create)),
+            )
+            .service(
+                resource("/reconcile")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(StockScope::List))))
+                    .route(get().to(handler::reconcile)),
+            ),
+    );
+}
@@ -0,0 +1,139 @@
+use crate::admin::stock::dto::{
+    AdminPublicStockDrift, AdminPublicStockMovement, CreateStockMovementCommand,
+};
+use crate::admin::stock::filters::StockMovementFilters;
+use crate::admin::stock::model::StockMovementModel;
+use crate::admin::stock::repository::AdminStockRepository;
+use crate::admin::stock::traits::IntoPublic;
+use crate::admin::warehouses::repository::AdminWarehouseRepository;
+use crate::app::products::cache::ProductCache;
+use crate::app::stock_subscriptions::alerts::StockSubscriptionAlerts;
+use crate::app::wishlists::alerts::WishlistAlerts;
+use crate::errors::error::AppError;
+use crate::notifications::notifier::Notifier;
+use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+pub struct AdminStockService {
+    repository: AdminStockRepository,
+    warehouse_repository: AdminWarehouseRepository,
+    product_cache: ProductCache,
+    wishlist_alerts: WishlistAlerts,
+    stock_subscription_alerts: StockSubscriptionAlerts,
+}
+
+impl AdminStockService {
+    pub fn with_notifier(
+        pool: PgPool,
+        product_cache: ProductCache,
+        notifier: Arc<dyn Notifier>,
+    ) -> Self {
+        Self {
+            repository: AdminStockRepository::new(pool.clone()),
+            warehouse_repository: AdminWarehouseRepository::new(pool.clone()),
+            product_cache,
+            wishlist_alerts: WishlistAlerts::with_notifier(pool.clone(), notifier.clone()),
+            stock_subscription_alerts: StockSubscriptionAlerts::with_notifier(pool, notifier),
+        }
+    }
+
+    pub async fn get_all_paginated(
+        &self,
+        pagination: &Paginate,
+        filters: &StockMovementFilters,
+    ) -> Result<PaginatedDataCollection<StockMovementModel>, AppError> {
+        let data = self.repository.index_paginated(pagination, filters).await?;
+        Ok(PaginatedDataCollection::new(data, pagination.clone()))
+    }
+
+    pub async fn get_all_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &StockMovementFilters,
+    ) -> Result<PaginatedDataCollection<AdminPublicStockMovement>, AppError> {
+        let data = self.get_all_paginated(pagination, filters).await?;
+        Ok(data.into_public())
+    }
+
+    /**
+     * Posts a movement to the ledger of a product, at the default warehouse
+     * unless another one is given. Stock of a warehouse can't go below zero,
+     * a movement that would take it there is rejected.
+     */
+    pub async fn create(
+        &self,
+        cmd: CreateStockMovementCommand,
+        actor_id: i64,
+    ) -> Result<AdminPublicStockMovement, AppError> {
+        let mut tx = self.repository.start_transaction().await?;
+
+        let warehouse = match cmd.warehouse_id {
+            Some(id) => self.warehouse_repository.show(&mut *tx, id).await?,
+            None => self.warehouse_repository.find_default(&mut *tx).await?,
+        }
+        .ok_or_else(|| AppError::NotFound("Warehouse not found".to_string()))?;
+
+        self.repository
+            .lock_stock(&mut *tx, cmd.product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Product not found".to_string()))?;
+
+        let in_warehouse = self
+            .warehouse_repository
+            .stock_level(&mut *tx, warehouse.id, cmd.product_id)
+            .await?;
+
+        let before = self
+            .wishlist_alerts
+            .snapshot(&mut *tx, cmd.product_id)
+            .await?;
+
+        let balance = in_warehouse
+            .checked_add(cmd.quantity)
+            .ok_or_else(|| AppError::field("quantity", "range", "is too large"))?;
+
+        if balance < 0 {
+            return Err(AppError::Conflict(format!(
+                "Only {} in stock at {}, can't take away {}",
+                in_warehouse, warehouse.code, -cmd.quantity
+            )));
+        }
+
+        let movement = self
+            .repository
+            .record(&mut *tx, &cmd.into_record(warehouse.id, actor_id))
+            .await?
+            .ok_or_else(|| AppError::NotFound("Product not found".to_string()))?;
+
+        let after = self
+            .wishlist_alerts
+            .snapshot(&mut *tx, movement.product_id)
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.product_cache.invalidate_product(movement.product_id);
+
+        self.wishlist_alerts
+            .product_changed_or_warn(&before, &after)
+            .await;
+
+        self.stock_subscription_alerts
+            .product_changed_or_warn(&before, &after)
+            .await;
+
+        Ok(movement.into())
+    }
+
+    /**
+     * Products whose stock drifted from their ledger or warehouse stock, none
+     * when all is well.
+     */
+    pub async fn get_drift(&self) -> Result<Vec<AdminPublicStockDrift>, AppError> {
+        let drift = self.repository.drift().await?;
+
+        Ok(drift.into_iter().map(AdminPublicStockDrift::from).collect())
+    }
+}
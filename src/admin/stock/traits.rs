@@ -0,0 +1,21 @@
+use crate::admin::stock::dto::AdminPublicStockMovement;
+use crate::admin::stock::model::StockMovementModel;
+use crate::utils::pagination::PaginatedDataCollection;
+
+pub trait IntoPublic<T> {
+    fn into_public(self) -> T;
+}
+
+impl IntoPublic<PaginatedDataCollection<AdminPublicStockMovement>>
+    for PaginatedDataCollection<StockMovementModel>
+{
+    fn into_public(self) -> PaginatedDataCollection<AdminPublicStockMovement> {
+        PaginatedDataCollection::new(
+            self.data
+                .into_iter()
+                .map(AdminPublicStockMovement::from)
+                .collect(),
+            self.pagination,
+        )
+    }
+}
@@ -0,0 +1,310 @@
+use crate::admin::tax_rates::filters::TaxRateFilters;
+use crate::admin::tax_rates::model::AdminTaxRateModel;
+use crate::errors::error::AppError;
+use crate::utils::money::deserialize_amount;
+use crate::utils::traits::HasId;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+/** Class of products without one and of rates created without one. */
+pub const DEFAULT_TAX_CLASS: &str = "standard";
+
+/** Decimals a tax rate keeps, matches the `NUMERIC(7,4)` column. */
+const RATE_SCALE: i64 = 4;
+
+/** Tax classes are lower case codes, `standard` when none is given. */
+pub fn normalize_tax_class(tax_class: Option<String>) -> Result<String, AppError> {
+    let tax_class = match tax_class {
+        Some(tax_class) => tax_class.trim().to_lowercase(),
+        None => return Ok(DEFAULT_TAX_CLASS.to_string()),
+    };
+
+    let valid = !tax_class.is_empty()
+        && tax_class
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if !valid {
+        return Err(AppError::field(
+            "tax_class",
+            "tax_class_format",
+            "may only contain letters, digits, - and _",
+        ));
+    }
+
+    Ok(tax_class)
+}
+
+/** ISO 3166-1 alpha-2 country code, stored upper case. */
+pub fn normalize_country(country: &str) -> Result<String, AppError> {
+    let country = country.trim().to_uppercase();
+
+    if country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(AppError::field(
+            "country",
+            "country_format",
+            "must be a two letter country code",
+        ));
+    }
+
+    Ok(country)
+}
+
+/**
+ * Postcodes and postcode patterns are compared upper case without spaces, a
+ * `*` in a pattern stands for any run of characters.
+ */
+pub fn normalize_postcode(postcode: &str, allow_wildcard: bool) -> Result<String, AppError> {
+    let postcode: String = postcode
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase();
+
+    let valid = !postcode.is_empty()
+        && postcode
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || (allow_wildcard && c == '*'));
+
+    if !valid {
+        return Err(AppError::field(
+            "postcode",
+            "postcode_format",
+            "may only contain letters, digits and -",
+        ));
+    }
+
+    Ok(postcode)
+}
+
+fn validate_rate(rate: &BigDecimal) -> Result<(), ValidationError> {
+    let error = |code: &'static str, message: &'static str| {
+        Err(ValidationError::new(code).with_message(Cow::Borrowed(message)))
+    };
+
+    if rate < &BigDecimal::from(0) || rate > &BigDecimal::from(100) {
+        return error("range", "must be between 0 and 100");
+    }
+
+    let (_, scale) = rate.normalized().as_bigint_and_exponent();
+
+    if scale > RATE_SCALE {
+        return error("precision", "must have at most 4 decimals");
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicTaxRate {
+    pub id: i64,
+    pub name: String,
+    pub tax_class: String,
+    pub country: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    /// Percent, e.g. `19` or `8.875`.
+    #[schema(value_type = String, example = "19")]
+    pub rate: BigDecimal,
+    pub priority: i32,
+    pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPublicTaxRate {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl From<AdminTaxRateModel> for AdminPublicTaxRate {
+    fn from(rate: AdminTaxRateModel) -> Self {
+        Self {
+            id: rate.id,
+            name: rate.name,
+            tax_class: rate.tax_class,
+            country: rate.country,
+            region: rate.region,
+            postcode: rate.postcode,
+            rate: rate.rate.normalized(),
+            priority: rate.priority,
+            is_active: rate.is_active,
+            updated_at: rate.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexTaxRateDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub search: Option<String>,
+
+    pub country: Option<String>,
+
+    pub tax_class: Option<String>,
+
+    pub is_active: Option<bool>,
+}
+
+impl TryFrom<IndexTaxRateDTO> for TaxRateFilters {
+    type Error = AppError;
+
+    fn try_from(dto: IndexTaxRateDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            country: dto.country.as_deref().map(normalize_country).transpose()?,
+            tax_class: dto
+                .tax_class
+                .map(|tax_class| normalize_tax_class(Some(tax_class)))
+                .transpose()?,
+            is_active: dto.is_active,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateTaxRateDTO {
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    /// Products of this class are taxed at the rate, `standard` when empty.
+    #[validate(length(min = 1, max = 32))]
+    #[schema(min_length = 1, max_length = 32, example = "standard")]
+    pub tax_class: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true, example = "DE")]
+    pub country: Option<String>,
+
+    /// Limits the rate to one region of the country, compared case-insensitively.
+    #[validate(length(min = 1, max = 64))]
+    #[schema(min_length = 1, max_length = 64)]
+    pub region: Option<String>,
+
+    /// Limits the rate to matching postcodes, `*` matches any run of characters.
+    #[validate(length(min = 1, max = 16))]
+    #[schema(min_length = 1, max_length = 16, example = "80*")]
+    pub postcode: Option<String>,
+
+    #[validate(required, custom(function = "validate_rate"))]
+    #[schema(required = true, value_type = String, example = "19")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub rate: Option<BigDecimal>,
+
+    /// Breaks ties between equally specific rates, higher wins.
+    pub priority: Option<i32>,
+
+    pub is_active: Option<bool>,
+}
+
+pub struct CreateTaxRateCommand {
+    pub name: String,
+    pub tax_class: String,
+    pub country: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    pub rate: BigDecimal,
+    pub priority: i32,
+    pub is_active: bool,
+}
+
+impl TryFrom<CreateTaxRateDTO> for CreateTaxRateCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreateTaxRateDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: dto.name.unwrap(),
+            tax_class: normalize_tax_class(dto.tax_class)?,
+            country: normalize_country(&dto.country.unwrap())?,
+            region: dto.region.map(|region| region.trim().to_string()),
+            postcode: dto
+                .postcode
+                .map(|postcode| normalize_postcode(&postcode, true))
+                .transpose()?,
+            rate: dto.rate.unwrap(),
+            priority: dto.priority.unwrap_or(0),
+            is_active: dto.is_active.unwrap_or(true),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateTaxRateDTO {
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    /// Products of this class are taxed at the rate, `standard` when empty.
+    #[validate(length(min = 1, max = 32))]
+    #[schema(min_length = 1, max_length = 32, example = "standard")]
+    pub tax_class: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true, example = "DE")]
+    pub country: Option<String>,
+
+    /// Limits the rate to one region of the country, compared case-insensitively.
+    #[validate(length(min = 1, max = 64))]
+    #[schema(min_length = 1, max_length = 64)]
+    pub region: Option<String>,
+
+    /// Limits the rate to matching postcodes, `*` matches any run of characters.
+    #[validate(length(min = 1, max = 16))]
+    #[schema(min_length = 1, max_length = 16, example = "80*")]
+    pub postcode: Option<String>,
+
+    #[validate(required, custom(function = "validate_rate"))]
+    #[schema(required = true, value_type = String, example = "19")]
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub rate: Option<BigDecimal>,
+
+    /// Breaks ties between equally specific rates, higher wins.
+    pub priority: Option<i32>,
+
+    pub is_active: Option<bool>,
+}
+
+pub struct UpdateTaxRateCommand {
+    pub name: String,
+    pub tax_class: String,
+    pub country: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    pub rate: BigDecimal,
+    pub priority: i32,
+    pub is_active: bool,
+}
+
+impl TryFrom<UpdateTaxRateDTO> for UpdateTaxRateCommand {
+    type Error = AppError;
+
+    fn try_from(dto: UpdateTaxRateDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: dto.name.unwrap(),
+            tax_class: normalize_tax_class(dto.tax_class)?,
+            country: normalize_country(&dto.country.unwrap())?,
+            region: dto.region.map(|region| region.trim().to_string()),
+            postcode: dto
+                .postcode
+                .map(|postcode| normalize_postcode(&postcode, true))
+                .transpose()?,
+            rate: dto.rate.unwrap(),
+            priority: dto.priority.unwrap_or(0),
+            is_active: dto.is_active.unwrap_or(true),
+        })
+    }
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxRateFilters {
+    pub country: Option<String>,
+    pub tax_class: Option<String>,
+    pub is_active: Option<bool>,
+}
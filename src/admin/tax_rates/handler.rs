@@ -0,0 +1,177 @@
+use crate::admin::tax_rates::dto::{
+    AdminPublicTaxRate, CreateTaxRateCommand, CreateTaxRateDTO, IndexTaxRateDTO,
+    UpdateTaxRateCommand, UpdateTaxRateDTO,
+};
+use crate::admin::tax_rates::filters::TaxRateFilters;
+use crate::admin::tax_rates::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use crate::utils::pagination::Paginate;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin/tax-rates/list",
+    tag = "admin-tax-rates",
+    params(IndexTaxRateDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicTaxRate>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["tax_rates:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    state: web::Data<AppState>,
+    body: web::Query<IndexTaxRateDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = TaxRateFilters::try_from(body.clone().into_inner())?;
+
+    let tax_rates = state
+        .admin_tax_rate_service
+        .get_all_paginated_public(&pagination, &filters, &body.search)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        tax_rates.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/tax-rates/get/{id}",
+    tag = "admin-tax-rates",
+    params(("id" = i64, Path, description = "Tax rate id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicTaxRate>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["tax_rates:read"])),
+)]
+#[instrument(skip_all)]
+pub async fn show(
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let tax_rate = state
+        .admin_tax_rate_service
+        .get_one_public(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(tax_rate)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/tax-rates/create",
+    tag = "admin-tax-rates",
+    request_body = CreateTaxRateDTO,
+    responses(
+        (status = 201, description = "TaxRate created", body = SuccessResponse<AdminPublicTaxRate>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["tax_rates:create"])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreateTaxRateDTO>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreateTaxRateCommand::try_from(body.into_inner())?;
+    let tax_rate = state
+        .admin_tax_rate_service
+        .create(command, actor_id)
+        .await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(tax_rate.into_public())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/tax-rates/update/{id}",
+    tag = "admin-tax-rates",
+    params(("id" = i64, Path, description = "Tax rate id")),
+    request_body = UpdateTaxRateDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["tax_rates:update"])),
+)]
+#[instrument(skip_all)]
+pub async fn update(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdateTaxRateDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = UpdateTaxRateCommand::try_from(body.into_inner())?;
+    state
+        .admin_tax_rate_service
+        .update(command, id.into_inner(), actor_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/tax-rates/delete/{id}",
+    tag = "admin-tax-rates",
+    params(("id" = i64, Path, description = "Tax rate id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["tax_rates:delete"])),
+)]
+#[instrument(skip_all)]
+pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    state
+        .admin_tax_rate_service
+        .delete(id.into_inner(), actor_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,25 @@
+use crate::utils::traits::HasId;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct AdminTaxRateModel {
+    pub id: i64,
+    pub name: String,
+    pub tax_class: String,
+    pub country: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    pub rate: BigDecimal,
+    pub priority: i32,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminTaxRateModel {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
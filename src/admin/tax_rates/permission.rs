@@ -0,0 +1,31 @@
+use crate::auth::traits::Scope;
+
+pub enum TaxRateScope {
+    Create,
+    Read,
+    Update,
+    Delete,
+    List,
+}
+
+impl Scope for TaxRateScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TaxRateScope::Create => "tax_rates:create",
+            TaxRateScope::Read => "tax_rates:read",
+            TaxRateScope::Update => "tax_rates:update",
+            TaxRateScope::Delete => "tax_rates:delete",
+            TaxRateScope::List => "tax_rates:list",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![
+            TaxRateScope::Create,
+            TaxRateScope::Read,
+            TaxRateScope::Update,
+            TaxRateScope::Delete,
+            TaxRateScope::List,
+        ]
+    }
+}
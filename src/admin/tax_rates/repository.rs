@@ -0,0 +1,185 @@
+use crate::admin::tax_rates::dto::{CreateTaxRateCommand, UpdateTaxRateCommand};
+use crate::admin::tax_rates::filters::TaxRateFilters;
+use crate::admin::tax_rates::model::AdminTaxRateModel;
+use crate::errors::error::AppError;
+use crate::utils::pagination::Paginate;
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+const TAX_RATE_COLUMNS: &str = r#"
+    SELECT
+        tax_rates.id,
+        tax_rates.name,
+        tax_rates.tax_class,
+        tax_rates.country,
+        tax_rates.region,
+        tax_rates.postcode,
+        tax_rates.rate,
+        tax_rates.priority,
+        tax_rates.is_active,
+        tax_rates.created_at,
+        tax_rates.updated_at
+    FROM tax_rates
+"#;
+
+pub struct AdminTaxRateRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AdminTaxRateRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminTaxRateRepository {
+    #[instrument(skip_all)]
+    pub async fn index_paginated(
+        &self,
+        pagination: &Paginate,
+        search: &Option<String>,
+        filters: &TaxRateFilters,
+    ) -> Result<Vec<AdminTaxRateModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(TAX_RATE_COLUMNS);
+
+        qb.push(" WHERE TRUE ");
+
+        // handle search
+        if let Some(search) = search {
+            qb.push(" AND tax_rates.name ILIKE ");
+            qb.push_bind(format!("%{}%", search));
+        }
+
+        // country
+        if let Some(country) = &filters.country {
+            qb.push(" AND tax_rates.country = ");
+            qb.push_bind(country);
+        }
+
+        // tax class
+        if let Some(tax_class) = &filters.tax_class {
+            qb.push(" AND tax_rates.tax_class = ");
+            qb.push_bind(tax_class);
+        }
+
+        // is active
+        if let Some(is_active) = filters.is_active {
+            qb.push(" AND tax_rates.is_active = ");
+            qb.push_bind(is_active);
+        }
+
+        qb.push(" ORDER BY tax_rates.country, tax_rates.tax_class, tax_rates.id ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        qb.build_query_as::<AdminTaxRateModel>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn show(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<Option<AdminTaxRateModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(TAX_RATE_COLUMNS);
+
+        qb.push(" WHERE tax_rates.id = ");
+        qb.push_bind(id);
+
+        qb.build_query_as::<AdminTaxRateModel>()
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &CreateTaxRateCommand,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar(
+            r#"
+        INSERT INTO tax_rates (
+            name, tax_class, country, region, postcode, rate, priority, is_active
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id;
+        "#,
+        )
+        .bind(&cmd.name)
+        .bind(&cmd.tax_class)
+        .bind(&cmd.country)
+        .bind(&cmd.region)
+        .bind(&cmd.postcode)
+        .bind(&cmd.rate)
+        .bind(cmd.priority)
+        .bind(cmd.is_active)
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn update(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &UpdateTaxRateCommand,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+        UPDATE tax_rates
+        SET (
+            name, tax_class, country, region, postcode, rate, priority, is_active, updated_at
+        ) = ($1, $2, $3, $4, $5, $6, $7, $8, now())
+        WHERE id = $9;
+        "#,
+        )
+        .bind(&cmd.name)
+        .bind(&cmd.tax_class)
+        .bind(&cmd.country)
+        .bind(&cmd.region)
+        .bind(&cmd.postcode)
+        .bind(&cmd.rate)
+        .bind(cmd.priority)
+        .bind(cmd.is_active)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM tax_rates WHERE id = $1;",
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+}
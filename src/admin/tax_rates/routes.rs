@@ -0,0 +1,37 @@
+use super::handler;
+use crate::admin::tax_rates::permission::TaxRateScope;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{delete, get, post, put, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/tax-rates")
+            .service(
+                resource("/list")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(TaxRateScope::List))))
+                    .route(get().to(handler::index)),
+            )
+            .service(
+                resource("/get/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(TaxRateScope::Read))))
+                    .route(get().to(handler::show)),
+            )
+            .service(
+                resource("/create")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(TaxRateScope::Create))))
+                    .route(post().to(handler::create)),
+            )
+            .service(
+                resource("/update/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(TaxRateScope::Update))))
+                    .route(put().to(handler::update)),
+            )
+            .service(
+                resource("/delete/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(TaxRateScope::Delete))))
+                    .route(delete().to(handler::delete)),
+            ),
+    );
+}
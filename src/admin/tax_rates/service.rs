@@ -0,0 +1,138 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
+use crate::admin::tax_rates::dto::{
+    AdminPublicTaxRate, CreateTaxRateCommand, UpdateTaxRateCommand,
+};
+use crate::admin::tax_rates::filters::TaxRateFilters;
+use crate::admin::tax_rates::model::AdminTaxRateModel;
+use crate::admin::tax_rates::repository::AdminTaxRateRepository;
+use crate::admin::tax_rates::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct AdminTaxRateService {
+    repository: AdminTaxRateRepository,
+    audit_repository: AdminAuditRepository,
+}
+
+impl AdminTaxRateService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: AdminTaxRateRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool),
+        }
+    }
+
+    pub async fn get_all_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &TaxRateFilters,
+        search: &Option<String>,
+    ) -> Result<PaginatedDataCollection<AdminPublicTaxRate>, AppError> {
+        let data = self
+            .repository
+            .index_paginated(pagination, search, filters)
+            .await?;
+
+        Ok(PaginatedDataCollection::new(data, pagination.clone()).into_public())
+    }
+
+    pub async fn get_one(&self, id: i64) -> Result<AdminTaxRateModel, AppError> {
+        let tax_rate = self.repository.show(self.repository.get_pool(), id).await?;
+
+        match tax_rate {
+            Some(tax_rate) => Ok(tax_rate),
+            None => Err(AppError::NotFound("Tax rate not found".to_string())),
+        }
+    }
+
+    pub async fn get_one_public(&self, id: i64) -> Result<AdminPublicTaxRate, AppError> {
+        let tax_rate = self.get_one(id).await?;
+
+        Ok(tax_rate.into_public())
+    }
+
+    pub async fn create(
+        &self,
+        cmd: CreateTaxRateCommand,
+        actor_id: i64,
+    ) -> Result<AdminTaxRateModel, AppError> {
+        let mut tx = self.repository.start_transaction().await?;
+
+        let id = self.repository.create(&mut *tx, &cmd).await?;
+
+        let tax_rate = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Tax rate not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(actor_id, AuditEntity::TaxRate, id, &tax_rate),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(tax_rate)
+    }
+
+    pub async fn update(
+        &self,
+        cmd: UpdateTaxRateCommand,
+        id: i64,
+        actor_id: i64,
+    ) -> Result<AdminTaxRateModel, AppError> {
+        let before = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.update(&mut *tx, &cmd, id).await?;
+
+        let after = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Tax rate not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::TaxRate,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(after)
+    }
+
+    pub async fn delete(&self, id: i64, actor_id: i64) -> Result<u64, AppError> {
+        let tax_rate = self.get_one(id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let deleted = self.repository.delete(&mut *tx, id).await?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::deleted(actor_id, AuditEntity::TaxRate, id, &tax_rate),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(deleted)
+    }
+}
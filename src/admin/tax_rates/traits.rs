@@ -0,0 +1,27 @@
+use crate::admin::tax_rates::dto::AdminPublicTaxRate;
+use crate::admin::tax_rates::model::AdminTaxRateModel;
+use crate::utils::pagination::PaginatedDataCollection;
+
+pub trait IntoPublic<T> {
+    fn into_public(self) -> T;
+}
+
+impl IntoPublic<AdminPublicTaxRate> for AdminTaxRateModel {
+    fn into_public(self) -> AdminPublicTaxRate {
+        AdminPublicTaxRate::from(self)
+    }
+}
+
+impl IntoPublic<PaginatedDataCollection<AdminPublicTaxRate>>
+    for PaginatedDataCollection<AdminTaxRateModel>
+{
+    fn into_public(self) -> PaginatedDataCollection<AdminPublicTaxRate> {
+        PaginatedDataCollection::new(
+            self.data
+                .into_iter()
+                .map(AdminPublicTaxRate::from)
+                .collect(),
+            self.pagination,
+        )
+    }
+}
@@ -0,0 +1,33 @@
+use crate::app::addresses::dto::PublicAddress;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use actix_web::{HttpResponse, Responder, web};
+use tracing::instrument;
+
+#[utoipa::path(
+    get,
+    path = "/admin/users/get/{id}/addresses",
+    tag = "admin-users",
+    params(("id" = i64, Path, description = "User id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<PublicAddress>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["users:read"])),
+)]
+#[instrument(skip_all)]
+pub async fn addresses(
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let addresses = state
+        .admin_user_service
+        .get_addresses(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(addresses)))
+}
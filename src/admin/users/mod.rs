@@ -1,3 +1,6 @@
+pub mod handler;
 pub mod model;
+pub mod permission;
 pub mod repository;
+pub mod routes;
 pub mod service;
@@ -0,0 +1,17 @@
+use crate::auth::traits::Scope;
+
+pub enum UserScope {
+    Read,
+}
+
+impl Scope for UserScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            UserScope::Read => "users:read",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![UserScope::Read]
+    }
+}
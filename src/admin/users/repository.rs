@@ -2,6 +2,7 @@ use crate::admin::users::model::AdminSafeUserModel;
 use crate::errors::error::AppError;
 use crate::utils::traits::IsRepository;
 use sqlx::PgPool;
+use tracing::instrument;
 
 pub struct AdminUserRepository {
     pool: PgPool,
@@ -20,6 +21,7 @@ impl IsRepository for AdminUserRepository {
 }
 
 impl AdminUserRepository {
+    #[instrument(skip_all)]
     pub async fn show_safe(&self, id: i64) -> Result<Option<AdminSafeUserModel>, AppError> {
         sqlx::query_as! {
             AdminSafeUserModel,
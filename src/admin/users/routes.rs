@@ -0,0 +1,16 @@
+use super::handler;
+use crate::admin::users::permission::UserScope;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{get, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/users").service(
+            resource("/get/{id}/addresses")
+                .wrap(AuthMiddleware::new(Some(Arc::new(UserScope::Read))))
+                .route(get().to(handler::addresses)),
+        ),
+    );
+}
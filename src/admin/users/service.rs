@@ -1,17 +1,21 @@
 use super::model::AdminSafeUserModel;
 use crate::admin::users::repository::AdminUserRepository;
+use crate::app::addresses::dto::PublicAddress;
+use crate::app::addresses::repository::AddressRepository;
 use crate::errors::error::AppError;
 use crate::utils::traits::IsRepository;
 use sqlx::PgPool;
 
 pub struct AdminUserService {
     repository: AdminUserRepository,
+    address_repository: AddressRepository,
 }
 
 impl AdminUserService {
     pub fn new(pool: PgPool) -> Self {
         Self {
-            repository: AdminUserRepository::new(pool),
+            repository: AdminUserRepository::new(pool.clone()),
+            address_repository: AddressRepository::new(pool),
         }
     }
 
@@ -23,4 +27,13 @@ impl AdminUserService {
             None => Err(AppError::NotFound("User not found".to_string())),
         }
     }
+
+    /** Address book of a user, the default shipping address first. */
+    pub async fn get_addresses(&self, id: i64) -> Result<Vec<PublicAddress>, AppError> {
+        self.get_one_safe(id).await?;
+
+        let addresses = self.address_repository.list_by_user(id).await?;
+
+        Ok(addresses.into_iter().map(PublicAddress::from).collect())
+    }
 }
@@ -0,0 +1,275 @@
+use crate::admin::warehouses::filters::WarehouseFilters;
+use crate::admin::warehouses::model::{AdminWarehouseModel, WarehouseStockModel};
+use crate::errors::error::AppError;
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+/** Codes are upper case letters, digits, `-` and `_`. */
+fn normalize_code(code: &str) -> Result<String, AppError> {
+    let code = code.trim().to_uppercase();
+
+    if code.is_empty()
+        || !code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(AppError::field(
+            "code",
+            "code_format",
+            "may only contain letters, digits, - and _",
+        ));
+    }
+
+    Ok(code)
+}
+
+/** The default warehouse receives stock, so it has to be sellable. */
+fn check_default(is_active: bool, is_default: bool) -> Result<(), AppError> {
+    if is_default && !is_active {
+        return Err(AppError::field(
+            "is_default",
+            "inactive_default",
+            "an inactive warehouse can't be the default",
+        ));
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicWarehouse {
+    pub id: i64,
+    pub name: String,
+    pub code: String,
+    /// Allocation order, lower goes first.
+    pub priority: i32,
+    /// Stock of an inactive warehouse isn't sold.
+    pub is_active: bool,
+    /// Receives stock set on the product itself.
+    pub is_default: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminPublicWarehouse {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl From<AdminWarehouseModel> for AdminPublicWarehouse {
+    fn from(warehouse: AdminWarehouseModel) -> Self {
+        Self {
+            id: warehouse.id,
+            name: warehouse.name,
+            code: warehouse.code,
+            priority: warehouse.priority,
+            is_active: warehouse.is_active,
+            is_default: warehouse.is_default,
+            updated_at: warehouse.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AdminPublicWarehouseStock {
+    pub warehouse_id: i64,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+    pub quantity: i32,
+}
+
+impl From<WarehouseStockModel> for AdminPublicWarehouseStock {
+    fn from(stock: WarehouseStockModel) -> Self {
+        Self {
+            warehouse_id: stock.warehouse_id,
+            code: stock.code,
+            name: stock.name,
+            is_active: stock.is_active,
+            quantity: stock.quantity,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IndexWarehouseDTO {
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub page: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
+    pub limit: Option<i64>,
+
+    /// Matches the name or the code.
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub search: Option<String>,
+
+    pub is_active: Option<bool>,
+}
+
+impl From<IndexWarehouseDTO> for WarehouseFilters {
+    fn from(dto: IndexWarehouseDTO) -> Self {
+        Self {
+            is_active: dto.is_active,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateWarehouseDTO {
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    /// Short unique code, stored upper case.
+    #[validate(required, length(min = 1, max = 32))]
+    #[schema(required = true, min_length = 1, max_length = 32, example = "BER-1")]
+    pub code: Option<String>,
+
+    /// Allocation order, lower goes first.
+    pub priority: Option<i32>,
+
+    pub is_active: Option<bool>,
+
+    /// Makes this the default warehouse in place of the current one.
+    pub is_default: Option<bool>,
+}
+
+pub struct CreateWarehouseCommand {
+    pub name: String,
+    pub code: String,
+    pub priority: i32,
+    pub is_active: bool,
+    pub is_default: bool,
+}
+
+impl TryFrom<CreateWarehouseDTO> for CreateWarehouseCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreateWarehouseDTO) -> Result<Self, Self::Error> {
+        let is_active = dto.is_active.unwrap_or(true);
+        let is_default = dto.is_default.unwrap_or(false);
+
+        check_default(is_active, is_default)?;
+
+        Ok(Self {
+            name: dto.name.unwrap(),
+            code: normalize_code(&dto.code.unwrap())?,
+            priority: dto.priority.unwrap_or(0),
+            is_active,
+            is_default,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateWarehouseDTO {
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub name: Option<String>,
+
+    /// Short unique code, stored upper case.
+    #[validate(required, length(min = 1, max = 32))]
+    #[schema(required = true, min_length = 1, max_length = 32, example = "BER-1")]
+    pub code: Option<String>,
+
+    /// Allocation order, lower goes first.
+    pub priority: Option<i32>,
+
+    pub is_active: Option<bool>,
+
+    /// Makes this the default warehouse in place of the current one.
+    pub is_default: Option<bool>,
+}
+
+pub struct UpdateWarehouseCommand {
+    pub name: String,
+    pub code: String,
+    pub priority: i32,
+    pub is_active: bool,
+    pub is_default: bool,
+}
+
+impl TryFrom<UpdateWarehouseDTO> for UpdateWarehouseCommand {
+    type Error = AppError;
+
+    fn try_from(dto: UpdateWarehouseDTO) -> Result<Self, Self::Error> {
+        let is_active = dto.is_active.unwrap_or(true);
+        let is_default = dto.is_default.unwrap_or(false);
+
+        check_default(is_active, is_default)?;
+
+        Ok(Self {
+            name: dto.name.unwrap(),
+            code: normalize_code(&dto.code.unwrap())?,
+            priority: dto.priority.unwrap_or(0),
+            is_active,
+            is_default,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct TransferStockDTO {
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub product_id: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub from_warehouse_id: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub to_warehouse_id: Option<i64>,
+
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub quantity: Option<i32>,
+
+    #[validate(length(min = 1, max = 255))]
+    #[schema(min_length = 1, max_length = 255, example = "Restock the Berlin store")]
+    pub reason: Option<String>,
+}
+
+pub struct TransferStockCommand {
+    pub product_id: i64,
+    pub from_warehouse_id: i64,
+    pub to_warehouse_id: i64,
+    pub quantity: i32,
+    pub reason: Option<String>,
+}
+
+impl TryFrom<TransferStockDTO> for TransferStockCommand {
+    type Error = AppError;
+
+    fn try_from(dto: TransferStockDTO) -> Result<Self, Self::Error> {
+        let from_warehouse_id = dto.from_warehouse_id.unwrap();
+        let to_warehouse_id = dto.to_warehouse_id.unwrap();
+
+        if from_warehouse_id == to_warehouse_id {
+            return Err(AppError::field(
+                "to_warehouse_id",
+                "same_warehouse",
+                "must differ from from_warehouse_id",
+            ));
+        }
+
+        Ok(Self {
+            product_id: dto.product_id.unwrap(),
+            from_warehouse_id,
+            to_warehouse_id,
+            quantity: dto.quantity.unwrap(),
+            reason: dto
+                .reason
+                .map(|reason| reason.trim().to_string())
+                .filter(|reason| !reason.is_empty()),
+        })
+    }
+}
@@ -0,0 +1,212 @@
+use crate::admin::warehouses::dto::{
+    AdminPublicWarehouse, AdminPublicWarehouseStock, CreateWarehouseCommand, CreateWarehouseDTO,
+    IndexWarehouseDTO, TransferStockCommand, TransferStockDTO, UpdateWarehouseCommand,
+    UpdateWarehouseDTO,
+};
+use crate::admin::warehouses::filters::WarehouseFilters;
+use crate::admin::warehouses::traits::IntoPublic;
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use crate::utils::pagination::Paginate;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin/warehouses/list",
+    tag = "admin-warehouses",
+    params(IndexWarehouseDTO),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<AdminPublicWarehouse>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["warehouses:list"])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    state: web::Data<AppState>,
+    body: web::Query<IndexWarehouseDTO>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+
+    let filters = WarehouseFilters::from(body.clone().into_inner());
+
+    let warehouses = state
+        .admin_warehouse_service
+        .get_all_paginated_public(&pagination, &filters, &body.search)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
+        warehouses.data,
+        pagination,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/warehouses/get/{id}",
+    tag = "admin-warehouses",
+    params(("id" = i64, Path, description = "Warehouse id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<AdminPublicWarehouse>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["warehouses:read"])),
+)]
+#[instrument(skip_all)]
+pub async fn show(
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let warehouse = state
+        .admin_warehouse_service
+        .get_one_public(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(warehouse)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/warehouses/create",
+    tag = "admin-warehouses",
+    request_body = CreateWarehouseDTO,
+    responses(
+        (status = 201, description = "Warehouse created", body = SuccessResponse<AdminPublicWarehouse>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["warehouses:create"])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreateWarehouseDTO>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreateWarehouseCommand::try_from(body.into_inner())?;
+    let warehouse = state
+        .admin_warehouse_service
+        .create(command, actor_id)
+        .await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(warehouse.into_public())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/warehouses/update/{id}",
+    tag = "admin-warehouses",
+    params(("id" = i64, Path, description = "Warehouse id")),
+    request_body = UpdateWarehouseDTO,
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["warehouses:update"])),
+)]
+#[instrument(skip_all)]
+pub async fn update(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdateWarehouseDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = UpdateWarehouseCommand::try_from(body.into_inner())?;
+    state
+        .admin_warehouse_service
+        .update(command, id.into_inner(), actor_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/warehouses/stock/{product_id}",
+    tag = "admin-warehouses",
+    params(("product_id" = i64, Path, description = "Product id")),
+    responses(
+        (status = 200, description = "Stock of the product in each warehouse", body = SuccessResponse<Vec<AdminPublicWarehouseStock>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["warehouses:read"])),
+)]
+#[instrument(skip_all)]
+pub async fn stock(
+    state: web::Data<AppState>,
+    product_id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let stock = state
+        .admin_warehouse_service
+        .get_stock(product_id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(stock)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/warehouses/transfer",
+    tag = "admin-warehouses",
+    request_body = TransferStockDTO,
+    responses(
+        (status = 200, description = "Stock of the product in each warehouse after the transfer", body = SuccessResponse<Vec<AdminPublicWarehouseStock>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Product or warehouse not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Not enough stock", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["warehouses:transfer"])),
+)]
+#[instrument(skip_all)]
+pub async fn transfer(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<TransferStockDTO>,
+) -> Result<impl Responder, AppError> {
+    let actor_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = TransferStockCommand::try_from(body.into_inner())?;
+    let stock = state
+        .admin_warehouse_service
+        .transfer(command, actor_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(stock)))
+}
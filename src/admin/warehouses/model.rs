@@ -0,0 +1,31 @@
+use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct AdminWarehouseModel {
+    pub id: i64,
+    pub name: String,
+    pub code: String,
+    pub priority: i32,
+    pub is_active: bool,
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HasId for AdminWarehouseModel {
+    fn get_id(&self) -> i64 {
+        self.id
+    }
+}
+
+/** Stock of a product in one warehouse. */
+#[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct WarehouseStockModel {
+    pub warehouse_id: i64,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+    pub quantity: i32,
+}
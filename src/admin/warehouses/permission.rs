@@ -0,0 +1,31 @@
+use crate::auth::traits::Scope;
+
+pub enum WarehouseScope {
+    Create,
+    Read,
+    Update,
+    List,
+    Transfer,
+}
+
+impl Scope for WarehouseScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            WarehouseScope::Create => "warehouses:create",
+            WarehouseScope::Read => "warehouses:read",
+            WarehouseScope::Update => "warehouses:update",
+            WarehouseScope::List => "warehouses:list",
+            WarehouseScope::Transfer => "warehouses:transfer",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![
+            WarehouseScope::Create,
+            WarehouseScope::Read,
+            WarehouseScope::Update,
+            WarehouseScope::List,
+            WarehouseScope::Transfer,
+        ]
+    }
+}
@@ -0,0 +1,279 @@
+use crate::admin::warehouses::dto::{CreateWarehouseCommand, UpdateWarehouseCommand};
+use crate::admin::warehouses::filters::WarehouseFilters;
+use crate::admin::warehouses::model::{AdminWarehouseModel, WarehouseStockModel};
+use crate::errors::error::AppError;
+use crate::utils::pagination::Paginate;
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+const WAREHOUSE_COLUMNS: &str = r#"
+    SELECT
+        warehouses.id,
+        warehouses.name,
+        warehouses.code,
+        warehouses.priority,
+        warehouses.is_active,
+        warehouses.is_default,
+        warehouses.created_at,
+        warehouses.updated_at
+    FROM warehouses
+"#;
+
+pub struct AdminWarehouseRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AdminWarehouseRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AdminWarehouseRepository {
+    /** In allocation order. */
+    #[instrument(skip_all)]
+    pub async fn index_paginated(
+        &self,
+        pagination: &Paginate,
+        search: &Option<String>,
+        filters: &WarehouseFilters,
+    ) -> Result<Vec<AdminWarehouseModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(WAREHOUSE_COLUMNS);
+
+        qb.push(" WHERE TRUE ");
+
+        // handle search
+        if let Some(search) = search {
+            let pattern = format!("%{}%", search);
+
+            qb.push(" AND (warehouses.name ILIKE ");
+            qb.push_bind(pattern.clone());
+            qb.push(" OR warehouses.code ILIKE ");
+            qb.push_bind(pattern);
+            qb.push(")");
+        }
+
+        // is active
+        if let Some(is_active) = filters.is_active {
+            qb.push(" AND warehouses.is_active = ");
+            qb.push_bind(is_active);
+        }
+
+        qb.push(" ORDER BY warehouses.priority, warehouses.id ");
+
+        // handle pagination
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.limit);
+        qb.push(" OFFSET ");
+        qb.push_bind(pagination.get_offset());
+
+        qb.build_query_as::<AdminWarehouseModel>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn show(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<Option<AdminWarehouseModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(WAREHOUSE_COLUMNS);
+
+        qb.push(" WHERE warehouses.id = ");
+        qb.push_bind(id);
+
+        qb.build_query_as::<AdminWarehouseModel>()
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn find_default(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+    ) -> Result<Option<AdminWarehouseModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(WAREHOUSE_COLUMNS);
+
+        qb.push(" WHERE warehouses.is_default ");
+
+        qb.build_query_as::<AdminWarehouseModel>()
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &CreateWarehouseCommand,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar! {
+            r#"
+        INSERT INTO warehouses (name, code, priority, is_active, is_default)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id;
+        "#,
+            cmd.name,
+            cmd.code,
+            cmd.priority,
+            cmd.is_active,
+            cmd.is_default
+        }
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn update(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &UpdateWarehouseCommand,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        UPDATE warehouses
+        SET (name, code, priority, is_active, is_default, updated_at)
+            = ($1, $2, $3, $4, $5, now())
+        WHERE id = $6;
+        "#,
+            cmd.name,
+            cmd.code,
+            cmd.priority,
+            cmd.is_active,
+            cmd.is_default,
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /** Unsets the current default, before another warehouse takes over. */
+    #[instrument(skip_all)]
+    pub async fn clear_default(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "UPDATE warehouses SET is_default = false, updated_at = now() WHERE is_default;"
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /** Products the warehouse holds any stock of, in id order. */
+    #[instrument(skip_all)]
+    pub async fn stocked_product_ids(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<Vec<i64>, AppError> {
+        sqlx::query_scalar! {
+            r#"
+        SELECT product_id FROM warehouse_stock
+        WHERE warehouse_id = $1 AND quantity > 0
+        ORDER BY product_id;
+        "#,
+            id
+        }
+        .fetch_all(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Bumps the products stocked in a warehouse, their sellable stock changes
+     * when it's switched on or off.
+     */
+    #[instrument(skip_all)]
+    pub async fn touch_products(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        UPDATE products SET updated_at = now()
+        WHERE id IN (
+            SELECT product_id FROM warehouse_stock
+            WHERE warehouse_id = $1 AND quantity > 0
+        );
+        "#,
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /** Units of a product in a warehouse, 0 when it never held any. */
+    #[instrument(skip_all)]
+    pub async fn stock_level(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        warehouse_id: i64,
+        product_id: i64,
+    ) -> Result<i32, AppError> {
+        let quantity = sqlx::query_scalar! {
+            r#"
+        SELECT quantity FROM warehouse_stock
+        WHERE warehouse_id = $1 AND product_id = $2;
+        "#,
+            warehouse_id,
+            product_id
+        }
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(quantity.unwrap_or(0))
+    }
+
+    /** Stock of a product in every warehouse, in allocation order. */
+    #[instrument(skip_all)]
+    pub async fn stock_by_product(
+        &self,
+        product_id: i64,
+    ) -> Result<Vec<WarehouseStockModel>, AppError> {
+        sqlx::query_as! {
+            WarehouseStockModel,
+            r#"
+        SELECT
+            warehouses.id AS warehouse_id,
+            warehouses.code,
+            warehouses.name,
+            warehouses.is_active,
+            COALESCE(warehouse_stock.quantity, 0) AS "quantity!"
+        FROM warehouses
+        LEFT JOIN warehouse_stock
+            ON warehouse_stock.warehouse_id = warehouses.id
+            AND warehouse_stock.product_id = $1
+        ORDER BY warehouses.priority, warehouses.id;
+        "#,
+            product_id
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+}
@@ -0,0 +1,44 @@
+use super::handler;
+use crate::admin::warehouses::permission::WarehouseScope;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{get, post, put, resource};
+use std::sync::Arc;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/warehouses")
+            .service(
+                resource("/list")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(WarehouseScope::List))))
+                    .route(get().to(handler::index)),
+            )
+            .service(
+                resource("/get/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(WarehouseScope::Read))))
+                    .route(get().to(handler::show)),
+            )
+            .service(
+                resource("/create")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(WarehouseScope::Create))))
+                    .route(post().to(handler::create)),
+            )
+            .service(
+                resource("/update/{id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(WarehouseScope::Update))))
+                    .route(put().to(handler::update)),
+            )
+            .service(
+                resource("/stock/{product_id}")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(WarehouseScope::Read))))
+                    .route(get().to(handler::stock)),
+            )
+            .service(
+                resource("/transfer")
+                    .wrap(AuthMiddleware::new(Some(Arc::new(
+                        WarehouseScope::Transfer,
+                    ))))
+                    .route(post().to(handler::transfer)),
+            ),
+    );
+}
@@ -0,0 +1,292 @@
+use crate::admin::audit::dto::{AuditEntity, CreateAuditEntryCommand};
+use crate::admin::audit::repository::AdminAuditRepository;
+use crate::admin::products::repository::AdminProductRepository;
+use crate::admin::stock::dto::{RecordStockMovementCommand, StockMovementKind};
+use crate::admin::stock::repository::AdminStockRepository;
+use crate::admin::warehouses::dto::{
+    AdminPublicWarehouse, AdminPublicWarehouseStock, CreateWarehouseCommand, TransferStockCommand,
+    UpdateWarehouseCommand,
+};
+use crate::admin::warehouses::filters::WarehouseFilters;
+use crate::admin::warehouses::model::AdminWarehouseModel;
+use crate::admin::warehouses::repository::AdminWarehouseRepository;
+use crate::admin::warehouses::traits::IntoPublic;
+use crate::app::products::cache::ProductCache;
+use crate::app::stock_subscriptions::alerts::StockSubscriptionAlerts;
+use crate::app::wishlists::alerts::WishlistAlerts;
+use crate::errors::error::AppError;
+use crate::notifications::notifier::Notifier;
+use crate::utils::pagination::{Paginate, PaginatedDataCollection};
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+pub struct AdminWarehouseService {
+    repository: AdminWarehouseRepository,
+    stock_repository: AdminStockRepository,
+    product_repository: AdminProductRepository,
+    audit_repository: AdminAuditRepository,
+    product_cache: ProductCache,
+    wishlist_alerts: WishlistAlerts,
+    stock_subscription_alerts: StockSubscriptionAlerts,
+}
+
+impl AdminWarehouseService {
+    pub fn with_notifier(
+        pool: PgPool,
+        product_cache: ProductCache,
+        notifier: Arc<dyn Notifier>,
+    ) -> Self {
+        Self {
+            repository: AdminWarehouseRepository::new(pool.clone()),
+            stock_repository: AdminStockRepository::new(pool.clone()),
+            product_repository: AdminProductRepository::new(pool.clone()),
+            audit_repository: AdminAuditRepository::new(pool.clone()),
+            product_cache,
+            wishlist_alerts: WishlistAlerts::with_notifier(pool.clone(), notifier.clone()),
+            stock_subscription_alerts: StockSubscriptionAlerts::with_notifier(pool, notifier),
+        }
+    }
+
+    pub async fn get_all_paginated_public(
+        &self,
+        pagination: &Paginate,
+        filters: &WarehouseFilters,
+        search: &Option<String>,
+    ) -> Result<PaginatedDataCollection<AdminPublicWarehouse>, AppError> {
+        let data = self
+            .repository
+            .index_paginated(pagination, search, filters)
+            .await?;
+
+        Ok(PaginatedDataCollection::new(data, pagination.clone()).into_public())
+    }
+
+    pub async fn get_one(&self, id: i64) -> Result<AdminWarehouseModel, AppError> {
+        let warehouse = self.repository.show(self.repository.get_pool(), id).await?;
+
+        match warehouse {
+            Some(warehouse) => Ok(warehouse),
+            None => Err(AppError::NotFound("Warehouse not found".to_string())),
+        }
+    }
+
+    pub async fn get_one_public(&self, id: i64) -> Result<AdminPublicWarehouse, AppError> {
+        let warehouse = self.get_one(id).await?;
+
+        Ok(warehouse.into_public())
+    }
+
+    pub async fn create(
+        &self,
+        cmd: CreateWarehouseCommand,
+        actor_id: i64,
+    ) -> Result<AdminWarehouseModel, AppError> {
+        let mut tx = self.repository.start_transaction().await?;
+
+        if cmd.is_default {
+            self.repository.clear_default(&mut *tx).await?;
+        }
+
+        let id = self.repository.create(&mut *tx, &cmd).await?;
+
+        let warehouse = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Warehouse not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::created(actor_id, AuditEntity::Warehouse, id, &warehouse),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(warehouse)
+    }
+
+    /**
+     * There's always one default warehouse: it can only hand over the role by
+     * another warehouse taking it. Switching a warehouse on or off changes
+     * what the storefront can sell, so it fires the restock alerts of the
+     * products it holds.
+     */
+    pub async fn update(
+        &self,
+        cmd: UpdateWarehouseCommand,
+        id: i64,
+        actor_id: i64,
+    ) -> Result<AdminWarehouseModel, AppError> {
+        let before = self.get_one(id).await?;
+
+        if before.is_default && !cmd.is_default {
+            return Err(AppError::field(
+                "is_default",
+                "default_required",
+                "make another warehouse the default instead",
+            ));
+        }
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let toggled = before.is_active != cmd.is_active;
+
+        let product_ids = if toggled {
+            self.repository.stocked_product_ids(&mut *tx, id).await?
+        } else {
+            Vec::new()
+        };
+
+        let snapshots_before = self
+            .wishlist_alerts
+            .snapshots(&mut *tx, &product_ids)
+            .await?;
+
+        if cmd.is_default && !before.is_default {
+            self.repository.clear_default(&mut *tx).await?;
+        }
+
+        self.repository.update(&mut *tx, &cmd, id).await?;
+
+        if toggled {
+            self.repository.touch_products(&mut *tx, id).await?;
+        }
+
+        let snapshots_after = self
+            .wishlist_alerts
+            .snapshots(&mut *tx, &product_ids)
+            .await?;
+
+        let after = self
+            .repository
+            .show(&mut *tx, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Warehouse not found".to_string()))?;
+
+        self.audit_repository
+            .create(
+                &mut *tx,
+                &CreateAuditEntryCommand::updated(
+                    actor_id,
+                    AuditEntity::Warehouse,
+                    id,
+                    &before,
+                    &after,
+                ),
+            )
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        if toggled {
+            self.product_cache.invalidate_all();
+        }
+
+        for (before, after) in snapshots_before.iter().zip(&snapshots_after) {
+            self.wishlist_alerts
+                .product_changed_or_warn(before, after)
+                .await;
+
+            self.stock_subscription_alerts
+                .product_changed_or_warn(before, after)
+                .await;
+        }
+
+        Ok(after)
+    }
+
+    /** Stock of a product in every warehouse, in allocation order. */
+    pub async fn get_stock(
+        &self,
+        product_id: i64,
+    ) -> Result<Vec<AdminPublicWarehouseStock>, AppError> {
+        self.product_repository
+            .show(product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Product not found".to_string()))?;
+
+        let stock = self.repository.stock_by_product(product_id).await?;
+
+        Ok(stock
+            .into_iter()
+            .map(AdminPublicWarehouseStock::from)
+            .collect())
+    }
+
+    /**
+     * Moves stock of a product between two warehouses as a pair of transfer
+     * movements, the product's total stays the same. What the storefront can
+     * sell still changes when only one side is active. Returns the stock in
+     * every warehouse afterwards.
+     */
+    pub async fn transfer(
+        &self,
+        cmd: TransferStockCommand,
+        actor_id: i64,
+    ) -> Result<Vec<AdminPublicWarehouseStock>, AppError> {
+        let from = self.get_one(cmd.from_warehouse_id).await?;
+        let to = self.get_one(cmd.to_warehouse_id).await?;
+
+        let mut tx = self.stock_repository.start_transaction().await?;
+
+        self.stock_repository
+            .lock_stock(&mut *tx, cmd.product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Product not found".to_string()))?;
+
+        let snapshot_before = self
+            .wishlist_alerts
+            .snapshot(&mut *tx, cmd.product_id)
+            .await?;
+
+        let available = self
+            .repository
+            .stock_level(&mut *tx, from.id, cmd.product_id)
+            .await?;
+
+        if available < cmd.quantity {
+            return Err(AppError::Conflict(format!(
+                "Only {} in stock at {}, can't move {}",
+                available, from.code, cmd.quantity
+            )));
+        }
+
+        for (warehouse, quantity) in [(&from, -cmd.quantity), (&to, cmd.quantity)] {
+            let movement = RecordStockMovementCommand {
+                product_id: cmd.product_id,
+                warehouse_id: Some(warehouse.id),
+                kind: StockMovementKind::Transfer,
+                quantity,
+                reason: cmd.reason.clone(),
+                actor_id: Some(actor_id),
+            };
+
+            self.stock_repository
+                .record(&mut *tx, &movement)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Product not found".to_string()))?;
+        }
+
+        let snapshot_after = self
+            .wishlist_alerts
+            .snapshot(&mut *tx, cmd.product_id)
+            .await?;
+
+        self.stock_repository.commit_transaction(tx).await?;
+
+        self.product_cache.invalidate_product(cmd.product_id);
+
+        self.wishlist_alerts
+            .product_changed_or_warn(&snapshot_before, &snapshot_after)
+            .await;
+
+        self.stock_subscription_alerts
+            .product_changed_or_warn(&snapshot_before, &snapshot_after)
+            .await;
+
+        self.get_stock(cmd.product_id).await
+    }
+}
@@ -0,0 +1,27 @@
+use crate::admin::warehouses::dto::AdminPublicWarehouse;
+use crate::admin::warehouses::model::AdminWarehouseModel;
+use crate::utils::pagination::PaginatedDataCollection;
+
+pub trait IntoPublic<T> {
+    fn into_public(self) -> T;
+}
+
+impl IntoPublic<AdminPublicWarehouse> for AdminWarehouseModel {
+    fn into_public(self) -> AdminPublicWarehouse {
+        AdminPublicWarehouse::from(self)
+    }
+}
+
+impl IntoPublic<PaginatedDataCollection<AdminPublicWarehouse>>
+    for PaginatedDataCollection<AdminWarehouseModel>
+{
+    fn into_public(self) -> PaginatedDataCollection<AdminPublicWarehouse> {
+        PaginatedDataCollection::new(
+            self.data
+                .into_iter()
+                .map(AdminPublicWarehouse::from)
+                .collect(),
+            self.pagination,
+        )
+    }
+}
@@ -0,0 +1,13 @@
+pub mod v1;
+pub mod v2;
+
+use actix_web::web;
+
+/**
+ * Mounts every versioned API. A v2 variant of a resource is registered in
+ * `v2::routes` next to its v1 counterpart, which keeps serving until sunset.
+ */
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::scope(v1::PREFIX).configure(v1::routes))
+        .service(web::scope(v2::PREFIX).configure(v2::routes));
+}
@@ -0,0 +1,15 @@
+use crate::{admin, app, auth};
+use actix_web::web;
+
+pub const PREFIX: &str = "/api/v1";
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.configure(auth::routes::routes)
+        .configure(admin::routes::routes)
+        .configure(app::categories::routes::routes)
+        .configure(app::products::routes::routes)
+        .configure(app::cart::routes::routes)
+        .configure(app::addresses::routes::routes)
+        .configure(app::wishlists::routes::routes)
+        .configure(app::stock_subscriptions::routes::routes);
+}
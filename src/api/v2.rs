@@ -0,0 +1,8 @@
+use crate::auth;
+use actix_web::web;
+
+pub const PREFIX: &str = "/api/v2";
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.configure(auth::routes::routes_v2);
+}
@@ -0,0 +1,240 @@
+use crate::admin::tax_rates::dto::normalize_country;
+use crate::app::addresses::model::AddressModel;
+use crate::app::addresses::rules::{normalize_postcode, validate_for_country};
+use crate::errors::error::AppError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct PublicAddress {
+    pub id: i64,
+    pub label: Option<String>,
+    pub full_name: String,
+    pub company: Option<String>,
+    pub line1: String,
+    pub line2: Option<String>,
+    pub city: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    pub country: String,
+    pub phone: Option<String>,
+    pub is_default_shipping: bool,
+    pub is_default_billing: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AddressModel> for PublicAddress {
+    fn from(address: AddressModel) -> Self {
+        Self {
+            id: address.id,
+            label: address.label,
+            full_name: address.full_name,
+            company: address.company,
+            line1: address.line1,
+            line2: address.line2,
+            city: address.city,
+            region: address.region,
+            postcode: address.postcode,
+            country: address.country,
+            phone: address.phone,
+            is_default_shipping: address.is_default_shipping,
+            is_default_billing: address.is_default_billing,
+            updated_at: address.updated_at,
+        }
+    }
+}
+
+/** Trims an optional field, an empty one counts as not given. */
+fn trimmed(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateAddressDTO {
+    /// Name the customer knows the address by, e.g. `Home`.
+    #[validate(length(max = 64))]
+    #[schema(max_length = 64, example = "Home")]
+    pub label: Option<String>,
+
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub full_name: Option<String>,
+
+    #[validate(length(max = 255))]
+    #[schema(max_length = 255)]
+    pub company: Option<String>,
+
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub line1: Option<String>,
+
+    #[validate(length(max = 255))]
+    #[schema(max_length = 255)]
+    pub line2: Option<String>,
+
+    #[validate(required, length(min = 1, max = 128))]
+    #[schema(required = true, min_length = 1, max_length = 128)]
+    pub city: Option<String>,
+
+    /// State or province, required in some countries.
+    #[validate(length(max = 64))]
+    #[schema(max_length = 64, example = "NY")]
+    pub region: Option<String>,
+
+    /// Checked against the formats of the country when it has any.
+    #[validate(length(max = 16))]
+    #[schema(max_length = 16, example = "10001")]
+    pub postcode: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true, example = "US")]
+    pub country: Option<String>,
+
+    #[validate(length(max = 32))]
+    #[schema(max_length = 32)]
+    pub phone: Option<String>,
+
+    pub is_default_shipping: Option<bool>,
+
+    pub is_default_billing: Option<bool>,
+}
+
+pub struct CreateAddressCommand {
+    pub label: Option<String>,
+    pub full_name: String,
+    pub company: Option<String>,
+    pub line1: String,
+    pub line2: Option<String>,
+    pub city: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    pub country: String,
+    pub phone: Option<String>,
+    pub is_default_shipping: bool,
+    pub is_default_billing: bool,
+}
+
+impl TryFrom<CreateAddressDTO> for CreateAddressCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreateAddressDTO) -> Result<Self, Self::Error> {
+        let country = normalize_country(&dto.country.unwrap())?;
+        let region = trimmed(dto.region);
+        let postcode = trimmed(dto.postcode).map(|postcode| normalize_postcode(&postcode));
+
+        validate_for_country(&country, region.as_deref(), postcode.as_deref())?;
+
+        Ok(Self {
+            label: trimmed(dto.label),
+            full_name: dto.full_name.unwrap().trim().to_string(),
+            company: trimmed(dto.company),
+            line1: dto.line1.unwrap().trim().to_string(),
+            line2: trimmed(dto.line2),
+            city: dto.city.unwrap().trim().to_string(),
+            region,
+            postcode,
+            country,
+            phone: trimmed(dto.phone),
+            is_default_shipping: dto.is_default_shipping.unwrap_or(false),
+            is_default_billing: dto.is_default_billing.unwrap_or(false),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateAddressDTO {
+    /// Name the customer knows the address by, e.g. `Home`.
+    #[validate(length(max = 64))]
+    #[schema(max_length = 64, example = "Home")]
+    pub label: Option<String>,
+
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub full_name: Option<String>,
+
+    #[validate(length(max = 255))]
+    #[schema(max_length = 255)]
+    pub company: Option<String>,
+
+    #[validate(required, length(min = 1, max = 255))]
+    #[schema(required = true, min_length = 1, max_length = 255)]
+    pub line1: Option<String>,
+
+    #[validate(length(max = 255))]
+    #[schema(max_length = 255)]
+    pub line2: Option<String>,
+
+    #[validate(required, length(min = 1, max = 128))]
+    #[schema(required = true, min_length = 1, max_length = 128)]
+    pub city: Option<String>,
+
+    /// State or province, required in some countries.
+    #[validate(length(max = 64))]
+    #[schema(max_length = 64, example = "NY")]
+    pub region: Option<String>,
+
+    /// Checked against the formats of the country when it has any.
+    #[validate(length(max = 16))]
+    #[schema(max_length = 16, example = "10001")]
+    pub postcode: Option<String>,
+
+    #[validate(required)]
+    #[schema(required = true, example = "US")]
+    pub country: Option<String>,
+
+    #[validate(length(max = 32))]
+    #[schema(max_length = 32)]
+    pub phone: Option<String>,
+
+    /// Leaves the flag as it is when empty.
+    pub is_default_shipping: Option<bool>,
+
+    /// Leaves the flag as it is when empty.
+    pub is_default_billing: Option<bool>,
+}
+
+pub struct UpdateAddressCommand {
+    pub label: Option<String>,
+    pub full_name: String,
+    pub company: Option<String>,
+    pub line1: String,
+    pub line2: Option<String>,
+    pub city: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    pub country: String,
+    pub phone: Option<String>,
+    pub is_default_shipping: Option<bool>,
+    pub is_default_billing: Option<bool>,
+}
+
+impl TryFrom<UpdateAddressDTO> for UpdateAddressCommand {
+    type Error = AppError;
+
+    fn try_from(dto: UpdateAddressDTO) -> Result<Self, Self::Error> {
+        let country = normalize_country(&dto.country.unwrap())?;
+        let region = trimmed(dto.region);
+        let postcode = trimmed(dto.postcode).map(|postcode| normalize_postcode(&postcode));
+
+        validate_for_country(&country, region.as_deref(), postcode.as_deref())?;
+
+        Ok(Self {
+            label: trimmed(dto.label),
+            full_name: dto.full_name.unwrap().trim().to_string(),
+            company: trimmed(dto.company),
+            line1: dto.line1.unwrap().trim().to_string(),
+            line2: trimmed(dto.line2),
+            city: dto.city.unwrap().trim().to_string(),
+            region,
+            postcode,
+            country,
+            phone: trimmed(dto.phone),
+            is_default_shipping: dto.is_default_shipping,
+            is_default_billing: dto.is_default_billing,
+        })
+    }
+}
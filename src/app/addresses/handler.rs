@@ -0,0 +1,158 @@
+use crate::app::addresses::dto::{
+    CreateAddressCommand, CreateAddressDTO, PublicAddress, UpdateAddressCommand, UpdateAddressDTO,
+};
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/me/addresses/list",
+    tag = "addresses",
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<PublicAddress>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let addresses = state.address_service.get_all_public(auth_user_id).await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(addresses)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/addresses/get/{id}",
+    tag = "addresses",
+    params(("id" = i64, Path, description = "Address id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<PublicAddress>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn show(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let address = state
+        .address_service
+        .get_one_public(auth_user_id, id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(address)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/addresses/create",
+    tag = "addresses",
+    request_body = CreateAddressDTO,
+    responses(
+        (status = 201, description = "Address created", body = SuccessResponse<PublicAddress>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Address book is full", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreateAddressDTO>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreateAddressCommand::try_from(body.into_inner())?;
+    let address = state.address_service.create(auth_user_id, command).await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(PublicAddress::from(address))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/me/addresses/update/{id}",
+    tag = "addresses",
+    params(("id" = i64, Path, description = "Address id")),
+    request_body = UpdateAddressDTO,
+    responses(
+        (status = 200, description = "Address updated", body = SuccessResponse<PublicAddress>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn update(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdateAddressDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = UpdateAddressCommand::try_from(body.into_inner())?;
+    let address = state
+        .address_service
+        .update(auth_user_id, id.into_inner(), command)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(PublicAddress::from(address))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/addresses/delete/{id}",
+    tag = "addresses",
+    params(("id" = i64, Path, description = "Address id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    state
+        .address_service
+        .delete(auth_user_id, id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
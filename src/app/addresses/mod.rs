@@ -0,0 +1,7 @@
+pub mod dto;
+pub mod handler;
+pub mod model;
+pub mod repository;
+pub mod routes;
+pub mod rules;
+pub mod service;
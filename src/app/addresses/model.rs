@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow, Clone)]
+pub struct AddressModel {
+    pub id: i64,
+    pub user_id: i64,
+    pub label: Option<String>,
+    pub full_name: String,
+    pub company: Option<String>,
+    pub line1: String,
+    pub line2: Option<String>,
+    pub city: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    pub country: String,
+    pub phone: Option<String>,
+    pub is_default_shipping: bool,
+    pub is_default_billing: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,249 @@
+use crate::app::addresses::dto::{CreateAddressCommand, UpdateAddressCommand};
+use crate::app::addresses::model::AddressModel;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres};
+use tracing::instrument;
+
+const ADDRESS_COLUMNS: &str = r#"
+    SELECT
+        id,
+        user_id,
+        label,
+        full_name,
+        company,
+        line1,
+        line2,
+        city,
+        region,
+        postcode,
+        country,
+        phone,
+        is_default_shipping,
+        is_default_billing,
+        created_at,
+        updated_at
+    FROM user_addresses
+"#;
+
+pub struct AddressRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for AddressRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl AddressRepository {
+    /** Addresses of a user, the default shipping one first. */
+    #[instrument(skip_all)]
+    pub async fn list_by_user(&self, user_id: i64) -> Result<Vec<AddressModel>, AppError> {
+        sqlx::query_as(&format!(
+            "{} WHERE user_id = $1 ORDER BY is_default_shipping DESC, id;",
+            ADDRESS_COLUMNS
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Address `id` when it belongs to `user_id`. */
+    #[instrument(skip_all)]
+    pub async fn find(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        id: i64,
+    ) -> Result<Option<AddressModel>, AppError> {
+        sqlx::query_as(&format!(
+            "{} WHERE user_id = $1 AND id = $2;",
+            ADDRESS_COLUMNS
+        ))
+        .bind(user_id)
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn count_by_user(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar! {
+            r#"SELECT COUNT(*) AS "count!" FROM user_addresses WHERE user_id = $1;"#,
+            user_id
+        }
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Locks the user row, so concurrent writes to one address book can't
+     * both claim a default or pass the size limit.
+     */
+    #[instrument(skip_all)]
+    pub async fn lock_user(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+    ) -> Result<(), AppError> {
+        sqlx::query!("SELECT id FROM users WHERE id = $1 FOR UPDATE;", user_id)
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        cmd: &CreateAddressCommand,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar(
+            r#"
+        INSERT INTO user_addresses (
+            user_id, label, full_name, company, line1, line2, city, region, postcode,
+            country, phone, is_default_shipping, is_default_billing
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING id;
+        "#,
+        )
+        .bind(user_id)
+        .bind(&cmd.label)
+        .bind(&cmd.full_name)
+        .bind(&cmd.company)
+        .bind(&cmd.line1)
+        .bind(&cmd.line2)
+        .bind(&cmd.city)
+        .bind(&cmd.region)
+        .bind(&cmd.postcode)
+        .bind(&cmd.country)
+        .bind(&cmd.phone)
+        .bind(cmd.is_default_shipping)
+        .bind(cmd.is_default_billing)
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Empty default flags of `cmd` leave the stored ones as they are. */
+    #[instrument(skip_all)]
+    pub async fn update(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        id: i64,
+        cmd: &UpdateAddressCommand,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+        UPDATE user_addresses
+        SET (
+            label, full_name, company, line1, line2, city, region, postcode, country, phone,
+            is_default_shipping, is_default_billing, updated_at
+        ) = (
+            $1, $2, $3, $4, $5, $6, $7, $8, $9, $10,
+            COALESCE($11, is_default_shipping), COALESCE($12, is_default_billing), now()
+        )
+        WHERE user_id = $13 AND id = $14;
+        "#,
+        )
+        .bind(&cmd.label)
+        .bind(&cmd.full_name)
+        .bind(&cmd.company)
+        .bind(&cmd.line1)
+        .bind(&cmd.line2)
+        .bind(&cmd.city)
+        .bind(&cmd.region)
+        .bind(&cmd.postcode)
+        .bind(&cmd.country)
+        .bind(&cmd.phone)
+        .bind(cmd.is_default_shipping)
+        .bind(cmd.is_default_billing)
+        .bind(user_id)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM user_addresses WHERE user_id = $1 AND id = $2;",
+            user_id,
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /** Drops the default shipping flag from every address of a user. */
+    #[instrument(skip_all)]
+    pub async fn clear_default_shipping(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        UPDATE user_addresses SET is_default_shipping = FALSE
+        WHERE user_id = $1 AND is_default_shipping;
+        "#,
+            user_id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /** Drops the default billing flag from every address of a user. */
+    #[instrument(skip_all)]
+    pub async fn clear_default_billing(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        UPDATE user_addresses SET is_default_billing = FALSE
+        WHERE user_id = $1 AND is_default_billing;
+        "#,
+            user_id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::app::addresses::handler;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{delete, get, post, put, resource};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/me/addresses")
+            .wrap(AuthMiddleware::new(None))
+            .service(resource("/list").route(get().to(handler::index)))
+            .service(resource("/get/{id}").route(get().to(handler::show)))
+            .service(resource("/create").route(post().to(handler::create)))
+            .service(resource("/update/{id}").route(put().to(handler::update)))
+            .service(resource("/delete/{id}").route(delete().to(handler::delete))),
+    );
+}
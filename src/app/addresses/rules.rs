@@ -0,0 +1,108 @@
+use crate::errors::error::AppError;
+
+/**
+ * What an address in one country needs. In `postcode_formats` a `9` stands
+ * for a digit, an `A` for a letter, a `?` for either and anything else for
+ * itself; spaces are ignored when matching.
+ */
+pub struct CountryRules {
+    pub postcode_formats: &'static [&'static str],
+    pub region_required: bool,
+}
+
+/** Rules of the countries the shop knows about, others take any postcode. */
+pub fn rules_for(country: &str) -> Option<CountryRules> {
+    let (postcode_formats, region_required): (&'static [&'static str], bool) = match country {
+        "AU" => (&["9999"], true),
+        "BR" => (&["99999-999"], true),
+        "CA" => (&["A9A 9A9"], true),
+        "DE" => (&["99999"], false),
+        "ES" => (&["99999"], false),
+        "FR" => (&["99999"], false),
+        "GB" => (
+            &[
+                "A9 9AA", "A99 9AA", "AA9 9AA", "AA99 9AA", "A9A 9AA", "AA9A 9AA",
+            ],
+            false,
+        ),
+        "IE" => (&["A99 ????", "A9A ????"], false),
+        "IN" => (&["999999"], true),
+        "IT" => (&["99999"], false),
+        "JP" => (&["999-9999"], false),
+        "MX" => (&["99999"], true),
+        "NL" => (&["9999 AA"], false),
+        "PL" => (&["99-999"], false),
+        "US" => (&["99999", "99999-9999"], true),
+        _ => return None,
+    };
+
+    Some(CountryRules {
+        postcode_formats,
+        region_required,
+    })
+}
+
+fn matches_format(format: &str, postcode: &str) -> bool {
+    let format: Vec<char> = format.chars().filter(|c| *c != ' ').collect();
+    let postcode: Vec<char> = postcode.chars().filter(|c| *c != ' ').collect();
+
+    format.len() == postcode.len()
+        && format.iter().zip(&postcode).all(|(f, c)| match f {
+            '9' => c.is_ascii_digit(),
+            'A' => c.is_ascii_alphabetic(),
+            '?' => c.is_ascii_alphanumeric(),
+            f => f == c,
+        })
+}
+
+/**
+ * Checks the region and postcode of an address against the rules of its
+ * country. `postcode` is expected normalized, see `normalize_postcode`.
+ */
+pub fn validate_for_country(
+    country: &str,
+    region: Option<&str>,
+    postcode: Option<&str>,
+) -> Result<(), AppError> {
+    let Some(rules) = rules_for(country) else {
+        return Ok(());
+    };
+
+    if rules.region_required && region.is_none() {
+        return Err(AppError::field(
+            "region",
+            "required",
+            "is required for this country",
+        ));
+    }
+
+    match postcode {
+        None => Err(AppError::field(
+            "postcode",
+            "required",
+            "is required for this country",
+        )),
+        Some(postcode)
+            if !rules
+                .postcode_formats
+                .iter()
+                .any(|format| matches_format(format, postcode)) =>
+        {
+            Err(AppError::field(
+                "postcode",
+                "postcode_format",
+                "is not a valid postcode for this country",
+            ))
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+/** Postcodes are stored upper case with single spaces. */
+pub fn normalize_postcode(postcode: &str) -> String {
+    postcode
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+}
@@ -0,0 +1,142 @@
+use crate::app::addresses::dto::{CreateAddressCommand, PublicAddress, UpdateAddressCommand};
+use crate::app::addresses::model::AddressModel;
+use crate::app::addresses::repository::AddressRepository;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+/** Most addresses one user can keep. */
+const MAX_ADDRESSES: i64 = 20;
+
+pub struct AddressService {
+    repository: AddressRepository,
+}
+
+impl AddressService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: AddressRepository::new(pool),
+        }
+    }
+
+    pub async fn get_all_public(&self, user_id: i64) -> Result<Vec<PublicAddress>, AppError> {
+        let addresses = self.repository.list_by_user(user_id).await?;
+
+        Ok(addresses.into_iter().map(PublicAddress::from).collect())
+    }
+
+    pub async fn get_one(&self, user_id: i64, id: i64) -> Result<AddressModel, AppError> {
+        self.repository
+            .find(self.repository.get_pool(), user_id, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Address not found".to_string()))
+    }
+
+    pub async fn get_one_public(&self, user_id: i64, id: i64) -> Result<PublicAddress, AppError> {
+        Ok(self.get_one(user_id, id).await?.into())
+    }
+
+    /**
+     * Adds an address to the book. The first address becomes the default for
+     * both shipping and billing, a later one only when asked to, taking the
+     * flag from the previous default.
+     */
+    pub async fn create(
+        &self,
+        user_id: i64,
+        mut cmd: CreateAddressCommand,
+    ) -> Result<AddressModel, AppError> {
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.lock_user(&mut *tx, user_id).await?;
+
+        let count = self.repository.count_by_user(&mut *tx, user_id).await?;
+
+        if count >= MAX_ADDRESSES {
+            return Err(AppError::Conflict(format!(
+                "An address book holds at most {} addresses",
+                MAX_ADDRESSES
+            )));
+        }
+
+        if count == 0 {
+            cmd.is_default_shipping = true;
+            cmd.is_default_billing = true;
+        }
+
+        if cmd.is_default_shipping {
+            self.repository
+                .clear_default_shipping(&mut *tx, user_id)
+                .await?;
+        }
+
+        if cmd.is_default_billing {
+            self.repository
+                .clear_default_billing(&mut *tx, user_id)
+                .await?;
+        }
+
+        let id = self.repository.create(&mut *tx, user_id, &cmd).await?;
+
+        let address = self
+            .repository
+            .find(&mut *tx, user_id, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Address not found".to_string()))?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(address)
+    }
+
+    pub async fn update(
+        &self,
+        user_id: i64,
+        id: i64,
+        cmd: UpdateAddressCommand,
+    ) -> Result<AddressModel, AppError> {
+        self.get_one(user_id, id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.lock_user(&mut *tx, user_id).await?;
+
+        if cmd.is_default_shipping == Some(true) {
+            self.repository
+                .clear_default_shipping(&mut *tx, user_id)
+                .await?;
+        }
+
+        if cmd.is_default_billing == Some(true) {
+            self.repository
+                .clear_default_billing(&mut *tx, user_id)
+                .await?;
+        }
+
+        self.repository.update(&mut *tx, user_id, id, &cmd).await?;
+
+        let address = self
+            .repository
+            .find(&mut *tx, user_id, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Address not found".to_string()))?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        Ok(address)
+    }
+
+    /** Deletes an address, deleting a default leaves the user without one. */
+    pub async fn delete(&self, user_id: i64, id: i64) -> Result<u64, AppError> {
+        let deleted = self
+            .repository
+            .delete(self.repository.get_pool(), user_id, id)
+            .await?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Address not found".to_string()));
+        }
+
+        Ok(deleted)
+    }
+}
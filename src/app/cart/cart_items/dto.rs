@@ -1,22 +1,22 @@
 use crate::app::cart::cart_items::model::CartItemModel;
+use crate::utils::money::Money;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Validate)]
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
 pub struct AddItemDto {
     #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
     pub product_id: Option<i64>,
 
-    #[validate(required, range(min = 0.0))]
-    pub price: Option<f64>,
-
     #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
     pub quantity: Option<i32>,
 }
 
 pub struct AddItemCommand {
     pub product_id: i64,
-    pub price: f64,
     pub quantity: i32,
     pub cart_id: i64,
 }
@@ -25,16 +25,16 @@ impl AddItemCommand {
     pub fn new(dto: AddItemDto, cart_id: i64) -> Self {
         Self {
             product_id: dto.product_id.unwrap(),
-            price: dto.price.unwrap(),
             quantity: dto.quantity.unwrap(),
             cart_id,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Validate)]
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
 pub struct RemoveItemDto {
     #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
     pub product_id: Option<i64>,
 }
 
@@ -52,12 +52,14 @@ impl RemoveItemCommand {
     }
 }
 
-#[derive(Serialize, Deserialize, Validate)]
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateItemDto {
     #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
     pub product_id: Option<i64>,
 
     #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
     pub quantity: Option<i32>,
 }
 
@@ -77,12 +79,13 @@ impl UpdateItemCommand {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PublicCartItems {
     pub id: i64,
     pub cart_id: i64,
     pub product_id: i64,
-    pub price: f64,
+    /// Unit price the product sold for when it was added.
+    pub price: Money,
     pub quantity: i32,
 }
 
@@ -92,7 +95,7 @@ impl From<CartItemModel> for PublicCartItems {
             id: item.id,
             cart_id: item.cart_id,
             product_id: item.product_id,
-            price: item.price,
+            price: Money::new(item.price),
             quantity: item.quantity,
         }
     }
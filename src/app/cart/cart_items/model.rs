@@ -1,3 +1,4 @@
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use sqlx::FromRow;
@@ -7,7 +8,7 @@ pub struct CartItemModel {
     pub id: i64,
     pub cart_id: i64,
     pub product_id: i64,
-    pub price: f64,
+    pub price: BigDecimal,
     pub quantity: i32,
     pub created_at: DateTime<Utc>,
 }
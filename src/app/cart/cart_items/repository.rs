@@ -2,7 +2,9 @@ use crate::app::cart::cart_items::dto::{AddItemCommand, RemoveItemCommand, Updat
 use crate::app::cart::cart_items::model::CartItemModel;
 use crate::errors::error::AppError;
 use crate::utils::traits::IsRepository;
-use sqlx::PgPool;
+use bigdecimal::BigDecimal;
+use sqlx::{Executor, PgPool, Postgres};
+use tracing::instrument;
 
 pub struct CartItemsRepository {
     pool: PgPool,
@@ -21,6 +23,7 @@ impl IsRepository for CartItemsRepository {
 }
 
 impl CartItemsRepository {
+    #[instrument(skip_all)]
     pub async fn get_items(&self, cart_id: &i64) -> Result<Vec<CartItemModel>, AppError> {
         sqlx::query_as!(
         CartItemModel,
@@ -32,23 +35,57 @@ impl CartItemsRepository {
             .map_err(AppError::Database)
     }
 
-    pub async fn add_item(&self, cmd: AddItemCommand) -> Result<u64, AppError> {
+    /**
+     * Quantity on the line of a product in a cart, `None` without one. Locks
+     * the line until the transaction ends.
+     */
+    #[instrument(skip_all)]
+    pub async fn lock_line_quantity(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cart_id: i64,
+        product_id: i64,
+    ) -> Result<Option<i32>, AppError> {
+        sqlx::query_scalar!(
+            "SELECT quantity FROM cart_items WHERE cart_id = $1 AND product_id = $2 FOR UPDATE;",
+            cart_id,
+            product_id
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Adds a line, or raises the quantity of the line the product already has
+     * in the cart. Either way the line takes the new price.
+     */
+    #[instrument(skip_all)]
+    pub async fn add_item(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cmd: &AddItemCommand,
+        price: BigDecimal,
+    ) -> Result<u64, AppError> {
         let result = sqlx::query_as!(
             CartItemModel,
             r#"INSERT INTO cart_items (cart_id, product_id, price, quantity, created_at)
-            VALUES ($1, $2, $3, $4, NOW());"#,
+            VALUES ($1, $2, $3, $4, NOW())
+            ON CONFLICT (cart_id, product_id) DO UPDATE
+            SET price = EXCLUDED.price, quantity = cart_items.quantity + EXCLUDED.quantity;"#,
             cmd.cart_id,
             cmd.product_id,
-            cmd.price,
+            price,
             cmd.quantity
         )
-        .execute(&self.pool)
+        .execute(executor)
         .await
         .map_err(AppError::Database)?;
 
         Ok(result.rows_affected())
     }
 
+    #[instrument(skip_all)]
     pub async fn remove_item(&self, cmd: RemoveItemCommand) -> Result<u64, AppError> {
         let result = sqlx::query_as!(
             CartItemModel,
@@ -63,6 +100,7 @@ impl CartItemsRepository {
         Ok(result.rows_affected())
     }
 
+    #[instrument(skip_all)]
     pub async fn update_item(&self, cmd: &UpdateItemCommand) -> Result<u64, AppError> {
         let result = sqlx::query_as!(
             CartItemModel,
@@ -78,6 +116,7 @@ impl CartItemsRepository {
         Ok(result.rows_affected())
     }
 
+    #[instrument(skip_all)]
     pub async fn check_product_exist_in_cart(&self, product_id: &i64) -> Result<bool, AppError> {
         sqlx::query_scalar!(
             r#"SELECT EXISTS(
@@ -92,6 +131,7 @@ impl CartItemsRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_cart_product_quantity(
         &self,
         cart_id: &i64,
@@ -111,4 +151,18 @@ impl CartItemsRepository {
         .await
         .map_err(AppError::Database)
     }
+
+    #[instrument(skip_all)]
+    pub async fn update_cart_total(
+        &self,
+        cart_id: &i64,
+        total: &BigDecimal,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query!("UPDATE cart SET total = $1 WHERE id = $2;", total, cart_id)
+            .execute(&self.pool)
+            .await
+            .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
 }
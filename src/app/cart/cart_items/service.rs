@@ -1,21 +1,28 @@
 use crate::app::cart::cart_items::dto::{AddItemCommand, RemoveItemCommand, UpdateItemCommand};
 use crate::app::cart::cart_items::model::CartItemModel;
 use crate::app::cart::cart_items::repository::CartItemsRepository;
+use crate::app::cart::coupons::service::CartCouponService;
+use crate::app::cart::stock::service::CartStockService;
 use crate::app::products::repository::ProductRepository;
+use crate::config::settings::InventoryConfig;
 use crate::errors::error::AppError;
 use crate::utils::traits::IsRepository;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
 pub struct CartItemsService {
     repository: CartItemsRepository,
     product_repository: ProductRepository,
+    coupon_service: CartCouponService,
+    stock_service: CartStockService,
 }
 
 impl CartItemsService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, inventory_config: InventoryConfig) -> Self {
         Self {
             repository: CartItemsRepository::new(pool.clone()),
             product_repository: ProductRepository::new(pool.clone()),
+            coupon_service: CartCouponService::new(pool.clone()),
+            stock_service: CartStockService::new(pool.clone(), inventory_config),
         }
     }
 
@@ -23,11 +30,59 @@ impl CartItemsService {
         self.repository.get_items(&cart_id).await
     }
 
+    /**
+     * Locks in the effective price at the moment of add, the client never
+     * sends one. Adding a product the cart already holds raises its line, and
+     * the sellable warehouses must be able to fill the new total.
+     */
     pub async fn add_item(&self, cmd: AddItemCommand) -> Result<(), AppError> {
-        self.repository.add_item(cmd).await?;
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.add_item_on(&mut tx, &cmd).await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.refresh_total(cmd.cart_id).await
+    }
+
+    /**
+     * Same as `add_item` on the transaction of the caller. The cart total is
+     * left alone, call `refresh_total` once that transaction commits.
+     */
+    pub async fn add_item_on(
+        &self,
+        conn: &mut PgConnection,
+        cmd: &AddItemCommand,
+    ) -> Result<(), AppError> {
+        let price = self
+            .product_repository
+            .get_effective_price(cmd.product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("product not found".to_string()))?;
+
+        let in_cart = self
+            .repository
+            .lock_line_quantity(&mut *conn, cmd.cart_id, cmd.product_id)
+            .await?
+            .unwrap_or(0);
+
+        let quantity = in_cart
+            .checked_add(cmd.quantity)
+            .ok_or_else(|| AppError::field("quantity", "range", "is too large"))?;
+
+        self.stock_service
+            .allocate(cmd.product_id, quantity)
+            .await?;
+
+        self.repository.add_item(&mut *conn, cmd, price).await?;
+
         Ok(())
     }
 
+    pub async fn refresh_total(&self, cart_id: i64) -> Result<(), AppError> {
+        self.coupon_service.refresh_total(cart_id).await
+    }
+
     pub async fn remove_item(&self, cmd: RemoveItemCommand) -> Result<(), AppError> {
         let product_exist = self
             .repository
@@ -38,8 +93,10 @@ impl CartItemsService {
             return Err(AppError::NotFound("product not found in cart".to_string()));
         }
 
+        let cart_id = cmd.cart_id;
+
         self.repository.remove_item(cmd).await?;
-        Ok(())
+        self.coupon_service.refresh_total(cart_id).await
     }
 
     pub async fn update_item(&self, cmd: UpdateItemCommand) -> Result<(), AppError> {
@@ -54,16 +111,11 @@ impl CartItemsService {
 
         // TODO: handle configurable products
 
-        let product_stock = self
-            .product_repository
-            .get_product_stock(&cmd.product_id)
+        self.stock_service
+            .allocate(cmd.product_id, cmd.quantity)
             .await?;
 
-        if cmd.quantity > product_stock {
-            return Err(AppError::Internal("not enough stock available".to_string()));
-        }
-
         self.repository.update_item(&cmd).await?;
-        Ok(())
+        self.coupon_service.refresh_total(cmd.cart_id).await
     }
 }
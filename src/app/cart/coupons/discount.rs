@@ -0,0 +1,108 @@
+use crate::admin::coupons::dto::CouponKind;
+use crate::app::cart::cart_items::model::CartItemModel;
+use crate::app::cart::coupons::model::CouponModel;
+use crate::errors::error::AppError;
+use crate::utils::money::Money;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+/** Why a coupon gives nothing on a cart right now. */
+pub enum CouponRejection {
+    Inactive,
+    NotStarted,
+    Expired,
+    BelowMinimum(Money),
+    NotApplicable,
+}
+
+impl From<CouponRejection> for AppError {
+    fn from(rejection: CouponRejection) -> Self {
+        match rejection {
+            CouponRejection::Inactive => invalid_code(),
+            CouponRejection::NotStarted => {
+                AppError::field("code", "not_started", "coupon is not valid yet")
+            }
+            CouponRejection::Expired => AppError::field("code", "expired", "coupon has expired"),
+            CouponRejection::BelowMinimum(minimum) => AppError::field(
+                "code",
+                "min_cart_value",
+                &format!("cart subtotal must be at least {}", minimum.amount),
+            ),
+            CouponRejection::NotApplicable => AppError::field(
+                "code",
+                "not_applicable",
+                "coupon does not apply to any item in the cart",
+            ),
+        }
+    }
+}
+
+/** Unknown and disabled codes look the same to customers. */
+pub fn invalid_code() -> AppError {
+    AppError::field("code", "invalid", "coupon code is not valid")
+}
+
+pub fn line_total(item: &CartItemModel) -> Money {
+    Money::new(item.price.clone()).times(item.quantity)
+}
+
+/**
+ * Discount `coupon` gives on `items` at `now`. `eligible` holds the products
+ * in the cart its product and category restrictions let through. Usage limits
+ * are not checked here, they only matter when the code is applied.
+ */
+pub fn evaluate(
+    coupon: &CouponModel,
+    items: &[CartItemModel],
+    eligible: &HashSet<i64>,
+    now: DateTime<Utc>,
+) -> Result<Money, CouponRejection> {
+    if !coupon.is_active {
+        return Err(CouponRejection::Inactive);
+    }
+
+    if coupon.starts_at.is_some_and(|starts_at| starts_at > now) {
+        return Err(CouponRejection::NotStarted);
+    }
+
+    if coupon.ends_at.is_some_and(|ends_at| ends_at <= now) {
+        return Err(CouponRejection::Expired);
+    }
+
+    let subtotal: Money = items.iter().map(line_total).sum();
+
+    if let Some(minimum) = &coupon.min_cart_value
+        && &subtotal.amount < minimum
+    {
+        return Err(CouponRejection::BelowMinimum(Money::new(minimum.clone())));
+    }
+
+    if coupon.kind == CouponKind::FreeItem {
+        let item = items
+            .iter()
+            .find(|item| Some(item.product_id) == coupon.free_product_id)
+            .ok_or(CouponRejection::NotApplicable)?;
+
+        return Ok(Money::new(item.price.clone()).times(coupon.free_quantity.min(item.quantity)));
+    }
+
+    let eligible_items: Vec<&CartItemModel> = items
+        .iter()
+        .filter(|item| eligible.contains(&item.product_id))
+        .collect();
+
+    if eligible_items.is_empty() {
+        return Err(CouponRejection::NotApplicable);
+    }
+
+    let base: Money = eligible_items.into_iter().map(line_total).sum();
+    let value = coupon.value.clone().unwrap_or_default();
+
+    let discount = match coupon.kind {
+        CouponKind::Percentage => Money::new(base.amount * value / BigDecimal::from(100)),
+        _ => Money::new(value.min(base.amount)),
+    };
+
+    Ok(discount)
+}
@@ -0,0 +1,50 @@
+use crate::admin::coupons::dto::CouponKind;
+use crate::app::cart::promotions::dto::PublicCartPromotion;
+use crate::app::cart::tax::dto::PublicCartTax;
+use crate::utils::money::Money;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct ApplyCouponDto {
+    #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1, example = "SPRING15")]
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PublicCartDiscount {
+    pub code: String,
+    pub kind: CouponKind,
+    /// The product handed out for free, `free_item` coupons only.
+    pub product_id: Option<i64>,
+    pub amount: Money,
+}
+
+/**
+ * What a cart costs: the item subtotal, the promotions and coupon discounts
+ * that currently apply, the tax when a destination is known and what is left
+ * to pay.
+ */
+pub struct CartTotals {
+    pub subtotal: Money,
+    pub promotions: Vec<PublicCartPromotion>,
+    pub coupon_code: Option<String>,
+    pub discounts: Vec<PublicCartDiscount>,
+    pub tax: Option<PublicCartTax>,
+    pub total: Money,
+}
+
+impl CartTotals {
+    pub fn empty() -> Self {
+        Self {
+            subtotal: Money::zero(),
+            promotions: Vec::new(),
+            coupon_code: None,
+            discounts: Vec::new(),
+            tax: None,
+            total: Money::zero(),
+        }
+    }
+}
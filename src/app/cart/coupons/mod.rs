@@ -0,0 +1,5 @@
+pub mod discount;
+pub mod dto;
+mod model;
+mod repository;
+pub mod service;
@@ -0,0 +1,22 @@
+use crate::admin::coupons::dto::CouponKind;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+#[derive(FromRow)]
+pub struct CouponModel {
+    pub id: i64,
+    pub code: String,
+    pub kind: CouponKind,
+    pub value: Option<BigDecimal>,
+    pub free_product_id: Option<i64>,
+    pub free_quantity: i32,
+    pub min_cart_value: Option<BigDecimal>,
+    pub usage_limit: Option<i32>,
+    pub usage_limit_per_user: Option<i32>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub products: Vec<i64>,
+    pub categories: Vec<i64>,
+}
@@ -0,0 +1,237 @@
+use crate::app::cart::coupons::model::CouponModel;
+use crate::app::cart::model::CartModel;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::{Executor, PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+const COUPON_COLUMNS: &str = r#"
+    SELECT
+        coupons.id,
+        coupons.code,
+        coupons.kind,
+        coupons.value,
+        coupons.free_product_id,
+        coupons.free_quantity,
+        coupons.min_cart_value,
+        coupons.usage_limit,
+        coupons.usage_limit_per_user,
+        coupons.starts_at,
+        coupons.ends_at,
+        coupons.is_active,
+        ARRAY(SELECT product_id FROM coupon_has_products WHERE coupon_id = coupons.id) AS products,
+        ARRAY(SELECT category_id FROM coupon_has_categories WHERE coupon_id = coupons.id) AS categories
+    FROM coupons
+"#;
+
+pub struct CartCouponRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for CartCouponRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl CartCouponRepository {
+    #[instrument(skip_all)]
+    pub async fn get_by_code(&self, code: &str) -> Result<Option<CouponModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(COUPON_COLUMNS);
+
+        qb.push(" WHERE coupons.code = ");
+        qb.push_bind(code);
+
+        qb.build_query_as::<CouponModel>()
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn get_for_cart(&self, cart_id: i64) -> Result<Option<CouponModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(COUPON_COLUMNS);
+
+        qb.push(" INNER JOIN cart ON cart.coupon_id = coupons.id WHERE cart.id = ");
+        qb.push_bind(cart_id);
+
+        qb.build_query_as::<CouponModel>()
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn get_cart(&self, cart_id: i64) -> Result<Option<CartModel>, AppError> {
+        sqlx::query_as!(
+            CartModel,
+            r#"
+        SELECT id, user_id, user_hash_id, total, coupon_id, created_at
+        FROM cart
+        WHERE id = $1;
+        "#,
+            cart_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Products out of `product_ids` that sit in one of `category_ids`.
+     */
+    #[instrument(skip_all)]
+    pub async fn get_products_in_categories(
+        &self,
+        product_ids: &[i64],
+        category_ids: &[i64],
+    ) -> Result<Vec<i64>, AppError> {
+        sqlx::query_scalar!(
+            r#"
+        SELECT DISTINCT product_id
+        FROM product_has_categories
+        WHERE product_id = ANY($1) AND category_id = ANY($2);
+        "#,
+            product_ids,
+            category_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Row lock on the coupon, so concurrent applies can't both squeeze
+     * under its usage limits.
+     */
+    #[instrument(skip_all)]
+    pub async fn lock_coupon(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        coupon_id: i64,
+    ) -> Result<(), AppError> {
+        sqlx::query!(
+            "SELECT id FROM coupons WHERE id = $1 FOR UPDATE;",
+            coupon_id
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(())
+    }
+
+    /** Uses of a coupon, not counting the one held by `cart_id`. */
+    #[instrument(skip_all)]
+    pub async fn count_redemptions(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        coupon_id: i64,
+        cart_id: i64,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar!(
+            r#"
+        SELECT COUNT(*) AS "count!"
+        FROM coupon_redemptions
+        WHERE coupon_id = $1 AND cart_id IS DISTINCT FROM $2;
+        "#,
+            coupon_id,
+            cart_id
+        )
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Uses of a coupon by the owner of `cart`, not counting `cart` itself. */
+    #[instrument(skip_all)]
+    pub async fn count_owner_redemptions(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        coupon_id: i64,
+        cart: &CartModel,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar!(
+            r#"
+        SELECT COUNT(*) AS "count!"
+        FROM coupon_redemptions
+        WHERE coupon_id = $1
+            AND cart_id IS DISTINCT FROM $2
+            AND (user_id = $3 OR user_hash_id = $4);
+        "#,
+            coupon_id,
+            cart.id,
+            cart.user_id,
+            cart.user_hash_id
+        )
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create_redemption(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        coupon_id: i64,
+        cart: &CartModel,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query!(
+            r#"
+        INSERT INTO coupon_redemptions (coupon_id, cart_id, user_id, user_hash_id)
+        VALUES ($1, $2, $3, $4);
+        "#,
+            coupon_id,
+            cart.id,
+            cart.user_id,
+            cart.user_hash_id
+        )
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete_redemption(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cart_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query!(
+            "DELETE FROM coupon_redemptions WHERE cart_id = $1;",
+            cart_id
+        )
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn set_cart_coupon(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        cart_id: i64,
+        coupon_id: Option<i64>,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query!(
+            "UPDATE cart SET coupon_id = $1 WHERE id = $2;",
+            coupon_id,
+            cart_id
+        )
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+}
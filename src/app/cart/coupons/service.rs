@@ -0,0 +1,228 @@
+use crate::admin::coupons::dto::{CouponKind, normalize_code};
+use crate::app::cart::cart_items::model::CartItemModel;
+use crate::app::cart::cart_items::repository::CartItemsRepository;
+use crate::app::cart::coupons::discount::{evaluate, invalid_code, line_total};
+use crate::app::cart::coupons::dto::{CartTotals, PublicCartDiscount};
+use crate::app::cart::coupons::model::CouponModel;
+use crate::app::cart::coupons::repository::CartCouponRepository;
+use crate::app::cart::model::CartModel;
+use crate::app::cart::promotions::service::CartPromotionService;
+use crate::errors::error::AppError;
+use crate::utils::money::Money;
+use crate::utils::traits::IsRepository;
+use bigdecimal::{BigDecimal, Zero};
+use chrono::Utc;
+use sqlx::PgPool;
+use std::collections::HashSet;
+
+pub struct CartCouponService {
+    repository: CartCouponRepository,
+    cart_items_repository: CartItemsRepository,
+    promotion_service: CartPromotionService,
+}
+
+impl CartCouponService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: CartCouponRepository::new(pool.clone()),
+            cart_items_repository: CartItemsRepository::new(pool.clone()),
+            promotion_service: CartPromotionService::new(pool),
+        }
+    }
+
+    /**
+     * Puts `code` on the cart, replacing the coupon it held. The coupon has
+     * to give a discount on the cart as it is and still be within its usage
+     * limits.
+     */
+    pub async fn apply(&self, cart_id: i64, code: &str) -> Result<(), AppError> {
+        let coupon = self
+            .repository
+            .get_by_code(&normalize_code(code))
+            .await?
+            .ok_or_else(invalid_code)?;
+
+        let cart = self.get_cart(cart_id).await?;
+
+        if cart.coupon_id == Some(coupon.id) {
+            return Ok(());
+        }
+
+        let items = self.cart_items_repository.get_items(&cart_id).await?;
+        let eligible = self.eligible_products(&coupon, &items).await?;
+
+        evaluate(&coupon, &items, &eligible, Utc::now())?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.lock_coupon(&mut *tx, coupon.id).await?;
+
+        if let Some(limit) = coupon.usage_limit {
+            let used = self
+                .repository
+                .count_redemptions(&mut *tx, coupon.id, cart_id)
+                .await?;
+
+            if used >= i64::from(limit) {
+                return Err(AppError::field(
+                    "code",
+                    "usage_limit_reached",
+                    "coupon has been used up",
+                ));
+            }
+        }
+
+        if let Some(limit) = coupon.usage_limit_per_user {
+            let used = self
+                .repository
+                .count_owner_redemptions(&mut *tx, coupon.id, &cart)
+                .await?;
+
+            if used >= i64::from(limit) {
+                return Err(AppError::field(
+                    "code",
+                    "usage_limit_reached",
+                    "coupon was already used",
+                ));
+            }
+        }
+
+        self.repository.delete_redemption(&mut *tx, cart_id).await?;
+        self.repository
+            .create_redemption(&mut *tx, coupon.id, &cart)
+            .await?;
+        self.repository
+            .set_cart_coupon(&mut *tx, cart_id, Some(coupon.id))
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.refresh_total(cart_id).await
+    }
+
+    /** Takes the coupon off the cart and gives its use back. */
+    pub async fn remove(&self, cart_id: i64) -> Result<(), AppError> {
+        let cart = self.get_cart(cart_id).await?;
+
+        if cart.coupon_id.is_none() {
+            return Err(AppError::NotFound("no coupon applied to cart".to_string()));
+        }
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.delete_redemption(&mut *tx, cart_id).await?;
+        self.repository
+            .set_cart_coupon(&mut *tx, cart_id, None)
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.refresh_total(cart_id).await
+    }
+
+    /**
+     * Prices `items` of a cart. Automatic promotions and the coupon both work
+     * off the subtotal and the total never drops under zero. A coupon that no
+     * longer applies, e.g. expired or the cart dropped under its minimum, stays
+     * on the cart without a discount line.
+     */
+    pub async fn totals(
+        &self,
+        cart_id: i64,
+        items: &[CartItemModel],
+    ) -> Result<CartTotals, AppError> {
+        let subtotal: Money = items.iter().map(line_total).sum();
+        let promotions = self.promotion_service.adjustments(items).await?;
+        let coupon = self.repository.get_for_cart(cart_id).await?;
+
+        let mut discounts = Vec::new();
+
+        if let Some(coupon) = &coupon {
+            let eligible = self.eligible_products(coupon, items).await?;
+
+            if let Ok(amount) = evaluate(coupon, items, &eligible, Utc::now()) {
+                discounts.push(PublicCartDiscount {
+                    code: coupon.code.clone(),
+                    kind: coupon.kind,
+                    product_id: match coupon.kind {
+                        CouponKind::FreeItem => coupon.free_product_id,
+                        _ => None,
+                    },
+                    amount,
+                });
+            }
+        }
+
+        let discount: Money = promotions
+            .iter()
+            .map(|line| line.amount.clone())
+            .chain(discounts.iter().map(|line| line.amount.clone()))
+            .sum();
+
+        let total = subtotal.clone() - discount;
+
+        Ok(CartTotals {
+            total: if total.amount < BigDecimal::zero() {
+                Money::zero()
+            } else {
+                total
+            },
+            subtotal,
+            promotions,
+            coupon_code: coupon.map(|coupon| coupon.code),
+            discounts,
+            tax: None,
+        })
+    }
+
+    /** Stores the discounted total of a cart after its items or coupon changed. */
+    pub async fn refresh_total(&self, cart_id: i64) -> Result<(), AppError> {
+        let items = self.cart_items_repository.get_items(&cart_id).await?;
+        let totals = self.totals(cart_id, &items).await?;
+
+        self.cart_items_repository
+            .update_cart_total(&cart_id, &totals.total.amount)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_cart(&self, cart_id: i64) -> Result<CartModel, AppError> {
+        self.repository
+            .get_cart(cart_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("cart not found".to_string()))
+    }
+
+    /**
+     * Cart products the coupon's restrictions let through, every product when
+     * it has none.
+     */
+    async fn eligible_products(
+        &self,
+        coupon: &CouponModel,
+        items: &[CartItemModel],
+    ) -> Result<HashSet<i64>, AppError> {
+        let in_cart: Vec<i64> = items.iter().map(|item| item.product_id).collect();
+
+        if coupon.products.is_empty() && coupon.categories.is_empty() {
+            return Ok(in_cart.into_iter().collect());
+        }
+
+        let mut eligible: HashSet<i64> = in_cart
+            .iter()
+            .copied()
+            .filter(|product_id| coupon.products.contains(product_id))
+            .collect();
+
+        if !coupon.categories.is_empty() {
+            eligible.extend(
+                self.repository
+                    .get_products_in_categories(&in_cart, &coupon.categories)
+                    .await?,
+            );
+        }
+
+        Ok(eligible)
+    }
+}
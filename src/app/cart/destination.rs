@@ -0,0 +1,62 @@
+use crate::admin::tax_rates::dto::{normalize_country, normalize_postcode};
+use crate::errors::error::AppError;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+/** Where the cart ships to, taxes and shipping options depend on it. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CartDestination {
+    pub country: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CartDestinationDto {
+    /// Two letter country code.
+    #[validate(length(min = 1))]
+    #[param(min_length = 1, example = "DE")]
+    pub country: Option<String>,
+
+    #[validate(length(min = 1, max = 64))]
+    #[param(min_length = 1, max_length = 64)]
+    pub region: Option<String>,
+
+    #[validate(length(min = 1, max = 16))]
+    #[param(min_length = 1, max_length = 16)]
+    pub postcode: Option<String>,
+}
+
+impl CartDestinationDto {
+    /** The destination to tax for, none when no country was given. */
+    pub fn into_destination(self) -> Result<Option<CartDestination>, AppError> {
+        let Some(country) = self.country else {
+            if self.region.is_some() || self.postcode.is_some() {
+                return Err(AppError::field(
+                    "country",
+                    "required",
+                    "is required with a region or postcode",
+                ));
+            }
+
+            return Ok(None);
+        };
+
+        Ok(Some(CartDestination {
+            country: normalize_country(&country)?,
+            region: self.region.map(|region| region.trim().to_string()),
+            postcode: self
+                .postcode
+                .map(|postcode| normalize_postcode(&postcode, false))
+                .transpose()?,
+        }))
+    }
+
+    /** Same as `into_destination`, for when the cart can't do without one. */
+    pub fn into_required_destination(self) -> Result<CartDestination, AppError> {
+        self.into_destination()?
+            .ok_or_else(|| AppError::field("country", "required", "field is required"))
+    }
+}
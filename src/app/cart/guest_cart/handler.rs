@@ -1,27 +1,100 @@
 use crate::app::cart::cart_items::dto::{
     AddItemCommand, AddItemDto, RemoveItemCommand, RemoveItemDto, UpdateItemCommand, UpdateItemDto,
 };
+use crate::app::cart::coupons::dto::ApplyCouponDto;
+use crate::app::cart::destination::CartDestinationDto;
+use crate::app::cart::guest_cart::dto::PublicGuestCart;
+use crate::app::cart::shipping::dto::PublicShippingOption;
 use crate::app::users::dto::GuestToken;
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
 use validator::Validate;
 
+#[utoipa::path(
+    get,
+    path = "/cart/guest/get",
+    tag = "cart",
+    params(CartDestinationDto),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<PublicGuestCart>),
+        (status = 401, description = "Missing or expired guest token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid destination", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("guest_token" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn get_guest_cart(
     request: HttpRequest,
     state: web::Data<AppState>,
+    query: web::Query<CartDestinationDto>,
 ) -> Result<impl Responder, AppError> {
+    query.validate()?;
+
+    let destination = query.into_inner().into_destination()?;
+
+    let guest_token = extract_guest_token(&request)?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(
+        state
+            .guest_cart_service
+            .get_cart_by_hash(&guest_token, destination.as_ref())
+            .await?,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/cart/guest/shipping-options",
+    tag = "cart",
+    params(CartDestinationDto),
+    responses(
+        (status = 200, description = "Methods that can ship the cart, cheapest first", body = SuccessResponse<Vec<PublicShippingOption>>),
+        (status = 401, description = "Missing or expired guest token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Missing or invalid destination", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("guest_token" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn shipping_options(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    query: web::Query<CartDestinationDto>,
+) -> Result<impl Responder, AppError> {
+    query.validate()?;
+
+    let destination = query.into_inner().into_required_destination()?;
+
     let guest_token = extract_guest_token(&request)?;
 
     Ok(HttpResponse::Ok().json(SuccessResponse::ok(
         state
             .guest_cart_service
-            .get_cart_by_hash(&guest_token)
+            .get_shipping_options(&guest_token, &destination)
             .await?,
     )))
 }
 
+#[utoipa::path(
+    post,
+    path = "/cart/guest/add",
+    tag = "cart",
+    request_body = AddItemDto,
+    responses(
+        (status = 200, description = "Item added"),
+        (status = 401, description = "Missing or expired guest token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("guest_token" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn add_item(
     request: HttpRequest,
     state: web::Data<AppState>,
@@ -39,9 +112,26 @@ pub async fn add_item(
     let command = AddItemCommand::new(body.into_inner(), cart_id);
 
     state.cart_items_service.add_item(command).await?;
+    state.metrics.cart_items_added_total.inc();
     Ok(HttpResponse::Ok().json(SuccessResponse::ok(())))
 }
 
+#[utoipa::path(
+    put,
+    path = "/cart/guest/update",
+    tag = "cart",
+    request_body = UpdateItemDto,
+    responses(
+        (status = 200, description = "Item updated"),
+        (status = 401, description = "Missing or expired guest token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("guest_token" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn update_item(
     request: HttpRequest,
     state: web::Data<AppState>,
@@ -62,6 +152,22 @@ pub async fn update_item(
     Ok(HttpResponse::Ok().json(SuccessResponse::<()>::empty()))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/cart/guest/remove",
+    tag = "cart",
+    request_body = RemoveItemDto,
+    responses(
+        (status = 200, description = "Item removed"),
+        (status = 401, description = "Missing or expired guest token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("guest_token" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn remove_item(
     request: HttpRequest,
     state: web::Data<AppState>,
@@ -82,6 +188,83 @@ pub async fn remove_item(
     Ok(HttpResponse::Ok().json(SuccessResponse::<()>::empty()))
 }
 
+#[utoipa::path(
+    post,
+    path = "/cart/guest/coupon",
+    tag = "cart",
+    request_body = ApplyCouponDto,
+    responses(
+        (status = 200, description = "Coupon applied", body = SuccessResponse<PublicGuestCart>),
+        (status = 401, description = "Missing or expired guest token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid code or the coupon doesn't apply to the cart", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("guest_token" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn apply_coupon(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<ApplyCouponDto>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let guest_token = extract_guest_token(&request)?;
+
+    let cart_id = state
+        .guest_cart_service
+        .get_cart_id_by_hash(&guest_token)
+        .await?;
+
+    state
+        .cart_coupon_service
+        .apply(cart_id, body.code.as_deref().unwrap())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(
+        state
+            .guest_cart_service
+            .get_cart_by_hash(&guest_token, None)
+            .await?,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/cart/guest/coupon",
+    tag = "cart",
+    responses(
+        (status = 200, description = "Coupon removed", body = SuccessResponse<PublicGuestCart>),
+        (status = 401, description = "Missing or expired guest token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "No coupon on the cart", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("guest_token" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn remove_coupon(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, AppError> {
+    let guest_token = extract_guest_token(&request)?;
+
+    let cart_id = state
+        .guest_cart_service
+        .get_cart_id_by_hash(&guest_token)
+        .await?;
+
+    state.cart_coupon_service.remove(cart_id).await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(
+        state
+            .guest_cart_service
+            .get_cart_by_hash(&guest_token, None)
+            .await?,
+    )))
+}
+
 fn extract_guest_token(req: &HttpRequest) -> Result<String, AppError> {
     req.extensions()
         .get::<GuestToken>()
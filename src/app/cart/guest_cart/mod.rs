@@ -1,5 +1,5 @@
-mod dto;
-mod handler;
+pub(crate) mod dto;
+pub(crate) mod handler;
 mod model;
 mod repository;
 pub mod routes;
@@ -1,6 +1,7 @@
 use crate::app::cart::guest_cart::model::{GuestCartIdModel, GuestCartModel};
 use crate::errors::error::AppError;
 use sqlx::PgPool;
+use tracing::instrument;
 
 pub struct GuestCartRepository {
     pool: PgPool,
@@ -11,6 +12,7 @@ impl GuestCartRepository {
         Self { pool }
     }
 
+    #[instrument(skip_all)]
     pub async fn get_cart_by_user_hash(
         &self,
         hash_id: &i64,
@@ -33,6 +35,7 @@ impl GuestCartRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_cart_id(&self, hash_id: &i64) -> Result<Option<GuestCartIdModel>, AppError> {
         sqlx::query_as!(
             GuestCartIdModel,
@@ -49,6 +52,7 @@ impl GuestCartRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn create_hash_cart(&self, hash_id: &i64) -> Result<GuestCartModel, AppError> {
         sqlx::query_as!(
             GuestCartModel,
@@ -64,6 +68,7 @@ impl GuestCartRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn delete_by_hash_id(&self, hash_id: &i64) -> Result<u64, AppError> {
         let result = sqlx::query!("DELETE FROM cart WHERE user_hash_id = $1;", hash_id)
             .execute(&self.pool)
@@ -1,5 +1,7 @@
 use crate::app::cart::guest_cart::handler;
 use crate::middlewares::guest::GuestMiddleware;
+use crate::middlewares::rate_limit::RateLimitMiddleware;
+use crate::rate_limit::policy::{RateLimitKey, RateLimitPolicy};
 use actix_web::web::{ServiceConfig, delete, get, post, put, resource, scope};
 
 pub fn routes(cfg: &mut ServiceConfig) {
@@ -7,8 +9,39 @@ pub fn routes(cfg: &mut ServiceConfig) {
         scope("/guest")
             .wrap(GuestMiddleware)
             .service(resource("/get").route(get().to(handler::get_guest_cart)))
-            .service(resource("/add").route(post().to(handler::add_item)))
-            .service(resource("/update").route(put().to(handler::update_item)))
-            .service(resource("/remove").route(delete().to(handler::remove_item))),
+            .service(resource("/shipping-options").route(get().to(handler::shipping_options)))
+            .service(
+                resource("/add")
+                    .wrap(RateLimitMiddleware::new(
+                        RateLimitPolicy::CartMutation,
+                        RateLimitKey::GuestToken,
+                    ))
+                    .route(post().to(handler::add_item)),
+            )
+            .service(
+                resource("/update")
+                    .wrap(RateLimitMiddleware::new(
+                        RateLimitPolicy::CartMutation,
+                        RateLimitKey::GuestToken,
+                    ))
+                    .route(put().to(handler::update_item)),
+            )
+            .service(
+                resource("/remove")
+                    .wrap(RateLimitMiddleware::new(
+                        RateLimitPolicy::CartMutation,
+                        RateLimitKey::GuestToken,
+                    ))
+                    .route(delete().to(handler::remove_item)),
+            )
+            .service(
+                resource("/coupon")
+                    .wrap(RateLimitMiddleware::new(
+                        RateLimitPolicy::CartMutation,
+                        RateLimitKey::GuestToken,
+                    ))
+                    .route(post().to(handler::apply_coupon))
+                    .route(delete().to(handler::remove_coupon)),
+            ),
     );
 }
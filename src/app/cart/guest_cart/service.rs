@@ -1,28 +1,48 @@
 use crate::app::cart::cart_items::model::CartItemModel;
 use crate::app::cart::cart_items::repository::CartItemsRepository;
+use crate::app::cart::coupons::service::CartCouponService;
+use crate::app::cart::destination::CartDestination;
 use crate::app::cart::guest_cart::dto::PublicGuestCart;
 use crate::app::cart::guest_cart::repository::GuestCartRepository;
+use crate::app::cart::shipping::dto::PublicShippingOption;
+use crate::app::cart::shipping::service::CartShippingService;
+use crate::app::cart::tax::service::CartTaxService;
 use crate::app::users::service::UserService;
+use crate::config::settings::TaxConfig;
 use crate::errors::error::AppError;
+use crate::metrics::registry::Metrics;
 use crate::utils::traits::IsRepository;
 use sqlx::PgPool;
 
 pub struct GuestCartService {
     repository: GuestCartRepository,
     cart_items_repository: CartItemsRepository,
+    coupon_service: CartCouponService,
+    tax_service: CartTaxService,
+    shipping_service: CartShippingService,
     user_service: UserService,
+    metrics: Metrics,
 }
 
 impl GuestCartService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, metrics: Metrics, tax_config: TaxConfig) -> Self {
         Self {
             repository: GuestCartRepository::new(pool.clone()),
             cart_items_repository: CartItemsRepository::new(pool.clone()),
+            coupon_service: CartCouponService::new(pool.clone()),
+            tax_service: CartTaxService::new(pool.clone(), tax_config),
+            shipping_service: CartShippingService::new(pool.clone()),
             user_service: UserService::new(pool.clone()),
+            metrics,
         }
     }
 
-    pub async fn get_cart_by_hash(&self, hash: &str) -> Result<PublicGuestCart, AppError> {
+    /** The cart of a guest, taxed for `destination` when one is given. */
+    pub async fn get_cart_by_hash(
+        &self,
+        hash: &str,
+        destination: Option<&CartDestination>,
+    ) -> Result<PublicGuestCart, AppError> {
         let user_hash = self
             .user_service
             .get_user_hash(hash)
@@ -35,13 +55,40 @@ impl GuestCartService {
             Some(cart) => cart,
             None => {
                 let cart = self.repository.create_hash_cart(&user_hash.id).await?;
+                self.metrics.carts_created_total.inc();
                 return Ok(PublicGuestCart::new_from_model(cart));
             }
         };
 
         let cart_items: Vec<CartItemModel> = self.cart_items_repository.get_items(&cart.id).await?;
 
-        Ok(PublicGuestCart::new_with_items(cart, cart_items))
+        let mut totals = self.coupon_service.totals(cart.id, &cart_items).await?;
+
+        if let Some(destination) = destination {
+            totals = self
+                .tax_service
+                .apply(totals, &cart_items, destination)
+                .await?;
+        }
+
+        Ok(PublicGuestCart::new_with_items(cart, cart_items, totals))
+    }
+
+    /** How the cart can ship to `destination`, cheapest first. */
+    pub async fn get_shipping_options(
+        &self,
+        hash: &str,
+        destination: &CartDestination,
+    ) -> Result<Vec<PublicShippingOption>, AppError> {
+        let cart_id = self.get_cart_id_by_hash(hash).await?;
+
+        let cart_items: Vec<CartItemModel> = self.cart_items_repository.get_items(&cart_id).await?;
+
+        let totals = self.coupon_service.totals(cart_id, &cart_items).await?;
+
+        self.shipping_service
+            .options(&cart_items, &totals, destination)
+            .await
     }
 
     pub async fn get_cart_id_by_hash(&self, hash: &str) -> Result<i64, AppError> {
@@ -57,6 +104,7 @@ impl GuestCartService {
             Some(cart_id) => Ok(cart_id.id),
             None => {
                 let cart = self.repository.create_hash_cart(&user_hash.id).await?;
+                self.metrics.carts_created_total.inc();
                 Ok(cart.id)
             }
         }
@@ -1,5 +1,11 @@
 pub mod cart_items;
+pub mod coupons;
+pub mod destination;
 pub mod guest_cart;
 mod model;
+pub mod promotions;
 pub mod routes;
+pub mod shipping;
+pub mod stock;
+pub mod tax;
 pub mod user_cart;
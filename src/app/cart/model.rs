@@ -1,3 +1,4 @@
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use sqlx::FromRow;
@@ -7,6 +8,7 @@ pub struct CartModel {
     pub id: i64,
     pub user_id: Option<i64>,
     pub user_hash_id: Option<i64>,
-    pub total: f64,
+    pub total: BigDecimal,
+    pub coupon_id: Option<i64>,
     pub created_at: DateTime<Utc>,
 }
@@ -0,0 +1,14 @@
+use crate::admin::promotions::dto::PromotionKind;
+use crate::utils::money::Money;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PublicCartPromotion {
+    pub promotion_id: i64,
+    pub name: String,
+    pub kind: PromotionKind,
+    /// The discounted product, when the promotion only touched one.
+    pub product_id: Option<i64>,
+    pub amount: Money,
+}
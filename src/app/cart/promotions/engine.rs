@@ -0,0 +1,218 @@
+use crate::admin::promotions::dto::PromotionRule;
+use crate::app::cart::cart_items::model::CartItemModel;
+use crate::app::cart::promotions::dto::PublicCartPromotion;
+use crate::app::cart::promotions::model::PromotionModel;
+use crate::utils::money::Money;
+use bigdecimal::{BigDecimal, Zero};
+use std::collections::{BTreeMap, HashMap};
+
+/** The items of one product in the cart, taken together. */
+struct Line {
+    quantity: i32,
+    /** Cheapest unit among the items, free units are priced at it. */
+    unit_price: BigDecimal,
+    value: BigDecimal,
+    /** Value earlier promotions haven't taken yet. */
+    remaining: BigDecimal,
+}
+
+/**
+ * Promotions `items` qualify for, in the order they were applied.
+ * `categories` maps the products in the cart to their categories.
+ *
+ * Promotions go by priority, highest first, ties by id. A stackable promotion
+ * applies next to the others, one that doesn't stack only applies when none
+ * did before it and keeps later ones out. Each promotion works on what earlier
+ * ones left of an item's value, so the discounts never exceed the cart.
+ */
+pub fn evaluate(
+    promotions: &[PromotionModel],
+    items: &[CartItemModel],
+    categories: &HashMap<i64, Vec<i64>>,
+) -> Vec<PublicCartPromotion> {
+    let mut lines = group_items(items);
+
+    let mut ordered: Vec<&PromotionModel> = promotions.iter().collect();
+    ordered.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.id.cmp(&b.id)));
+
+    let mut applied = Vec::new();
+
+    for promotion in ordered {
+        if !promotion.stackable && !applied.is_empty() {
+            continue;
+        }
+
+        let shares: Vec<(i64, BigDecimal)> = discounts(&promotion.rule, &lines, categories)
+            .into_iter()
+            .map(|(product_id, amount)| {
+                let amount = Money::new(amount)
+                    .amount
+                    .min(lines[&product_id].remaining.clone());
+
+                (product_id, amount)
+            })
+            .filter(|(_, amount)| amount > &BigDecimal::zero())
+            .collect();
+
+        if shares.is_empty() {
+            continue;
+        }
+
+        for (product_id, amount) in &shares {
+            let line = lines.get_mut(product_id).unwrap();
+            line.remaining = &line.remaining - amount;
+        }
+
+        applied.push(PublicCartPromotion {
+            promotion_id: promotion.id,
+            name: promotion.name.clone(),
+            kind: promotion.rule.kind(),
+            product_id: match shares.as_slice() {
+                [(product_id, _)] => Some(*product_id),
+                _ => None,
+            },
+            amount: Money::new(shares.into_iter().map(|(_, amount)| amount).sum()),
+        });
+
+        if !promotion.stackable {
+            break;
+        }
+    }
+
+    applied
+}
+
+fn group_items(items: &[CartItemModel]) -> BTreeMap<i64, Line> {
+    let mut lines: BTreeMap<i64, Line> = BTreeMap::new();
+
+    for item in items {
+        let value = &item.price * BigDecimal::from(item.quantity);
+
+        match lines.get_mut(&item.product_id) {
+            Some(line) => {
+                line.quantity += item.quantity;
+                line.unit_price = line.unit_price.clone().min(item.price.clone());
+                line.value = &line.value + &value;
+                line.remaining = &line.remaining + &value;
+            }
+            None => {
+                lines.insert(
+                    item.product_id,
+                    Line {
+                        quantity: item.quantity,
+                        unit_price: item.price.clone(),
+                        value: value.clone(),
+                        remaining: value,
+                    },
+                );
+            }
+        }
+    }
+
+    lines
+}
+
+/** What a rule takes off each product before earlier promotions are considered. */
+fn discounts(
+    rule: &PromotionRule,
+    lines: &BTreeMap<i64, Line>,
+    categories: &HashMap<i64, Vec<i64>>,
+) -> Vec<(i64, BigDecimal)> {
+    match rule {
+        PromotionRule::BuyXGetY {
+            product_id,
+            buy_quantity,
+            get_product_id,
+            get_quantity,
+        } => {
+            let get_product_id = get_product_id.unwrap_or(*product_id);
+
+            let (Some(bought), Some(given)) = (lines.get(product_id), lines.get(&get_product_id))
+            else {
+                return Vec::new();
+            };
+
+            // on the same product the free units are part of each group
+            let free = if get_product_id == *product_id {
+                bought.quantity / (buy_quantity + get_quantity) * get_quantity
+            } else {
+                (bought.quantity / buy_quantity * get_quantity).min(given.quantity)
+            };
+
+            vec![(get_product_id, &given.unit_price * BigDecimal::from(free))]
+        }
+        PromotionRule::TieredVolume { product_id, tiers } => {
+            let Some(line) = lines.get(product_id) else {
+                return Vec::new();
+            };
+
+            tiers
+                .iter()
+                .filter(|tier| tier.min_quantity <= line.quantity)
+                .max_by_key(|tier| tier.min_quantity)
+                .map(|tier| vec![(*product_id, percent_of(&line.value, &tier.percent))])
+                .unwrap_or_default()
+        }
+        PromotionRule::CategoryPercentage {
+            category_id,
+            percent,
+        } => lines
+            .iter()
+            .filter(|(product_id, _)| {
+                categories
+                    .get(product_id)
+                    .is_some_and(|ids| ids.contains(category_id))
+            })
+            .map(|(product_id, line)| (*product_id, percent_of(&line.value, percent)))
+            .collect(),
+        PromotionRule::Bundle { product_ids, price } => {
+            let Some(members) = product_ids
+                .iter()
+                .map(|product_id| lines.get(product_id).map(|line| (*product_id, line)))
+                .collect::<Option<Vec<_>>>()
+            else {
+                return Vec::new();
+            };
+
+            let bundles = members
+                .iter()
+                .map(|(_, line)| line.quantity)
+                .min()
+                .unwrap_or(0);
+            let regular: BigDecimal = members
+                .iter()
+                .map(|(_, line)| line.unit_price.clone())
+                .sum();
+
+            if bundles == 0 || &regular <= price {
+                return Vec::new();
+            }
+
+            // the saving is spread by unit price, the last product takes what
+            // rounding left so the cents add up
+            let saving = Money::new((&regular - price) * BigDecimal::from(bundles)).amount;
+            let mut left = saving.clone();
+            let last = members.len() - 1;
+
+            members
+                .iter()
+                .enumerate()
+                .map(|(index, (product_id, line))| {
+                    let share = if index == last {
+                        left.clone()
+                    } else {
+                        Money::new(&saving * &line.unit_price / &regular).amount
+                    };
+
+                    left = &left - &share;
+
+                    (*product_id, share)
+                })
+                .collect()
+        }
+    }
+}
+
+fn percent_of(amount: &BigDecimal, percent: &BigDecimal) -> BigDecimal {
+    amount * percent / BigDecimal::from(100)
+}
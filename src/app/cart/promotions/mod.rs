@@ -0,0 +1,5 @@
+pub mod dto;
+pub mod engine;
+pub mod model;
+mod repository;
+pub mod service;
@@ -0,0 +1,13 @@
+use crate::admin::promotions::dto::PromotionRule;
+use sqlx::FromRow;
+use sqlx::types::Json;
+
+/** A promotion that is active and running, as the cart sees it. */
+#[derive(FromRow)]
+pub struct PromotionModel {
+    pub id: i64,
+    pub name: String,
+    pub rule: Json<PromotionRule>,
+    pub priority: i32,
+    pub stackable: bool,
+}
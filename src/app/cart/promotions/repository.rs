@@ -0,0 +1,73 @@
+use crate::app::cart::promotions::model::PromotionModel;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tracing::instrument;
+
+pub struct CartPromotionRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for CartPromotionRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl CartPromotionRepository {
+    /** Active promotions whose window is open right now. */
+    #[instrument(skip_all)]
+    pub async fn get_running(&self) -> Result<Vec<PromotionModel>, AppError> {
+        sqlx::query_as(
+            r#"
+        SELECT id, name, rule, priority, stackable
+        FROM promotions
+        WHERE is_active
+            AND (starts_at IS NULL OR starts_at <= now())
+            AND (ends_at IS NULL OR ends_at > now())
+        ORDER BY priority DESC, id;
+        "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Categories of each of `product_ids`, trashed categories left out. */
+    #[instrument(skip_all)]
+    pub async fn get_product_categories(
+        &self,
+        product_ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<i64>>, AppError> {
+        let rows = sqlx::query!(
+            r#"
+        SELECT product_has_categories.product_id, product_has_categories.category_id
+        FROM product_has_categories
+        INNER JOIN categories ON categories.id = product_has_categories.category_id
+        WHERE product_has_categories.product_id = ANY($1) AND categories.deleted_at IS NULL;
+        "#,
+            product_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)?;
+
+        let mut categories: HashMap<i64, Vec<i64>> = HashMap::new();
+
+        for row in rows {
+            categories
+                .entry(row.product_id)
+                .or_default()
+                .push(row.category_id);
+        }
+
+        Ok(categories)
+    }
+}
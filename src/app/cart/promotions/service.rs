@@ -0,0 +1,40 @@
+use crate::app::cart::cart_items::model::CartItemModel;
+use crate::app::cart::promotions::dto::PublicCartPromotion;
+use crate::app::cart::promotions::engine::evaluate;
+use crate::app::cart::promotions::repository::CartPromotionRepository;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct CartPromotionService {
+    repository: CartPromotionRepository,
+}
+
+impl CartPromotionService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: CartPromotionRepository::new(pool),
+        }
+    }
+
+    /** Automatic promotions `items` qualify for right now. */
+    pub async fn adjustments(
+        &self,
+        items: &[CartItemModel],
+    ) -> Result<Vec<PublicCartPromotion>, AppError> {
+        if items.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let promotions = self.repository.get_running().await?;
+
+        if promotions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let product_ids: Vec<i64> = items.iter().map(|item| item.product_id).collect();
+        let categories = self.repository.get_product_categories(&product_ids).await?;
+
+        Ok(evaluate(&promotions, items, &categories))
+    }
+}
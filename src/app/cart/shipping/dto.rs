@@ -0,0 +1,26 @@
+use crate::admin::shipping_methods::dto::ShippingKind;
+use crate::utils::money::Money;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/** One cart line as shipping weighs it: units and the size of each. */
+#[derive(Debug, Clone, Default)]
+pub struct ShippableLine {
+    pub quantity: i32,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PublicShippingOption {
+    pub method_id: i64,
+    pub name: String,
+    pub kind: ShippingKind,
+    /// Name of the zone the destination fell in.
+    pub zone: String,
+    /// Weight the cost was worked out on, in grams.
+    pub billable_weight_grams: i64,
+    pub cost: Money,
+}
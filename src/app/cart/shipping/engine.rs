@@ -0,0 +1,130 @@
+use crate::admin::shipping_methods::dto::ShippingRule;
+use crate::app::cart::destination::CartDestination;
+use crate::app::cart::shipping::dto::{PublicShippingOption, ShippableLine};
+use crate::app::cart::shipping::model::{ShippingMethodModel, ShippingZoneModel};
+use crate::app::cart::tax::engine::matches_postcode;
+use crate::utils::money::Money;
+use bigdecimal::{BigDecimal, Zero};
+use std::cmp::Reverse;
+
+/**
+ * Cubic millimetres per gram of volumetric weight, the usual 5000 cm³ per kg
+ * of couriers.
+ */
+pub const VOLUMETRIC_DIVISOR: i64 = 5000;
+
+/**
+ * Weight `lines` are charged on, in grams. Each unit counts with its actual
+ * weight or, when all its dimensions are known, its volumetric weight if
+ * that's more. Products without either weigh nothing.
+ */
+pub fn billable_weight(lines: &[ShippableLine]) -> i64 {
+    lines
+        .iter()
+        .map(|line| {
+            let actual = i64::from(line.weight_grams.unwrap_or(0));
+
+            let volumetric = match (line.length_mm, line.width_mm, line.height_mm) {
+                (Some(length), Some(width), Some(height)) => {
+                    i64::from(length) * i64::from(width) * i64::from(height) / VOLUMETRIC_DIVISOR
+                }
+                _ => 0,
+            };
+
+            actual.max(volumetric) * i64::from(line.quantity)
+        })
+        .sum()
+}
+
+fn covers(zone: &ShippingZoneModel, destination: &CartDestination) -> bool {
+    if !zone.countries.contains(&destination.country) {
+        return false;
+    }
+
+    if zone.postcodes.is_empty() {
+        return true;
+    }
+
+    match &destination.postcode {
+        Some(postcode) => zone
+            .postcodes
+            .iter()
+            .any(|pattern| matches_postcode(pattern, postcode)),
+        None => false,
+    }
+}
+
+/**
+ * The zone `destination` ships from. A zone limited to postcodes wins over a
+ * country wide one; zones left tied go by priority, highest first, then by id.
+ */
+pub fn find_zone<'a>(
+    zones: &'a [ShippingZoneModel],
+    destination: &CartDestination,
+) -> Option<&'a ShippingZoneModel> {
+    zones
+        .iter()
+        .filter(|zone| covers(zone, destination))
+        .max_by_key(|zone| (!zone.postcodes.is_empty(), zone.priority, Reverse(zone.id)))
+}
+
+/**
+ * What `rule` charges for a cart of `weight` grams worth `value`, none when
+ * the method can't ship it.
+ */
+pub fn quote(rule: &ShippingRule, weight: i64, value: &BigDecimal) -> Option<BigDecimal> {
+    match rule {
+        ShippingRule::FlatRate { price } => Some(price.clone()),
+        ShippingRule::WeightTiers { tiers } => tiers
+            .iter()
+            .filter(|tier| i64::from(tier.max_weight_grams) >= weight)
+            .min_by_key(|tier| tier.max_weight_grams)
+            .map(|tier| tier.price.clone()),
+        ShippingRule::FreeOver { threshold, price } => {
+            if value >= threshold {
+                Some(BigDecimal::zero())
+            } else {
+                Some(price.clone())
+            }
+        }
+    }
+}
+
+/**
+ * Methods of `zone` that can ship `lines` worth `value`, cheapest first.
+ * `value` is what the cart costs after discounts, before tax.
+ */
+pub fn options(
+    zone: &ShippingZoneModel,
+    methods: &[ShippingMethodModel],
+    lines: &[ShippableLine],
+    value: &BigDecimal,
+) -> Vec<PublicShippingOption> {
+    let weight = billable_weight(lines);
+
+    let mut options: Vec<PublicShippingOption> = methods
+        .iter()
+        .filter(|method| method.zone_id == zone.id)
+        .filter_map(|method| {
+            let cost = quote(&method.rule, weight, value)?;
+
+            Some(PublicShippingOption {
+                method_id: method.id,
+                name: method.name.clone(),
+                kind: method.rule.kind(),
+                zone: zone.name.clone(),
+                billable_weight_grams: weight,
+                cost: Money::new(cost),
+            })
+        })
+        .collect();
+
+    options.sort_by(|a, b| {
+        a.cost
+            .amount
+            .cmp(&b.cost.amount)
+            .then(a.method_id.cmp(&b.method_id))
+    });
+
+    options
+}
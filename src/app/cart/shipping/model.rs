@@ -0,0 +1,32 @@
+use crate::admin::shipping_methods::dto::ShippingRule;
+use sqlx::FromRow;
+use sqlx::types::Json;
+
+/** An active shipping zone, as the cart sees it. */
+#[derive(FromRow, Clone)]
+pub struct ShippingZoneModel {
+    pub id: i64,
+    pub name: String,
+    pub countries: Vec<String>,
+    pub postcodes: Vec<String>,
+    pub priority: i32,
+}
+
+/** An active shipping method of a zone. */
+#[derive(FromRow, Clone)]
+pub struct ShippingMethodModel {
+    pub id: i64,
+    pub zone_id: i64,
+    pub name: String,
+    pub rule: Json<ShippingRule>,
+}
+
+/** What shipping needs to know about a product. */
+#[derive(FromRow, Clone)]
+pub struct ProductParcelModel {
+    pub id: i64,
+    pub weight_grams: Option<i32>,
+    pub length_mm: Option<i32>,
+    pub width_mm: Option<i32>,
+    pub height_mm: Option<i32>,
+}
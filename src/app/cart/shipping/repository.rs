@@ -0,0 +1,79 @@
+use crate::app::cart::shipping::model::{
+    ProductParcelModel, ShippingMethodModel, ShippingZoneModel,
+};
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tracing::instrument;
+
+pub struct CartShippingRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for CartShippingRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl CartShippingRepository {
+    /** Active zones covering `country`. */
+    #[instrument(skip_all)]
+    pub async fn get_zones(&self, country: &str) -> Result<Vec<ShippingZoneModel>, AppError> {
+        sqlx::query_as(
+            r#"
+        SELECT id, name, countries, postcodes, priority
+        FROM shipping_zones
+        WHERE is_active AND $1 = ANY(countries);
+        "#,
+        )
+        .bind(country)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Active methods of `zone_id`. */
+    #[instrument(skip_all)]
+    pub async fn get_methods(&self, zone_id: i64) -> Result<Vec<ShippingMethodModel>, AppError> {
+        sqlx::query_as(
+            r#"
+        SELECT id, zone_id, name, rule
+        FROM shipping_methods
+        WHERE is_active AND zone_id = $1;
+        "#,
+        )
+        .bind(zone_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Weight and dimensions of each of `product_ids`. */
+    #[instrument(skip_all)]
+    pub async fn get_product_parcels(
+        &self,
+        product_ids: &[i64],
+    ) -> Result<HashMap<i64, ProductParcelModel>, AppError> {
+        let rows: Vec<ProductParcelModel> = sqlx::query_as(
+            r#"
+        SELECT id, weight_grams, length_mm, width_mm, height_mm
+        FROM products
+        WHERE id = ANY($1);
+        "#,
+        )
+        .bind(product_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(rows.into_iter().map(|row| (row.id, row)).collect())
+    }
+}
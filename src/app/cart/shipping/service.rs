@@ -0,0 +1,66 @@
+use crate::app::cart::cart_items::model::CartItemModel;
+use crate::app::cart::coupons::dto::CartTotals;
+use crate::app::cart::destination::CartDestination;
+use crate::app::cart::shipping::dto::{PublicShippingOption, ShippableLine};
+use crate::app::cart::shipping::engine::{find_zone, options};
+use crate::app::cart::shipping::repository::CartShippingRepository;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct CartShippingService {
+    repository: CartShippingRepository,
+}
+
+impl CartShippingService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: CartShippingRepository::new(pool),
+        }
+    }
+
+    /**
+     * How the cart's `items` can ship to `destination`, cheapest first. Empty
+     * when the cart is empty or no zone covers the destination.
+     */
+    pub async fn options(
+        &self,
+        items: &[CartItemModel],
+        totals: &CartTotals,
+        destination: &CartDestination,
+    ) -> Result<Vec<PublicShippingOption>, AppError> {
+        if items.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let zones = self.repository.get_zones(&destination.country).await?;
+
+        let Some(zone) = find_zone(&zones, destination) else {
+            return Ok(Vec::new());
+        };
+
+        let methods = self.repository.get_methods(zone.id).await?;
+
+        let product_ids: Vec<i64> = items.iter().map(|item| item.product_id).collect();
+        let parcels = self.repository.get_product_parcels(&product_ids).await?;
+
+        let lines: Vec<ShippableLine> = items
+            .iter()
+            .map(|item| match parcels.get(&item.product_id) {
+                Some(parcel) => ShippableLine {
+                    quantity: item.quantity,
+                    weight_grams: parcel.weight_grams,
+                    length_mm: parcel.length_mm,
+                    width_mm: parcel.width_mm,
+                    height_mm: parcel.height_mm,
+                },
+                None => ShippableLine {
+                    quantity: item.quantity,
+                    ..Default::default()
+                },
+            })
+            .collect();
+
+        Ok(options(zone, &methods, &lines, &totals.total.amount))
+    }
+}
@@ -0,0 +1,82 @@
+use crate::app::cart::stock::model::SellableStockModel;
+use crate::config::settings::AllocationStrategy;
+use std::cmp::Reverse;
+
+/** Units of an order taken from one warehouse. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocation {
+    pub warehouse_id: i64,
+    pub quantity: i32,
+}
+
+/** Warehouses holding stock, in the order `strategy` takes from them. */
+fn ordered(
+    levels: &[SellableStockModel],
+    strategy: AllocationStrategy,
+) -> Vec<&SellableStockModel> {
+    let mut ordered: Vec<&SellableStockModel> =
+        levels.iter().filter(|level| level.quantity > 0).collect();
+
+    match strategy {
+        AllocationStrategy::Priority | AllocationStrategy::Single => {
+            ordered.sort_by_key(|level| (level.priority, level.warehouse_id))
+        }
+        AllocationStrategy::MostStock => ordered
+            .sort_by_key(|level| (Reverse(level.quantity), level.priority, level.warehouse_id)),
+    }
+
+    ordered
+}
+
+/** Most units of a product one order can take under `strategy`. */
+pub fn sellable(levels: &[SellableStockModel], strategy: AllocationStrategy) -> i32 {
+    let quantities = levels.iter().map(|level| level.quantity.max(0));
+
+    match strategy {
+        AllocationStrategy::Single => quantities.max().unwrap_or(0),
+        _ => quantities.fold(0, i32::saturating_add),
+    }
+}
+
+/**
+ * Where `quantity` units ship from under `strategy`, `None` when the
+ * warehouses can't fill it.
+ */
+pub fn allocate(
+    levels: &[SellableStockModel],
+    quantity: i32,
+    strategy: AllocationStrategy,
+) -> Option<Vec<Allocation>> {
+    let ordered = ordered(levels, strategy);
+
+    if strategy == AllocationStrategy::Single {
+        return ordered
+            .into_iter()
+            .find(|level| level.quantity >= quantity)
+            .map(|level| {
+                vec![Allocation {
+                    warehouse_id: level.warehouse_id,
+                    quantity,
+                }]
+            });
+    }
+
+    let mut remaining = quantity;
+    let mut allocations = Vec::new();
+
+    for level in ordered {
+        if remaining <= 0 {
+            break;
+        }
+
+        let taken = level.quantity.min(remaining);
+        remaining -= taken;
+
+        allocations.push(Allocation {
+            warehouse_id: level.warehouse_id,
+            quantity: taken,
+        });
+    }
+
+    (remaining <= 0).then_some(allocations)
+}
@@ -0,0 +1,4 @@
+pub mod engine;
+pub mod model;
+mod repository;
+pub mod service;
@@ -0,0 +1,9 @@
+use sqlx::FromRow;
+
+/** Stock of a product in one active warehouse, as the cart sees it. */
+#[derive(FromRow, Debug, Clone, PartialEq, Eq)]
+pub struct SellableStockModel {
+    pub warehouse_id: i64,
+    pub priority: i32,
+    pub quantity: i32,
+}
@@ -0,0 +1,50 @@
+use crate::app::cart::stock::model::SellableStockModel;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use tracing::instrument;
+
+pub struct CartStockRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for CartStockRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl CartStockRepository {
+    /** Stock of a product in each active warehouse holding some. */
+    #[instrument(skip_all)]
+    pub async fn get_sellable_stock(
+        &self,
+        product_id: i64,
+    ) -> Result<Vec<SellableStockModel>, AppError> {
+        sqlx::query_as! {
+            SellableStockModel,
+            r#"
+        SELECT
+            warehouse_stock.warehouse_id,
+            warehouses.priority,
+            warehouse_stock.quantity
+        FROM warehouse_stock
+        JOIN warehouses ON warehouses.id = warehouse_stock.warehouse_id
+        WHERE warehouse_stock.product_id = $1
+            AND warehouses.is_active
+            AND warehouse_stock.quantity > 0
+        ORDER BY warehouses.priority, warehouses.id;
+        "#,
+            product_id
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+}
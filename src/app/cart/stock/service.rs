@@ -0,0 +1,39 @@
+use crate::app::cart::stock::engine::{Allocation, allocate, sellable};
+use crate::app::cart::stock::repository::CartStockRepository;
+use crate::config::settings::{AllocationStrategy, InventoryConfig};
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct CartStockService {
+    repository: CartStockRepository,
+    strategy: AllocationStrategy,
+}
+
+impl CartStockService {
+    pub fn new(pool: PgPool, config: InventoryConfig) -> Self {
+        Self {
+            repository: CartStockRepository::new(pool),
+            strategy: config.allocation,
+        }
+    }
+
+    /**
+     * Where `quantity` units of a product would ship from. Conflict when the
+     * sellable warehouses can't fill it.
+     */
+    pub async fn allocate(
+        &self,
+        product_id: i64,
+        quantity: i32,
+    ) -> Result<Vec<Allocation>, AppError> {
+        let levels = self.repository.get_sellable_stock(product_id).await?;
+
+        allocate(&levels, quantity, self.strategy).ok_or_else(|| {
+            AppError::Conflict(format!(
+                "not enough stock available, {} left",
+                sellable(&levels, self.strategy)
+            ))
+        })
+    }
+}
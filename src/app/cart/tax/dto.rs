@@ -0,0 +1,31 @@
+use crate::utils::money::Money;
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/** What one cart line is taxed on: its tax class and discounted value. */
+#[derive(Debug, Clone)]
+pub struct TaxableLine {
+    pub tax_class: String,
+    pub amount: BigDecimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PublicCartTaxLine {
+    pub name: String,
+    pub tax_class: String,
+    /// Percent, e.g. `19`.
+    #[schema(value_type = String, example = "19")]
+    pub rate: BigDecimal,
+    /// Value of the items taxed at this rate, after discounts.
+    pub taxable: Money,
+    pub amount: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PublicCartTax {
+    /// Whether prices already contain the tax or it comes on top of them.
+    pub prices_include_tax: bool,
+    pub lines: Vec<PublicCartTaxLine>,
+    pub total: Money,
+}
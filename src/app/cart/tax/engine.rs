@@ -0,0 +1,163 @@
+use crate::app::cart::destination::CartDestination;
+use crate::app::cart::tax::dto::{PublicCartTax, PublicCartTaxLine, TaxableLine};
+use crate::app::cart::tax::model::TaxRateModel;
+use crate::utils::money::Money;
+use bigdecimal::{BigDecimal, Zero};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+/**
+ * Whether `postcode` matches `pattern`, where `*` stands for any run of
+ * characters. Both are expected upper case without spaces.
+ */
+pub fn matches_postcode(pattern: &str, postcode: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let postcode: Vec<char> = postcode.chars().collect();
+
+    let (mut p, mut s) = (0, 0);
+    // last `*` seen and where in the postcode it started matching
+    let mut star: Option<(usize, usize)> = None;
+
+    while s < postcode.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, s));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == postcode[s] {
+            p += 1;
+            s += 1;
+        } else if let Some((star_p, star_s)) = star {
+            // let the `*` take one more character
+            p = star_p + 1;
+            s = star_s + 1;
+            star = Some((star_p, star_s + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+fn applies(rate: &TaxRateModel, tax_class: &str, destination: &CartDestination) -> bool {
+    if rate.tax_class != tax_class || rate.country != destination.country {
+        return false;
+    }
+
+    if let Some(region) = &rate.region {
+        match &destination.region {
+            Some(destination) if destination.to_lowercase() == region.to_lowercase() => {}
+            _ => return false,
+        }
+    }
+
+    if let Some(pattern) = &rate.postcode {
+        match &destination.postcode {
+            Some(postcode) if matches_postcode(pattern, postcode) => {}
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+/**
+ * The rate products of `tax_class` shipped to `destination` are taxed at.
+ *
+ * The most specific matching rate wins: one with a postcode over one without,
+ * among postcode rates the longer pattern, then one with a region over a
+ * country wide one. Rates left tied go by priority, highest first, then by id.
+ */
+pub fn find_rate<'a>(
+    rates: &'a [TaxRateModel],
+    tax_class: &str,
+    destination: &CartDestination,
+) -> Option<&'a TaxRateModel> {
+    rates
+        .iter()
+        .filter(|rate| applies(rate, tax_class, destination))
+        .max_by_key(|rate| {
+            let literals = rate
+                .postcode
+                .as_deref()
+                .map_or(0, |pattern| pattern.chars().filter(|c| *c != '*').count());
+
+            (
+                rate.postcode.is_some(),
+                literals,
+                rate.region.is_some(),
+                rate.priority,
+                Reverse(rate.id),
+            )
+        })
+}
+
+/**
+ * Tax on `lines` shipped to `destination`.
+ *
+ * `discount` is what promotions and coupons took off the cart, it's spread
+ * over the lines by their value so tax is only paid on what the customer pays.
+ * Lines are grouped by the rate they fall under; a line without a rate is not
+ * taxed. With `prices_include_tax` the tax is the share already contained in
+ * the prices, otherwise it comes on top of them.
+ */
+pub fn calculate(
+    lines: &[TaxableLine],
+    discount: &BigDecimal,
+    rates: &[TaxRateModel],
+    destination: &CartDestination,
+    prices_include_tax: bool,
+) -> PublicCartTax {
+    let subtotal: BigDecimal = lines.iter().map(|line| line.amount.clone()).sum();
+
+    let discount = if discount > &subtotal {
+        subtotal.clone()
+    } else {
+        discount.clone()
+    };
+
+    let mut groups: BTreeMap<i64, (&TaxRateModel, BigDecimal)> = BTreeMap::new();
+
+    for line in lines {
+        let Some(rate) = find_rate(rates, &line.tax_class, destination) else {
+            continue;
+        };
+
+        let share = if subtotal.is_zero() {
+            BigDecimal::zero()
+        } else {
+            &discount * &line.amount / &subtotal
+        };
+
+        groups
+            .entry(rate.id)
+            .or_insert_with(|| (rate, BigDecimal::zero()))
+            .1 += &line.amount - share;
+    }
+
+    let hundred = BigDecimal::from(100);
+
+    let lines: Vec<PublicCartTaxLine> = groups
+        .into_values()
+        .map(|(rate, taxable)| {
+            let amount = if prices_include_tax {
+                &taxable * &rate.rate / (&hundred + &rate.rate)
+            } else {
+                &taxable * &rate.rate / &hundred
+            };
+
+            PublicCartTaxLine {
+                name: rate.name.clone(),
+                tax_class: rate.tax_class.clone(),
+                rate: rate.rate.normalized(),
+                taxable: Money::new(taxable),
+                amount: Money::new(amount),
+            }
+        })
+        .collect();
+
+    PublicCartTax {
+        prices_include_tax,
+        total: lines.iter().map(|line| line.amount.clone()).sum(),
+        lines,
+    }
+}
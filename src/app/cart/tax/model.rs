@@ -0,0 +1,15 @@
+use bigdecimal::BigDecimal;
+use sqlx::FromRow;
+
+/** An active tax rate, as the cart sees it. */
+#[derive(FromRow, Clone)]
+pub struct TaxRateModel {
+    pub id: i64,
+    pub name: String,
+    pub tax_class: String,
+    pub country: String,
+    pub region: Option<String>,
+    pub postcode: Option<String>,
+    pub rate: BigDecimal,
+    pub priority: i32,
+}
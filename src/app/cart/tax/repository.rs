@@ -0,0 +1,60 @@
+use crate::app::cart::tax::model::TaxRateModel;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tracing::instrument;
+
+pub struct CartTaxRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for CartTaxRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl CartTaxRepository {
+    /** Active rates of `country`, every tax class. */
+    #[instrument(skip_all)]
+    pub async fn get_rates(&self, country: &str) -> Result<Vec<TaxRateModel>, AppError> {
+        sqlx::query_as(
+            r#"
+        SELECT id, name, tax_class, country, region, postcode, rate, priority
+        FROM tax_rates
+        WHERE is_active AND country = $1;
+        "#,
+        )
+        .bind(country)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Tax class of each of `product_ids`. */
+    #[instrument(skip_all)]
+    pub async fn get_product_tax_classes(
+        &self,
+        product_ids: &[i64],
+    ) -> Result<HashMap<i64, String>, AppError> {
+        let rows = sqlx::query!(
+            "SELECT id, tax_class FROM products WHERE id = ANY($1);",
+            product_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.id, row.tax_class))
+            .collect())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::admin::tax_rates::dto::DEFAULT_TAX_CLASS;
+use crate::app::cart::cart_items::model::CartItemModel;
+use crate::app::cart::coupons::discount::line_total;
+use crate::app::cart::coupons::dto::CartTotals;
+use crate::app::cart::destination::CartDestination;
+use crate::app::cart::tax::dto::TaxableLine;
+use crate::app::cart::tax::engine::calculate;
+use crate::app::cart::tax::repository::CartTaxRepository;
+use crate::config::settings::TaxConfig;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct CartTaxService {
+    repository: CartTaxRepository,
+    config: TaxConfig,
+}
+
+impl CartTaxService {
+    pub fn new(pool: PgPool, config: TaxConfig) -> Self {
+        Self {
+            repository: CartTaxRepository::new(pool),
+            config,
+        }
+    }
+
+    /**
+     * Adds the tax breakdown for `destination` to `totals`. When prices don't
+     * include tax it's added to the total as well.
+     */
+    pub async fn apply(
+        &self,
+        mut totals: CartTotals,
+        items: &[CartItemModel],
+        destination: &CartDestination,
+    ) -> Result<CartTotals, AppError> {
+        let rates = self.repository.get_rates(&destination.country).await?;
+
+        let product_ids: Vec<i64> = items.iter().map(|item| item.product_id).collect();
+        let classes = self
+            .repository
+            .get_product_tax_classes(&product_ids)
+            .await?;
+
+        let lines: Vec<TaxableLine> = items
+            .iter()
+            .map(|item| TaxableLine {
+                tax_class: classes
+                    .get(&item.product_id)
+                    .cloned()
+                    .unwrap_or_else(|| DEFAULT_TAX_CLASS.to_string()),
+                amount: line_total(item).amount,
+            })
+            .collect();
+
+        let discount = &totals.subtotal.amount - &totals.total.amount;
+
+        let tax = calculate(
+            &lines,
+            &discount,
+            &rates,
+            destination,
+            self.config.prices_include_tax,
+        );
+
+        if !tax.prices_include_tax {
+            totals.total = totals.total + tax.total.clone();
+        }
+
+        totals.tax = Some(tax);
+
+        Ok(totals)
+    }
+}
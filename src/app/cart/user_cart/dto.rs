@@ -1,34 +1,52 @@
 use crate::app::cart::cart_items::dto::PublicCartItems;
 use crate::app::cart::cart_items::model::CartItemModel;
+use crate::app::cart::coupons::dto::{CartTotals, PublicCartDiscount};
+use crate::app::cart::promotions::dto::PublicCartPromotion;
+use crate::app::cart::tax::dto::PublicCartTax;
 use crate::app::cart::user_cart::model::UserCartModel;
+use crate::utils::money::Money;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PublicUserCart {
     pub id: i64,
     pub user_id: i64,
-    pub total: f64,
+    pub subtotal: Money,
+    /// Automatic promotions the cart qualifies for.
+    pub promotions: Vec<PublicCartPromotion>,
+    /// Coupon on the cart, it may currently give no discount.
+    pub coupon_code: Option<String>,
+    pub discounts: Vec<PublicCartDiscount>,
+    /// Tax breakdown, only when a destination was given.
+    pub tax: Option<PublicCartTax>,
+    /// What is left to pay, the subtotal minus promotions and discounts plus
+    /// tax when prices don't include it.
+    pub total: Money,
     pub created_at: DateTime<Utc>,
     pub items: Vec<PublicCartItems>,
 }
 
 impl PublicUserCart {
     pub fn new_from_model(cart: UserCartModel) -> Self {
-        PublicUserCart {
-            id: cart.id,
-            user_id: cart.user_id,
-            total: cart.total,
-            created_at: cart.created_at,
-            items: Vec::new(),
-        }
+        Self::new_with_items(cart, Vec::new(), CartTotals::empty())
     }
 
-    pub fn new_with_items(cart: UserCartModel, items: Vec<CartItemModel>) -> Self {
+    pub fn new_with_items(
+        cart: UserCartModel,
+        items: Vec<CartItemModel>,
+        totals: CartTotals,
+    ) -> Self {
         PublicUserCart {
             id: cart.id,
             user_id: cart.user_id,
-            total: cart.total,
+            subtotal: totals.subtotal,
+            promotions: totals.promotions,
+            coupon_code: totals.coupon_code,
+            discounts: totals.discounts,
+            tax: totals.tax,
+            total: totals.total,
             created_at: cart.created_at,
             items: items.into_iter().map(PublicCartItems::from).collect(),
         }
@@ -1,27 +1,100 @@
 use crate::app::cart::cart_items::dto::{
     AddItemCommand, AddItemDto, RemoveItemCommand, RemoveItemDto, UpdateItemCommand, UpdateItemDto,
 };
+use crate::app::cart::coupons::dto::ApplyCouponDto;
+use crate::app::cart::destination::CartDestinationDto;
+use crate::app::cart::shipping::dto::PublicShippingOption;
+use crate::app::cart::user_cart::dto::PublicUserCart;
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
 use crate::utils::extractors::extract_auth_user_id;
 use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
 use validator::Validate;
 
+#[utoipa::path(
+    get,
+    path = "/cart/user/get",
+    tag = "cart",
+    params(CartDestinationDto),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<PublicUserCart>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid destination", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn get_user_cart(
     request: HttpRequest,
     state: web::Data<AppState>,
+    query: web::Query<CartDestinationDto>,
 ) -> Result<impl Responder, AppError> {
+    query.validate()?;
+
+    let destination = query.into_inner().into_destination()?;
+
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(
+        state
+            .user_cart_service
+            .get_cart_by_user(&auth_user_id, destination.as_ref())
+            .await?,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/cart/user/shipping-options",
+    tag = "cart",
+    params(CartDestinationDto),
+    responses(
+        (status = 200, description = "Methods that can ship the cart, cheapest first", body = SuccessResponse<Vec<PublicShippingOption>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Missing or invalid destination", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn shipping_options(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    query: web::Query<CartDestinationDto>,
+) -> Result<impl Responder, AppError> {
+    query.validate()?;
+
+    let destination = query.into_inner().into_required_destination()?;
+
     let auth_user_id = extract_auth_user_id(&request)?;
 
     Ok(HttpResponse::Ok().json(SuccessResponse::ok(
         state
             .user_cart_service
-            .get_cart_by_user(&auth_user_id)
+            .get_shipping_options(&auth_user_id, &destination)
             .await?,
     )))
 }
 
+#[utoipa::path(
+    post,
+    path = "/cart/user/add",
+    tag = "cart",
+    request_body = AddItemDto,
+    responses(
+        (status = 200, description = "Item added"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn add_item(
     request: HttpRequest,
     state: web::Data<AppState>,
@@ -44,9 +117,26 @@ pub async fn add_item(
     }
 
     state.cart_items_service.add_item(command).await?;
+    state.metrics.cart_items_added_total.inc();
     Ok(HttpResponse::Ok().json(SuccessResponse::ok(())))
 }
 
+#[utoipa::path(
+    put,
+    path = "/cart/user/update",
+    tag = "cart",
+    request_body = UpdateItemDto,
+    responses(
+        (status = 200, description = "Item updated"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn update_item(
     request: HttpRequest,
     state: web::Data<AppState>,
@@ -72,6 +162,22 @@ pub async fn update_item(
     Ok(HttpResponse::Ok().json(SuccessResponse::<()>::empty()))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/cart/user/remove",
+    tag = "cart",
+    request_body = RemoveItemDto,
+    responses(
+        (status = 200, description = "Item removed"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn remove_item(
     request: HttpRequest,
     state: web::Data<AppState>,
@@ -96,3 +202,80 @@ pub async fn remove_item(
     state.cart_items_service.remove_item(command).await?;
     Ok(HttpResponse::Ok().json(SuccessResponse::<()>::empty()))
 }
+
+#[utoipa::path(
+    post,
+    path = "/cart/user/coupon",
+    tag = "cart",
+    request_body = ApplyCouponDto,
+    responses(
+        (status = 200, description = "Coupon applied", body = SuccessResponse<PublicUserCart>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid code or the coupon doesn't apply to the cart", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn apply_coupon(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<ApplyCouponDto>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let cart_id = state
+        .user_cart_service
+        .get_cart_id_by_user(&auth_user_id)
+        .await?;
+
+    state
+        .cart_coupon_service
+        .apply(cart_id, body.code.as_deref().unwrap())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(
+        state
+            .user_cart_service
+            .get_cart_by_user(&auth_user_id, None)
+            .await?,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/cart/user/coupon",
+    tag = "cart",
+    responses(
+        (status = 200, description = "Coupon removed", body = SuccessResponse<PublicUserCart>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "No coupon on the cart", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn remove_coupon(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let cart_id = state
+        .user_cart_service
+        .get_cart_id_by_user(&auth_user_id)
+        .await?;
+
+    state.cart_coupon_service.remove(cart_id).await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(
+        state
+            .user_cart_service
+            .get_cart_by_user(&auth_user_id, None)
+            .await?,
+    )))
+}
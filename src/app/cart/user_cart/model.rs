@@ -1,3 +1,4 @@
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use sqlx::FromRow;
@@ -6,7 +7,7 @@ use sqlx::FromRow;
 pub struct UserCartModel {
     pub id: i64,
     pub user_id: i64,
-    pub total: f64,
+    pub total: BigDecimal,
     pub created_at: DateTime<Utc>,
 }
 
@@ -1,6 +1,7 @@
 use crate::app::cart::user_cart::model::{UserCartIdModel, UserCartModel};
 use crate::errors::error::AppError;
 use sqlx::PgPool;
+use tracing::instrument;
 
 pub struct UserCartRepository {
     pool: PgPool,
@@ -11,6 +12,7 @@ impl UserCartRepository {
         Self { pool }
     }
 
+    #[instrument(skip_all)]
     pub async fn get_cart_by_user_id(
         &self,
         user_id: &i64,
@@ -33,6 +35,7 @@ impl UserCartRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_cart_id(&self, user_id: &i64) -> Result<Option<UserCartIdModel>, AppError> {
         sqlx::query_as!(
             UserCartIdModel,
@@ -49,6 +52,7 @@ impl UserCartRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn create_user_cart(&self, user_id: &i64) -> Result<UserCartModel, AppError> {
         sqlx::query_as!(
             UserCartModel,
@@ -64,6 +68,7 @@ impl UserCartRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn delete_by_user_id(&self, user_id: &i64) -> Result<u64, AppError> {
         let result = sqlx::query!("DELETE FROM cart WHERE user_id = $1;", user_id)
             .execute(&self.pool)
@@ -1,14 +1,18 @@
 use crate::app::categories::model::CategoryModel;
+use crate::utils::etag::Versioned;
 use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct PublicCategory {
     pub id: i64,
     pub name: String,
     pub slug: String,
     pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl HasId for PublicCategory {
@@ -17,6 +21,12 @@ impl HasId for PublicCategory {
     }
 }
 
+impl Versioned for PublicCategory {
+    fn version(&self) -> (i64, DateTime<Utc>) {
+        (self.id, self.updated_at)
+    }
+}
+
 impl From<CategoryModel> for PublicCategory {
     fn from(category: CategoryModel) -> Self {
         Self {
@@ -24,6 +34,7 @@ impl From<CategoryModel> for PublicCategory {
             name: category.name,
             slug: category.slug,
             is_active: category.is_active,
+            updated_at: category.updated_at,
         }
     }
 }
@@ -1,12 +1,30 @@
 use crate::admin::categories::dto::IndexCategoryDTO;
+use crate::app::categories::dto::PublicCategory;
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
+use crate::utils::etag::{conditional_json, entity_tag};
 use crate::utils::pagination::Paginate;
-use actix_web::{HttpResponse, Responder, web};
+use actix_web::{HttpRequest, Responder, web};
+use tracing::instrument;
 use validator::Validate;
 
+#[utoipa::path(
+    get,
+    path = "/categories/list",
+    tag = "categories",
+    params(IndexCategoryDTO, ("If-None-Match" = Option<String>, Header, description = "Entity tag from a previous response")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<PublicCategory>>,
+            headers(("ETag" = String), ("Cache-Control" = String))),
+        (status = 304, description = "Not modified"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[instrument(skip_all)]
 pub async fn index(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Query<IndexCategoryDTO>,
 ) -> Result<impl Responder, AppError> {
@@ -19,13 +37,35 @@ pub async fn index(
         .get_all_paginated_public(&pagination, &body.search)
         .await?;
 
-    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
-        categories.data,
-        pagination,
-    )))
+    let etag = entity_tag(&categories.data, request.query_string());
+
+    Ok(conditional_json(
+        &request,
+        etag,
+        state.config.cache.catalog_max_age_secs,
+        &SuccessResponse::ok_with_pagination(categories.data, pagination),
+    ))
 }
 
+#[utoipa::path(
+    get,
+    path = "/categories/get/{slug}",
+    tag = "categories",
+    params(
+        ("slug" = String, Path, description = "Resource slug"),
+        ("If-None-Match" = Option<String>, Header, description = "Entity tag from a previous response"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<PublicCategory>,
+            headers(("ETag" = String), ("Cache-Control" = String))),
+        (status = 304, description = "Not modified"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[instrument(skip_all)]
 pub async fn show(
+    request: HttpRequest,
     state: web::Data<AppState>,
     slug: web::Path<String>,
 ) -> Result<impl Responder, AppError> {
@@ -34,5 +74,12 @@ pub async fn show(
         .get_one_public(&slug.into_inner())
         .await?;
 
-    Ok(HttpResponse::Ok().json(SuccessResponse::ok(category)))
+    let etag = entity_tag([&category], "");
+
+    Ok(conditional_json(
+        &request,
+        etag,
+        state.config.cache.catalog_max_age_secs,
+        &SuccessResponse::ok(category),
+    ))
 }
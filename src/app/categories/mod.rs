@@ -1,5 +1,5 @@
 pub mod dto;
-mod handler;
+pub(crate) mod handler;
 mod model;
 mod repository;
 pub mod routes;
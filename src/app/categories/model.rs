@@ -1,4 +1,5 @@
 use crate::utils::traits::HasId;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, sqlx::FromRow, Clone)]
@@ -7,6 +8,7 @@ pub struct CategoryModel {
     pub name: String,
     pub slug: String,
     pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl HasId for CategoryModel {
@@ -2,6 +2,7 @@ use crate::app::categories::model::CategoryModel;
 use crate::errors::error::AppError;
 use crate::utils::pagination::Paginate;
 use sqlx::{PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
 
 pub struct CategoryRepository {
     pool: PgPool,
@@ -12,6 +13,7 @@ impl CategoryRepository {
         Self { pool }
     }
 
+    #[instrument(skip_all)]
     pub async fn index_paginated(
         &self,
         pagination: &Paginate,
@@ -23,9 +25,10 @@ impl CategoryRepository {
                 id,
                 name,
                 slug,
-                is_active
+                is_active,
+                updated_at
             FROM categories
-            WHERE is_active = true
+            WHERE is_active = true AND deleted_at IS NULL
             "#,
         );
 
@@ -49,6 +52,7 @@ impl CategoryRepository {
             .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn show(&self, slug: &str) -> Result<Option<CategoryModel>, AppError> {
         sqlx::query_as! {
             CategoryModel,
@@ -57,9 +61,10 @@ impl CategoryRepository {
             id,
             name,
             slug,
-            is_active
+            is_active,
+            updated_at
         FROM categories
-        WHERE slug = $1 AND is_active = true;
+        WHERE slug = $1 AND is_active = true AND deleted_at IS NULL;
         "#,
             slug,
         }
@@ -1,5 +1,8 @@
+pub mod addresses;
 pub mod cart;
 pub mod categories;
 pub mod products;
 pub mod roles;
+pub mod stock_subscriptions;
 pub mod users;
+pub mod wishlists;
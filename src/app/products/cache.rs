@@ -0,0 +1,120 @@
+use crate::app::products::dto::PublicProduct;
+use crate::app::products::relations::ProductLoadRelations;
+use crate::config::settings::CacheConfig;
+use actix_web::http::header::EntityTag;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ProductCacheKey {
+    slug: String,
+    relations: ProductLoadRelations,
+}
+
+#[derive(Debug, Clone)]
+pub struct CachedProduct {
+    pub product: PublicProduct,
+    pub etag: EntityTag,
+}
+
+struct Entry {
+    value: CachedProduct,
+    stored_at: Instant,
+}
+
+/**
+ * In-process TTL cache for public product pages, keyed by slug and loaded
+ * relations. Cheap to clone, every clone shares the same entries so admin
+ * services can invalidate what the public service stored.
+ */
+#[derive(Clone)]
+pub struct ProductCache {
+    entries: Arc<Mutex<HashMap<ProductCacheKey, Entry>>>,
+    ttl: Duration,
+    capacity: usize,
+}
+
+impl ProductCache {
+    pub fn new(config: &CacheConfig) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            ttl: config.product_ttl(),
+            capacity: config.product_capacity,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero() && self.capacity > 0
+    }
+
+    pub fn get(&self, slug: &str, relations: ProductLoadRelations) -> Option<CachedProduct> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let key = ProductCacheKey {
+            slug: slug.to_string(),
+            relations,
+        };
+
+        let mut entries = self.entries.lock().unwrap();
+
+        match entries.get(&key) {
+            Some(entry) if entry.stored_at.elapsed() < self.ttl => Some(entry.value.clone()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, slug: &str, relations: ProductLoadRelations, value: CachedProduct) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+
+        if entries.len() >= self.capacity {
+            entries.retain(|_, entry| entry.stored_at.elapsed() < self.ttl);
+        }
+
+        // still full, drop the oldest entry
+        if entries.len() >= self.capacity
+            && let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.stored_at)
+                .map(|(key, _)| key.clone())
+        {
+            entries.remove(&oldest);
+        }
+
+        entries.insert(
+            ProductCacheKey {
+                slug: slug.to_string(),
+                relations,
+            },
+            Entry {
+                value,
+                stored_at: Instant::now(),
+            },
+        );
+    }
+
+    /**
+     * Drops every cached page of a product, whatever relations it was loaded with.
+     */
+    pub fn invalidate_product(&self, product_id: i64) {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|_, entry| entry.value.product.id != product_id);
+    }
+
+    /** Drops every cached page, for changes that touch many products at once. */
+    pub fn invalidate_all(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
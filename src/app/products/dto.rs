@@ -6,8 +6,15 @@ use crate::app::products::reviews::dto::PublicProductReview;
 use crate::app::products::traits::IntoPublic;
 use crate::app::products::videos::dto::PublicProductVideo;
 use crate::errors::error::AppError;
-use crate::utils::traits::{HasId, HasQuantity};
+use crate::utils::etag::Versioned;
+use crate::utils::money::{Money, validate_amount};
+use crate::utils::pagination::PaginationMode;
+use crate::utils::traits::HasId;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -42,15 +49,19 @@ impl PublicProductBuilder {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct PublicProduct {
     pub id: i64,
     pub name: String,
     pub slug: String,
-    pub price: f64,
+    /// Regular price, shown as the compare-at price while a sale runs.
+    pub price: Money,
+    /// What the product sells for right now.
+    pub effective_price: Money,
     pub quantity: i32,
     pub configurable: bool,
     pub is_active: bool,
+    pub updated_at: DateTime<Utc>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub images: Option<Vec<PublicProductImage>>,
@@ -68,16 +79,30 @@ impl HasId for PublicProduct {
     }
 }
 
+impl Versioned for PublicProduct {
+    fn version(&self) -> (i64, DateTime<Utc>) {
+        (self.id, self.updated_at)
+    }
+
+    fn scheduled_state(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.effective_price.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
 impl From<ProductModel> for PublicProduct {
     fn from(product: ProductModel) -> Self {
         Self {
             id: product.id,
             name: product.name,
             slug: product.slug,
-            price: product.price,
+            price: Money::new(product.price),
+            effective_price: Money::new(product.effective_price),
             quantity: product.quantity,
             configurable: product.configurable,
             is_active: product.is_active,
+            updated_at: product.updated_at,
             images: None,
             videos: None,
             reviews: None,
@@ -85,26 +110,42 @@ impl From<ProductModel> for PublicProduct {
     }
 }
 
-#[derive(Serialize, Deserialize, Validate, Clone)]
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct IndexProductDTO {
-    #[validate(required, range(min = 1))]
+    /// Required in `page` mode, not allowed in `cursor` mode.
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
     pub page: Option<i64>,
 
     #[validate(required, range(min = 1))]
+    #[param(required = true, minimum = 1)]
     pub limit: Option<i64>,
 
+    /// `page` (default) or `cursor`.
+    pub pagination: Option<PaginationMode>,
+
+    /// `next_cursor` or `prev_cursor` from a previous response.
     #[validate(length(min = 1))]
+    #[param(min_length = 1)]
+    pub cursor: Option<String>,
+
+    #[validate(length(min = 1))]
+    #[param(min_length = 1)]
     pub search: Option<String>,
 
     // filters
     #[validate(range(min = 1))]
+    #[param(minimum = 1)]
     pub category: Option<i64>,
 
-    #[validate(range(min = 0.0))]
-    pub price_min: Option<f64>,
+    #[validate(custom(function = "validate_amount"))]
+    #[param(value_type = Option<String>)]
+    pub price_min: Option<BigDecimal>,
 
-    #[validate(range(min = 0.0))]
-    pub price_max: Option<f64>,
+    #[validate(custom(function = "validate_amount"))]
+    #[param(value_type = Option<String>)]
+    pub price_max: Option<BigDecimal>,
 
     // relations
     pub images: Option<bool>,
@@ -134,7 +175,8 @@ impl From<IndexProductDTO> for ProductLoadRelations {
     }
 }
 
-#[derive(Serialize, Deserialize, Validate, Clone)]
+#[derive(Serialize, Deserialize, Validate, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ShowProductDTO {
     pub images: Option<bool>,
     pub videos: Option<bool>,
@@ -150,23 +192,3 @@ impl From<ShowProductDTO> for ProductLoadRelations {
         }
     }
 }
-
-pub struct UpdateStockDto {
-    pub product_id: i64,
-    pub quantity: i32,
-}
-
-impl UpdateStockDto {
-    pub fn new(product_id: i64, quantity: i32) -> Self {
-        Self {
-            product_id,
-            quantity,
-        }
-    }
-}
-
-impl HasQuantity for UpdateStockDto {
-    fn get_quantity(&self) -> i32 {
-        self.quantity
-    }
-}
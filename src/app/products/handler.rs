@@ -1,47 +1,121 @@
-use crate::app::products::dto::{IndexProductDTO, ShowProductDTO};
+use crate::app::products::dto::{IndexProductDTO, PublicProduct, ShowProductDTO};
 use crate::app::products::filters::ProductFilters;
 use crate::app::products::relations::ProductLoadRelations;
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
-use crate::utils::pagination::Paginate;
-use actix_web::{HttpResponse, Responder, web};
+use crate::utils::etag::{conditional_json, entity_tag};
+use crate::utils::pagination::{CursorMeta, PageRequest};
+use actix_web::{HttpRequest, Responder, web};
+use tracing::instrument;
 use validator::Validate;
 
+#[utoipa::path(
+    get,
+    path = "/products/list",
+    tag = "products",
+    params(IndexProductDTO, ("If-None-Match" = Option<String>, Header, description = "Entity tag from a previous response")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<PublicProduct>>,
+            headers(("ETag" = String), ("Cache-Control" = String))),
+        (status = 304, description = "Not modified"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[instrument(skip_all)]
 pub async fn index(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Query<IndexProductDTO>,
 ) -> Result<impl Responder, AppError> {
     body.validate()?;
 
-    let pagination = Paginate::new(body.limit.unwrap(), body.page.unwrap());
+    let page_request = PageRequest::new(
+        body.pagination,
+        body.limit.unwrap(),
+        body.page,
+        body.cursor.as_deref(),
+        &state.cursor_codec,
+    )?;
 
     let filters = ProductFilters::try_from(body.clone().into_inner())?;
 
     let relations = ProductLoadRelations::from(body.clone().into_inner());
 
-    let products = state
-        .product_service
-        .get_all_paginated_public(&pagination, &filters, &body.search, relations)
-        .await?;
+    let max_age_secs = state.config.cache.catalog_max_age_secs;
+
+    match page_request {
+        PageRequest::Page(pagination) => {
+            let products = state
+                .product_service
+                .get_all_paginated_public(&pagination, &filters, &body.search, relations)
+                .await?;
+
+            let etag = entity_tag(&products.data, request.query_string());
+
+            Ok(conditional_json(
+                &request,
+                etag,
+                max_age_secs,
+                &SuccessResponse::ok_with_pagination(products.data, pagination),
+            ))
+        }
+        PageRequest::Cursor(pagination) => {
+            let products = state
+                .product_service
+                .get_all_cursor_public(&pagination, &filters, &body.search, relations)
+                .await?;
+
+            let etag = entity_tag(&products.data, request.query_string());
+            let meta = CursorMeta::new(&products, &state.cursor_codec);
 
-    Ok(HttpResponse::Ok().json(SuccessResponse::ok_with_pagination(
-        products.data,
-        pagination,
-    )))
+            Ok(conditional_json(
+                &request,
+                etag,
+                max_age_secs,
+                &SuccessResponse::ok_with_cursor(products.data, meta),
+            ))
+        }
+    }
 }
 
+#[utoipa::path(
+    get,
+    path = "/products/get/{slug}",
+    tag = "products",
+    params(
+        ("slug" = String, Path, description = "Resource slug"),
+        ShowProductDTO,
+        ("If-None-Match" = Option<String>, Header, description = "Entity tag from a previous response"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<PublicProduct>,
+            headers(("ETag" = String), ("Cache-Control" = String))),
+        (status = 304, description = "Not modified"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[instrument(skip_all)]
 pub async fn show(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Query<ShowProductDTO>,
     slug: web::Path<String>,
 ) -> Result<impl Responder, AppError> {
     let relations = ProductLoadRelations::from(body.into_inner());
 
-    let product = state
+    let cached = state
         .product_service
-        .get_one_public(slug.into_inner().as_str(), relations)
+        .get_one_public_cached(slug.into_inner().as_str(), relations)
         .await?;
 
-    Ok(HttpResponse::Ok().json(SuccessResponse::ok(product)))
+    Ok(conditional_json(
+        &request,
+        cached.etag,
+        state.config.cache.catalog_max_age_secs,
+        &SuccessResponse::ok(cached.product),
+    ))
 }
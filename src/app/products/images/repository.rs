@@ -2,6 +2,7 @@ use crate::app::products::images::model::ProductImageModel;
 use crate::errors::error::AppError;
 use crate::utils::traits::IsRepository;
 use sqlx::{Executor, PgPool};
+use tracing::instrument;
 
 pub struct ProductImageRepository {
     pool: PgPool,
@@ -20,6 +21,7 @@ impl IsRepository for ProductImageRepository {
 }
 
 impl ProductImageRepository {
+    #[instrument(skip_all)]
     pub async fn get_all_by_product(
         &self,
         product_id: i64,
@@ -44,6 +46,7 @@ impl ProductImageRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_all_for_multiple_products(
         &self,
         product_ids: &Vec<i64>,
@@ -1,7 +1,8 @@
+pub mod cache;
 pub mod dto;
 pub mod filters;
 pub mod handler;
-mod images;
+pub(crate) mod images;
 mod model;
 mod relations;
 pub mod repository;
@@ -9,4 +10,4 @@ pub mod reviews;
 pub mod routes;
 pub mod service;
 mod traits;
-mod videos;
+pub(crate) mod videos;
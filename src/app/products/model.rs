@@ -1,4 +1,7 @@
+use crate::utils::cursor::HasCursorKey;
 use crate::utils::traits::HasId;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -7,10 +10,13 @@ pub struct ProductModel {
     pub id: i64,
     pub name: String,
     pub slug: String,
-    pub price: f64,
+    pub price: BigDecimal,
+    pub effective_price: BigDecimal,
     pub quantity: i32,
     pub configurable: bool,
     pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl HasId for ProductModel {
@@ -19,6 +25,12 @@ impl HasId for ProductModel {
     }
 }
 
+impl HasCursorKey for ProductModel {
+    fn cursor_key(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
 #[derive(FromRow)]
 pub struct ProductIdModel {
     pub id: i64,
@@ -3,13 +3,23 @@ use crate::app::products::reviews::dto::PublicProductReview;
 use crate::app::products::videos::dto::PublicProductVideo;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ProductLoadRelations {
     pub images: bool,
     pub videos: bool,
     pub reviews: bool,
 }
 
+impl ProductLoadRelations {
+    /** Distinguishes entity tags of the same product loaded with different relations. */
+    pub fn variant(&self) -> String {
+        format!(
+            "images={};videos={};reviews={}",
+            self.images, self.videos, self.reviews
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ProductRelations {
     Images(Vec<PublicProductImage>),
@@ -1,8 +1,33 @@
 use super::model::{ProductIdModel, ProductModel};
 use crate::app::products::filters::ProductFilters;
 use crate::errors::error::AppError;
-use crate::utils::pagination::Paginate;
+use crate::utils::cursor::CursorDirection;
+use crate::utils::pagination::{CursorPaginate, Paginate};
+use bigdecimal::BigDecimal;
 use sqlx::{PgPool, Postgres, QueryBuilder};
+use tracing::instrument;
+
+/**
+ * What the storefront may show: active, not trashed and inside the publish
+ * window. Repeated inline in the `query!` macros below, keep them in sync.
+ */
+const PUBLISHED: &str = r#"
+    products.is_active = true
+    AND products.deleted_at IS NULL
+    AND (products.publish_at IS NULL OR products.publish_at <= now())
+    AND (products.unpublish_at IS NULL OR products.unpublish_at > now())
+"#;
+
+/**
+ * Stock the storefront can sell: the sum over active warehouses. Also
+ * repeated inline in the `query!` macros below.
+ */
+const SELLABLE_STOCK: &str = r#"
+    (SELECT COALESCE(SUM(warehouse_stock.quantity), 0)::INTEGER
+    FROM warehouse_stock
+    JOIN warehouses ON warehouses.id = warehouse_stock.warehouse_id
+    WHERE warehouse_stock.product_id = products.id AND warehouses.is_active)
+"#;
 
 pub struct ProductRepository {
     pool: PgPool,
@@ -13,27 +38,30 @@ impl ProductRepository {
         Self { pool }
     }
 
+    #[instrument(skip_all)]
     pub async fn index_paginated(
         &self,
         pagination: &Paginate,
         search: &Option<String>,
         filters: &ProductFilters,
     ) -> Result<Vec<ProductModel>, AppError> {
-        let mut qb = QueryBuilder::<Postgres>::new(
+        let mut qb = QueryBuilder::<Postgres>::new(format!(
             r#"
             SELECT
                 products.id,
                 products.name,
                 products.slug,
                 products.price,
-                products.quantity,
+                product_effective_price(products) AS effective_price,
+                {} AS quantity,
                 products.configurable,
                 products.is_active,
-                products.created_at
+                products.created_at,
+                products.updated_at
             FROM products
-            WHERE is_active = true
         "#,
-        );
+            SELLABLE_STOCK
+        ));
 
         // category
         if let Some(category) = filters.category {
@@ -45,6 +73,9 @@ impl ProductRepository {
             qb.push_bind(category);
         }
 
+        qb.push(" WHERE ");
+        qb.push(PUBLISHED);
+
         // handle search
         if let Some(search) = search {
             qb.push(" AND products.name ILIKE ");
@@ -52,15 +83,15 @@ impl ProductRepository {
         }
 
         // min price
-        if let Some(min_price) = filters.price_min {
-            qb.push(" AND products.price >= ");
-            qb.push_bind(min_price);
+        if let Some(min_price) = &filters.price_min {
+            qb.push(" AND product_effective_price(products) >= ");
+            qb.push_bind(min_price.clone());
         }
 
         // max price
-        if let Some(max_price) = filters.price_max {
-            qb.push(" AND products.price <= ");
-            qb.push_bind(max_price);
+        if let Some(max_price) = &filters.price_max {
+            qb.push(" AND product_effective_price(products) <= ");
+            qb.push_bind(max_price.clone());
         }
 
         // handle pagination
@@ -77,6 +108,98 @@ impl ProductRepository {
             .map_err(AppError::Database)
     }
 
+    /**
+     * Keyset variant of `index_paginated`, newest first. Rows before a `Prev`
+     * cursor come back in ascending order.
+     */
+    #[instrument(skip_all)]
+    pub async fn index_cursor(
+        &self,
+        pagination: &CursorPaginate,
+        search: &Option<String>,
+        filters: &ProductFilters,
+    ) -> Result<Vec<ProductModel>, AppError> {
+        let mut qb = QueryBuilder::<Postgres>::new(format!(
+            r#"
+            SELECT
+                products.id,
+                products.name,
+                products.slug,
+                products.price,
+                product_effective_price(products) AS effective_price,
+                {} AS quantity,
+                products.configurable,
+                products.is_active,
+                products.created_at,
+                products.updated_at
+            FROM products
+        "#,
+            SELLABLE_STOCK
+        ));
+
+        // category
+        if let Some(category) = filters.category {
+            qb.push(
+                " JOIN product_has_categories ON product_has_categories.product_id = products.id ",
+            );
+
+            qb.push(" AND product_has_categories.category_id = ");
+            qb.push_bind(category);
+        }
+
+        qb.push(" WHERE ");
+        qb.push(PUBLISHED);
+
+        // handle search
+        if let Some(search) = search {
+            qb.push(" AND products.name ILIKE ");
+            qb.push_bind(format!("%{}%", search));
+        }
+
+        // min price
+        if let Some(min_price) = &filters.price_min {
+            qb.push(" AND product_effective_price(products) >= ");
+            qb.push_bind(min_price.clone());
+        }
+
+        // max price
+        if let Some(max_price) = &filters.price_max {
+            qb.push(" AND product_effective_price(products) <= ");
+            qb.push_bind(max_price.clone());
+        }
+
+        // handle cursor
+        if let Some(cursor) = &pagination.cursor {
+            match cursor.direction {
+                CursorDirection::Next => qb.push(" AND (products.created_at, products.id) < ("),
+                CursorDirection::Prev => qb.push(" AND (products.created_at, products.id) > ("),
+            };
+
+            qb.push_bind(cursor.sort_key);
+            qb.push(", ");
+            qb.push_bind(cursor.id);
+            qb.push(")");
+        }
+
+        match pagination.direction() {
+            CursorDirection::Next => {
+                qb.push(" ORDER BY products.created_at DESC, products.id DESC")
+            }
+            CursorDirection::Prev => qb.push(" ORDER BY products.created_at ASC, products.id ASC"),
+        };
+
+        qb.push(" LIMIT ");
+        qb.push_bind(pagination.fetch_limit());
+
+        let query = qb.build_query_as::<ProductModel>();
+
+        query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
     pub async fn show(&self, slug: &str) -> Result<Option<ProductModel>, AppError> {
         sqlx::query_as! {
             ProductModel,
@@ -86,11 +209,23 @@ impl ProductRepository {
             name,
             slug,
             price,
-            quantity,
+            product_effective_price(products) AS "effective_price!",
+            (
+                SELECT COALESCE(SUM(warehouse_stock.quantity), 0)::INTEGER
+                FROM warehouse_stock
+                JOIN warehouses ON warehouses.id = warehouse_stock.warehouse_id
+                WHERE warehouse_stock.product_id = products.id AND warehouses.is_active
+            ) AS "quantity!",
             configurable,
-            is_active
+            is_active,
+            created_at,
+            updated_at
         FROM products
-        WHERE slug = $1 AND is_active = true;
+        WHERE slug = $1
+            AND is_active = true
+            AND deleted_at IS NULL
+            AND (publish_at IS NULL OR publish_at <= now())
+            AND (unpublish_at IS NULL OR unpublish_at > now());
         "#,
             slug,
         }
@@ -99,13 +234,21 @@ impl ProductRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn check_exist_and_active(
         &self,
         id: i64,
     ) -> Result<Option<ProductIdModel>, AppError> {
         sqlx::query_as! {
             ProductIdModel,
-            "SELECT id FROM products WHERE id = $1 AND is_active = true;",
+            r#"
+        SELECT id FROM products
+        WHERE id = $1
+            AND is_active = true
+            AND deleted_at IS NULL
+            AND (publish_at IS NULL OR publish_at <= now())
+            AND (unpublish_at IS NULL OR unpublish_at > now());
+        "#,
             id,
         }
         .fetch_optional(&self.pool)
@@ -113,38 +256,56 @@ impl ProductRepository {
         .map_err(AppError::Database)
     }
 
-    pub async fn get_product_stock(&self, product_id: &i64) -> Result<i32, AppError> {
-        sqlx::query_scalar!(
+    /**
+     * Price a published product sells for right now, `None` when it can't be
+     * bought.
+     */
+    #[instrument(skip_all)]
+    pub async fn get_effective_price(&self, id: i64) -> Result<Option<BigDecimal>, AppError> {
+        sqlx::query_scalar! {
             r#"
         SELECT
-            quantity
+            product_effective_price(products) AS "effective_price!"
         FROM products
-        WHERE id = $1;
+        WHERE id = $1
+            AND is_active = true
+            AND deleted_at IS NULL
+            AND (publish_at IS NULL OR publish_at <= now())
+            AND (unpublish_at IS NULL OR unpublish_at > now());
         "#,
-            product_id
-        )
-        .fetch_one(&self.pool)
+            id,
+        }
+        .fetch_optional(&self.pool)
         .await
         .map_err(AppError::Database)
     }
 
-    pub async fn update_product_stock(
-        &self,
-        product_id: i64,
-        new_quantity: i32,
-    ) -> Result<u64, AppError> {
-        let result = sqlx::query!(
+    /**
+     * Stock the storefront can sell of a published product, `None` when it
+     * can't be bought.
+     */
+    #[instrument(skip_all)]
+    pub async fn get_sellable_stock(&self, id: i64) -> Result<Option<i32>, AppError> {
+        sqlx::query_scalar! {
             r#"
-        UPDATE products
-        SET quantity = $1 WHERE id = $2;
+        SELECT
+            (
+                SELECT COALESCE(SUM(warehouse_stock.quantity), 0)::INTEGER
+                FROM warehouse_stock
+                JOIN warehouses ON warehouses.id = warehouse_stock.warehouse_id
+                WHERE warehouse_stock.product_id = products.id AND warehouses.is_active
+            ) AS "quantity!"
+        FROM products
+        WHERE id = $1
+            AND is_active = true
+            AND deleted_at IS NULL
+            AND (publish_at IS NULL OR publish_at <= now())
+            AND (unpublish_at IS NULL OR unpublish_at > now());
         "#,
-            new_quantity,
-            product_id
-        )
-        .execute(&self.pool)
+            id,
+        }
+        .fetch_optional(&self.pool)
         .await
-        .map_err(AppError::Database)?;
-
-        Ok(result.rows_affected())
+        .map_err(AppError::Database)
     }
 }
@@ -2,9 +2,10 @@ use crate::admin::reviews::dto::ReviewApprovalStatus;
 use crate::app::products::reviews::model::ProductReviewModel;
 use crate::utils::traits::HasId;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct PublicProductReview {
     pub id: i64,
     pub user_id: Option<i64>,
@@ -35,18 +36,22 @@ impl From<ProductReviewModel> for PublicProductReview {
     }
 }
 
-#[derive(Validate, Serialize, Deserialize, Debug, Clone)]
+#[derive(Validate, Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct CreateProductReviewDto {
     #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
     pub product_id: Option<i64>,
 
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub title: Option<String>,
 
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub content: Option<String>,
 
     #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
     pub rating: Option<i16>,
 }
 
@@ -1,11 +1,31 @@
-use crate::app::products::reviews::dto::{CreateProductReviewCommand, CreateProductReviewDto};
+use crate::app::products::reviews::dto::{
+    CreateProductReviewCommand, CreateProductReviewDto, PublicProductReview,
+};
 use crate::errors::error::AppError;
-use crate::responses::error_responses::SuccessResponse;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
 use crate::utils::extractors::extract_auth_user_id;
 use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
 use validator::Validate;
 
+#[utoipa::path(
+    post,
+    path = "/products/{product_id}/reviews/create-user",
+    tag = "reviews",
+    params(("product_id" = i64, Path, description = "Product id")),
+    request_body = CreateProductReviewDto,
+    responses(
+        (status = 201, description = "Review submitted", body = SuccessResponse<PublicProductReview>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
 pub async fn create_user(
     request: HttpRequest,
     state: web::Data<AppState>,
@@ -17,10 +37,26 @@ pub async fn create_user(
 
     let command = CreateProductReviewCommand::from_dto(body.into_inner(), Some(auth_user_id));
     let review = state.reviews_service.create(command).await?;
+    state.metrics.reviews_submitted_total.inc();
 
     Ok(HttpResponse::Created().json(SuccessResponse::ok(review)))
 }
 
+#[utoipa::path(
+    post,
+    path = "/products/{product_id}/reviews/create-guest",
+    tag = "reviews",
+    params(("product_id" = i64, Path, description = "Product id")),
+    request_body = CreateProductReviewDto,
+    responses(
+        (status = 201, description = "Review submitted", body = SuccessResponse<PublicProductReview>),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[instrument(skip_all)]
 pub async fn create_guest(
     state: web::Data<AppState>,
     body: web::Json<CreateProductReviewDto>,
@@ -29,6 +65,7 @@ pub async fn create_guest(
 
     let command = CreateProductReviewCommand::from_dto(body.into_inner(), None);
     let review = state.reviews_service.create(command).await?;
+    state.metrics.reviews_submitted_total.inc();
 
     Ok(HttpResponse::Created().json(SuccessResponse::ok(review)))
 }
@@ -4,6 +4,7 @@ use crate::app::products::reviews::model::ProductReviewModel;
 use crate::errors::error::AppError;
 use crate::utils::traits::IsRepository;
 use sqlx::{Executor, PgPool, Postgres};
+use tracing::instrument;
 
 pub struct ProductReviewRepository {
     pool: PgPool,
@@ -22,6 +23,7 @@ impl IsRepository for ProductReviewRepository {
 }
 
 impl ProductReviewRepository {
+    #[instrument(skip_all)]
     pub async fn get_all_by_product(
         &self,
         product_id: i64,
@@ -48,6 +50,7 @@ impl ProductReviewRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_all_for_multiple_products(
         &self,
         product_ids: &Vec<i64>,
@@ -74,6 +77,7 @@ impl ProductReviewRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn create(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
@@ -1,5 +1,7 @@
 use crate::app::products::reviews::handler;
 use crate::middlewares::auth::AuthMiddleware;
+use crate::middlewares::rate_limit::RateLimitMiddleware;
+use crate::rate_limit::policy::{RateLimitKey, RateLimitPolicy};
 use actix_web::web;
 use actix_web::web::{post, resource};
 
@@ -8,9 +10,20 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
         web::scope("/{product_id}/reviews")
             .service(
                 resource("/create-user")
+                    .wrap(RateLimitMiddleware::new(
+                        RateLimitPolicy::ReviewCreate,
+                        RateLimitKey::AuthUser,
+                    ))
                     .wrap(AuthMiddleware::new(None))
                     .route(post().to(handler::create_user)),
             )
-            .service(resource("/create-guest").route(post().to(handler::create_guest))),
+            .service(
+                resource("/create-guest")
+                    .wrap(RateLimitMiddleware::new(
+                        RateLimitPolicy::ReviewCreate,
+                        RateLimitKey::Ip,
+                    ))
+                    .route(post().to(handler::create_guest)),
+            ),
     );
 }
@@ -1,5 +1,6 @@
 use crate::admin::products::service::AdminProductService;
 use crate::admin::users::service::AdminUserService;
+use crate::app::products::cache::ProductCache;
 use crate::app::products::reviews::dto::{CreateProductReviewCommand, PublicProductReview};
 use crate::app::products::reviews::repository::ProductReviewRepository;
 use crate::app::products::reviews::traits::IntoPublic;
@@ -14,10 +15,10 @@ pub struct ProductReviewService {
 }
 
 impl ProductReviewService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, product_cache: ProductCache) -> Self {
         Self {
             repository: ProductReviewRepository::new(pool.clone()),
-            admin_product_service: AdminProductService::new(pool.clone()),
+            admin_product_service: AdminProductService::new(pool.clone(), product_cache),
             admin_user_service: AdminUserService::new(pool),
         }
     }
@@ -37,6 +38,9 @@ impl ProductReviewService {
             .create(self.repository.get_pool(), cmd)
             .await?;
 
+        self.admin_product_service
+            .invalidate_cached_pages(review.product_id);
+
         Ok(review.into_public())
     }
 }
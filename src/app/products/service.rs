@@ -1,5 +1,6 @@
 use super::model::ProductModel;
-use crate::app::products::dto::{PublicProduct, PublicProductBuilder, UpdateStockDto};
+use crate::app::products::cache::{CachedProduct, ProductCache};
+use crate::app::products::dto::{PublicProduct, PublicProductBuilder};
 use crate::app::products::filters::ProductFilters;
 use crate::app::products::images::dto::PublicProductImage;
 use crate::app::products::images::repository::ProductImageRepository;
@@ -13,8 +14,11 @@ use crate::app::products::videos::dto::PublicProductVideo;
 use crate::app::products::videos::repository::ProductVideoRepository;
 use crate::app::products::videos::traits::IntoPublic as IntoPublicProductVideo;
 use crate::errors::error::AppError;
-use crate::utils::pagination::{Paginate, PaginatedDataCollection};
-use crate::utils::traits::{HasQuantity, IsRepository};
+use crate::utils::etag::entity_tag;
+use crate::utils::pagination::{
+    CursorPaginate, CursorPaginatedDataCollection, Paginate, PaginatedDataCollection,
+};
+use crate::utils::traits::IsRepository;
 use futures_util::future::try_join_all;
 use futures_util::{FutureExt, TryFutureExt};
 use sqlx::PgPool;
@@ -25,15 +29,17 @@ pub struct ProductService {
     product_image_repository: ProductImageRepository,
     product_video_repository: ProductVideoRepository,
     product_review_repository: ProductReviewRepository,
+    cache: ProductCache,
 }
 
 impl ProductService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, cache: ProductCache) -> Self {
         Self {
             repository: ProductRepository::new(pool.clone()),
             product_image_repository: ProductImageRepository::new(pool.clone()),
             product_video_repository: ProductVideoRepository::new(pool.clone()),
             product_review_repository: ProductReviewRepository::new(pool),
+            cache,
         }
     }
 
@@ -63,10 +69,49 @@ impl ProductService {
     ) -> Result<PaginatedDataCollection<PublicProduct>, AppError> {
         let product_collection = self.get_all_paginated(pagination, filters, search).await?;
 
-        let loaded_relations = self
-            .load_relations(relations, product_collection.extract_ids())
+        let public_products = self
+            .build_public_products(product_collection.get_data(), relations)
             .await?;
 
+        Ok(PaginatedDataCollection::new(
+            public_products,
+            product_collection.get_pagination(),
+        ))
+    }
+
+    /**
+     * Cursor paginated products, newest first (public version).
+     */
+    pub async fn get_all_cursor_public(
+        &self,
+        pagination: &CursorPaginate,
+        filters: &ProductFilters,
+        search: &Option<String>,
+        relations: ProductLoadRelations,
+    ) -> Result<CursorPaginatedDataCollection<PublicProduct>, AppError> {
+        let rows = self
+            .repository
+            .index_cursor(pagination, search, filters)
+            .await?;
+
+        let product_collection = CursorPaginatedDataCollection::new(rows, pagination);
+
+        let public_products = self
+            .build_public_products(product_collection.data.clone(), relations)
+            .await?;
+
+        Ok(product_collection.with_data(public_products))
+    }
+
+    async fn build_public_products(
+        &self,
+        products: Vec<ProductModel>,
+        relations: ProductLoadRelations,
+    ) -> Result<Vec<PublicProduct>, AppError> {
+        let product_ids = products.iter().map(|product| product.id).collect();
+
+        let loaded_relations = self.load_relations(relations, product_ids).await?;
+
         let mut images_by_product_id: HashMap<i64, Vec<PublicProductImage>> = HashMap::new();
         let mut videos_by_product_id: HashMap<i64, Vec<PublicProductVideo>> = HashMap::new();
         let mut reviews_by_product_id: HashMap<i64, Vec<PublicProductReview>> = HashMap::new();
@@ -102,7 +147,7 @@ impl ProductService {
 
         let mut public_products = Vec::new();
 
-        for product in product_collection.get_data() {
+        for product in products {
             let mut product_builder = PublicProductBuilder::new(&product);
 
             if let Some(images) = images_by_product_id.remove(&product.id) {
@@ -120,10 +165,7 @@ impl ProductService {
             public_products.push(product_builder.build())
         }
 
-        Ok(PaginatedDataCollection::new(
-            public_products,
-            product_collection.get_pagination(),
-        ))
+        Ok(public_products)
     }
 
     pub async fn get_one(&self, slug: &str) -> Result<ProductModel, AppError> {
@@ -163,6 +205,30 @@ impl ProductService {
         Ok(product_builder.build())
     }
 
+    /**
+     * Product page with its entity tag, served from the page cache when possible.
+     */
+    pub async fn get_one_public_cached(
+        &self,
+        slug: &str,
+        relations: ProductLoadRelations,
+    ) -> Result<CachedProduct, AppError> {
+        if let Some(cached) = self.cache.get(slug, relations) {
+            return Ok(cached);
+        }
+
+        let product = self.get_one_public(slug, relations).await?;
+
+        let cached = CachedProduct {
+            etag: entity_tag([&product], &relations.variant()),
+            product,
+        };
+
+        self.cache.insert(slug, relations, cached.clone());
+
+        Ok(cached)
+    }
+
     pub async fn exist(&self, id: i64) -> Result<bool, AppError> {
         let product = self.repository.check_exist_and_active(id).await?;
 
@@ -172,27 +238,6 @@ impl ProductService {
         }
     }
 
-    pub async fn update_stock(&self, dto: UpdateStockDto) -> Result<u64, AppError> {
-        let product = self
-            .repository
-            .check_exist_and_active(dto.product_id)
-            .await?;
-
-        if product.is_none() {
-            return Err(AppError::NotFound("Product not found".to_string()));
-        }
-
-        if !dto.is_safe_quantity() {
-            return Err(AppError::Internal(
-                "Quantity cannot be negative".to_string(),
-            ));
-        }
-
-        self.repository
-            .update_product_stock(dto.product_id, dto.quantity)
-            .await
-    }
-
     async fn load_relations(
         &self,
         relations: ProductLoadRelations,
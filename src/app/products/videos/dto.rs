@@ -2,14 +2,16 @@ use crate::app::products::videos::model::ProductVideoModel;
 use crate::utils::traits::HasId;
 use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct PublicProductVideo {
     pub id: i64,
     pub product_id: i64,
     pub url: String,
     pub alt: String,
     pub is_main: bool,
+    #[schema(value_type = String)]
     pub sort: BigDecimal,
 }
 
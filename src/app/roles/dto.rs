@@ -1,5 +1,15 @@
+use crate::admin::audit::permission::AuditScope;
 use crate::admin::categories::permission::CategoryScope;
+use crate::admin::coupons::permission::CouponScope;
 use crate::admin::products::permission::ProductScope;
+use crate::admin::promotions::permission::PromotionScope;
+use crate::admin::shipping_methods::permission::ShippingMethodScope;
+use crate::admin::shipping_zones::permission::ShippingZoneScope;
+use crate::admin::stock::permission::StockScope;
+use crate::admin::tax_rates::permission::TaxRateScope;
+use crate::admin::users::permission::UserScope;
+use crate::admin::warehouses::permission::WarehouseScope;
+use crate::auth::permission::AccountScope;
 use crate::auth::traits::Scope;
 use crate::errors::error::AppError;
 use std::collections::HashSet;
@@ -34,6 +44,44 @@ impl RoleEnum {
                 // category scopes
                 scopes.extend(CategoryScope::all().iter().map(|s| s.as_str().to_string()));
 
+                // coupon scopes
+                scopes.extend(CouponScope::all().iter().map(|s| s.as_str().to_string()));
+
+                // promotion scopes
+                scopes.extend(PromotionScope::all().iter().map(|s| s.as_str().to_string()));
+
+                // tax rate scopes
+                scopes.extend(TaxRateScope::all().iter().map(|s| s.as_str().to_string()));
+
+                // shipping zone scopes
+                scopes.extend(
+                    ShippingZoneScope::all()
+                        .iter()
+                        .map(|s| s.as_str().to_string()),
+                );
+
+                // shipping method scopes
+                scopes.extend(
+                    ShippingMethodScope::all()
+                        .iter()
+                        .map(|s| s.as_str().to_string()),
+                );
+
+                // stock scopes
+                scopes.extend(StockScope::all().iter().map(|s| s.as_str().to_string()));
+
+                // warehouse scopes
+                scopes.extend(WarehouseScope::all().iter().map(|s| s.as_str().to_string()));
+
+                // user scopes
+                scopes.extend(UserScope::all().iter().map(|s| s.as_str().to_string()));
+
+                // audit scopes
+                scopes.extend(AuditScope::all().iter().map(|s| s.as_str().to_string()));
+
+                // account scopes
+                scopes.extend(AccountScope::all().iter().map(|s| s.as_str().to_string()));
+
                 scopes
             }
             RoleEnum::User => HashSet::from([]),
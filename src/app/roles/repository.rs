@@ -2,6 +2,7 @@ use crate::app::roles::dto::RoleEnum;
 use crate::app::roles::model::RoleModel;
 use crate::errors::error::AppError;
 use sqlx::{Executor, PgPool, Postgres};
+use tracing::instrument;
 
 #[derive(Clone)]
 pub struct RoleRepository;
@@ -11,6 +12,7 @@ impl RoleRepository {
         Self
     }
 
+    #[instrument(skip_all)]
     pub async fn get_user_role(&self, pool: &PgPool, user_id: &i64) -> Result<RoleModel, AppError> {
         sqlx::query_as!(
             RoleModel,
@@ -29,6 +31,7 @@ impl RoleRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_role_by_name<'e, E>(
         &self,
         executor: E,
@@ -47,6 +50,7 @@ impl RoleRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn assign_role<'e, E>(
         &self,
         executor: E,
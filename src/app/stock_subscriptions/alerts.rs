@@ -0,0 +1,84 @@
+use crate::app::stock_subscriptions::model::StockSubscriberModel;
+use crate::app::stock_subscriptions::repository::StockSubscriptionRepository;
+use crate::app::wishlists::alerts::ProductSnapshot;
+use crate::errors::error::AppError;
+use crate::notifications::dto::Notification;
+use crate::notifications::notifier::Notifier;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::warn;
+
+/**
+ * Whether a product change takes it from something customers couldn't buy to
+ * something they can, e.g. a restock or a warehouse coming back online.
+ */
+pub fn is_back_in_stock(before: &ProductSnapshot, after: &ProductSnapshot) -> bool {
+    after.is_sellable() && !before.is_sellable()
+}
+
+/** The notifications a restock sends to the subscribers of the product. */
+pub fn notifications_for(
+    product: &ProductSnapshot,
+    subscribers: Vec<StockSubscriberModel>,
+) -> Vec<Notification> {
+    subscribers
+        .into_iter()
+        .map(|subscriber| Notification::Available {
+            user_id: subscriber.user_id,
+            email: subscriber.email,
+            product_id: product.id,
+            product_name: product.name.clone(),
+        })
+        .collect()
+}
+
+/**
+ * Tells everyone who asked to be notified when a product is available again,
+ * then drops their subscriptions. Product writes call it once they are
+ * committed, next to the wishlist alerts.
+ */
+pub struct StockSubscriptionAlerts {
+    repository: StockSubscriptionRepository,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl StockSubscriptionAlerts {
+    pub fn with_notifier(pool: PgPool, notifier: Arc<dyn Notifier>) -> Self {
+        Self {
+            repository: StockSubscriptionRepository::new(pool),
+            notifier,
+        }
+    }
+
+    /** Sends the notifications a product change calls for, returns how many. */
+    pub async fn product_changed(
+        &self,
+        before: &ProductSnapshot,
+        after: &ProductSnapshot,
+    ) -> Result<usize, AppError> {
+        if !is_back_in_stock(before, after) {
+            return Ok(0);
+        }
+
+        let subscribers = self.repository.claim(after.id).await?;
+        let notifications = notifications_for(after, subscribers);
+        let sent = notifications.len();
+
+        for notification in notifications {
+            self.notifier.notify(notification);
+        }
+
+        Ok(sent)
+    }
+
+    /**
+     * Same as `product_changed` for callers whose write is already
+     * committed, a failed lookup is logged instead of failing the request.
+     */
+    pub async fn product_changed_or_warn(&self, before: &ProductSnapshot, after: &ProductSnapshot) {
+        if let Err(error) = self.product_changed(before, after).await {
+            warn!(product_id = after.id, %error, "failed to send stock subscription alerts");
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::app::stock_subscriptions::model::StockSubscriptionModel;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct PublicStockSubscription {
+    pub product_id: i64,
+    pub name: String,
+    pub slug: String,
+    pub subscribed_at: DateTime<Utc>,
+}
+
+impl From<StockSubscriptionModel> for PublicStockSubscription {
+    fn from(subscription: StockSubscriptionModel) -> Self {
+        Self {
+            product_id: subscription.product_id,
+            name: subscription.name,
+            slug: subscription.slug,
+            subscribed_at: subscription.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct StockSubscriptionDto {
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub product_id: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct GuestStockSubscriptionDto {
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub product_id: Option<i64>,
+
+    /// Where to send the notification.
+    #[validate(required, email, length(max = 255))]
+    #[schema(required = true, format = Email, max_length = 255)]
+    pub email: Option<String>,
+}
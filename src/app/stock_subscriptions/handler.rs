@@ -0,0 +1,144 @@
+use crate::app::stock_subscriptions::dto::{
+    GuestStockSubscriptionDto, PublicStockSubscription, StockSubscriptionDto,
+};
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/me/stock-alerts/list",
+    tag = "stock-alerts",
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<PublicStockSubscription>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let subscriptions = state
+        .stock_subscription_service
+        .get_all_public(auth_user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(subscriptions)))
+}
+
+/**
+ * Asks to be told once a sold out product is available again, at the email
+ * of the account. The subscription goes away once it's sent.
+ */
+#[utoipa::path(
+    post,
+    path = "/me/stock-alerts/subscribe",
+    tag = "stock-alerts",
+    request_body = StockSubscriptionDto,
+    responses(
+        (status = 200, description = "Subscribed, responds with all subscriptions of the user", body = SuccessResponse<Vec<PublicStockSubscription>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Product is in stock", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn subscribe(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<StockSubscriptionDto>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let subscriptions = state
+        .stock_subscription_service
+        .subscribe_user(auth_user_id, body.product_id.unwrap())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(subscriptions)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/stock-alerts/unsubscribe",
+    tag = "stock-alerts",
+    request_body = StockSubscriptionDto,
+    responses(
+        (status = 200, description = "Unsubscribed, responds with the remaining subscriptions", body = SuccessResponse<Vec<PublicStockSubscription>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn unsubscribe(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<StockSubscriptionDto>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let subscriptions = state
+        .stock_subscription_service
+        .unsubscribe(auth_user_id, body.product_id.unwrap())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(subscriptions)))
+}
+
+/**
+ * Same as `subscribe` for guests, who leave the email to write to. Answers
+ * the same whether or not the address was already subscribed.
+ */
+#[utoipa::path(
+    post,
+    path = "/stock-alerts/guest/subscribe",
+    tag = "stock-alerts",
+    request_body = GuestStockSubscriptionDto,
+    responses(
+        (status = 204, description = "Subscribed"),
+        (status = 401, description = "Missing or invalid guest token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Product is in stock", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("guest_token" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn subscribe_guest(
+    state: web::Data<AppState>,
+    body: web::Json<GuestStockSubscriptionDto>,
+) -> Result<impl Responder, AppError> {
+    body.validate()?;
+
+    let body = body.into_inner();
+
+    state
+        .stock_subscription_service
+        .subscribe_guest(&body.email.unwrap(), body.product_id.unwrap())
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,7 @@
+pub mod alerts;
+pub mod dto;
+pub mod handler;
+pub mod model;
+pub mod repository;
+pub mod routes;
+pub mod service;
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/** A subscription joined with the product it waits for. */
+#[derive(Serialize, Deserialize, FromRow, Clone)]
+pub struct StockSubscriptionModel {
+    pub product_id: i64,
+    pub name: String,
+    pub slug: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/** Who to tell once a product is back, guests have no user. */
+#[derive(Serialize, Deserialize, FromRow, Clone, Debug, PartialEq, Eq)]
+pub struct StockSubscriberModel {
+    pub user_id: Option<i64>,
+    pub email: String,
+}
@@ -0,0 +1,120 @@
+use crate::app::stock_subscriptions::model::{StockSubscriberModel, StockSubscriptionModel};
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use tracing::instrument;
+
+pub struct StockSubscriptionRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for StockSubscriptionRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl StockSubscriptionRepository {
+    #[instrument(skip_all)]
+    pub async fn list_by_user(
+        &self,
+        user_id: i64,
+    ) -> Result<Vec<StockSubscriptionModel>, AppError> {
+        sqlx::query_as! {
+            StockSubscriptionModel,
+            r#"
+        SELECT
+            stock_subscriptions.product_id,
+            products.name,
+            products.slug,
+            stock_subscriptions.created_at
+        FROM stock_subscriptions
+        JOIN products ON products.id = stock_subscriptions.product_id
+        WHERE stock_subscriptions.user_id = $1
+        ORDER BY stock_subscriptions.id;
+        "#,
+            user_id
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Subscribes an email to a product, doing nothing when it already is. A
+     * user taking over an address a guest subscribed with gets the row.
+     */
+    #[instrument(skip_all)]
+    pub async fn subscribe(
+        &self,
+        product_id: i64,
+        user_id: Option<i64>,
+        email: &str,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        INSERT INTO stock_subscriptions (product_id, user_id, email)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (product_id, lower(email))
+            DO UPDATE SET user_id = COALESCE(stock_subscriptions.user_id, EXCLUDED.user_id);
+        "#,
+            product_id,
+            user_id,
+            email
+        }
+        .execute(&self.pool)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn unsubscribe(&self, user_id: i64, product_id: i64) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM stock_subscriptions WHERE user_id = $1 AND product_id = $2;",
+            user_id,
+            product_id
+        }
+        .execute(&self.pool)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /**
+     * Removes and returns the subscribers of a product, so each of them is
+     * told once even when two restocks race. Users are reached at the email
+     * of their account as it is now.
+     */
+    #[instrument(skip_all)]
+    pub async fn claim(&self, product_id: i64) -> Result<Vec<StockSubscriberModel>, AppError> {
+        sqlx::query_as! {
+            StockSubscriberModel,
+            r#"
+        WITH claimed AS (
+            DELETE FROM stock_subscriptions
+            WHERE product_id = $1
+            RETURNING id, user_id, email
+        )
+        SELECT
+            claimed.user_id,
+            COALESCE(users.email, claimed.email) AS "email!"
+        FROM claimed
+        LEFT JOIN users ON users.id = claimed.user_id
+        ORDER BY claimed.id;
+        "#,
+            product_id
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+}
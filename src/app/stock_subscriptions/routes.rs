@@ -0,0 +1,20 @@
+use crate::app::stock_subscriptions::handler;
+use crate::middlewares::auth::AuthMiddleware;
+use crate::middlewares::guest::GuestMiddleware;
+use actix_web::web;
+use actix_web::web::{delete, get, post, resource};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/me/stock-alerts")
+            .wrap(AuthMiddleware::new(None))
+            .service(resource("/list").route(get().to(handler::index)))
+            .service(resource("/subscribe").route(post().to(handler::subscribe)))
+            .service(resource("/unsubscribe").route(delete().to(handler::unsubscribe))),
+    )
+    .service(
+        web::scope("/stock-alerts/guest")
+            .wrap(GuestMiddleware)
+            .service(resource("/subscribe").route(post().to(handler::subscribe_guest))),
+    );
+}
@@ -0,0 +1,96 @@
+use crate::app::products::repository::ProductRepository;
+use crate::app::stock_subscriptions::dto::PublicStockSubscription;
+use crate::app::stock_subscriptions::repository::StockSubscriptionRepository;
+use crate::app::users::repository::UserRepository;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+
+pub struct StockSubscriptionService {
+    repository: StockSubscriptionRepository,
+    product_repository: ProductRepository,
+    user_repository: UserRepository,
+}
+
+impl StockSubscriptionService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: StockSubscriptionRepository::new(pool.clone()),
+            product_repository: ProductRepository::new(pool.clone()),
+            user_repository: UserRepository::new(pool),
+        }
+    }
+
+    pub async fn get_all_public(
+        &self,
+        user_id: i64,
+    ) -> Result<Vec<PublicStockSubscription>, AppError> {
+        let subscriptions = self.repository.list_by_user(user_id).await?;
+
+        Ok(subscriptions
+            .into_iter()
+            .map(PublicStockSubscription::from)
+            .collect())
+    }
+
+    /** Subscribes the user at the email of their account. */
+    pub async fn subscribe_user(
+        &self,
+        user_id: i64,
+        product_id: i64,
+    ) -> Result<Vec<PublicStockSubscription>, AppError> {
+        self.check_sold_out(product_id).await?;
+
+        let user = self
+            .user_repository
+            .get_user_by_id(&user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("user not found".to_string()))?;
+
+        self.repository
+            .subscribe(product_id, Some(user_id), &user.email)
+            .await?;
+
+        self.get_all_public(user_id).await
+    }
+
+    pub async fn subscribe_guest(&self, email: &str, product_id: i64) -> Result<(), AppError> {
+        self.check_sold_out(product_id).await?;
+
+        self.repository.subscribe(product_id, None, email).await?;
+
+        Ok(())
+    }
+
+    pub async fn unsubscribe(
+        &self,
+        user_id: i64,
+        product_id: i64,
+    ) -> Result<Vec<PublicStockSubscription>, AppError> {
+        let removed = self.repository.unsubscribe(user_id, product_id).await?;
+
+        if removed == 0 {
+            return Err(AppError::NotFound("subscription not found".to_string()));
+        }
+
+        self.get_all_public(user_id).await
+    }
+
+    /**
+     * Only products the storefront sells but has run out of can be waited
+     * for, anything in stock can be bought right away.
+     */
+    async fn check_sold_out(&self, product_id: i64) -> Result<(), AppError> {
+        let quantity = self
+            .product_repository
+            .get_sellable_stock(product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("product not found".to_string()))?;
+
+        if quantity > 0 {
+            return Err(AppError::Conflict("Product is in stock".to_string()));
+        }
+
+        Ok(())
+    }
+}
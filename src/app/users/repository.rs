@@ -1,6 +1,7 @@
 use crate::app::users::model::{UserHashModel, UserModel};
 use crate::errors::error::AppError;
 use sqlx::PgPool;
+use tracing::instrument;
 
 #[derive(Clone)]
 pub struct UserRepository {
@@ -12,6 +13,7 @@ impl UserRepository {
         Self { pool }
     }
 
+    #[instrument(skip_all)]
     pub async fn get_user_by_id(&self, user_id: &i64) -> Result<Option<UserModel>, AppError> {
         sqlx::query_as!(
             UserModel,
@@ -23,6 +25,7 @@ impl UserRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_user_hash(&self, hash: &str) -> Result<Option<UserHashModel>, AppError> {
         sqlx::query_as!(
             UserHashModel,
@@ -0,0 +1,152 @@
+use crate::app::wishlists::repository::WishlistRepository;
+use crate::errors::error::AppError;
+use crate::notifications::dto::Notification;
+use crate::notifications::notifier::Notifier;
+use crate::utils::money::Money;
+use crate::utils::traits::IsRepository;
+use bigdecimal::BigDecimal;
+use sqlx::{Executor, PgPool, Postgres};
+use std::sync::Arc;
+use tracing::warn;
+
+/** What wishlist alerts look at of a product, before and after a change. */
+#[derive(Debug, Clone)]
+pub struct ProductSnapshot {
+    pub id: i64,
+    pub name: String,
+    /// The price it sells for, the sale price while a sale runs.
+    pub price: BigDecimal,
+    /// Stock the storefront can sell, summed over active warehouses.
+    pub quantity: i32,
+    /// Whether customers can see and buy it.
+    pub available: bool,
+}
+
+impl ProductSnapshot {
+    /** Whether customers can buy it right now. */
+    pub fn is_sellable(&self) -> bool {
+        self.available && self.quantity > 0
+    }
+}
+
+/**
+ * Whether watchers hear about a change: the product can be bought after it
+ * and either couldn't be before or got cheaper.
+ */
+fn is_alert(before: &ProductSnapshot, after: &ProductSnapshot) -> bool {
+    after.is_sellable() && (!before.is_sellable() || after.price < before.price)
+}
+
+/** The notifications a product change sends to each of its watchers. */
+pub fn alerts_for(
+    before: &ProductSnapshot,
+    after: &ProductSnapshot,
+    watchers: &[i64],
+) -> Vec<Notification> {
+    if !is_alert(before, after) {
+        return Vec::new();
+    }
+
+    let back_in_stock = !before.is_sellable();
+    let price_drop = after.price < before.price;
+
+    let mut notifications = Vec::new();
+
+    for user_id in watchers.iter().copied() {
+        if back_in_stock {
+            notifications.push(Notification::BackInStock {
+                user_id,
+                product_id: after.id,
+                product_name: after.name.clone(),
+            });
+        }
+
+        if price_drop {
+            notifications.push(Notification::PriceDrop {
+                user_id,
+                product_id: after.id,
+                product_name: after.name.clone(),
+                old_price: Money::new(before.price.clone()),
+                new_price: Money::new(after.price.clone()),
+            });
+        }
+    }
+
+    notifications
+}
+
+/**
+ * Tells users with a product on a wishlist when it comes back in stock or
+ * drops in price. Product writes call it once they are committed.
+ */
+pub struct WishlistAlerts {
+    repository: WishlistRepository,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl WishlistAlerts {
+    pub fn with_notifier(pool: PgPool, notifier: Arc<dyn Notifier>) -> Self {
+        Self {
+            repository: WishlistRepository::new(pool),
+            notifier,
+        }
+    }
+
+    /**
+     * The product as alerts compare it. Product writes take one before and
+     * one after the change on their transaction, which sees its own writes.
+     */
+    pub async fn snapshot(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        product_id: i64,
+    ) -> Result<ProductSnapshot, AppError> {
+        self.repository
+            .get_product_snapshots(executor, &[product_id])
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::NotFound("Product not found".to_string()))
+    }
+
+    /** Same as `snapshot` for many products at once, in id order. */
+    pub async fn snapshots(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        product_ids: &[i64],
+    ) -> Result<Vec<ProductSnapshot>, AppError> {
+        self.repository
+            .get_product_snapshots(executor, product_ids)
+            .await
+    }
+
+    /** Sends the alerts a product change calls for, returns how many. */
+    pub async fn product_changed(
+        &self,
+        before: &ProductSnapshot,
+        after: &ProductSnapshot,
+    ) -> Result<usize, AppError> {
+        if !is_alert(before, after) {
+            return Ok(0);
+        }
+
+        let watchers = self.repository.watchers(after.id).await?;
+        let notifications = alerts_for(before, after, &watchers);
+        let sent = notifications.len();
+
+        for notification in notifications {
+            self.notifier.notify(notification);
+        }
+
+        Ok(sent)
+    }
+
+    /**
+     * Same as `product_changed` for callers whose write is already
+     * committed, a failed lookup is logged instead of failing the request.
+     */
+    pub async fn product_changed_or_warn(&self, before: &ProductSnapshot, after: &ProductSnapshot) {
+        if let Err(error) = self.product_changed(before, after).await {
+            warn!(product_id = after.id, %error, "failed to send wishlist alerts");
+        }
+    }
+}
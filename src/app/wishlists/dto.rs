@@ -0,0 +1,149 @@
+use crate::app::wishlists::model::{WishlistItemModel, WishlistModel};
+use crate::errors::error::AppError;
+use crate::utils::money::Money;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct PublicWishlistItem {
+    pub product_id: i64,
+    pub name: String,
+    pub slug: String,
+    /// What the product cost when it was added.
+    pub added_price: Money,
+    /// What it costs now, the sale price while a sale runs.
+    pub price: Money,
+    pub in_stock: bool,
+    /// False once the product is unpublished or removed from the catalog.
+    pub available: bool,
+    pub added_at: DateTime<Utc>,
+}
+
+impl From<WishlistItemModel> for PublicWishlistItem {
+    fn from(item: WishlistItemModel) -> Self {
+        Self {
+            product_id: item.product_id,
+            name: item.name,
+            slug: item.slug,
+            added_price: Money::new(item.added_price),
+            price: Money::new(item.effective_price),
+            in_stock: item.quantity > 0,
+            available: item.available,
+            added_at: item.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct PublicWishlist {
+    pub id: i64,
+    pub name: String,
+    /// Anyone holding the token can view the list, `null` while it isn't shared.
+    pub share_token: Option<String>,
+    pub items: Vec<PublicWishlistItem>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PublicWishlist {
+    pub fn new(wishlist: WishlistModel, items: Vec<WishlistItemModel>) -> Self {
+        Self {
+            id: wishlist.id,
+            name: wishlist.name,
+            share_token: wishlist.share_token,
+            items: items.into_iter().map(PublicWishlistItem::from).collect(),
+            updated_at: wishlist.updated_at,
+        }
+    }
+}
+
+/** A wishlist as seen through its share link, without anything of the owner. */
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct PublicSharedWishlist {
+    pub name: String,
+    pub items: Vec<PublicWishlistItem>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PublicWishlist> for PublicSharedWishlist {
+    fn from(wishlist: PublicWishlist) -> Self {
+        Self {
+            name: wishlist.name,
+            items: wishlist.items,
+            updated_at: wishlist.updated_at,
+        }
+    }
+}
+
+/** Trims a wishlist name, one made of whitespace only is rejected. */
+fn wishlist_name(name: String) -> Result<String, AppError> {
+    let name = name.trim().to_string();
+
+    if name.is_empty() {
+        return Err(AppError::field("name", "length", "must not be blank"));
+    }
+
+    Ok(name)
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateWishlistDTO {
+    #[validate(required, length(min = 1, max = 64))]
+    #[schema(required = true, min_length = 1, max_length = 64, example = "Birthday")]
+    pub name: Option<String>,
+}
+
+pub struct CreateWishlistCommand {
+    pub name: String,
+}
+
+impl TryFrom<CreateWishlistDTO> for CreateWishlistCommand {
+    type Error = AppError;
+
+    fn try_from(dto: CreateWishlistDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: wishlist_name(dto.name.unwrap())?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateWishlistDTO {
+    #[validate(required, length(min = 1, max = 64))]
+    #[schema(required = true, min_length = 1, max_length = 64, example = "Birthday")]
+    pub name: Option<String>,
+}
+
+pub struct UpdateWishlistCommand {
+    pub name: String,
+}
+
+impl TryFrom<UpdateWishlistDTO> for UpdateWishlistCommand {
+    type Error = AppError;
+
+    fn try_from(dto: UpdateWishlistDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: wishlist_name(dto.name.unwrap())?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct WishlistItemDto {
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub product_id: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct MoveWishlistItemDto {
+    #[validate(required, range(min = 1))]
+    #[schema(required = true, minimum = 1)]
+    pub product_id: Option<i64>,
+
+    /// How many go into the cart, one when empty.
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub quantity: Option<i32>,
+}
@@ -0,0 +1,370 @@
+use crate::app::cart::cart_items::dto::AddItemCommand;
+use crate::app::cart::user_cart::dto::PublicUserCart;
+use crate::app::wishlists::dto::{
+    CreateWishlistCommand, CreateWishlistDTO, MoveWishlistItemDto, PublicSharedWishlist,
+    PublicWishlist, UpdateWishlistCommand, UpdateWishlistDTO, WishlistItemDto,
+};
+use crate::errors::error::AppError;
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
+use crate::state::AppState;
+use crate::utils::extractors::extract_auth_user_id;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use tracing::instrument;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/me/wishlists/list",
+    tag = "wishlists",
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<Vec<PublicWishlist>>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn index(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let wishlists = state.wishlist_service.get_all_public(auth_user_id).await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(wishlists)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/wishlists/get/{id}",
+    tag = "wishlists",
+    params(("id" = i64, Path, description = "Wishlist id")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<PublicWishlist>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn show(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let wishlist = state
+        .wishlist_service
+        .get_one_public(auth_user_id, id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(wishlist)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/wishlists/create",
+    tag = "wishlists",
+    request_body = CreateWishlistDTO,
+    responses(
+        (status = 201, description = "Wishlist created", body = SuccessResponse<PublicWishlist>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Too many wishlists or the name is taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn create(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<CreateWishlistDTO>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = CreateWishlistCommand::try_from(body.into_inner())?;
+    let wishlist = state.wishlist_service.create(auth_user_id, command).await?;
+
+    Ok(HttpResponse::Created().json(SuccessResponse::ok(wishlist)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/me/wishlists/update/{id}",
+    tag = "wishlists",
+    params(("id" = i64, Path, description = "Wishlist id")),
+    request_body = UpdateWishlistDTO,
+    responses(
+        (status = 200, description = "Wishlist renamed", body = SuccessResponse<PublicWishlist>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "The name is taken", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn update(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdateWishlistDTO>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let command = UpdateWishlistCommand::try_from(body.into_inner())?;
+    let wishlist = state
+        .wishlist_service
+        .update(auth_user_id, id.into_inner(), command)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(wishlist)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/wishlists/delete/{id}",
+    tag = "wishlists",
+    params(("id" = i64, Path, description = "Wishlist id")),
+    responses(
+        (status = 204, description = "No content"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn delete(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    state
+        .wishlist_service
+        .delete(auth_user_id, id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/wishlists/items/add/{id}",
+    tag = "wishlists",
+    params(("id" = i64, Path, description = "Wishlist id")),
+    request_body = WishlistItemDto,
+    responses(
+        (status = 200, description = "Product added", body = SuccessResponse<PublicWishlist>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "Wishlist is full", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn add_item(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<WishlistItemDto>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let wishlist = state
+        .wishlist_service
+        .add_item(auth_user_id, id.into_inner(), body.product_id.unwrap())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(wishlist)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/wishlists/items/remove/{id}",
+    tag = "wishlists",
+    params(("id" = i64, Path, description = "Wishlist id")),
+    request_body = WishlistItemDto,
+    responses(
+        (status = 200, description = "Product removed", body = SuccessResponse<PublicWishlist>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn remove_item(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<WishlistItemDto>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let wishlist = state
+        .wishlist_service
+        .remove_item(auth_user_id, id.into_inner(), body.product_id.unwrap())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(wishlist)))
+}
+
+/**
+ * Puts a wishlist item into the cart of the user and takes it off the list.
+ * The product goes in at the price it sells for now, like any cart add.
+ */
+#[utoipa::path(
+    post,
+    path = "/me/wishlists/items/move/{id}",
+    tag = "wishlists",
+    params(("id" = i64, Path, description = "Wishlist id")),
+    request_body = MoveWishlistItemDto,
+    responses(
+        (status = 200, description = "Item moved, responds with the cart", body = SuccessResponse<PublicUserCart>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn move_to_cart(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<MoveWishlistItemDto>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    body.validate()?;
+
+    let cart_id = state
+        .user_cart_service
+        .get_cart_id_by_user(&auth_user_id)
+        .await?;
+
+    state
+        .wishlist_service
+        .move_to_cart(
+            auth_user_id,
+            id.into_inner(),
+            AddItemCommand {
+                product_id: body.product_id.unwrap(),
+                quantity: body.quantity.unwrap_or(1),
+                cart_id,
+            },
+        )
+        .await?;
+    state.metrics.cart_items_added_total.inc();
+
+    let cart = state
+        .user_cart_service
+        .get_cart_by_user(&auth_user_id, None)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(cart)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/wishlists/share/{id}",
+    tag = "wishlists",
+    params(("id" = i64, Path, description = "Wishlist id")),
+    responses(
+        (status = 200, description = "Wishlist shared under a new token", body = SuccessResponse<PublicWishlist>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn share(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let wishlist = state
+        .wishlist_service
+        .share(auth_user_id, id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(wishlist)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/wishlists/share/{id}",
+    tag = "wishlists",
+    params(("id" = i64, Path, description = "Wishlist id")),
+    responses(
+        (status = 200, description = "Share link revoked", body = SuccessResponse<PublicWishlist>),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[instrument(skip_all)]
+pub async fn unshare(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    let wishlist = state
+        .wishlist_service
+        .unshare(auth_user_id, id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(wishlist)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/wishlists/shared/{token}",
+    tag = "wishlists",
+    params(("token" = String, Path, description = "Share token of the wishlist")),
+    responses(
+        (status = 200, description = "Success", body = SuccessResponse<PublicSharedWishlist>),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[instrument(skip_all)]
+pub async fn shared(
+    state: web::Data<AppState>,
+    token: web::Path<String>,
+) -> Result<impl Responder, AppError> {
+    let wishlist = state.wishlist_service.get_shared(&token).await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(wishlist)))
+}
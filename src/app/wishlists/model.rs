@@ -0,0 +1,29 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow, Clone)]
+pub struct WishlistModel {
+    pub id: i64,
+    pub user_id: i64,
+    pub name: String,
+    pub share_token: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/** A wishlist entry joined with the product as it is now. */
+#[derive(Serialize, Deserialize, FromRow, Clone)]
+pub struct WishlistItemModel {
+    pub id: i64,
+    pub wishlist_id: i64,
+    pub product_id: i64,
+    pub name: String,
+    pub slug: String,
+    pub added_price: BigDecimal,
+    pub effective_price: BigDecimal,
+    pub quantity: i32,
+    pub available: bool,
+    pub created_at: DateTime<Utc>,
+}
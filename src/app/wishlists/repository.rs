@@ -0,0 +1,415 @@
+use crate::app::wishlists::alerts::ProductSnapshot;
+use crate::app::wishlists::model::{WishlistItemModel, WishlistModel};
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use bigdecimal::BigDecimal;
+use sqlx::{Executor, PgPool, Postgres};
+use tracing::instrument;
+
+const WISHLIST_COLUMNS: &str = r#"
+    SELECT
+        id,
+        user_id,
+        name,
+        share_token,
+        created_at,
+        updated_at
+    FROM wishlists
+"#;
+
+pub struct WishlistRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for WishlistRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl WishlistRepository {
+    #[instrument(skip_all)]
+    pub async fn list_by_user(&self, user_id: i64) -> Result<Vec<WishlistModel>, AppError> {
+        sqlx::query_as(&format!(
+            "{} WHERE user_id = $1 ORDER BY id;",
+            WISHLIST_COLUMNS
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Wishlist `id` when it belongs to `user_id`. */
+    #[instrument(skip_all)]
+    pub async fn find(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        id: i64,
+    ) -> Result<Option<WishlistModel>, AppError> {
+        sqlx::query_as(&format!(
+            "{} WHERE user_id = $1 AND id = $2;",
+            WISHLIST_COLUMNS
+        ))
+        .bind(user_id)
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn find_by_share_token(
+        &self,
+        share_token: &str,
+    ) -> Result<Option<WishlistModel>, AppError> {
+        sqlx::query_as(&format!("{} WHERE share_token = $1;", WISHLIST_COLUMNS))
+            .bind(share_token)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn count_by_user(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar! {
+            r#"SELECT COUNT(*) AS "count!" FROM wishlists WHERE user_id = $1;"#,
+            user_id
+        }
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Whether another wishlist of the user has `name`, ignoring case. */
+    #[instrument(skip_all)]
+    pub async fn name_taken(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        name: &str,
+        except_id: Option<i64>,
+    ) -> Result<bool, AppError> {
+        sqlx::query_scalar! {
+            r#"
+        SELECT EXISTS(
+            SELECT 1 FROM wishlists
+            WHERE user_id = $1
+                AND lower(name) = lower($2)
+                AND ($3::BIGINT IS NULL OR id <> $3)
+        ) AS "exists!";
+        "#,
+            user_id,
+            name,
+            except_id
+        }
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Locks the user row, so concurrent creates can't both pass the size
+     * limit or claim the same name.
+     */
+    #[instrument(skip_all)]
+    pub async fn lock_user(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+    ) -> Result<(), AppError> {
+        sqlx::query!("SELECT id FROM users WHERE id = $1 FOR UPDATE;", user_id)
+            .fetch_optional(executor)
+            .await
+            .map_err(AppError::Database)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        name: &str,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar! {
+            "INSERT INTO wishlists (user_id, name) VALUES ($1, $2) RETURNING id;",
+            user_id,
+            name
+        }
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn rename(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        id: i64,
+        name: &str,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        UPDATE wishlists SET name = $1, updated_at = now()
+        WHERE user_id = $2 AND id = $3;
+        "#,
+            name,
+            user_id,
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /** Sets or, with `None`, revokes the share token. */
+    #[instrument(skip_all)]
+    pub async fn set_share_token(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        id: i64,
+        share_token: Option<&str>,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        UPDATE wishlists SET share_token = $1, updated_at = now()
+        WHERE user_id = $2 AND id = $3;
+        "#,
+            share_token,
+            user_id,
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        user_id: i64,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM wishlists WHERE user_id = $1 AND id = $2;",
+            user_id,
+            id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /** Items of the given wishlists, the latest added first. */
+    #[instrument(skip_all)]
+    pub async fn get_items(
+        &self,
+        wishlist_ids: &[i64],
+    ) -> Result<Vec<WishlistItemModel>, AppError> {
+        sqlx::query_as! {
+            WishlistItemModel,
+            r#"
+        SELECT
+            wishlist_items.id,
+            wishlist_items.wishlist_id,
+            wishlist_items.product_id,
+            products.name,
+            products.slug,
+            wishlist_items.added_price,
+            product_effective_price(products) AS "effective_price!",
+            (
+                SELECT COALESCE(SUM(warehouse_stock.quantity), 0)::INTEGER
+                FROM warehouse_stock
+                JOIN warehouses ON warehouses.id = warehouse_stock.warehouse_id
+                WHERE warehouse_stock.product_id = products.id AND warehouses.is_active
+            ) AS "quantity!",
+            (
+                products.is_active
+                AND products.deleted_at IS NULL
+                AND (products.publish_at IS NULL OR products.publish_at <= now())
+                AND (products.unpublish_at IS NULL OR products.unpublish_at > now())
+            ) AS "available!",
+            wishlist_items.created_at
+        FROM wishlist_items
+        JOIN products ON products.id = wishlist_items.product_id
+        WHERE wishlist_items.wishlist_id = ANY($1)
+        ORDER BY wishlist_items.created_at DESC, wishlist_items.id DESC;
+        "#,
+            wishlist_ids
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn count_items(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        wishlist_id: i64,
+    ) -> Result<i64, AppError> {
+        sqlx::query_scalar! {
+            r#"SELECT COUNT(*) AS "count!" FROM wishlist_items WHERE wishlist_id = $1;"#,
+            wishlist_id
+        }
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /**
+     * Adds a product with the price it sells for now, adding one already on
+     * the list changes nothing.
+     */
+    #[instrument(skip_all)]
+    pub async fn add_item(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        wishlist_id: i64,
+        product_id: i64,
+        added_price: &BigDecimal,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            r#"
+        INSERT INTO wishlist_items (wishlist_id, product_id, added_price)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (wishlist_id, product_id) DO NOTHING;
+        "#,
+            wishlist_id,
+            product_id,
+            added_price
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn remove_item(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        wishlist_id: i64,
+        product_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query! {
+            "DELETE FROM wishlist_items WHERE wishlist_id = $1 AND product_id = $2;",
+            wishlist_id,
+            product_id
+        }
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn has_item(&self, wishlist_id: i64, product_id: i64) -> Result<bool, AppError> {
+        sqlx::query_scalar! {
+            r#"
+        SELECT EXISTS(
+            SELECT 1 FROM wishlist_items WHERE wishlist_id = $1 AND product_id = $2
+        ) AS "exists!";
+        "#,
+            wishlist_id,
+            product_id
+        }
+        .fetch_one(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Bumps `updated_at` after the items of a wishlist change. */
+    #[instrument(skip_all)]
+    pub async fn touch(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        id: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query!("UPDATE wishlists SET updated_at = now() WHERE id = $1;", id)
+            .execute(executor)
+            .await
+            .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+
+    /** Products as alerts compare them, in id order, missing ones left out. */
+    #[instrument(skip_all)]
+    pub async fn get_product_snapshots(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        product_ids: &[i64],
+    ) -> Result<Vec<ProductSnapshot>, AppError> {
+        sqlx::query_as! {
+            ProductSnapshot,
+            r#"
+        SELECT
+            id,
+            name,
+            product_effective_price(products) AS "price!",
+            (
+                SELECT COALESCE(SUM(warehouse_stock.quantity), 0)::INTEGER
+                FROM warehouse_stock
+                JOIN warehouses ON warehouses.id = warehouse_stock.warehouse_id
+                WHERE warehouse_stock.product_id = products.id AND warehouses.is_active
+            ) AS "quantity!",
+            (
+                is_active
+                AND deleted_at IS NULL
+                AND (publish_at IS NULL OR publish_at <= now())
+                AND (unpublish_at IS NULL OR unpublish_at > now())
+            ) AS "available!"
+        FROM products
+        WHERE id = ANY($1)
+        ORDER BY id;
+        "#,
+            product_ids
+        }
+        .fetch_all(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** Users with `product_id` on any of their wishlists. */
+    #[instrument(skip_all)]
+    pub async fn watchers(&self, product_id: i64) -> Result<Vec<i64>, AppError> {
+        sqlx::query_scalar! {
+            r#"
+        SELECT DISTINCT wishlists.user_id
+        FROM wishlist_items
+        JOIN wishlists ON wishlists.id = wishlist_items.wishlist_id
+        WHERE wishlist_items.product_id = $1
+        ORDER BY wishlists.user_id;
+        "#,
+            product_id
+        }
+        .fetch_all(&self.pool)
+        .await
+        .map_err(AppError::Database)
+    }
+}
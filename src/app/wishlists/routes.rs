@@ -0,0 +1,25 @@
+use crate::app::wishlists::handler;
+use crate::middlewares::auth::AuthMiddleware;
+use actix_web::web;
+use actix_web::web::{delete, get, post, put, resource};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/me/wishlists")
+            .wrap(AuthMiddleware::new(None))
+            .service(resource("/list").route(get().to(handler::index)))
+            .service(resource("/get/{id}").route(get().to(handler::show)))
+            .service(resource("/create").route(post().to(handler::create)))
+            .service(resource("/update/{id}").route(put().to(handler::update)))
+            .service(resource("/delete/{id}").route(delete().to(handler::delete)))
+            .service(resource("/items/add/{id}").route(post().to(handler::add_item)))
+            .service(resource("/items/remove/{id}").route(delete().to(handler::remove_item)))
+            .service(resource("/items/move/{id}").route(post().to(handler::move_to_cart)))
+            .service(
+                resource("/share/{id}")
+                    .route(post().to(handler::share))
+                    .route(delete().to(handler::unshare)),
+            ),
+    )
+    .service(resource("/wishlists/shared/{token}").route(get().to(handler::shared)));
+}
@@ -0,0 +1,298 @@
+use crate::app::cart::cart_items::dto::AddItemCommand;
+use crate::app::cart::cart_items::service::CartItemsService;
+use crate::app::products::repository::ProductRepository;
+use crate::app::wishlists::dto::{
+    CreateWishlistCommand, PublicSharedWishlist, PublicWishlist, UpdateWishlistCommand,
+};
+use crate::app::wishlists::model::{WishlistItemModel, WishlistModel};
+use crate::app::wishlists::repository::WishlistRepository;
+use crate::config::settings::InventoryConfig;
+use crate::errors::error::AppError;
+use crate::utils::traits::IsRepository;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/** Most wishlists one user can keep. */
+const MAX_WISHLISTS: i64 = 20;
+
+/** Most products one wishlist holds. */
+const MAX_ITEMS: i64 = 100;
+
+pub struct WishlistService {
+    repository: WishlistRepository,
+    product_repository: ProductRepository,
+    cart_items_service: CartItemsService,
+}
+
+impl WishlistService {
+    pub fn new(pool: PgPool, inventory_config: InventoryConfig) -> Self {
+        Self {
+            repository: WishlistRepository::new(pool.clone()),
+            product_repository: ProductRepository::new(pool.clone()),
+            cart_items_service: CartItemsService::new(pool, inventory_config),
+        }
+    }
+
+    pub async fn get_all_public(&self, user_id: i64) -> Result<Vec<PublicWishlist>, AppError> {
+        let wishlists = self.repository.list_by_user(user_id).await?;
+
+        let ids: Vec<i64> = wishlists.iter().map(|wishlist| wishlist.id).collect();
+        let mut items: HashMap<i64, Vec<WishlistItemModel>> = HashMap::new();
+
+        for item in self.repository.get_items(&ids).await? {
+            items.entry(item.wishlist_id).or_default().push(item);
+        }
+
+        Ok(wishlists
+            .into_iter()
+            .map(|wishlist| {
+                let own = items.remove(&wishlist.id).unwrap_or_default();
+
+                PublicWishlist::new(wishlist, own)
+            })
+            .collect())
+    }
+
+    pub async fn get_one(&self, user_id: i64, id: i64) -> Result<WishlistModel, AppError> {
+        self.repository
+            .find(self.repository.get_pool(), user_id, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Wishlist not found".to_string()))
+    }
+
+    pub async fn get_one_public(&self, user_id: i64, id: i64) -> Result<PublicWishlist, AppError> {
+        let wishlist = self.get_one(user_id, id).await?;
+
+        self.with_items(wishlist).await
+    }
+
+    /** A wishlist through its share link, revoked links are not found. */
+    pub async fn get_shared(&self, share_token: &str) -> Result<PublicSharedWishlist, AppError> {
+        let wishlist = self
+            .repository
+            .find_by_share_token(share_token)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Wishlist not found".to_string()))?;
+
+        Ok(self.with_items(wishlist).await?.into())
+    }
+
+    async fn with_items(&self, wishlist: WishlistModel) -> Result<PublicWishlist, AppError> {
+        let items = self.repository.get_items(&[wishlist.id]).await?;
+
+        Ok(PublicWishlist::new(wishlist, items))
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i64,
+        cmd: CreateWishlistCommand,
+    ) -> Result<PublicWishlist, AppError> {
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.lock_user(&mut *tx, user_id).await?;
+
+        let count = self.repository.count_by_user(&mut *tx, user_id).await?;
+
+        if count >= MAX_WISHLISTS {
+            return Err(AppError::Conflict(format!(
+                "A user can keep at most {} wishlists",
+                MAX_WISHLISTS
+            )));
+        }
+
+        if self
+            .repository
+            .name_taken(&mut *tx, user_id, &cmd.name, None)
+            .await?
+        {
+            return Err(AppError::Conflict(
+                "Wishlist with the same name already exists".to_string(),
+            ));
+        }
+
+        let id = self.repository.create(&mut *tx, user_id, &cmd.name).await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.get_one_public(user_id, id).await
+    }
+
+    pub async fn update(
+        &self,
+        user_id: i64,
+        id: i64,
+        cmd: UpdateWishlistCommand,
+    ) -> Result<PublicWishlist, AppError> {
+        self.get_one(user_id, id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.lock_user(&mut *tx, user_id).await?;
+
+        if self
+            .repository
+            .name_taken(&mut *tx, user_id, &cmd.name, Some(id))
+            .await?
+        {
+            return Err(AppError::Conflict(
+                "Wishlist with the same name already exists".to_string(),
+            ));
+        }
+
+        self.repository
+            .rename(&mut *tx, user_id, id, &cmd.name)
+            .await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.get_one_public(user_id, id).await
+    }
+
+    pub async fn delete(&self, user_id: i64, id: i64) -> Result<u64, AppError> {
+        let deleted = self
+            .repository
+            .delete(self.repository.get_pool(), user_id, id)
+            .await?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Wishlist not found".to_string()));
+        }
+
+        Ok(deleted)
+    }
+
+    /**
+     * Adds a product that can be bought right now, remembering its price.
+     * Adding one that is already on the list keeps the original price.
+     */
+    pub async fn add_item(
+        &self,
+        user_id: i64,
+        id: i64,
+        product_id: i64,
+    ) -> Result<PublicWishlist, AppError> {
+        self.get_one(user_id, id).await?;
+
+        let price = self
+            .product_repository
+            .get_effective_price(product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("product not found".to_string()))?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository.lock_user(&mut *tx, user_id).await?;
+
+        let count = self.repository.count_items(&mut *tx, id).await?;
+
+        if count >= MAX_ITEMS && !self.repository.has_item(id, product_id).await? {
+            return Err(AppError::Conflict(format!(
+                "A wishlist holds at most {} products",
+                MAX_ITEMS
+            )));
+        }
+
+        let added = self
+            .repository
+            .add_item(&mut *tx, id, product_id, &price)
+            .await?;
+
+        if added > 0 {
+            self.repository.touch(&mut *tx, id).await?;
+        }
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.get_one_public(user_id, id).await
+    }
+
+    /**
+     * Takes a product off wishlist `id` and adds it to the cart in `cmd`,
+     * both or neither. The cart add works like any other.
+     */
+    pub async fn move_to_cart(
+        &self,
+        user_id: i64,
+        id: i64,
+        cmd: AddItemCommand,
+    ) -> Result<(), AppError> {
+        self.get_one(user_id, id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let removed = self
+            .repository
+            .remove_item(&mut *tx, id, cmd.product_id)
+            .await?;
+
+        if removed == 0 {
+            return Err(AppError::NotFound(
+                "product not found in wishlist".to_string(),
+            ));
+        }
+
+        self.cart_items_service.add_item_on(&mut tx, &cmd).await?;
+
+        self.repository.touch(&mut *tx, id).await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.cart_items_service.refresh_total(cmd.cart_id).await
+    }
+
+    pub async fn remove_item(
+        &self,
+        user_id: i64,
+        id: i64,
+        product_id: i64,
+    ) -> Result<PublicWishlist, AppError> {
+        self.get_one(user_id, id).await?;
+
+        let mut tx = self.repository.start_transaction().await?;
+
+        let removed = self
+            .repository
+            .remove_item(&mut *tx, id, product_id)
+            .await?;
+
+        if removed == 0 {
+            return Err(AppError::NotFound(
+                "product not found in wishlist".to_string(),
+            ));
+        }
+
+        self.repository.touch(&mut *tx, id).await?;
+
+        self.repository.commit_transaction(tx).await?;
+
+        self.get_one_public(user_id, id).await
+    }
+
+    /**
+     * Gives the wishlist a new share link. Sharing it again replaces the
+     * token, so earlier links stop working.
+     */
+    pub async fn share(&self, user_id: i64, id: i64) -> Result<PublicWishlist, AppError> {
+        self.get_one(user_id, id).await?;
+
+        let share_token = Uuid::new_v4().simple().to_string();
+
+        self.repository
+            .set_share_token(self.repository.get_pool(), user_id, id, Some(&share_token))
+            .await?;
+
+        self.get_one_public(user_id, id).await
+    }
+
+    pub async fn unshare(&self, user_id: i64, id: i64) -> Result<PublicWishlist, AppError> {
+        self.get_one(user_id, id).await?;
+
+        self.repository
+            .set_share_token(self.repository.get_pool(), user_id, id, None)
+            .await?;
+
+        self.get_one_public(user_id, id).await
+    }
+}
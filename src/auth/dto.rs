@@ -4,22 +4,27 @@ use crate::errors::error::AppError;
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::{Validate, ValidationError};
 
-#[derive(Deserialize, Validate)]
+#[derive(Deserialize, Validate, ToSchema)]
 #[validate(schema(function = "passwords_match"))]
 pub struct RegisterDTO {
     #[validate(required, length(min = 3))]
+    #[schema(required = true, min_length = 3)]
     pub username: Option<String>,
 
     #[validate(required, email)]
+    #[schema(required = true, format = Email)]
     pub email: Option<String>,
 
     #[validate(required, length(min = 6))]
+    #[schema(required = true, min_length = 6)]
     pub password: Option<String>,
 
     #[validate(required, length(min = 6))]
+    #[schema(required = true, min_length = 6)]
     pub password_confirmation: Option<String>,
 }
 
@@ -52,12 +57,14 @@ impl TryFrom<RegisterDTO> for RegisterCommand {
     }
 }
 
-#[derive(Serialize, Deserialize, Validate)]
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
 pub struct LoginDTO {
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub email: Option<String>,
 
     #[validate(required, length(min = 1))]
+    #[schema(required = true, min_length = 1)]
     pub password: Option<String>,
 }
 
@@ -77,6 +84,21 @@ impl TryFrom<LoginDTO> for LoginCommand {
     }
 }
 
+pub struct NewLoginAttempt<'a> {
+    pub email: &'a str,
+    pub user_id: Option<i64>,
+    pub ip: &'a str,
+    pub successful: bool,
+}
+
+pub struct NewAccountLockout<'a> {
+    pub email: &'a str,
+    pub user_id: Option<i64>,
+    pub ip: &'a str,
+    pub failed_attempts: i64,
+    pub locked_until: DateTime<Utc>,
+}
+
 #[derive(Debug)]
 pub struct AuthToken {
     pub token: String,
@@ -86,13 +108,13 @@ pub struct AuthToken {
 }
 
 impl AuthToken {
-    pub fn new(user_id: &i64, scopes: HashSet<String>) -> Self {
+    pub fn new(user_id: &i64, scopes: HashSet<String>, ttl: Duration) -> Self {
         let token = Uuid::new_v4().to_string();
 
         AuthToken {
             token,
             user_id: user_id.clone(),
-            expires_at: Utc::now() + Duration::days(2),
+            expires_at: Utc::now() + ttl,
             scopes,
         }
     }
@@ -106,7 +128,7 @@ impl AuthToken {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct PublicAuthToken {
     pub token: String,
     pub expires_at: DateTime<Utc>,
@@ -130,7 +152,7 @@ impl From<AuthTokenModel> for PublicAuthToken {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct PublicUser {
     pub id: i64,
     pub username: String,
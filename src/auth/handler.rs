@@ -1,10 +1,39 @@
-use crate::auth::dto::{LoginCommand, LoginDTO, PublicUser, RegisterCommand, RegisterDTO};
+use crate::auth::dto::{
+    LoginCommand, LoginDTO, PublicAuthToken, PublicUser, RegisterCommand, RegisterDTO,
+};
+use crate::auth::permission::AccountScope;
 use crate::errors::error::AppError;
+use crate::middlewares::auth::AuthMiddleware;
+use crate::middlewares::deprecation::{Deprecation, DeprecationMiddleware};
+use crate::middlewares::rate_limit::RateLimitMiddleware;
+use crate::rate_limit::policy::{RateLimitKey, RateLimitPolicy};
+use crate::responses::error_responses::{ErrorResponse, SuccessResponse};
 use crate::state::AppState;
-use actix_web::{HttpResponse, Responder, post, web};
+use crate::utils::extractors::{extract_auth_user_id, extract_client_ip};
+use actix_web::{HttpRequest, HttpResponse, Responder, post, web};
+use std::sync::Arc;
+use tracing::instrument;
 use validator::Validate;
 
-#[post("/auth/register")]
+#[utoipa::path(
+    post,
+    path = "/auth/register",
+    tag = "auth",
+    request_body = RegisterDTO,
+    responses(
+        (status = 201, description = "User registered", body = PublicUser),
+        (status = 400, description = "Invalid request", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 409, description = "User already exists", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[post(
+    "/auth/register",
+    wrap = "RateLimitMiddleware::new(RateLimitPolicy::Register, RateLimitKey::Ip)"
+)]
+#[instrument(skip_all)]
 pub async fn register(
     state: web::Data<AppState>,
     body: web::Json<RegisterDTO>,
@@ -16,14 +45,117 @@ pub async fn register(
     Ok(HttpResponse::Created().json(PublicUser::from(user)))
 }
 
-#[post("/auth/login")]
+/**
+ * v1 login answers with a bare token instead of the usual `SuccessResponse`
+ * envelope; v2 fixes that, so v1 is deprecated and sunsets six months later.
+ */
+pub const LOGIN_V1_DEPRECATION: Deprecation = Deprecation {
+    since: 1792368000,
+    sunset: Some(1808092800),
+    successor: Some("/api/v2/auth/login"),
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "auth",
+    description = "Deprecated in favour of `/api/v2/auth/login`.",
+    request_body = LoginDTO,
+    responses(
+        (status = 200, description = "Access token issued", body = PublicAuthToken),
+        (status = 401, description = "Invalid credentials", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 423, description = "Account locked", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[post(
+    "/auth/login",
+    wrap = "RateLimitMiddleware::new(RateLimitPolicy::Login, RateLimitKey::Ip)",
+    wrap = "DeprecationMiddleware::new(LOGIN_V1_DEPRECATION)"
+)]
+#[instrument(skip_all)]
 pub async fn login(
+    request: HttpRequest,
     state: web::Data<AppState>,
     body: web::Json<LoginDTO>,
 ) -> Result<impl Responder, AppError> {
+    let auth_token = issue_token(&request, &state, body.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(auth_token))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "auth",
+    request_body = LoginDTO,
+    responses(
+        (status = 200, description = "Access token issued", body = SuccessResponse<PublicAuthToken>),
+        (status = 401, description = "Invalid credentials", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 423, description = "Account locked", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 429, description = "Too many requests", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+#[post(
+    "/auth/login",
+    wrap = "RateLimitMiddleware::new(RateLimitPolicy::Login, RateLimitKey::Ip)"
+)]
+#[instrument(skip_all)]
+pub async fn login_v2(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<LoginDTO>,
+) -> Result<impl Responder, AppError> {
+    let auth_token = issue_token(&request, &state, body.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(SuccessResponse::ok(auth_token)))
+}
+
+async fn issue_token(
+    request: &HttpRequest,
+    state: &AppState,
+    body: LoginDTO,
+) -> Result<PublicAuthToken, AppError> {
     body.validate()?;
 
-    let command = LoginCommand::try_from(body.into_inner())?;
-    let auth_token = state.auth_service.login(command).await?;
-    Ok(HttpResponse::Ok().json(auth_token))
+    let client_ip = extract_client_ip(request, state.config.server.trust_forwarded_for);
+
+    let command = LoginCommand::try_from(body)?;
+    state.auth_service.login(command, &client_ip).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/lockouts/{user_id}/unlock",
+    tag = "auth",
+    params(("user_id" = i64, Path, description = "User id")),
+    responses(
+        (status = 200, description = "Account unlocked"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 403, description = "Missing required scope", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 404, description = "Resource not found", body = ErrorResponse, content_type = "application/problem+json"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = ["users:unlock"])),
+)]
+#[post(
+    "/auth/lockouts/{user_id}/unlock",
+    wrap = "AuthMiddleware::new(Some(Arc::new(AccountScope::Unlock)))"
+)]
+#[instrument(skip_all)]
+pub async fn unlock(
+    request: HttpRequest,
+    state: web::Data<AppState>,
+    user_id: web::Path<i64>,
+) -> Result<impl Responder, AppError> {
+    let auth_user_id = extract_auth_user_id(&request)?;
+
+    state
+        .auth_service
+        .unlock_account(user_id.into_inner(), auth_user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::<()>::empty()))
 }
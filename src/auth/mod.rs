@@ -1,6 +1,7 @@
 pub mod dto;
-mod handler;
+pub(crate) mod handler;
 mod model;
+pub mod permission;
 pub mod repository;
 pub mod routes;
 pub mod service;
@@ -0,0 +1,17 @@
+use crate::auth::traits::Scope;
+
+pub enum AccountScope {
+    Unlock,
+}
+
+impl Scope for AccountScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AccountScope::Unlock => "users:unlock",
+        }
+    }
+
+    fn all() -> Vec<Self> {
+        vec![AccountScope::Unlock]
+    }
+}
@@ -1,9 +1,11 @@
 use super::model::{AuthTokenModel, UserModel};
-use crate::auth::dto::{AuthToken, NewUser};
+use crate::auth::dto::{AuthToken, NewAccountLockout, NewLoginAttempt, NewUser};
 use crate::errors::error::AppError;
+use chrono::{DateTime, Utc};
 use sqlx::types::Json;
 use sqlx::{Executor, Postgres};
 use std::collections::HashSet;
+use tracing::instrument;
 
 #[derive(Clone)]
 pub struct AuthRepository;
@@ -13,6 +15,7 @@ impl AuthRepository {
         Self
     }
 
+    #[instrument(skip_all)]
     pub async fn register<'e, E>(&self, executor: E, user: NewUser) -> Result<UserModel, AppError>
     where
         E: Executor<'e, Database = Postgres>,
@@ -30,6 +33,7 @@ impl AuthRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn find_by_email<'e, E>(
         &self,
         executor: E,
@@ -48,6 +52,7 @@ impl AuthRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn save_token<'e, E>(
         &self,
         executor: E,
@@ -81,6 +86,7 @@ impl AuthRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_token<'e, E>(
         &self,
         executor: E,
@@ -108,6 +114,7 @@ impl AuthRepository {
         .map_err(AppError::Database)
     }
 
+    #[instrument(skip_all)]
     pub async fn get_token_by_user_id<'e, E>(
         &self,
         executor: E,
@@ -135,4 +142,169 @@ impl AuthRepository {
         .await
         .map_err(AppError::Database)
     }
+
+    #[instrument(skip_all)]
+    pub async fn record_login_attempt<'e, E>(
+        &self,
+        executor: E,
+        attempt: NewLoginAttempt<'_>,
+    ) -> Result<(), AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+        INSERT INTO login_attempts (email, user_id, ip, successful)
+        VALUES ($1, $2, $3, $4);
+        "#,
+            attempt.email,
+            attempt.user_id,
+            attempt.ip,
+            attempt.successful,
+        )
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(())
+    }
+
+    /**
+     * Counts failures for an email since `since`, ignoring the ones that happened
+     * before the last successful login or the last lockout of the email.
+     */
+    #[instrument(skip_all)]
+    pub async fn count_failed_attempts_by_email<'e, E>(
+        &self,
+        executor: E,
+        email: &str,
+        since: DateTime<Utc>,
+    ) -> Result<i64, AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query_scalar!(
+            r#"
+        SELECT COUNT(*) AS "count!"
+        FROM login_attempts
+        WHERE email = $1
+          AND successful = false
+          AND created_at > $2
+          AND created_at > COALESCE(
+              (SELECT MAX(created_at) FROM login_attempts WHERE email = $1 AND successful = true),
+              '-infinity'
+          )
+          AND created_at > COALESCE(
+              (SELECT MAX(COALESCE(unlocked_at, locked_at)) FROM account_lockouts WHERE email = $1),
+              '-infinity'
+          );
+        "#,
+            email,
+            since,
+        )
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn count_failed_attempts_by_ip<'e, E>(
+        &self,
+        executor: E,
+        ip: &str,
+        since: DateTime<Utc>,
+    ) -> Result<i64, AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query_scalar!(
+            r#"
+        SELECT COUNT(*) AS "count!"
+        FROM login_attempts
+        WHERE ip = $1 AND successful = false AND created_at > $2;
+        "#,
+            ip,
+            since,
+        )
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn create_lockout<'e, E>(
+        &self,
+        executor: E,
+        lockout: NewAccountLockout<'_>,
+    ) -> Result<DateTime<Utc>, AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query_scalar! {
+            r#"
+        INSERT INTO account_lockouts (email, user_id, ip, failed_attempts, locked_until)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING locked_until;
+        "#,
+            lockout.email,
+            lockout.user_id,
+            lockout.ip,
+            lockout.failed_attempts,
+            lockout.locked_until,
+        }
+        .fetch_one(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    /** When the active lockout of an email ends, `None` when it isn't locked. */
+    #[instrument(skip_all)]
+    pub async fn get_locked_until<'e, E>(
+        &self,
+        executor: E,
+        email: &str,
+    ) -> Result<Option<DateTime<Utc>>, AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query_scalar! {
+            r#"
+        SELECT locked_until
+        FROM account_lockouts
+        WHERE email = $1 AND unlocked_at IS NULL AND locked_until > now()
+        ORDER BY locked_until DESC
+        LIMIT 1;
+        "#,
+            email,
+        }
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)
+    }
+
+    #[instrument(skip_all)]
+    pub async fn unlock<'e, E>(
+        &self,
+        executor: E,
+        user_id: i64,
+        unlocked_by: i64,
+    ) -> Result<u64, AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let result = sqlx::query!(
+            r#"
+        UPDATE account_lockouts
+        SET unlocked_at = now(), unlocked_by = $2
+        WHERE user_id = $1 AND unlocked_at IS NULL AND locked_until > now();
+        "#,
+            user_id,
+            unlocked_by,
+        )
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
 }
@@ -2,5 +2,11 @@ use super::handler;
 use actix_web::web;
 
 pub fn routes(cfg: &mut web::ServiceConfig) {
-    cfg.service(handler::register).service(handler::login);
+    cfg.service(handler::register)
+        .service(handler::login)
+        .service(handler::unlock);
+}
+
+pub fn routes_v2(cfg: &mut web::ServiceConfig) {
+    cfg.service(handler::login_v2);
 }
@@ -1,26 +1,34 @@
 use crate::app::roles::dto::RoleEnum;
 use crate::app::roles::service::RoleService;
-use crate::auth::dto::{AuthToken, LoginCommand, NewUser, PublicAuthToken, RegisterCommand};
+use crate::auth::dto::{
+    AuthToken, LoginCommand, NewAccountLockout, NewLoginAttempt, NewUser, PublicAuthToken,
+    RegisterCommand,
+};
 use crate::auth::model::{AuthTokenModel, UserModel};
 use crate::auth::repository::AuthRepository;
+use crate::config::settings::AuthConfig;
 use crate::errors::error::AppError;
 use argon2::password_hash::phc::PasswordHash;
 use argon2::{Argon2, PasswordVerifier, password_hash::PasswordHasher};
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
+use tracing::warn;
 
 #[derive(Clone)]
 pub struct AuthService {
     pub pool: PgPool,
     pub repository: AuthRepository,
     pub roles_service: RoleService,
+    pub config: AuthConfig,
 }
 
 impl AuthService {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, config: AuthConfig) -> Self {
         Self {
             pool: pool.clone(),
             repository: AuthRepository::new(),
             roles_service: RoleService::new(pool),
+            config,
         }
     }
 
@@ -54,24 +62,135 @@ impl AuthService {
         }
     }
 
-    pub async fn login(&self, cmd: LoginCommand) -> Result<PublicAuthToken, AppError> {
+    /**
+     * Checks the credentials while tracking failures per email and per ip.
+     * Repeated failures slow every further attempt down and eventually lock the email,
+     * whether or not an account uses it, so the answers don't tell which accounts exist.
+     */
+    pub async fn login(
+        &self,
+        cmd: LoginCommand,
+        client_ip: &str,
+    ) -> Result<PublicAuthToken, AppError> {
+        let protection = &self.config.login_protection;
+        let since = Utc::now() - protection.window();
+
+        let ip_failures = self
+            .repository
+            .count_failed_attempts_by_ip(&self.pool, client_ip, since)
+            .await?;
+
+        if ip_failures >= protection.max_failed_attempts_per_ip {
+            return Err(AppError::Locked(
+                "too many failed login attempts, try again later".to_string(),
+            ));
+        }
+
         let user = self
             .repository
             .find_by_email(&self.pool, &cmd.email)
+            .await?;
+
+        let user_id = user.as_ref().map(|user| user.id);
+
+        if let Some(locked_until) = self
+            .repository
+            .get_locked_until(&self.pool, &cmd.email)
             .await?
-            .ok_or_else(|| AppError::Unauthorized("wrong credentials".to_string()))?;
+        {
+            return Err(locked(locked_until));
+        }
+
+        let account_failures = self
+            .repository
+            .count_failed_attempts_by_email(&self.pool, &cmd.email, since)
+            .await?;
 
-        let valid_password = verify_password(&cmd.password, &user.password)?;
+        let delay = protection.delay_for(account_failures.max(ip_failures));
 
-        if !valid_password {
-            return Err(AppError::Unauthorized("wrong credentials".to_string()));
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
         }
 
+        let valid_password = match &user {
+            Some(user) => verify_password(&cmd.password, &user.password)?,
+            None => false,
+        };
+
+        self.repository
+            .record_login_attempt(
+                &self.pool,
+                NewLoginAttempt {
+                    email: &cmd.email,
+                    user_id,
+                    ip: client_ip,
+                    successful: valid_password,
+                },
+            )
+            .await?;
+
+        let Some(user) = user.filter(|_| valid_password) else {
+            if account_failures + 1 >= protection.max_failed_attempts {
+                let locked_until = self
+                    .lock_account(&cmd.email, user_id, client_ip, account_failures + 1)
+                    .await?;
+
+                return Err(locked(locked_until));
+            }
+
+            return Err(AppError::Unauthorized("wrong credentials".to_string()));
+        };
+
         let auth_token = self.get_auth_token(user.id).await?;
 
         Ok(auth_token)
     }
 
+    async fn lock_account(
+        &self,
+        email: &str,
+        user_id: Option<i64>,
+        client_ip: &str,
+        failed_attempts: i64,
+    ) -> Result<DateTime<Utc>, AppError> {
+        let locked_until = self
+            .repository
+            .create_lockout(
+                &self.pool,
+                NewAccountLockout {
+                    email,
+                    user_id,
+                    ip: client_ip,
+                    failed_attempts,
+                    locked_until: Utc::now() + self.config.login_protection.lockout_duration(),
+                },
+            )
+            .await?;
+
+        warn!(
+            user_id,
+            ip = client_ip,
+            failed_attempts,
+            locked_until = %locked_until,
+            "account locked after repeated failed logins"
+        );
+
+        Ok(locked_until)
+    }
+
+    pub async fn unlock_account(&self, user_id: i64, unlocked_by: i64) -> Result<(), AppError> {
+        let unlocked = self
+            .repository
+            .unlock(&self.pool, user_id, unlocked_by)
+            .await?;
+
+        if unlocked == 0 {
+            return Err(AppError::NotFound("no active lockout for user".to_string()));
+        }
+
+        Ok(())
+    }
+
     async fn get_auth_token(&self, user_id: i64) -> Result<PublicAuthToken, AppError> {
         let check_token = self
             .repository
@@ -83,7 +202,11 @@ impl AuthService {
             _ => {
                 let user_role = self.roles_service.get_user_role(&user_id).await?;
 
-                let auth_token = AuthToken::new(&user_id, user_role.get_scopes());
+                let auth_token = AuthToken::new(
+                    &user_id,
+                    user_role.get_scopes(),
+                    self.config.access_token_ttl(),
+                );
 
                 AuthToken::from(self.repository.save_token(&self.pool, &auth_token).await?)
             }
@@ -100,6 +223,13 @@ impl AuthService {
     }
 }
 
+fn locked(locked_until: DateTime<Utc>) -> AppError {
+    AppError::Locked(format!(
+        "account is locked until {}",
+        locked_until.to_rfc3339()
+    ))
+}
+
 fn hash_password(password: &str) -> Result<String, AppError> {
     let argon2 = Argon2::default();
 
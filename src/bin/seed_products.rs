@@ -1,17 +1,17 @@
-use dotenvy::from_filename;
+use ecomm::config::settings::Config;
 use ecomm::db::seeders;
-use sqlx::PgPool;
-use std::env;
+use ecomm::utils::telemetry;
 
 #[tokio::main]
 async fn main() -> Result<(), sqlx::Error> {
-    from_filename(".env.dev").ok();
-    env_logger::try_init().ok();
+    let config = Config::load().expect("Failed to load configuration");
+    telemetry::init(&config.logging).ok();
 
     println!("Start seeding products...");
 
-    let database_url = env::var("DEV_DATABASE_URL").expect("DEV_DATABASE_URL must be set");
-    let pool = PgPool::connect(&database_url)
+    let pool = config
+        .database
+        .connect()
         .await
         .expect("Failed to create pool");
 
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to load configuration: {0}")]
+    Load(#[from] ::config::ConfigError),
+
+    #[error("invalid configuration: {}", .0.join("; "))]
+    Invalid(Vec<String>),
+
+    #[error("unknown profile: {0}")]
+    UnknownProfile(String),
+}
@@ -0,0 +1,752 @@
+use crate::config::error::ConfigError;
+use ::config::{Environment, File};
+use actix_cors::Cors;
+use actix_web::http::Method;
+use chrono::Duration;
+use dotenvy::from_filename;
+use serde::Deserialize;
+use sqlx::PgPool;
+use sqlx::postgres::PgPoolOptions;
+use std::env;
+use std::str::FromStr;
+
+const PROFILE_ENV: &str = "APP_PROFILE";
+const ENV_PREFIX: &str = "APP";
+const ENV_SEPARATOR: &str = "__";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Profile {
+    #[default]
+    Dev,
+    Test,
+    Prod,
+}
+
+impl Profile {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Dev => "dev",
+            Profile::Test => "test",
+            Profile::Prod => "prod",
+        }
+    }
+
+    /**
+     * Reads the active profile from `APP_PROFILE`, falling back to `dev`.
+     */
+    pub fn from_env() -> Result<Self, ConfigError> {
+        match env::var(PROFILE_ENV) {
+            Ok(profile) => Profile::from_str(&profile),
+            Err(_) => Ok(Profile::default()),
+        }
+    }
+}
+
+impl FromStr for Profile {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dev" | "development" => Ok(Profile::Dev),
+            "test" => Ok(Profile::Test),
+            "prod" | "production" => Ok(Profile::Prod),
+            _ => Err(ConfigError::UnknownProfile(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    #[serde(skip)]
+    pub profile: Profile,
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub auth: AuthConfig,
+    pub storage: StorageConfig,
+    pub uploads: UploadConfig,
+    pub cors: CorsConfig,
+    pub logging: LoggingConfig,
+    pub rate_limit: RateLimitConfig,
+    pub cache: CacheConfig,
+    pub pagination: PaginationConfig,
+    pub tax: TaxConfig,
+    pub inventory: InventoryConfig,
+    pub notifications: NotificationsConfig,
+}
+
+impl Config {
+    /**
+     * Loads the configuration for the profile selected through `APP_PROFILE`.
+     */
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::load_profile(Profile::from_env()?)
+    }
+
+    /**
+     * Loads and validates the configuration for the given profile.
+     *
+     * Layers, from lowest to highest priority: built-in defaults, `config/default.toml`,
+     * `config/{profile}.toml`, then `APP__SECTION__KEY` environment variables
+     * (a `.env.{profile}` file is read into the environment first).
+     */
+    pub fn load_profile(profile: Profile) -> Result<Self, ConfigError> {
+        from_filename(format!(".env.{}", profile.as_str())).ok();
+
+        let mut config: Config = ::config::Config::builder()
+            .add_source(File::with_name("config/default").required(false))
+            .add_source(File::with_name(&format!("config/{}", profile.as_str())).required(false))
+            .add_source(
+                Environment::with_prefix(ENV_PREFIX)
+                    .separator(ENV_SEPARATOR)
+                    .try_parsing(true)
+                    .list_separator(",")
+                    .with_list_parse_key("cors.allowed_origins")
+                    .with_list_parse_key("cors.allowed_methods")
+                    .with_list_parse_key("cors.allowed_headers"),
+            )
+            .build()?
+            .try_deserialize()?;
+
+        config.profile = profile;
+
+        // keep supporting the legacy `DEV_DATABASE_URL` style variables
+        if config.database.url.is_empty() {
+            let legacy_key = format!("{}_DATABASE_URL", profile.as_str().to_uppercase());
+
+            if let Ok(url) = env::var(legacy_key) {
+                config.database.url = url;
+            }
+        }
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+
+        self.server.validate(&mut errors);
+        self.database.validate(&mut errors);
+        self.auth.validate(&mut errors);
+        self.storage.validate(&mut errors);
+        self.uploads.validate(&mut errors);
+        self.cors.validate(&mut errors);
+        self.logging.validate(&mut errors);
+        self.rate_limit.validate(&mut errors);
+        self.cache.validate(&mut errors);
+        self.pagination.validate(self.profile, &mut errors);
+        self.inventory.validate(&mut errors);
+        self.notifications.validate(&mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(errors))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    pub workers: Option<usize>,
+    /// Use `X-Forwarded-For`/`Forwarded` for the client ip, only safe behind a trusted proxy.
+    pub trust_forwarded_for: bool,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8080,
+            workers: None,
+            trust_forwarded_for: false,
+        }
+    }
+}
+
+impl ServerConfig {
+    pub fn bind_address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.host.trim().is_empty() {
+            errors.push("server.host cannot be empty".to_string());
+        }
+
+        if self.workers == Some(0) {
+            errors.push("server.workers must be at least 1".to_string());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DatabaseConfig {
+    pub url: String,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub acquire_timeout_secs: u64,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            max_connections: 10,
+            min_connections: 0,
+            acquire_timeout_secs: 30,
+        }
+    }
+}
+
+impl DatabaseConfig {
+    pub fn pool_options(&self) -> PgPoolOptions {
+        PgPoolOptions::new()
+            .max_connections(self.max_connections)
+            .min_connections(self.min_connections)
+            .acquire_timeout(std::time::Duration::from_secs(self.acquire_timeout_secs))
+    }
+
+    pub async fn connect(&self) -> Result<PgPool, sqlx::Error> {
+        if self.url.is_empty() {
+            return Err(sqlx::Error::Configuration(
+                "database.url is not configured".into(),
+            ));
+        }
+
+        self.pool_options().connect(&self.url).await
+    }
+
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.max_connections == 0 {
+            errors.push("database.max_connections must be at least 1".to_string());
+        }
+
+        if self.min_connections > self.max_connections {
+            errors.push(
+                "database.min_connections cannot exceed database.max_connections".to_string(),
+            );
+        }
+
+        if self.acquire_timeout_secs == 0 {
+            errors.push("database.acquire_timeout_secs must be at least 1".to_string());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AuthConfig {
+    pub access_token_ttl_minutes: i64,
+    pub login_protection: LoginProtectionConfig,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            access_token_ttl_minutes: 60 * 24 * 2,
+            login_protection: LoginProtectionConfig::default(),
+        }
+    }
+}
+
+impl AuthConfig {
+    pub fn access_token_ttl(&self) -> Duration {
+        Duration::minutes(self.access_token_ttl_minutes)
+    }
+
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.access_token_ttl_minutes <= 0 {
+            errors.push("auth.access_token_ttl_minutes must be positive".to_string());
+        }
+
+        self.login_protection.validate(errors);
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LoginProtectionConfig {
+    /// Failed attempts on one account, within the window, before it gets locked.
+    pub max_failed_attempts: i64,
+    /// Failed attempts from one ip, within the window, before it gets blocked.
+    pub max_failed_attempts_per_ip: i64,
+    pub window_minutes: i64,
+    pub lockout_minutes: i64,
+    /// Failed attempts after which every new attempt is delayed.
+    pub delay_after_attempts: i64,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl Default for LoginProtectionConfig {
+    fn default() -> Self {
+        Self {
+            max_failed_attempts: 5,
+            max_failed_attempts_per_ip: 20,
+            window_minutes: 15,
+            lockout_minutes: 15,
+            delay_after_attempts: 2,
+            base_delay_ms: 250,
+            max_delay_ms: 4000,
+        }
+    }
+}
+
+impl LoginProtectionConfig {
+    pub fn window(&self) -> Duration {
+        Duration::minutes(self.window_minutes)
+    }
+
+    pub fn lockout_duration(&self) -> Duration {
+        Duration::minutes(self.lockout_minutes)
+    }
+
+    /**
+     * Delay applied before checking the password, doubling with every failure past the threshold.
+     */
+    pub fn delay_for(&self, failed_attempts: i64) -> std::time::Duration {
+        if failed_attempts < self.delay_after_attempts {
+            return std::time::Duration::ZERO;
+        }
+
+        let exponent = (failed_attempts - self.delay_after_attempts).min(16) as u32;
+        let delay_ms = self.base_delay_ms.saturating_mul(2u64.pow(exponent));
+
+        std::time::Duration::from_millis(delay_ms.min(self.max_delay_ms))
+    }
+
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.max_failed_attempts <= 0 {
+            errors.push("auth.login_protection.max_failed_attempts must be positive".to_string());
+        }
+
+        if self.max_failed_attempts_per_ip <= 0 {
+            errors.push(
+                "auth.login_protection.max_failed_attempts_per_ip must be positive".to_string(),
+            );
+        }
+
+        if self.window_minutes <= 0 || self.lockout_minutes <= 0 {
+            errors.push(
+                "auth.login_protection.window_minutes and lockout_minutes must be positive"
+                    .to_string(),
+            );
+        }
+
+        if self.base_delay_ms > self.max_delay_ms {
+            errors
+                .push("auth.login_protection.base_delay_ms cannot exceed max_delay_ms".to_string());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    #[default]
+    Local,
+    S3,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    pub local: LocalStorageConfig,
+    pub s3: Option<S3StorageConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LocalStorageConfig {
+    pub base_path: String,
+}
+
+impl Default for LocalStorageConfig {
+    fn default() -> Self {
+        Self {
+            base_path: "public/uploads".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct S3StorageConfig {
+    pub bucket: String,
+    pub region: String,
+}
+
+impl StorageConfig {
+    fn validate(&self, errors: &mut Vec<String>) {
+        match self.backend {
+            StorageBackend::Local => {
+                if self.local.base_path.trim().is_empty() {
+                    errors.push("storage.local.base_path cannot be empty".to_string());
+                }
+            }
+            StorageBackend::S3 => match &self.s3 {
+                Some(s3) if !s3.bucket.is_empty() && !s3.region.is_empty() => {
+                    // uploads are only wired to the local backend so far
+                    errors.push("storage.backend \"s3\" is not supported yet".to_string());
+                }
+                _ => errors.push(
+                    "storage.s3.bucket and storage.s3.region are required for the s3 backend"
+                        .to_string(),
+                ),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct UploadConfig {
+    pub max_request_size_bytes: usize,
+    pub memory_limit_bytes: usize,
+}
+
+impl Default for UploadConfig {
+    fn default() -> Self {
+        Self {
+            max_request_size_bytes: 50 * 1024 * 1024,
+            memory_limit_bytes: 2 * 1024 * 1024,
+        }
+    }
+}
+
+impl UploadConfig {
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.max_request_size_bytes == 0 {
+            errors.push("uploads.max_request_size_bytes must be positive".to_string());
+        }
+
+        if self.memory_limit_bytes > self.max_request_size_bytes {
+            errors.push(
+                "uploads.memory_limit_bytes cannot exceed uploads.max_request_size_bytes"
+                    .to_string(),
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CorsConfig {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub max_age_secs: usize,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: Vec::new(),
+            allowed_methods: vec![
+                "GET".to_string(),
+                "POST".to_string(),
+                "PUT".to_string(),
+                "DELETE".to_string(),
+            ],
+            allowed_headers: vec![
+                "Authorization".to_string(),
+                "Content-Type".to_string(),
+                "x-guest-token".to_string(),
+            ],
+            max_age_secs: 3600,
+        }
+    }
+}
+
+impl CorsConfig {
+    pub fn build(&self) -> Cors {
+        let mut cors = Cors::default()
+            .allowed_methods(self.allowed_methods.iter().map(String::as_str))
+            .allowed_headers(self.allowed_headers.iter().map(String::as_str))
+            .max_age(self.max_age_secs);
+
+        for origin in &self.allowed_origins {
+            cors = if origin == "*" {
+                cors.allow_any_origin()
+            } else {
+                cors.allowed_origin(origin)
+            };
+        }
+
+        cors
+    }
+
+    fn validate(&self, errors: &mut Vec<String>) {
+        for origin in &self.allowed_origins {
+            if origin != "*" && !origin.starts_with("http://") && !origin.starts_with("https://") {
+                errors.push(format!(
+                    "cors.allowed_origins: invalid origin \"{}\"",
+                    origin
+                ));
+            }
+        }
+
+        for method in &self.allowed_methods {
+            if Method::from_str(method).is_err() {
+                errors.push(format!(
+                    "cors.allowed_methods: invalid method \"{}\"",
+                    method
+                ));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Json,
+    Pretty,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+    pub level: String,
+    pub format: LogFormat,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            format: LogFormat::Json,
+        }
+    }
+}
+
+impl LoggingConfig {
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.level.trim().is_empty() {
+            errors.push("logging.level cannot be empty".to_string());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitStoreKind {
+    #[default]
+    Memory,
+    Postgres,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct RateLimitPolicyConfig {
+    pub capacity: u32,
+    pub refill_per_minute: u32,
+}
+
+impl RateLimitPolicyConfig {
+    pub fn new(capacity: u32, refill_per_minute: u32) -> Self {
+        Self {
+            capacity,
+            refill_per_minute,
+        }
+    }
+
+    fn validate(&self, name: &str, errors: &mut Vec<String>) {
+        if self.capacity == 0 {
+            errors.push(format!("rate_limit.{}.capacity must be at least 1", name));
+        }
+
+        if self.refill_per_minute == 0 {
+            errors.push(format!(
+                "rate_limit.{}.refill_per_minute must be at least 1",
+                name
+            ));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    pub store: RateLimitStoreKind,
+    pub login: RateLimitPolicyConfig,
+    pub register: RateLimitPolicyConfig,
+    pub reviews: RateLimitPolicyConfig,
+    pub cart: RateLimitPolicyConfig,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            store: RateLimitStoreKind::Memory,
+            login: RateLimitPolicyConfig::new(5, 5),
+            register: RateLimitPolicyConfig::new(5, 2),
+            reviews: RateLimitPolicyConfig::new(10, 5),
+            cart: RateLimitPolicyConfig::new(60, 60),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    fn validate(&self, errors: &mut Vec<String>) {
+        self.login.validate("login", errors);
+        self.register.validate("register", errors);
+        self.reviews.validate("reviews", errors);
+        self.cart.validate("cart", errors);
+    }
+}
+
+/**
+ * HTTP caching for the public catalog. `catalog_max_age_secs` goes into the
+ * `Cache-Control` header, the product page cache is disabled with a zero ttl.
+ */
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    pub catalog_max_age_secs: u32,
+    pub product_ttl_secs: u64,
+    pub product_capacity: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            catalog_max_age_secs: 60,
+            product_ttl_secs: 30,
+            product_capacity: 1000,
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn product_ttl(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.product_ttl_secs)
+    }
+
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.product_ttl_secs > 0 && self.product_capacity == 0 {
+            errors.push(
+                "cache.product_capacity must be at least 1 when the product cache is enabled"
+                    .to_string(),
+            );
+        }
+    }
+}
+
+/**
+ * Cursor pagination. Cursors are signed with `cursor_secret`; when it is empty
+ * (dev and test) a random key is generated per process, so cursors do not
+ * survive a restart.
+ */
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PaginationConfig {
+    pub cursor_secret: String,
+}
+
+impl PaginationConfig {
+    fn validate(&self, profile: Profile, errors: &mut Vec<String>) {
+        if profile == Profile::Prod && self.cursor_secret.len() < 32 {
+            errors.push("pagination.cursor_secret must be at least 32 characters".to_string());
+        }
+    }
+}
+
+/**
+ * Whether catalog prices already include tax. When they do the cart's tax
+ * breakdown is carved out of the total, otherwise it's added on top.
+ */
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TaxConfig {
+    pub prices_include_tax: bool,
+}
+
+/**
+ * How an order is filled from the sellable warehouses.
+ *
+ * `priority` takes from the warehouses in priority order, `most_stock` from
+ * the ones holding the most first so fewer parcels ship, and `single` only
+ * sells what one warehouse can ship on its own.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllocationStrategy {
+    #[default]
+    Priority,
+    MostStock,
+    Single,
+}
+
+/**
+ * `low_stock_threshold` is what products without a threshold of their own
+ * count as low stock at, on the admin low stock report.
+ */
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct InventoryConfig {
+    pub allocation: AllocationStrategy,
+    pub low_stock_threshold: i32,
+}
+
+impl Default for InventoryConfig {
+    fn default() -> Self {
+        Self {
+            allocation: AllocationStrategy::default(),
+            low_stock_threshold: 5,
+        }
+    }
+}
+
+impl InventoryConfig {
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.low_stock_threshold < 0 {
+            errors.push("inventory.low_stock_threshold cannot be negative".to_string());
+        }
+    }
+}
+
+/**
+ * Where customer notifications go. `log` writes them to the application log,
+ * `file` appends them to `path` as JSON lines, which tests can read back.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationChannel {
+    #[default]
+    Log,
+    File,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NotificationsConfig {
+    pub channel: NotificationChannel,
+    pub path: String,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            channel: NotificationChannel::Log,
+            path: "storage/notifications.jsonl".to_string(),
+        }
+    }
+}
+
+impl NotificationsConfig {
+    fn validate(&self, errors: &mut Vec<String>) {
+        if self.channel == NotificationChannel::File && self.path.trim().is_empty() {
+            errors.push("notifications.path cannot be empty for the file channel".to_string());
+        }
+    }
+}
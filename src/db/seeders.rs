@@ -58,8 +58,24 @@ pub async fn seed_dummy_products(pool: &PgPool) -> Result<(), sqlx::Error> {
     for p in products {
         sqlx::query!(
             r#"
-            INSERT INTO products (name, slug, price, quantity, configurable, is_active, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            WITH product AS (
+                INSERT INTO products (name, slug, price, quantity, configurable, is_active, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                RETURNING id, quantity
+            ),
+            warehouse AS (
+                SELECT id FROM warehouses WHERE is_default
+            ),
+            stock AS (
+                INSERT INTO warehouse_stock (warehouse_id, product_id, quantity)
+                SELECT warehouse.id, product.id, product.quantity
+                FROM product, warehouse
+                WHERE product.quantity > 0
+            )
+            INSERT INTO stock_movements (product_id, warehouse_id, kind, quantity, balance, reason)
+            SELECT product.id, warehouse.id, 'adjustment', product.quantity, product.quantity, 'opening balance'
+            FROM product, warehouse
+            WHERE product.quantity <> 0
             "#,
             p.name,
             p.slug,
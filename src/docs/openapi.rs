@@ -0,0 +1,346 @@
+use crate::admin::audit::dto::AdminPublicAuditEntry;
+use crate::admin::categories::dto::{AdminPublicCategory, CreateCategoryDTO, UpdateCategoryDTO};
+use crate::admin::coupons::dto::{AdminPublicCoupon, CouponKind, CreateCouponDTO, UpdateCouponDTO};
+use crate::admin::products::dto::{
+    AdminPublicLowStockProduct, AdminPublicProduct, CreateProductDTO, LowStockThresholdDTO,
+    ProductStatus, UpdateProductDTO,
+};
+use crate::admin::products::images::dto::{
+    AdminPublicProductImage, CreateProductImageDTO, UpdateProductImageSortDTO,
+};
+use crate::admin::products::transfer::dto::{
+    ImportMode, ImportProductsDTO, ImportReport, TransferFormat,
+};
+use crate::admin::products::videos::dto::{
+    AdminPublicProductVideo, CreateProductVideoDTO, UpdateProductVideoSortDTO,
+};
+use crate::admin::promotions::dto::{
+    AdminPublicPromotion, CreatePromotionDTO, PromotionKind, PromotionRule, UpdatePromotionDTO,
+    VolumeTier,
+};
+use crate::admin::reviews::dto::{AdminPublicReview, ReviewApprovalStatus, UpdateReviewStatusDTO};
+use crate::admin::shipping_methods::dto::{
+    AdminPublicShippingMethod, CreateShippingMethodDTO, ShippingKind, ShippingRule,
+    UpdateShippingMethodDTO, WeightTier,
+};
+use crate::admin::shipping_zones::dto::{
+    AdminPublicShippingZone, CreateShippingZoneDTO, UpdateShippingZoneDTO,
+};
+use crate::admin::stock::dto::{
+    AdminPublicStockDrift, AdminPublicStockMovement, CreateStockMovementDTO, StockMovementKind,
+};
+use crate::admin::tax_rates::dto::{AdminPublicTaxRate, CreateTaxRateDTO, UpdateTaxRateDTO};
+use crate::admin::warehouses::dto::{
+    AdminPublicWarehouse, AdminPublicWarehouseStock, CreateWarehouseDTO, TransferStockDTO,
+    UpdateWarehouseDTO,
+};
+use crate::app::addresses::dto::{CreateAddressDTO, PublicAddress, UpdateAddressDTO};
+use crate::app::cart::cart_items::dto::{
+    AddItemDto, PublicCartItems, RemoveItemDto, UpdateItemDto,
+};
+use crate::app::cart::coupons::dto::{ApplyCouponDto, PublicCartDiscount};
+use crate::app::cart::guest_cart::dto::PublicGuestCart;
+use crate::app::cart::promotions::dto::PublicCartPromotion;
+use crate::app::cart::shipping::dto::PublicShippingOption;
+use crate::app::cart::tax::dto::{PublicCartTax, PublicCartTaxLine};
+use crate::app::cart::user_cart::dto::PublicUserCart;
+use crate::app::categories::dto::PublicCategory;
+use crate::app::products::dto::PublicProduct;
+use crate::app::products::images::dto::PublicProductImage;
+use crate::app::products::reviews::dto::{CreateProductReviewDto, PublicProductReview};
+use crate::app::products::videos::dto::PublicProductVideo;
+use crate::app::stock_subscriptions::dto::{
+    GuestStockSubscriptionDto, PublicStockSubscription, StockSubscriptionDto,
+};
+use crate::app::wishlists::dto::{
+    CreateWishlistDTO, MoveWishlistItemDto, PublicSharedWishlist, PublicWishlist,
+    PublicWishlistItem, UpdateWishlistDTO, WishlistItemDto,
+};
+use crate::auth::dto::{LoginDTO, PublicAuthToken, PublicUser, RegisterDTO};
+use crate::health::dto::{HealthReport, HealthStatus};
+use crate::responses::error_responses::{ErrorResponse, FieldError};
+use crate::utils::money::{Currency, Money};
+use crate::utils::pagination::{CursorMeta, Paginate, PaginationMeta, PaginationMode};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+/**
+ * Scopes listed under `bearer_auth` are the ones the route's AuthMiddleware
+ * checks; an empty list means any authenticated user.
+ */
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "ecomm API", description = "Storefront and admin API"),
+    paths(
+        crate::health::handler::live,
+        crate::health::handler::ready,
+        crate::metrics::handler::metrics,
+    ),
+    components(schemas(HealthReport, HealthStatus)),
+    nest(
+        (path = "/api/v1", api = ApiV1Doc),
+        (path = "/api/v2", api = ApiV2Doc),
+    ),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "auth", description = "Registration, login and account lockouts"),
+        (name = "products", description = "Public product catalogue"),
+        (name = "reviews", description = "Product reviews"),
+        (name = "categories", description = "Public categories"),
+        (name = "cart", description = "User and guest carts"),
+        (name = "addresses", description = "Address book of the signed in user"),
+        (name = "wishlists", description = "Wishlists of the signed in user and their share links"),
+        (name = "stock-alerts", description = "Back in stock subscriptions of users and guests"),
+        (name = "admin-products", description = "Product, image and video management"),
+        (name = "admin-categories", description = "Category management"),
+        (name = "admin-reviews", description = "Review moderation"),
+        (name = "admin-coupons", description = "Coupon and discount code management"),
+        (name = "admin-promotions", description = "Automatic cart promotion management"),
+        (name = "admin-tax-rates", description = "Tax rates by class and jurisdiction"),
+        (name = "admin-shipping-zones", description = "Regions shipping methods apply to"),
+        (name = "admin-shipping-methods", description = "Shipping methods and their rates"),
+        (name = "admin-stock", description = "Stock ledger, movements and reconciliation"),
+        (name = "admin-warehouses", description = "Warehouses, their stock and transfers between them"),
+        (name = "admin-users", description = "Customer accounts"),
+        (name = "admin-audit", description = "Audit trail of admin changes"),
+        (name = "operations", description = "Health checks and metrics"),
+    )
+)]
+pub struct ApiDoc;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::auth::handler::register,
+        crate::auth::handler::login,
+        crate::auth::handler::unlock,
+        crate::app::products::handler::index,
+        crate::app::products::handler::show,
+        crate::app::products::reviews::handler::create_user,
+        crate::app::products::reviews::handler::create_guest,
+        crate::app::categories::handler::index,
+        crate::app::categories::handler::show,
+        crate::app::cart::user_cart::handler::get_user_cart,
+        crate::app::cart::user_cart::handler::shipping_options,
+        crate::app::cart::user_cart::handler::add_item,
+        crate::app::cart::user_cart::handler::update_item,
+        crate::app::cart::user_cart::handler::remove_item,
+        crate::app::cart::user_cart::handler::apply_coupon,
+        crate::app::cart::user_cart::handler::remove_coupon,
+        crate::app::cart::guest_cart::handler::get_guest_cart,
+        crate::app::cart::guest_cart::handler::shipping_options,
+        crate::app::cart::guest_cart::handler::add_item,
+        crate::app::cart::guest_cart::handler::update_item,
+        crate::app::cart::guest_cart::handler::remove_item,
+        crate::app::cart::guest_cart::handler::apply_coupon,
+        crate::app::cart::guest_cart::handler::remove_coupon,
+        crate::admin::products::handler::index,
+        crate::admin::products::handler::show,
+        crate::admin::products::handler::create,
+        crate::admin::products::handler::update,
+        crate::admin::products::handler::delete,
+        crate::admin::products::handler::trash,
+        crate::admin::products::handler::restore,
+        crate::admin::products::handler::purge,
+        crate::admin::products::handler::low_stock,
+        crate::admin::products::handler::low_stock_threshold,
+        crate::admin::products::transfer::handler::import,
+        crate::admin::products::transfer::handler::export,
+        crate::admin::products::images::handler::upload,
+        crate::admin::products::images::handler::update_sort,
+        crate::admin::products::images::handler::delete,
+        crate::admin::products::videos::handler::upload,
+        crate::admin::products::videos::handler::update_sort,
+        crate::admin::products::videos::handler::stream,
+        crate::admin::products::videos::handler::delete,
+        crate::admin::categories::handler::index,
+        crate::admin::categories::handler::show,
+        crate::admin::categories::handler::create,
+        crate::admin::categories::handler::update,
+        crate::admin::categories::handler::delete,
+        crate::admin::categories::handler::trash,
+        crate::admin::categories::handler::restore,
+        crate::admin::categories::handler::purge,
+        crate::admin::reviews::handler::index,
+        crate::admin::reviews::handler::show,
+        crate::admin::reviews::handler::update_status,
+        crate::admin::reviews::handler::delete,
+        crate::admin::coupons::handler::index,
+        crate::admin::coupons::handler::show,
+        crate::admin::coupons::handler::create,
+        crate::admin::coupons::handler::update,
+        crate::admin::coupons::handler::delete,
+        crate::admin::promotions::handler::index,
+        crate::admin::promotions::handler::show,
+        crate::admin::promotions::handler::create,
+        crate::admin::promotions::handler::update,
+        crate::admin::promotions::handler::delete,
+        crate::admin::tax_rates::handler::index,
+        crate::admin::tax_rates::handler::show,
+        crate::admin::tax_rates::handler::create,
+        crate::admin::tax_rates::handler::update,
+        crate::admin::tax_rates::handler::delete,
+        crate::admin::shipping_zones::handler::index,
+        crate::admin::shipping_zones::handler::show,
+        crate::admin::shipping_zones::handler::create,
+        crate::admin::shipping_zones::handler::update,
+        crate::admin::shipping_zones::handler::delete,
+        crate::admin::shipping_methods::handler::index,
+        crate::admin::shipping_methods::handler::show,
+        crate::admin::shipping_methods::handler::create,
+        crate::admin::shipping_methods::handler::update,
+        crate::admin::shipping_methods::handler::delete,
+        crate::admin::stock::handler::index,
+        crate::admin::stock::handler::create,
+        crate::admin::stock::handler::reconcile,
+        crate::admin::warehouses::handler::index,
+        crate::admin::warehouses::handler::show,
+        crate::admin::warehouses::handler::create,
+        crate::admin::warehouses::handler::update,
+        crate::admin::warehouses::handler::stock,
+        crate::admin::warehouses::handler::transfer,
+        crate::admin::users::handler::addresses,
+        crate::admin::audit::handler::index,
+        crate::app::addresses::handler::index,
+        crate::app::addresses::handler::show,
+        crate::app::addresses::handler::create,
+        crate::app::addresses::handler::update,
+        crate::app::addresses::handler::delete,
+        crate::app::wishlists::handler::index,
+        crate::app::wishlists::handler::show,
+        crate::app::wishlists::handler::create,
+        crate::app::wishlists::handler::update,
+        crate::app::wishlists::handler::delete,
+        crate::app::wishlists::handler::add_item,
+        crate::app::wishlists::handler::remove_item,
+        crate::app::wishlists::handler::move_to_cart,
+        crate::app::wishlists::handler::share,
+        crate::app::wishlists::handler::unshare,
+        crate::app::wishlists::handler::shared,
+        crate::app::stock_subscriptions::handler::index,
+        crate::app::stock_subscriptions::handler::subscribe,
+        crate::app::stock_subscriptions::handler::unsubscribe,
+        crate::app::stock_subscriptions::handler::subscribe_guest,
+    ),
+    components(schemas(
+        ErrorResponse,
+        FieldError,
+        Paginate,
+        PaginationMeta,
+        PaginationMode,
+        CursorMeta,
+        RegisterDTO,
+        LoginDTO,
+        PublicAuthToken,
+        PublicUser,
+        Money,
+        Currency,
+        PublicProduct,
+        PublicProductImage,
+        PublicProductVideo,
+        PublicProductReview,
+        CreateProductReviewDto,
+        PublicCategory,
+        PublicCartItems,
+        PublicUserCart,
+        PublicGuestCart,
+        AddItemDto,
+        UpdateItemDto,
+        RemoveItemDto,
+        ApplyCouponDto,
+        PublicCartDiscount,
+        PublicCartPromotion,
+        PublicCartTax,
+        PublicCartTaxLine,
+        PublicShippingOption,
+        PublicAddress,
+        CreateAddressDTO,
+        UpdateAddressDTO,
+        PublicWishlist,
+        PublicWishlistItem,
+        PublicSharedWishlist,
+        CreateWishlistDTO,
+        UpdateWishlistDTO,
+        WishlistItemDto,
+        MoveWishlistItemDto,
+        PublicStockSubscription,
+        StockSubscriptionDto,
+        GuestStockSubscriptionDto,
+        AdminPublicProduct,
+        ProductStatus,
+        CreateProductDTO,
+        UpdateProductDTO,
+        AdminPublicLowStockProduct,
+        LowStockThresholdDTO,
+        AdminPublicProductImage,
+        CreateProductImageDTO,
+        ImportProductsDTO,
+        ImportReport,
+        ImportMode,
+        TransferFormat,
+        UpdateProductImageSortDTO,
+        AdminPublicProductVideo,
+        CreateProductVideoDTO,
+        UpdateProductVideoSortDTO,
+        AdminPublicCategory,
+        CreateCategoryDTO,
+        UpdateCategoryDTO,
+        AdminPublicReview,
+        UpdateReviewStatusDTO,
+        ReviewApprovalStatus,
+        AdminPublicCoupon,
+        CouponKind,
+        CreateCouponDTO,
+        UpdateCouponDTO,
+        AdminPublicPromotion,
+        PromotionKind,
+        PromotionRule,
+        VolumeTier,
+        CreatePromotionDTO,
+        UpdatePromotionDTO,
+        AdminPublicTaxRate,
+        CreateTaxRateDTO,
+        UpdateTaxRateDTO,
+        AdminPublicShippingZone,
+        CreateShippingZoneDTO,
+        UpdateShippingZoneDTO,
+        AdminPublicShippingMethod,
+        ShippingKind,
+        ShippingRule,
+        WeightTier,
+        CreateShippingMethodDTO,
+        UpdateShippingMethodDTO,
+        AdminPublicStockMovement,
+        AdminPublicStockDrift,
+        StockMovementKind,
+        CreateStockMovementDTO,
+        AdminPublicWarehouse,
+        AdminPublicWarehouseStock,
+        CreateWarehouseDTO,
+        UpdateWarehouseDTO,
+        TransferStockDTO,
+        AdminPublicAuditEntry,
+    ))
+)]
+struct ApiV1Doc;
+
+#[derive(OpenApi)]
+#[openapi(paths(crate::auth::handler::login_v2))]
+struct ApiV2Doc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+        components.add_security_scheme(
+            "guest_token",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-guest-token"))),
+        );
+    }
+}
@@ -1,10 +1,58 @@
-use crate::responses::error_responses::ErrorResponse;
+use crate::responses::error_responses::{ErrorResponse, FieldError};
 use actix_web::http::StatusCode;
 use actix_web::{HttpResponse, ResponseError};
-use log::error;
-use std::collections::HashMap;
 use thiserror::Error;
-use validator::ValidationErrors;
+use tracing::{error, info};
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/**
+ * Machine-readable error codes. These are part of the public contract, clients
+ * branch on them, so existing values must never be renamed.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    ValidationFailed,
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    Locked,
+    RateLimited,
+    Internal,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::ValidationFailed => "validation_failed",
+            ErrorCode::BadRequest => "bad_request",
+            ErrorCode::Unauthorized => "unauthorized",
+            ErrorCode::Forbidden => "forbidden",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::Conflict => "conflict",
+            ErrorCode::Locked => "locked",
+            ErrorCode::RateLimited => "rate_limited",
+            ErrorCode::Internal => "internal_error",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ErrorCode::ValidationFailed => "Validation failed",
+            ErrorCode::BadRequest => "Bad request",
+            ErrorCode::Unauthorized => "Unauthorized",
+            ErrorCode::Forbidden => "Forbidden",
+            ErrorCode::NotFound => "Not found",
+            ErrorCode::Conflict => "Conflict",
+            ErrorCode::Locked => "Locked",
+            ErrorCode::RateLimited => "Too many requests",
+            ErrorCode::Internal => "Internal server error",
+        }
+    }
+}
 
 #[derive(Debug, Error)]
 pub enum AppError {
@@ -12,7 +60,10 @@ pub enum AppError {
     Validation(ValidationErrors),
 
     #[error("validation error")]
-    ValidationSingle(HashMap<String, Vec<String>>),
+    ValidationSingle(Vec<FieldError>),
+
+    #[error("bad request: {0}")]
+    BadRequest(String),
 
     #[error("unauthorized: {0:?}")]
     Unauthorized(String),
@@ -29,114 +80,236 @@ pub enum AppError {
     #[error("resource not found")]
     NotFound(String),
 
+    #[error("locked: {0}")]
+    Locked(String),
+
+    #[error("too many requests")]
+    TooManyRequests,
+
     #[error(transparent)]
     Database(sqlx::Error),
 }
 
-impl From<ValidationErrors> for AppError {
-    fn from(err: ValidationErrors) -> Self {
-        AppError::Validation(err)
+impl AppError {
+    /** Single field-level validation error, e.g. from a hand-written check. */
+    pub fn field(field: &str, code: &str, message: &str) -> Self {
+        AppError::ValidationSingle(vec![FieldError::new(field, code, message)])
     }
-}
 
-impl ResponseError for AppError {
-    fn status_code(&self) -> StatusCode {
+    /**
+     * Field errors of a validation failure re-rooted under `prefix`, e.g.
+     * `rows[3]` when validating one entry of a batch. Other errors have none.
+     */
+    pub fn field_errors_at(&self, prefix: &str) -> Vec<FieldError> {
+        let mut fields = Vec::new();
+
         match self {
-            AppError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
-            AppError::ValidationSingle(_) => StatusCode::UNPROCESSABLE_ENTITY,
-            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
-            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
-            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
-            AppError::Conflict(_) => StatusCode::BAD_REQUEST,
-            AppError::NotFound(_) => StatusCode::NOT_FOUND,
-            AppError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::Validation(errors) => collect_validation_errors(errors, prefix, &mut fields),
+            AppError::ValidationSingle(errors) => {
+                fields.extend(errors.iter().map(|error| {
+                    FieldError::new(
+                        &format!("{}.{}", prefix, error.field),
+                        &error.code,
+                        &error.message,
+                    )
+                }));
+            }
+            _ => {}
         }
+
+        fields
     }
 
-    fn error_response(&self) -> HttpResponse {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            AppError::Validation(_) | AppError::ValidationSingle(_) => ErrorCode::ValidationFailed,
+            AppError::BadRequest(_) => ErrorCode::BadRequest,
+            AppError::Unauthorized(_) => ErrorCode::Unauthorized,
+            AppError::Forbidden(_) => ErrorCode::Forbidden,
+            AppError::Internal(_) => ErrorCode::Internal,
+            AppError::Conflict(_) => ErrorCode::Conflict,
+            AppError::NotFound(_) => ErrorCode::NotFound,
+            AppError::Locked(_) => ErrorCode::Locked,
+            AppError::TooManyRequests => ErrorCode::RateLimited,
+            AppError::Database(err) if is_unique_violation(err) => ErrorCode::Conflict,
+            AppError::Database(_) => ErrorCode::Internal,
+        }
+    }
+
+    fn problem(&self) -> ErrorResponse {
+        let status = self.status_code();
+        let code = self.code();
+
         match self {
             AppError::Validation(errors) => {
-                HttpResponse::UnprocessableEntity().json(ErrorResponse {
-                    message: "validation failed".to_string(),
-                    errors: Some(extract_validation_errors(errors)),
-                })
+                let mut fields = Vec::new();
+                collect_validation_errors(errors, "", &mut fields);
+                fields.sort_by(|a, b| a.field.cmp(&b.field));
+
+                ErrorResponse::new(code, status, "validation failed").with_errors(fields)
             }
 
-            AppError::ValidationSingle(error) => {
-                HttpResponse::UnprocessableEntity().json(ErrorResponse {
-                    message: "validation failed".to_string(),
-                    errors: Some(error.clone()),
-                })
+            AppError::ValidationSingle(fields) => {
+                ErrorResponse::new(code, status, "validation failed").with_errors(fields.clone())
             }
 
-            AppError::Unauthorized(err) => HttpResponse::Unauthorized().json(ErrorResponse {
-                message: err.clone(),
-                errors: None,
-            }),
+            AppError::BadRequest(err)
+            | AppError::Unauthorized(err)
+            | AppError::Forbidden(err)
+            | AppError::NotFound(err)
+            | AppError::Locked(err) => ErrorResponse::new(code, status, err),
+
+            AppError::TooManyRequests => ErrorResponse::new(code, status, "too many requests"),
 
-            AppError::Forbidden(err) => HttpResponse::Forbidden().json(ErrorResponse {
-                message: err.clone(),
-                errors: None,
-            }),
+            AppError::Conflict(err) => {
+                info!(error = %err, "conflict error");
+
+                ErrorResponse::new(code, status, err)
+            }
 
             AppError::Internal(err) => {
-                error!("Internal error: {}", err);
-
-                #[cfg(debug_assertions)]
-                return HttpResponse::InternalServerError().json(ErrorResponse {
-                    message: err.clone(),
-                    errors: None,
-                });
-
-                #[cfg(not(debug_assertions))]
-                HttpResponse::InternalServerError().json(ErrorResponse {
-                    message: "internal server error".to_string(),
-                })
+                error!(error = %err, "internal error");
+
+                if cfg!(debug_assertions) {
+                    ErrorResponse::new(code, status, err)
+                } else {
+                    ErrorResponse::new(code, status, "internal server error")
+                }
             }
 
-            AppError::Conflict(err) => {
-                error!("Conflict error: {}", err);
+            AppError::Database(err) => {
+                if is_unique_violation(err) {
+                    info!(error = %err, "unique violation");
+
+                    let Some(field) = unique_violation_field(err) else {
+                        return ErrorResponse::new(code, status, "resource already exists");
+                    };
 
-                HttpResponse::BadRequest().json(ErrorResponse {
-                    message: err.to_string(),
-                    errors: None,
-                })
+                    let message = format!("{} is already taken", field);
+                    return ErrorResponse::new(code, status, &message)
+                        .with_errors(vec![FieldError::new(&field, "unique", &message)]);
+                }
+
+                error!(error = %err, "database error");
+
+                if cfg!(debug_assertions) {
+                    ErrorResponse::new(code, status, &err.to_string())
+                } else {
+                    ErrorResponse::new(code, status, "database error")
+                }
             }
+        }
+    }
+}
 
-            AppError::NotFound(err) => HttpResponse::NotFound().json(ErrorResponse {
-                message: err.to_string(),
-                errors: None,
-            }),
+impl From<ValidationErrors> for AppError {
+    fn from(err: ValidationErrors) -> Self {
+        AppError::Validation(err)
+    }
+}
 
-            AppError::Database(err) => {
-                error!("Database error: {}", err);
-
-                #[cfg(debug_assertions)]
-                return HttpResponse::InternalServerError().json(ErrorResponse {
-                    message: err.to_string(),
-                    errors: None,
-                });
-
-                #[cfg(not(debug_assertions))]
-                HttpResponse::InternalServerError().json(ErrorResponse {
-                    message: "database error".to_string(),
-                })
+impl ResponseError for AppError {
+    fn status_code(&self) -> StatusCode {
+        match self.code() {
+            ErrorCode::ValidationFailed => StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorCode::BadRequest => StatusCode::BAD_REQUEST,
+            ErrorCode::Unauthorized => StatusCode::UNAUTHORIZED,
+            ErrorCode::Forbidden => StatusCode::FORBIDDEN,
+            ErrorCode::NotFound => StatusCode::NOT_FOUND,
+            ErrorCode::Conflict => StatusCode::CONFLICT,
+            ErrorCode::Locked => StatusCode::LOCKED,
+            ErrorCode::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            ErrorCode::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type(PROBLEM_CONTENT_TYPE)
+            .json(self.problem())
+    }
+}
+
+/**
+ * Flattens validator output into dotted paths: `address.city` for nested
+ * structs and `items[0].quantity` for lists.
+ */
+fn collect_validation_errors(errors: &ValidationErrors, prefix: &str, out: &mut Vec<FieldError>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errs) => {
+                for err in errs {
+                    out.push(FieldError::new(&path, &err.code, &validation_message(err)));
+                }
+            }
+            ValidationErrorsKind::Struct(nested) => collect_validation_errors(nested, &path, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect_validation_errors(nested, &format!("{}[{}]", path, index), out);
+                }
             }
         }
     }
 }
 
-fn extract_validation_errors(errors: &ValidationErrors) -> HashMap<String, Vec<String>> {
-    let mut map = HashMap::new();
+fn validation_message(err: &ValidationError) -> String {
+    if let Some(message) = &err.message {
+        return message.to_string();
+    }
 
-    for (field, errs) in errors.field_errors() {
-        let messages = errs
-            .iter()
-            .map(|err| err.code.to_string())
-            .collect::<Vec<String>>();
+    let param = |name: &str| {
+        err.params.get(name).map(|value| match value.as_str() {
+            Some(text) => text.to_string(),
+            None => value.to_string(),
+        })
+    };
 
-        map.insert(field.to_string(), messages);
+    match err.code.as_ref() {
+        "required" => "is required".to_string(),
+        "email" => "must be a valid email address".to_string(),
+        "url" => "must be a valid url".to_string(),
+        "length" => match (param("min"), param("max")) {
+            (Some(min), Some(max)) => format!("must be between {} and {} characters", min, max),
+            (Some(min), None) => format!("must be at least {} characters", min),
+            (None, Some(max)) => format!("must be at most {} characters", max),
+            (None, None) => "has an invalid length".to_string(),
+        },
+        "range" => match (param("min"), param("max")) {
+            (Some(min), Some(max)) => format!("must be between {} and {}", min, max),
+            (Some(min), None) => format!("must be at least {}", min),
+            (None, Some(max)) => format!("must be at most {}", max),
+            (None, None) => "is out of range".to_string(),
+        },
+        code => code.replace('_', " "),
     }
+}
+
+fn is_unique_violation(err: &sqlx::Error) -> bool {
+    matches!(err, sqlx::Error::Database(db_err) if db_err.is_unique_violation())
+}
+
+/**
+ * The column behind a unique violation, read off constraints that follow the
+ * Postgres default name `<table>_<column>_key`, e.g. `users_email_key`. Any
+ * other name, like a multi-column or partial index, doesn't point at one
+ * field, so the error carries none.
+ */
+fn unique_violation_field(err: &sqlx::Error) -> Option<String> {
+    let sqlx::Error::Database(db_err) = err else {
+        return None;
+    };
+
+    let column = db_err
+        .constraint()?
+        .strip_prefix(db_err.table()?)?
+        .strip_prefix('_')?
+        .strip_suffix("_key")?;
 
-    map
+    Some(column.to_string())
 }
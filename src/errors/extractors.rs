@@ -0,0 +1,47 @@
+use crate::config::settings::UploadConfig;
+use crate::errors::error::AppError;
+use actix_multipart::MultipartError;
+use actix_multipart::form::MultipartFormConfig;
+use actix_web::error::InternalError;
+use actix_web::web::{JsonConfig, PathConfig, QueryConfig, ServiceConfig};
+use actix_web::{Error, ResponseError};
+
+/**
+ * Extractor rejections (malformed JSON, bad query strings, unparsable path
+ * segments) otherwise fall back to actix's plain-text bodies.
+ */
+pub fn configure(cfg: &mut ServiceConfig) {
+    cfg.app_data(JsonConfig::default().error_handler(|err, _req| {
+        reject(err, AppError::BadRequest("invalid json body".to_string()))
+    }))
+    .app_data(QueryConfig::default().error_handler(|err, _req| {
+        let error = AppError::BadRequest(format!("invalid query string: {}", err));
+        reject(err, error)
+    }))
+    .app_data(PathConfig::default().error_handler(|err, _req| {
+        reject(err, AppError::NotFound("resource not found".to_string()))
+    }));
+}
+
+pub fn multipart_config(uploads: &UploadConfig) -> MultipartFormConfig {
+    MultipartFormConfig::default()
+        .total_limit(uploads.max_request_size_bytes)
+        .memory_limit(uploads.memory_limit_bytes)
+        .error_handler(|err, _req| {
+            let error = match &err {
+                MultipartError::MissingField(field) => {
+                    AppError::field(field, "required", "field is required")
+                }
+                _ => AppError::BadRequest("invalid multipart payload".to_string()),
+            };
+
+            reject(err, error)
+        })
+}
+
+fn reject<E>(cause: E, error: AppError) -> Error
+where
+    E: std::fmt::Debug + std::fmt::Display + 'static,
+{
+    InternalError::from_response(cause, error.error_response()).into()
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    Unavailable,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checks: BTreeMap<String, HealthStatus>,
+}
+
+impl HealthReport {
+    pub fn live() -> Self {
+        Self {
+            status: HealthStatus::Ok,
+            checks: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_checks(checks: BTreeMap<String, HealthStatus>) -> Self {
+        let status = if checks.values().all(|c| *c == HealthStatus::Ok) {
+            HealthStatus::Ok
+        } else {
+            HealthStatus::Unavailable
+        };
+
+        Self { status, checks }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.status == HealthStatus::Ok
+    }
+}
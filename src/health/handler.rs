@@ -0,0 +1,57 @@
+use crate::health::dto::{HealthReport, HealthStatus};
+use crate::state::AppState;
+use crate::utils::traits::UseStorage;
+use actix_web::{HttpResponse, Responder, web};
+use std::collections::BTreeMap;
+use tracing::error;
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "operations",
+    responses(
+        (status = 200, description = "Process is up", body = HealthReport),
+    ),
+)]
+pub async fn live() -> impl Responder {
+    HttpResponse::Ok().json(HealthReport::live())
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "operations",
+    responses(
+        (status = 200, description = "All dependencies reachable", body = HealthReport),
+        (status = 503, description = "A dependency is unavailable", body = HealthReport),
+    ),
+)]
+pub async fn ready(state: web::Data<AppState>) -> impl Responder {
+    let mut checks = BTreeMap::new();
+
+    let database = match state.health_service.check_database().await {
+        Ok(()) => HealthStatus::Ok,
+        Err(e) => {
+            error!(error = %e, "readiness check failed for database");
+            HealthStatus::Unavailable
+        }
+    };
+    checks.insert("database".to_string(), database);
+
+    let storage = match state.local_storage.health_check().await {
+        Ok(()) => HealthStatus::Ok,
+        Err(e) => {
+            error!(error = %e, "readiness check failed for storage");
+            HealthStatus::Unavailable
+        }
+    };
+    checks.insert("storage".to_string(), storage);
+
+    let report = HealthReport::from_checks(checks);
+
+    if report.is_ok() {
+        HttpResponse::Ok().json(report)
+    } else {
+        HttpResponse::ServiceUnavailable().json(report)
+    }
+}
@@ -0,0 +1,11 @@
+use super::handler;
+use actix_web::web;
+use actix_web::web::{get, resource};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/health")
+            .service(resource("/live").route(get().to(handler::live)))
+            .service(resource("/ready").route(get().to(handler::ready))),
+    );
+}
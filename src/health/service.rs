@@ -0,0 +1,26 @@
+use crate::errors::error::AppError;
+use sqlx::PgPool;
+
+#[derive(Clone)]
+pub struct HealthService {
+    pool: PgPool,
+}
+
+impl HealthService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    pub async fn check_database(&self) -> Result<(), AppError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map_err(AppError::Database)?;
+
+        Ok(())
+    }
+}
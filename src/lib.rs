@@ -1,9 +1,16 @@
 pub mod admin;
+pub mod api;
 pub mod app;
 pub mod auth;
+pub mod config;
 pub mod db;
+pub mod docs;
 pub mod errors;
+pub mod health;
+pub mod metrics;
 pub mod middlewares;
+pub mod notifications;
+pub mod rate_limit;
 pub mod responses;
 pub mod state;
 pub mod utils;
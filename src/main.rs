@@ -1,59 +1,65 @@
 mod admin;
+mod api;
 mod app;
 mod auth;
+mod config;
+mod docs;
 mod errors;
+mod health;
+mod metrics;
 mod middlewares;
+mod notifications;
+mod rate_limit;
 mod responses;
 mod state;
 mod utils;
 
-use crate::errors::error::AppError;
-use actix_multipart::MultipartError;
-use actix_multipart::form::MultipartFormConfig;
-use actix_web::error::InternalError;
-use actix_web::{App, HttpServer, ResponseError, web};
-use dotenvy::from_filename;
-use sqlx::PgPool;
+use crate::config::settings::Config;
+use crate::errors::extractors;
+use crate::middlewares::metrics::MetricsMiddleware;
+use crate::middlewares::request_id::RequestIdMiddleware;
+use crate::utils::telemetry;
+use actix_web::{App, HttpServer, web};
 use state::AppState;
-use std::collections::HashMap;
-use std::env;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    from_filename(".env.dev").ok();
-    env_logger::try_init().ok();
+    let config = Config::load().expect("Failed to load configuration");
+    telemetry::init(&config.logging).expect("Failed to initialize logging");
 
-    let database_url = env::var("DEV_DATABASE_URL").expect("DEV_DATABASE_URL must be set");
-    let pool = PgPool::connect(&database_url)
+    let pool = config
+        .database
+        .connect()
         .await
         .expect("Failed to create pool");
 
-    HttpServer::new(move || {
+    let bind_address = config.server.bind_address();
+    let workers = config.server.workers;
+    let uploads = config.uploads.clone();
+    let cors = config.cors.clone();
+
+    // built once so every worker shares the same services
+    let state = web::Data::new(AppState::new(pool, config));
+
+    let mut server = HttpServer::new(move || {
         App::new()
-            .app_data(web::Data::new(AppState::new(pool.clone())))
-            .app_data(MultipartFormConfig::default().error_handler(|err, _req| {
-                let response = match &err {
-                    MultipartError::MissingField(field) => {
-                        let mut validation_error = HashMap::new();
-                        validation_error
-                            .insert(field.to_owned(), vec!["Field is required".to_string()]);
-
-                        AppError::ValidationSingle(validation_error)
-                    }
-                    _ => AppError::Conflict("Invalid multipart payload".to_string()),
-                };
-
-                InternalError::from_response(err, response.error_response()).into()
-            }))
-            .configure(auth::routes::routes)
-            .configure(admin::routes::routes)
-            .configure(app::categories::routes::routes)
-            .configure(app::products::routes::routes)
-            .configure(app::cart::routes::routes)
-    })
-    .bind("127.0.0.1:8080")?
-    .run()
-    .await
+            .wrap(MetricsMiddleware::new(state.metrics.clone()))
+            .wrap(cors.build())
+            .wrap(RequestIdMiddleware)
+            .app_data(state.clone())
+            .app_data(extractors::multipart_config(&uploads))
+            .configure(extractors::configure)
+            .configure(health::routes::routes)
+            .configure(metrics::routes::routes)
+            .configure(docs::routes::routes)
+            .configure(api::routes)
+    });
+
+    if let Some(workers) = workers {
+        server = server.workers(workers);
+    }
+
+    server.bind(bind_address)?.run().await
 }
 
 // TODO: handle review replies
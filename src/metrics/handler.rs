@@ -0,0 +1,26 @@
+use crate::errors::error::AppError;
+use crate::responses::error_responses::ErrorResponse;
+use crate::state::AppState;
+use actix_web::{HttpResponse, Responder, web};
+
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "operations",
+    responses(
+        (status = 200, description = "Prometheus text exposition", body = String, content_type = "text/plain"),
+        (status = 500, description = "Internal error", body = ErrorResponse, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn metrics(state: web::Data<AppState>) -> Result<impl Responder, AppError> {
+    state.metrics.observe_pool(state.health_service.pool());
+
+    let body = state
+        .metrics
+        .render()
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(state.metrics.content_type())
+        .body(body))
+}
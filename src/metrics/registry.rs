@@ -0,0 +1,155 @@
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use sqlx::PgPool;
+
+/// Route label used for requests that did not match any registered resource,
+/// keeps unknown paths from blowing up the label cardinality.
+pub const UNMATCHED_ROUTE: &str = "unmatched";
+
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+
+    // http
+    pub http_requests_total: IntCounterVec,
+    pub http_request_duration_seconds: HistogramVec,
+
+    // database pool
+    pub db_pool_connections: IntGauge,
+    pub db_pool_idle_connections: IntGauge,
+    pub db_pool_max_connections: IntGauge,
+
+    // business
+    pub carts_created_total: IntCounter,
+    pub cart_items_added_total: IntCounter,
+    pub reviews_submitted_total: IntCounter,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new_custom(Some("ecomm".to_string()), None)
+            .expect("Failed to create metrics registry");
+
+        let http_requests_total = IntCounterVec::new(
+            Opts::new("http_requests_total", "Total number of HTTP requests"),
+            &["method", "route", "status"],
+        )
+        .expect("Failed to create http_requests_total");
+
+        let http_request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency in seconds",
+            ),
+            &["method", "route"],
+        )
+        .expect("Failed to create http_request_duration_seconds");
+
+        let db_pool_connections = IntGauge::new(
+            "db_pool_connections",
+            "Number of connections currently held by the pool",
+        )
+        .expect("Failed to create db_pool_connections");
+
+        let db_pool_idle_connections = IntGauge::new(
+            "db_pool_idle_connections",
+            "Number of idle connections in the pool",
+        )
+        .expect("Failed to create db_pool_idle_connections");
+
+        let db_pool_max_connections = IntGauge::new(
+            "db_pool_max_connections",
+            "Maximum number of connections the pool can open",
+        )
+        .expect("Failed to create db_pool_max_connections");
+
+        let carts_created_total =
+            IntCounter::new("carts_created_total", "Total number of carts created")
+                .expect("Failed to create carts_created_total");
+
+        let cart_items_added_total = IntCounter::new(
+            "cart_items_added_total",
+            "Total number of items added to carts",
+        )
+        .expect("Failed to create cart_items_added_total");
+
+        let reviews_submitted_total = IntCounter::new(
+            "reviews_submitted_total",
+            "Total number of product reviews submitted",
+        )
+        .expect("Failed to create reviews_submitted_total");
+
+        let metrics = Self {
+            registry,
+            http_requests_total,
+            http_request_duration_seconds,
+            db_pool_connections,
+            db_pool_idle_connections,
+            db_pool_max_connections,
+            carts_created_total,
+            cart_items_added_total,
+            reviews_submitted_total,
+        };
+
+        metrics.register_all();
+        metrics
+    }
+
+    fn register_all(&self) {
+        let collectors: Vec<Box<dyn prometheus::core::Collector>> = vec![
+            Box::new(self.http_requests_total.clone()),
+            Box::new(self.http_request_duration_seconds.clone()),
+            Box::new(self.db_pool_connections.clone()),
+            Box::new(self.db_pool_idle_connections.clone()),
+            Box::new(self.db_pool_max_connections.clone()),
+            Box::new(self.carts_created_total.clone()),
+            Box::new(self.cart_items_added_total.clone()),
+            Box::new(self.reviews_submitted_total.clone()),
+        ];
+
+        for collector in collectors {
+            self.registry
+                .register(collector)
+                .expect("Failed to register metric");
+        }
+    }
+
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, duration_secs: f64) {
+        self.http_requests_total
+            .with_label_values(&[method, route, &status.to_string()])
+            .inc();
+
+        self.http_request_duration_seconds
+            .with_label_values(&[method, route])
+            .observe(duration_secs);
+    }
+
+    /**
+     * Pool gauges are sampled on scrape instead of being tracked on every acquire.
+     */
+    pub fn observe_pool(&self, pool: &PgPool) {
+        self.db_pool_connections.set(pool.size() as i64);
+        self.db_pool_idle_connections.set(pool.num_idle() as i64);
+        self.db_pool_max_connections
+            .set(pool.options().get_max_connections() as i64);
+    }
+
+    pub fn content_type(&self) -> String {
+        TextEncoder::new().format_type().to_string()
+    }
+
+    pub fn render(&self) -> Result<String, prometheus::Error> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+
+        String::from_utf8(buffer).map_err(|e| prometheus::Error::Msg(e.to_string()))
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,7 @@
+use super::handler;
+use actix_web::web;
+use actix_web::web::{get, resource};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(resource("/metrics").route(get().to(handler::metrics)));
+}
@@ -1,11 +1,11 @@
 use crate::auth::dto::{AuthScopes, AuthToken, AuthUserId};
 use crate::auth::traits::Scope;
-use crate::responses::error_responses::ErrorResponse;
+use crate::errors::error::AppError;
 use crate::state::AppState;
 use actix_web::body::{BoxBody, EitherBody};
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
 use actix_web::web::Data;
-use actix_web::{Error, HttpMessage, HttpRequest, HttpResponse};
+use actix_web::{Error, HttpMessage, HttpRequest, ResponseError};
 use futures_util::future::{LocalBoxFuture, Ready, ok};
 use std::rc::Rc;
 use std::sync::Arc;
@@ -74,8 +74,8 @@ where
                 Some(t) => t,
                 None => {
                     return Ok(req.into_response(
-                        HttpResponse::Unauthorized()
-                            .json(ErrorResponse::new("missing bearer".to_string()))
+                        AppError::Unauthorized("missing bearer".to_string())
+                            .error_response()
                             .map_into_left_body(),
                     ));
                 }
@@ -83,8 +83,8 @@ where
 
             let Some(auth_token_model) = auth_service.get_token_if_exist(token).await? else {
                 return Ok(req.into_response(
-                    HttpResponse::Unauthorized()
-                        .json(ErrorResponse::new("invalid token".to_string()))
+                    AppError::Unauthorized("invalid token".to_string())
+                        .error_response()
                         .map_into_left_body(),
                 ));
             };
@@ -93,20 +93,22 @@ where
 
             if auth_token.is_expired() {
                 return Ok(req.into_response(
-                    HttpResponse::Unauthorized()
-                        .json(ErrorResponse::new("expired token".to_string()))
+                    AppError::Unauthorized("expired token".to_string())
+                        .error_response()
                         .map_into_left_body(),
                 ));
             }
 
             if required_scope.is_some_and(|scope| !auth_token.scopes.contains(scope)) {
                 return Ok(req.into_response(
-                    HttpResponse::Forbidden()
-                        .json(ErrorResponse::new("permission not sufficient".to_string()))
+                    AppError::Forbidden("permission not sufficient".to_string())
+                        .error_response()
                         .map_into_left_body(),
                 ));
             }
 
+            tracing::Span::current().record("user_id", auth_token.user_id);
+
             req.extensions_mut().insert(AuthUserId(auth_token.user_id));
             req.extensions_mut().insert(AuthScopes(auth_token.scopes));
 
@@ -0,0 +1,111 @@
+use actix_web::Error;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{HeaderName, HeaderValue, LINK};
+use chrono::DateTime;
+use futures_util::future::{LocalBoxFuture, Ready, ok};
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/**
+ * Marks a route as deprecated. `since` and `sunset` are unix timestamps; the
+ * headers follow RFC 9745 (`Deprecation: @<ts>`) and RFC 8594 (`Sunset` as an
+ * HTTP-date). `successor` is advertised as a `successor-version` link.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct Deprecation {
+    pub since: i64,
+    pub sunset: Option<i64>,
+    pub successor: Option<&'static str>,
+}
+
+impl Deprecation {
+    fn headers(&self) -> Vec<(HeaderName, HeaderValue)> {
+        let mut headers = Vec::with_capacity(3);
+
+        if let Ok(value) = HeaderValue::from_str(&format!("@{}", self.since)) {
+            headers.push((DEPRECATION, value));
+        }
+
+        if let Some(sunset) = self.sunset.and_then(|ts| DateTime::from_timestamp(ts, 0))
+            && let Ok(value) =
+                HeaderValue::from_str(&sunset.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+        {
+            headers.push((SUNSET, value));
+        }
+
+        if let Some(successor) = self.successor
+            && let Ok(value) =
+                HeaderValue::from_str(&format!("<{}>; rel=\"successor-version\"", successor))
+        {
+            headers.push((LINK, value));
+        }
+
+        headers
+    }
+}
+
+pub struct DeprecationMiddleware {
+    deprecation: Deprecation,
+}
+
+impl DeprecationMiddleware {
+    pub fn new(deprecation: Deprecation) -> Self {
+        Self { deprecation }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for DeprecationMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = DeprecationMiddlewareInner<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(DeprecationMiddlewareInner {
+            service: Rc::new(service),
+            headers: Rc::new(self.deprecation.headers()),
+        })
+    }
+}
+
+pub struct DeprecationMiddlewareInner<S> {
+    service: Rc<S>,
+    headers: Rc<Vec<(HeaderName, HeaderValue)>>,
+}
+
+impl<S, B> Service<ServiceRequest> for DeprecationMiddlewareInner<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let headers = self.headers.clone();
+
+        Box::pin(async move {
+            let mut res = service.call(req).await?;
+
+            for (name, value) in headers.iter() {
+                res.headers_mut().insert(name.clone(), value.clone());
+            }
+
+            Ok(res)
+        })
+    }
+}
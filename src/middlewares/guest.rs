@@ -1,10 +1,10 @@
 use crate::app::users::dto::{GuestDto, GuestToken};
-use crate::responses::error_responses::ErrorResponse;
+use crate::errors::error::AppError;
 use crate::state::AppState;
 use actix_web::body::{BoxBody, EitherBody};
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
 use actix_web::web::Data;
-use actix_web::{Error, HttpMessage, HttpRequest, HttpResponse};
+use actix_web::{Error, HttpMessage, HttpRequest, ResponseError};
 use futures_util::future::{LocalBoxFuture, Ready, ok};
 use std::rc::Rc;
 use std::task::{Context, Poll};
@@ -60,8 +60,8 @@ where
                 Some(t) => t,
                 None => {
                     return Ok(req.into_response(
-                        HttpResponse::Unauthorized()
-                            .json(ErrorResponse::new("missing guest token".to_string()))
+                        AppError::Unauthorized("missing guest token".to_string())
+                            .error_response()
                             .map_into_left_body(),
                     ));
                 }
@@ -70,8 +70,8 @@ where
             let Some(user_hash_model) = user_service.get_user_hash(user_hash.as_str()).await?
             else {
                 return Ok(req.into_response(
-                    HttpResponse::Unauthorized()
-                        .json(ErrorResponse::new("token not found".to_string()))
+                    AppError::Unauthorized("token not found".to_string())
+                        .error_response()
                         .map_into_left_body(),
                 ));
             };
@@ -79,9 +79,11 @@ where
             let guest = GuestDto::from(user_hash_model);
 
             if guest.is_expired() {
-                return Ok(
-                    req.into_response(HttpResponse::Unauthorized().finish().map_into_left_body())
-                );
+                return Ok(req.into_response(
+                    AppError::Unauthorized("expired guest token".to_string())
+                        .error_response()
+                        .map_into_left_body(),
+                ));
             }
 
             req.extensions_mut().insert(GuestToken(guest.hash));
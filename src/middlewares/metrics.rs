@@ -0,0 +1,85 @@
+use crate::metrics::registry::{Metrics, UNMATCHED_ROUTE};
+use actix_web::Error;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use futures_util::future::{LocalBoxFuture, Ready, ok};
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+pub struct MetricsMiddleware {
+    metrics: Metrics,
+}
+
+impl MetricsMiddleware {
+    pub fn new(metrics: Metrics) -> Self {
+        Self { metrics }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for MetricsMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = MetricsMiddlewareInner<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(MetricsMiddlewareInner {
+            service: Rc::new(service),
+            metrics: self.metrics.clone(),
+        })
+    }
+}
+
+pub struct MetricsMiddlewareInner<S> {
+    service: Rc<S>,
+    metrics: Metrics,
+}
+
+impl<S, B> Service<ServiceRequest> for MetricsMiddlewareInner<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let metrics = self.metrics.clone();
+        let method = req.method().to_string();
+        let started_at = Instant::now();
+
+        Box::pin(async move {
+            let result = service.call(req).await;
+            let elapsed = started_at.elapsed().as_secs_f64();
+
+            // the matched pattern is only known once routing has happened
+            let (route, status) = match &result {
+                Ok(res) => (
+                    res.request()
+                        .match_pattern()
+                        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string()),
+                    res.status().as_u16(),
+                ),
+                Err(err) => (
+                    UNMATCHED_ROUTE.to_string(),
+                    err.as_response_error().status_code().as_u16(),
+                ),
+            };
+
+            metrics.observe_request(&method, &route, status, elapsed);
+
+            result
+        })
+    }
+}
@@ -1,2 +1,6 @@
 pub mod auth;
+pub mod deprecation;
 pub mod guest;
+pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
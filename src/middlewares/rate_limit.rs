@@ -0,0 +1,168 @@
+use crate::app::users::dto::GuestToken;
+use crate::auth::dto::AuthUserId;
+use crate::errors::error::AppError;
+use crate::rate_limit::model::RateLimitDecision;
+use crate::rate_limit::policy::{RateLimitKey, RateLimitPolicy};
+use crate::state::AppState;
+use crate::utils::extractors::extract_client_ip;
+use actix_web::body::{BoxBody, EitherBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER};
+use actix_web::web::Data;
+use actix_web::{Error, HttpMessage, ResponseError};
+use futures_util::future::{LocalBoxFuture, Ready, ok};
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use tracing::error;
+
+const RATE_LIMIT_LIMIT: &str = "ratelimit-limit";
+const RATE_LIMIT_REMAINING: &str = "ratelimit-remaining";
+const RATE_LIMIT_RESET: &str = "ratelimit-reset";
+
+pub struct RateLimitMiddleware {
+    policy: RateLimitPolicy,
+    key: RateLimitKey,
+}
+
+impl RateLimitMiddleware {
+    pub fn new(policy: RateLimitPolicy, key: RateLimitKey) -> Self {
+        Self { policy, key }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimitMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<BoxBody, B>>;
+    type Error = Error;
+    type Transform = RateLimitMiddlewareInner<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(RateLimitMiddlewareInner {
+            service: Rc::new(service),
+            policy: self.policy,
+            key: self.key,
+        })
+    }
+}
+
+pub struct RateLimitMiddlewareInner<S> {
+    service: Rc<S>,
+    policy: RateLimitPolicy,
+    key: RateLimitKey,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitMiddlewareInner<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<BoxBody, B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let state = req
+            .app_data::<Data<AppState>>()
+            .expect("App State missing from app data")
+            .get_ref();
+
+        let rate_limit_service = state.rate_limit_service.clone();
+        let client_key =
+            extract_client_key(&req, self.key, state.config.server.trust_forwarded_for);
+        let policy = self.policy;
+
+        Box::pin(async move {
+            // a broken store must not take the whole api down, so failures let the request through
+            let decision = match rate_limit_service.check(policy, &client_key).await {
+                Ok(decision) => decision,
+                Err(e) => {
+                    error!(error = %e, policy = policy.as_str(), "rate limit check failed");
+                    None
+                }
+            };
+
+            let Some(decision) = decision else {
+                return service.call(req).await.map(|res| res.map_into_right_body());
+            };
+
+            if !decision.allowed {
+                let mut response = AppError::TooManyRequests.error_response();
+
+                insert_rate_limit_headers(response.headers_mut(), &decision);
+
+                return Ok(req.into_response(response.map_into_left_body()));
+            }
+
+            let mut res = service.call(req).await?;
+            insert_rate_limit_headers(res.headers_mut(), &decision);
+
+            Ok(res.map_into_right_body())
+        })
+    }
+}
+
+fn extract_client_key(
+    req: &ServiceRequest,
+    key: RateLimitKey,
+    trust_forwarded_for: bool,
+) -> String {
+    match key {
+        RateLimitKey::AuthUser => {
+            if let Some(user_id) = req.extensions().get::<AuthUserId>() {
+                return format!("user:{}", user_id.0);
+            }
+        }
+        RateLimitKey::GuestToken => {
+            let token = req
+                .extensions()
+                .get::<GuestToken>()
+                .map(|token| token.0.clone())
+                .or_else(|| {
+                    req.headers()
+                        .get("x-guest-token")
+                        .and_then(|h| h.to_str().ok())
+                        .map(|s| s.to_string())
+                });
+
+            if let Some(token) = token {
+                return format!("guest:{}", token);
+            }
+        }
+        RateLimitKey::Ip => {}
+    }
+
+    // requests without a user or guest identity fall back to the client ip
+    format!(
+        "ip:{}",
+        extract_client_ip(req.request(), trust_forwarded_for)
+    )
+}
+
+fn insert_rate_limit_headers(headers: &mut HeaderMap, decision: &RateLimitDecision) {
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_LIMIT),
+        HeaderValue::from(decision.limit),
+    );
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_REMAINING),
+        HeaderValue::from(decision.remaining),
+    );
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_RESET),
+        HeaderValue::from(decision.reset_after_secs),
+    );
+
+    if !decision.allowed {
+        headers.insert(RETRY_AFTER, HeaderValue::from(decision.retry_after_secs));
+    }
+}
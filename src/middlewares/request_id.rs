@@ -0,0 +1,116 @@
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::{Error, HttpRequest};
+use futures_util::future::{LocalBoxFuture, Ready, ok};
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tracing::{Instrument, field, info, info_span};
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
+/**
+ * Returns the id of the request being handled on the current task, if any.
+ * Used by `ErrorResponse` so every error body carries the id.
+ */
+pub fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+pub struct RequestIdMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestIdMiddlewareInner<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(RequestIdMiddlewareInner {
+            service: Rc::new(service),
+        })
+    }
+}
+
+pub struct RequestIdMiddlewareInner<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdMiddlewareInner<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        let request_id =
+            incoming_request_id(req.request()).unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        // `user_id` is filled in by the auth middleware once the token is resolved
+        let span = info_span!(
+            "http_request",
+            request_id = %request_id,
+            method = %req.method(),
+            path = %req.path(),
+            user_id = field::Empty,
+            status = field::Empty,
+        );
+
+        let started_at = Instant::now();
+
+        let fut = async move {
+            let mut res = service.call(req).await?;
+
+            if let Ok(value) = HeaderValue::from_str(&CURRENT_REQUEST_ID.with(|id| id.clone())) {
+                res.headers_mut()
+                    .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+            }
+
+            let status = res.status().as_u16();
+            tracing::Span::current().record("status", status);
+
+            info!(
+                status,
+                latency_ms = started_at.elapsed().as_millis() as u64,
+                "request completed"
+            );
+
+            Ok(res)
+        };
+
+        Box::pin(CURRENT_REQUEST_ID.scope(request_id, fut.instrument(span)))
+    }
+}
+
+fn incoming_request_id(req: &HttpRequest) -> Option<String> {
+    let value = req.headers().get(REQUEST_ID_HEADER)?.to_str().ok()?.trim();
+
+    let is_valid = !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'));
+
+    is_valid.then(|| value.to_string())
+}
@@ -0,0 +1,38 @@
+use crate::utils::money::Money;
+use serde::{Deserialize, Serialize};
+
+/** Something a customer asked to be told about. */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Notification {
+    BackInStock {
+        user_id: i64,
+        product_id: i64,
+        product_name: String,
+    },
+    PriceDrop {
+        user_id: i64,
+        product_id: i64,
+        product_name: String,
+        old_price: Money,
+        new_price: Money,
+    },
+    /// A "notify me when available" subscription was met, guests have no user.
+    Available {
+        user_id: Option<i64>,
+        email: String,
+        product_id: i64,
+        product_name: String,
+    },
+}
+
+impl Notification {
+    pub fn user_id(&self) -> Option<i64> {
+        match self {
+            Notification::BackInStock { user_id, .. } | Notification::PriceDrop { user_id, .. } => {
+                Some(*user_id)
+            }
+            Notification::Available { user_id, .. } => *user_id,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::config::settings::{NotificationChannel, NotificationsConfig};
+use crate::notifications::dto::Notification;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+/**
+ * Delivers notifications to customers. Called after the change that caused
+ * them is committed, so an implementation that talks to the network should
+ * hand the work off instead of blocking the request.
+ */
+pub trait Notifier: Send + Sync {
+    fn notify(&self, notification: Notification);
+}
+
+/** The notifier `notifications.channel` selects. */
+pub fn from_config(config: &NotificationsConfig) -> Arc<dyn Notifier> {
+    match config.channel {
+        NotificationChannel::Log => Arc::new(LogNotifier),
+        NotificationChannel::File => Arc::new(FileNotifier::new(config.path.clone())),
+    }
+}
+
+/** Writes notifications to the log, until a real delivery channel exists. */
+pub struct LogNotifier;
+
+impl Notifier for LogNotifier {
+    fn notify(&self, notification: Notification) {
+        info!(
+            user_id = ?notification.user_id(),
+            notification = %serde_json::to_string(&notification).unwrap_or_default(),
+            "notification"
+        );
+    }
+}
+
+/**
+ * Appends notifications to a file, one JSON object per line. Meant for
+ * development and tests, a failed write is logged and the notification lost.
+ */
+pub struct FileNotifier {
+    path: PathBuf,
+    // keeps lines from concurrent requests whole
+    lock: Mutex<()>,
+}
+
+impl FileNotifier {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn append(&self, line: &str) -> std::io::Result<()> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        writeln!(file, "{}", line)
+    }
+}
+
+impl Notifier for FileNotifier {
+    fn notify(&self, notification: Notification) {
+        let line = serde_json::to_string(&notification).unwrap_or_default();
+
+        if let Err(error) = self.append(&line) {
+            warn!(path = %self.path.display(), %error, "failed to write notification");
+        }
+    }
+}
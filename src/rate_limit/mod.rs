@@ -0,0 +1,5 @@
+pub mod model;
+pub mod policy;
+pub mod repository;
+pub mod service;
+pub mod store;
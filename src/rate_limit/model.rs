@@ -0,0 +1,72 @@
+use crate::config::settings::RateLimitPolicyConfig;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Bucket {
+    pub tokens: f64,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Seconds until the bucket is full again.
+    pub reset_after_secs: u64,
+    /// Seconds until the next request is allowed, zero when allowed.
+    pub retry_after_secs: u64,
+}
+
+impl Bucket {
+    pub fn full(policy: &RateLimitPolicyConfig, now: DateTime<Utc>) -> Self {
+        Self {
+            tokens: policy.capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    /**
+     * Refills the bucket for the time elapsed since the last update and tries to take one token.
+     */
+    pub fn take(
+        &mut self,
+        policy: &RateLimitPolicyConfig,
+        now: DateTime<Utc>,
+    ) -> RateLimitDecision {
+        let capacity = policy.capacity as f64;
+        let rate_per_sec = policy.refill_per_minute as f64 / 60.0;
+
+        let elapsed = (now - self.updated_at).num_milliseconds().max(0) as f64 / 1000.0;
+
+        self.tokens = (self.tokens + elapsed * rate_per_sec).min(capacity);
+        self.updated_at = now;
+
+        let allowed = self.tokens >= 1.0;
+
+        if allowed {
+            self.tokens -= 1.0;
+        }
+
+        let retry_after_secs = if allowed {
+            0
+        } else {
+            ((1.0 - self.tokens) / rate_per_sec).ceil() as u64
+        };
+
+        RateLimitDecision {
+            allowed,
+            limit: policy.capacity,
+            remaining: self.tokens.floor() as u32,
+            reset_after_secs: ((capacity - self.tokens) / rate_per_sec).ceil() as u64,
+            retry_after_secs,
+        }
+    }
+
+    pub fn is_full(&self, policy: &RateLimitPolicyConfig, now: DateTime<Utc>) -> bool {
+        let rate_per_sec = policy.refill_per_minute as f64 / 60.0;
+        let elapsed = (now - self.updated_at).num_milliseconds().max(0) as f64 / 1000.0;
+
+        self.tokens + elapsed * rate_per_sec >= policy.capacity as f64
+    }
+}
@@ -0,0 +1,36 @@
+use crate::config::settings::{RateLimitConfig, RateLimitPolicyConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKey {
+    Ip,
+    AuthUser,
+    GuestToken,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitPolicy {
+    Login,
+    Register,
+    ReviewCreate,
+    CartMutation,
+}
+
+impl RateLimitPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitPolicy::Login => "login",
+            RateLimitPolicy::Register => "register",
+            RateLimitPolicy::ReviewCreate => "reviews",
+            RateLimitPolicy::CartMutation => "cart",
+        }
+    }
+
+    pub fn config(&self, config: &RateLimitConfig) -> RateLimitPolicyConfig {
+        match self {
+            RateLimitPolicy::Login => config.login,
+            RateLimitPolicy::Register => config.register,
+            RateLimitPolicy::ReviewCreate => config.reviews,
+            RateLimitPolicy::CartMutation => config.cart,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::errors::error::AppError;
+use crate::rate_limit::model::Bucket;
+use crate::utils::traits::IsRepository;
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, PgPool, Postgres};
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct RateLimitRepository {
+    pool: PgPool,
+}
+
+impl IsRepository for RateLimitRepository {
+    type Repository = Self;
+
+    fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+impl RateLimitRepository {
+    #[instrument(skip_all)]
+    pub async fn create_if_missing<'e, E>(
+        &self,
+        executor: E,
+        key: &str,
+        initial: Bucket,
+    ) -> Result<(), AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+        INSERT INTO rate_limit_buckets (key, tokens, updated_at)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (key) DO NOTHING;
+        "#,
+            key,
+            initial.tokens,
+            initial.updated_at,
+        )
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(())
+    }
+
+    /**
+     * Locks the bucket row until the surrounding transaction ends,
+     * so concurrent instances serialize on the same key.
+     */
+    #[instrument(skip_all)]
+    pub async fn lock<'e, E>(&self, executor: E, key: &str) -> Result<Option<Bucket>, AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let row = sqlx::query!(
+            r#"
+        SELECT tokens, updated_at
+        FROM rate_limit_buckets
+        WHERE key = $1
+        FOR UPDATE;
+        "#,
+            key,
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(row.map(|row| Bucket {
+            tokens: row.tokens,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    #[instrument(skip_all)]
+    pub async fn save<'e, E>(&self, executor: E, key: &str, bucket: Bucket) -> Result<(), AppError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+        UPDATE rate_limit_buckets
+        SET tokens = $2, updated_at = $3
+        WHERE key = $1;
+        "#,
+            key,
+            bucket.tokens,
+            bucket.updated_at,
+        )
+        .execute(executor)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete_stale(&self, older_than: DateTime<Utc>) -> Result<u64, AppError> {
+        let result = sqlx::query!(
+            "DELETE FROM rate_limit_buckets WHERE updated_at < $1;",
+            older_than,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(AppError::Database)?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::config::settings::{RateLimitConfig, RateLimitStoreKind};
+use crate::errors::error::AppError;
+use crate::rate_limit::model::RateLimitDecision;
+use crate::rate_limit::policy::RateLimitPolicy;
+use crate::rate_limit::store::{MemoryStore, PostgresStore, RateLimitStore};
+use chrono::Utc;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct RateLimitService {
+    pub config: RateLimitConfig,
+    store: Arc<RateLimitStore>,
+}
+
+impl RateLimitService {
+    pub fn new(pool: PgPool, config: RateLimitConfig) -> Self {
+        let store = match config.store {
+            RateLimitStoreKind::Memory => RateLimitStore::Memory(MemoryStore::new()),
+            RateLimitStoreKind::Postgres => RateLimitStore::Postgres(PostgresStore::new(pool)),
+        };
+
+        Self {
+            config,
+            store: Arc::new(store),
+        }
+    }
+
+    /**
+     * Takes one token for the given policy and client key.
+     * Returns `None` when rate limiting is disabled.
+     */
+    pub async fn check(
+        &self,
+        policy: RateLimitPolicy,
+        client_key: &str,
+    ) -> Result<Option<RateLimitDecision>, AppError> {
+        if !self.config.enabled {
+            return Ok(None);
+        }
+
+        let key = format!("{}:{}", policy.as_str(), client_key);
+        let policy_config = policy.config(&self.config);
+
+        self.store
+            .take(&key, &policy_config, Utc::now())
+            .await
+            .map(Some)
+    }
+}
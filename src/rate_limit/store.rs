@@ -0,0 +1,115 @@
+use crate::config::settings::RateLimitPolicyConfig;
+use crate::errors::error::AppError;
+use crate::rate_limit::model::{Bucket, RateLimitDecision};
+use crate::rate_limit::repository::RateLimitRepository;
+use crate::utils::traits::IsRepository;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Buckets are pruned once the in-memory map grows past this size.
+const MEMORY_PRUNE_THRESHOLD: usize = 10_000;
+
+/// Postgres buckets untouched for this long are refilled anyway and can be dropped.
+const POSTGRES_STALE_AFTER_MINUTES: i64 = 60;
+
+pub enum RateLimitStore {
+    Memory(MemoryStore),
+    Postgres(PostgresStore),
+}
+
+impl RateLimitStore {
+    pub async fn take(
+        &self,
+        key: &str,
+        policy: &RateLimitPolicyConfig,
+        now: DateTime<Utc>,
+    ) -> Result<RateLimitDecision, AppError> {
+        match self {
+            RateLimitStore::Memory(store) => Ok(store.take(key, policy, now)),
+            RateLimitStore::Postgres(store) => store.take(key, policy, now).await,
+        }
+    }
+}
+
+/**
+ * Per-process store, only correct when a single instance serves the traffic.
+ */
+#[derive(Default)]
+pub struct MemoryStore {
+    buckets: Mutex<HashMap<String, (Bucket, RateLimitPolicyConfig)>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn take(
+        &self,
+        key: &str,
+        policy: &RateLimitPolicyConfig,
+        now: DateTime<Utc>,
+    ) -> RateLimitDecision {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if buckets.len() >= MEMORY_PRUNE_THRESHOLD {
+            buckets.retain(|_, (bucket, policy)| !bucket.is_full(policy, now));
+        }
+
+        let (bucket, _) = buckets
+            .entry(key.to_string())
+            .or_insert_with(|| (Bucket::full(policy, now), *policy));
+
+        bucket.take(policy, now)
+    }
+}
+
+/**
+ * Shared store for multi-instance deployments, buckets live in `rate_limit_buckets`.
+ */
+pub struct PostgresStore {
+    repository: RateLimitRepository,
+}
+
+impl PostgresStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            repository: RateLimitRepository::new(pool),
+        }
+    }
+
+    pub async fn take(
+        &self,
+        key: &str,
+        policy: &RateLimitPolicyConfig,
+        now: DateTime<Utc>,
+    ) -> Result<RateLimitDecision, AppError> {
+        let mut tx = self.repository.start_transaction().await?;
+
+        self.repository
+            .create_if_missing(&mut *tx, key, Bucket::full(policy, now))
+            .await?;
+
+        let mut bucket = self
+            .repository
+            .lock(&mut *tx, key)
+            .await?
+            .unwrap_or_else(|| Bucket::full(policy, now));
+
+        let decision = bucket.take(policy, now);
+
+        self.repository.save(&mut *tx, key, bucket).await?;
+        self.repository.commit_transaction(tx).await?;
+
+        // opportunistic cleanup instead of a background job
+        if rand::random_ratio(1, 1000) {
+            self.repository
+                .delete_stale(now - Duration::minutes(POSTGRES_STALE_AFTER_MINUTES))
+                .await?;
+        }
+
+        Ok(decision)
+    }
+}
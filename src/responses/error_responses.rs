@@ -1,30 +1,75 @@
-use crate::utils::pagination::Paginate;
+use crate::errors::error::ErrorCode;
+use crate::middlewares::request_id::current_request_id;
+use crate::utils::pagination::{CursorMeta, Paginate, PaginationMeta};
+use actix_web::http::StatusCode;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug)]
+/**
+ * RFC 7807 problem details. `code` is the stable machine-readable identifier
+ * (also used to build `type`), `detail` is meant for humans.
+ */
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct ErrorResponse {
-    pub message: String,
-    pub errors: Option<HashMap<String, Vec<String>>>,
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    pub code: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 
 impl ErrorResponse {
-    pub fn new(message: String) -> Self {
+    pub fn new(code: ErrorCode, status: StatusCode, detail: &str) -> Self {
         Self {
-            message,
-            errors: None,
+            problem_type: format!("urn:ecomm:problem:{}", code.as_str()),
+            title: code.title().to_string(),
+            status: status.as_u16(),
+            detail: detail.to_string(),
+            code: code.as_str().to_string(),
+            errors: Vec::new(),
+            request_id: current_request_id(),
         }
     }
+
+    pub fn with_errors(mut self, errors: Vec<FieldError>) -> Self {
+        self.errors = errors;
+        self
+    }
 }
 
-#[derive(Serialize)]
+/**
+ * `field` is a dotted path into the request body (`items[0].quantity`).
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, code: &str, message: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
 pub struct SuccessResponse<T>
 where
     T: Serialize,
 {
     pub message: String,
     pub data: Option<T>,
-    pub meta: Option<Paginate>,
+    pub meta: Option<PaginationMeta>,
 }
 
 impl<T: Serialize> SuccessResponse<T> {
@@ -40,7 +85,15 @@ impl<T: Serialize> SuccessResponse<T> {
         Self {
             message: "success".to_string(),
             data: Some(data),
-            meta: Some(pagination),
+            meta: Some(PaginationMeta::Page(pagination)),
+        }
+    }
+
+    pub fn ok_with_cursor(data: T, meta: CursorMeta) -> Self {
+        Self {
+            message: "success".to_string(),
+            data: Some(data),
+            meta: Some(PaginationMeta::Cursor(meta)),
         }
     }
 
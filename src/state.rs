@@ -1,17 +1,37 @@
+use crate::admin::audit::service::AdminAuditService;
 use crate::admin::categories::service::AdminCategoryService;
+use crate::admin::coupons::service::AdminCouponService;
 use crate::admin::products::images::service::AdminProductImageService;
 use crate::admin::products::service::AdminProductService;
+use crate::admin::products::transfer::service::AdminProductTransferService;
 use crate::admin::products::videos::service::AdminProductVideoService;
+use crate::admin::promotions::service::AdminPromotionService;
 use crate::admin::reviews::service::AdminReviewService;
+use crate::admin::shipping_methods::service::AdminShippingMethodService;
+use crate::admin::shipping_zones::service::AdminShippingZoneService;
+use crate::admin::stock::service::AdminStockService;
+use crate::admin::tax_rates::service::AdminTaxRateService;
 use crate::admin::users::service::AdminUserService;
+use crate::admin::warehouses::service::AdminWarehouseService;
+use crate::app::addresses::service::AddressService;
 use crate::app::cart::cart_items::service::CartItemsService;
+use crate::app::cart::coupons::service::CartCouponService;
 use crate::app::cart::guest_cart::service::GuestCartService;
 use crate::app::cart::user_cart::service::UserCartService;
 use crate::app::categories::service::CategoryService;
+use crate::app::products::cache::ProductCache;
 use crate::app::products::reviews::service::ProductReviewService;
 use crate::app::products::service::ProductService;
+use crate::app::stock_subscriptions::service::StockSubscriptionService;
 use crate::app::users::service::UserService;
+use crate::app::wishlists::service::WishlistService;
 use crate::auth::service::AuthService;
+use crate::config::settings::Config;
+use crate::health::service::HealthService;
+use crate::metrics::registry::Metrics;
+use crate::notifications::notifier;
+use crate::rate_limit::service::RateLimitService;
+use crate::utils::cursor::CursorCodec;
 use crate::utils::storage::LocalStorage;
 use sqlx::PgPool;
 
@@ -22,43 +42,121 @@ pub struct AppState {
     pub user_cart_service: UserCartService,
     pub guest_cart_service: GuestCartService,
     pub cart_items_service: CartItemsService,
+    pub cart_coupon_service: CartCouponService,
     pub user_service: UserService,
+    pub address_service: AddressService,
+    pub wishlist_service: WishlistService,
+    pub stock_subscription_service: StockSubscriptionService,
     pub reviews_service: ProductReviewService,
 
     // admin services
     pub admin_product_service: AdminProductService,
     pub admin_product_images_service: AdminProductImageService,
     pub admin_product_videos_service: AdminProductVideoService,
+    pub admin_product_transfer_service: AdminProductTransferService,
     pub admin_category_service: AdminCategoryService,
     pub admin_reviews_service: AdminReviewService,
+    pub admin_coupon_service: AdminCouponService,
+    pub admin_promotion_service: AdminPromotionService,
+    pub admin_tax_rate_service: AdminTaxRateService,
+    pub admin_shipping_zone_service: AdminShippingZoneService,
+    pub admin_shipping_method_service: AdminShippingMethodService,
+    pub admin_stock_service: AdminStockService,
+    pub admin_warehouse_service: AdminWarehouseService,
     pub admin_user_service: AdminUserService,
+    pub admin_audit_service: AdminAuditService,
+
+    // operational
+    pub health_service: HealthService,
+    pub metrics: Metrics,
+    pub rate_limit_service: RateLimitService,
+    pub cursor_codec: CursorCodec,
 
     // storage
     pub local_storage: LocalStorage,
+
+    // configuration
+    pub config: Config,
 }
 
 impl AppState {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, config: Config) -> Self {
+        let metrics = Metrics::new();
+        let product_cache = ProductCache::new(&config.cache);
+        let notifier = notifier::from_config(&config.notifications);
+
         Self {
-            auth_service: AuthService::new(pool.clone()),
-            product_service: ProductService::new(pool.clone()),
+            auth_service: AuthService::new(pool.clone(), config.auth.clone()),
+            product_service: ProductService::new(pool.clone(), product_cache.clone()),
             category_service: CategoryService::new(pool.clone()),
-            user_cart_service: UserCartService::new(pool.clone()),
-            guest_cart_service: GuestCartService::new(pool.clone()),
-            cart_items_service: CartItemsService::new(pool.clone()),
+            user_cart_service: UserCartService::new(
+                pool.clone(),
+                metrics.clone(),
+                config.tax.clone(),
+            ),
+            guest_cart_service: GuestCartService::new(
+                pool.clone(),
+                metrics.clone(),
+                config.tax.clone(),
+            ),
+            cart_items_service: CartItemsService::new(pool.clone(), config.inventory.clone()),
+            cart_coupon_service: CartCouponService::new(pool.clone()),
             user_service: UserService::new(pool.clone()),
-            reviews_service: ProductReviewService::new(pool.clone()),
+            address_service: AddressService::new(pool.clone()),
+            wishlist_service: WishlistService::new(pool.clone(), config.inventory.clone()),
+            stock_subscription_service: StockSubscriptionService::new(pool.clone()),
+            reviews_service: ProductReviewService::new(pool.clone(), product_cache.clone()),
 
             // admin services
-            admin_product_service: AdminProductService::new(pool.clone()),
-            admin_product_images_service: AdminProductImageService::new(pool.clone()),
-            admin_product_videos_service: AdminProductVideoService::new(pool.clone()),
+            admin_product_service: AdminProductService::with_notifier(
+                pool.clone(),
+                product_cache.clone(),
+                notifier.clone(),
+            ),
+            admin_product_images_service: AdminProductImageService::new(
+                pool.clone(),
+                product_cache.clone(),
+            ),
+            admin_product_videos_service: AdminProductVideoService::new(
+                pool.clone(),
+                product_cache.clone(),
+            ),
+            admin_product_transfer_service: AdminProductTransferService::with_notifier(
+                pool.clone(),
+                product_cache.clone(),
+                notifier.clone(),
+            ),
             admin_category_service: AdminCategoryService::new(pool.clone()),
-            admin_reviews_service: AdminReviewService::new(pool.clone()),
-            admin_user_service: AdminUserService::new(pool),
+            admin_reviews_service: AdminReviewService::new(pool.clone(), product_cache.clone()),
+            admin_coupon_service: AdminCouponService::new(pool.clone()),
+            admin_promotion_service: AdminPromotionService::new(pool.clone()),
+            admin_tax_rate_service: AdminTaxRateService::new(pool.clone()),
+            admin_shipping_zone_service: AdminShippingZoneService::new(pool.clone()),
+            admin_shipping_method_service: AdminShippingMethodService::new(pool.clone()),
+            admin_stock_service: AdminStockService::with_notifier(
+                pool.clone(),
+                product_cache.clone(),
+                notifier.clone(),
+            ),
+            admin_warehouse_service: AdminWarehouseService::with_notifier(
+                pool.clone(),
+                product_cache,
+                notifier,
+            ),
+            admin_user_service: AdminUserService::new(pool.clone()),
+            admin_audit_service: AdminAuditService::new(pool.clone()),
+
+            // operational
+            health_service: HealthService::new(pool.clone()),
+            metrics,
+            rate_limit_service: RateLimitService::new(pool, config.rate_limit.clone()),
+            cursor_codec: CursorCodec::new(&config.pagination.cursor_secret),
 
             // storage
-            local_storage: LocalStorage::new("public/uploads".to_string()),
+            local_storage: LocalStorage::new(config.storage.local.base_path.clone()),
+
+            // configuration
+            config,
         }
     }
 }
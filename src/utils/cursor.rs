@@ -0,0 +1,109 @@
+use crate::errors::error::AppError;
+use crate::utils::traits::HasId;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::sync::Arc;
+use tracing::warn;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/**
+ * Rows listed with cursor pagination are ordered by a sort key, newest first,
+ * with the id as a tie breaker.
+ */
+pub trait HasCursorKey: HasId {
+    fn cursor_key(&self) -> DateTime<Utc>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CursorDirection {
+    #[serde(rename = "n")]
+    Next,
+    #[serde(rename = "p")]
+    Prev,
+}
+
+/**
+ * Position in a keyset listing: the sort key and id of the boundary row and
+ * which way to read from it.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursor {
+    #[serde(rename = "k")]
+    pub sort_key: DateTime<Utc>,
+    #[serde(rename = "i")]
+    pub id: i64,
+    #[serde(rename = "d")]
+    pub direction: CursorDirection,
+}
+
+impl Cursor {
+    pub fn at<T: HasCursorKey>(row: &T, direction: CursorDirection) -> Self {
+        Self {
+            sort_key: row.cursor_key(),
+            id: row.get_id(),
+            direction,
+        }
+    }
+}
+
+/**
+ * Encodes cursors as `base64(json).base64(hmac)` so clients can pass them
+ * around but not forge positions.
+ */
+#[derive(Clone)]
+pub struct CursorCodec {
+    key: Arc<Vec<u8>>,
+}
+
+impl CursorCodec {
+    pub fn new(secret: &str) -> Self {
+        let key = if secret.is_empty() {
+            warn!("pagination.cursor_secret is not set, using a random per-process key");
+            rand::random::<[u8; 32]>().to_vec()
+        } else {
+            secret.as_bytes().to_vec()
+        };
+
+        Self { key: Arc::new(key) }
+    }
+
+    pub fn encode(&self, cursor: &Cursor) -> String {
+        let payload = serde_json::to_vec(cursor).expect("cursor serializes to json");
+
+        format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(&payload),
+            URL_SAFE_NO_PAD.encode(self.sign(&payload))
+        )
+    }
+
+    pub fn decode(&self, token: &str) -> Result<Cursor, AppError> {
+        let invalid = || AppError::field("cursor", "invalid_cursor", "is invalid or expired");
+
+        let (payload, signature) = token.split_once('.').ok_or_else(invalid)?;
+
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| invalid())?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid())?;
+
+        let mut mac = self.mac();
+        mac.update(&payload);
+        mac.verify_slice(&signature).map_err(|_| invalid())?;
+
+        serde_json::from_slice(&payload).map_err(|_| invalid())
+    }
+
+    fn sign(&self, payload: &[u8]) -> Vec<u8> {
+        let mut mac = self.mac();
+        mac.update(payload);
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.key).expect("hmac accepts keys of any length")
+    }
+}
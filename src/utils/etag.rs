@@ -0,0 +1,79 @@
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag, Header, IfNoneMatch};
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/**
+ * Row version of a catalog entity, kept current by the `updated_at` triggers.
+ */
+pub trait Versioned {
+    fn version(&self) -> (i64, DateTime<Utc>);
+
+    /**
+     * Shown state that changes on a schedule rather than through an update,
+     * so `updated_at` alone would keep serving a stale tag.
+     */
+    fn scheduled_state(&self) -> u64 {
+        0
+    }
+}
+
+/**
+ * Strong entity tag over the versions of every row in a representation.
+ * `variant` tells apart representations built from the same rows, e.g. a
+ * different query string or set of loaded relations.
+ */
+pub fn entity_tag<'a, T, I>(items: I, variant: &str) -> EntityTag
+where
+    T: Versioned + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let mut hasher = DefaultHasher::new();
+    variant.hash(&mut hasher);
+
+    for item in items {
+        let (id, updated_at) = item.version();
+        id.hash(&mut hasher);
+        updated_at.timestamp_micros().hash(&mut hasher);
+        item.scheduled_state().hash(&mut hasher);
+    }
+
+    EntityTag::new_strong(format!("{:016x}", hasher.finish()))
+}
+
+/**
+ * Answers `304 Not Modified` when `If-None-Match` matches the current tag,
+ * otherwise the json body. Both carry the tag and the public cache policy.
+ */
+pub fn conditional_json<T: Serialize>(
+    request: &HttpRequest,
+    etag: EntityTag,
+    max_age_secs: u32,
+    body: &T,
+) -> HttpResponse {
+    let cache_control = CacheControl(vec![
+        CacheDirective::Public,
+        CacheDirective::MaxAge(max_age_secs),
+    ]);
+
+    if is_fresh(request, &etag) {
+        return HttpResponse::NotModified()
+            .insert_header(ETag(etag))
+            .insert_header(cache_control)
+            .finish();
+    }
+
+    HttpResponse::Ok()
+        .insert_header(ETag(etag))
+        .insert_header(cache_control)
+        .json(body)
+}
+
+fn is_fresh(request: &HttpRequest, etag: &EntityTag) -> bool {
+    match IfNoneMatch::parse(request) {
+        Ok(IfNoneMatch::Any) => true,
+        Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(etag)),
+        Err(_) => false,
+    }
+}
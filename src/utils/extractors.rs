@@ -8,3 +8,19 @@ pub fn extract_auth_user_id(req: &HttpRequest) -> Result<i64, AppError> {
         .map(|id| id.0)
         .ok_or(AppError::Unauthorized("unauthorized".to_string()))
 }
+
+/**
+ * Client ip used for throttling and login tracking. Forwarding headers are only
+ * honored when the server runs behind a trusted proxy.
+ */
+pub fn extract_client_ip(req: &HttpRequest, trust_forwarded_for: bool) -> String {
+    let ip = if trust_forwarded_for {
+        req.connection_info()
+            .realip_remote_addr()
+            .map(|addr| addr.to_string())
+    } else {
+        req.peer_addr().map(|addr| addr.ip().to_string())
+    };
+
+    ip.unwrap_or_else(|| "unknown".to_string())
+}
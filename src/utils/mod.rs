@@ -1,5 +1,9 @@
+pub mod cursor;
+pub mod etag;
 pub mod extractors;
+pub mod money;
 pub mod pagination;
 pub mod storage;
+pub mod telemetry;
 pub mod traits;
 pub mod validation_utils;
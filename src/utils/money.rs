@@ -0,0 +1,206 @@
+use bigdecimal::{BigDecimal, RoundingMode, Zero};
+use serde::de::{self, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use utoipa::ToSchema;
+use validator::ValidationError;
+
+/** Decimals kept for every amount, matches the `NUMERIC(12,2)` columns. */
+pub const MONEY_SCALE: i64 = 2;
+
+/** Largest amount a `NUMERIC(12,2)` column holds. */
+const MAX_AMOUNT: &str = "9999999999.99";
+
+/**
+ * The shop prices everything in a single currency, it's carried on every
+ * amount so clients never have to assume it.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, ToSchema)]
+pub enum Currency {
+    #[default]
+    #[serde(rename = "USD")]
+    Usd,
+}
+
+/**
+ * Exact monetary amount. The amount serializes as a decimal string,
+ * `{ "amount": "10.99", "currency": "USD" }`, so it never goes through a float.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, ToSchema)]
+pub struct Money {
+    #[serde(serialize_with = "serialize_amount")]
+    #[schema(value_type = String, example = "10.99")]
+    pub amount: BigDecimal,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: BigDecimal) -> Self {
+        Self {
+            amount: amount.with_scale_round(MONEY_SCALE, RoundingMode::HalfEven),
+            currency: Currency::default(),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(BigDecimal::zero())
+    }
+
+    /** Line total of `quantity` units at this price. */
+    pub fn times(&self, quantity: i32) -> Self {
+        Self {
+            amount: &self.amount * BigDecimal::from(quantity),
+            currency: self.currency,
+        }
+    }
+}
+
+impl From<BigDecimal> for Money {
+    fn from(amount: BigDecimal) -> Self {
+        Self::new(amount)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money {
+            amount: self.amount + other.amount,
+            currency: self.currency,
+        }
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money {
+            amount: self.amount - other.amount,
+            currency: self.currency,
+        }
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Self {
+        iter.fold(Money::zero(), Add::add)
+    }
+}
+
+/**
+ * Validator for incoming amounts: not negative, at most two decimals and
+ * small enough for the column.
+ */
+pub fn validate_amount(amount: &BigDecimal) -> Result<(), ValidationError> {
+    let error = |code: &'static str, message: &'static str| {
+        Err(ValidationError::new(code).with_message(Cow::Borrowed(message)))
+    };
+
+    if amount < &BigDecimal::zero() {
+        return error("range", "must be at least 0");
+    }
+
+    if amount > &BigDecimal::from_str(MAX_AMOUNT).unwrap() {
+        return error("range", "must be at most 9999999999.99");
+    }
+
+    let (_, scale) = amount.normalized().as_bigint_and_exponent();
+
+    if scale > MONEY_SCALE {
+        return error("precision", "must have at most 2 decimals");
+    }
+
+    Ok(())
+}
+
+/**
+ * Writes an amount as a decimal string with exactly two decimals. Postgres
+ * hands `NUMERIC` values back with a wider scale and `BigDecimal` prints zero
+ * as `0` whatever its scale, so the scale can't be left to `Display`.
+ */
+pub fn serialize_amount<S>(amount: &BigDecimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(&format_args!("{:.*}", MONEY_SCALE as usize, amount))
+}
+
+pub fn serialize_optional_amount<S>(
+    amount: &Option<BigDecimal>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match amount {
+        Some(amount) => serialize_amount(amount, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/**
+ * Reads an optional amount from a decimal string or a json number. Numbers go
+ * through their shortest decimal form, so `10.99` stays `10.99` instead of
+ * the nearest binary float.
+ */
+pub fn deserialize_amount<'de, D>(deserializer: D) -> Result<Option<BigDecimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct AmountVisitor;
+
+    impl<'de> Visitor<'de> for AmountVisitor {
+        type Value = Option<BigDecimal>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a decimal amount")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D2: Deserializer<'de>>(
+            self,
+            deserializer: D2,
+        ) -> Result<Self::Value, D2::Error> {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            let value = value.trim();
+
+            if value.is_empty() {
+                return Ok(None);
+            }
+
+            BigDecimal::from_str(value)
+                .map(Some)
+                .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            Ok(Some(BigDecimal::from(value)))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(Some(BigDecimal::from(value)))
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            self.visit_str(&value.to_string())
+        }
+    }
+
+    deserializer.deserialize_any(AmountVisitor)
+}
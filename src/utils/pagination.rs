@@ -1,5 +1,8 @@
+use crate::errors::error::AppError;
+use crate::utils::cursor::{Cursor, CursorCodec, CursorDirection, HasCursorKey};
 use crate::utils::traits::HasId;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 pub struct DataCollection<T> {
     pub data: Vec<T>,
@@ -18,7 +21,7 @@ where
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Paginate {
     pub limit: i64,
     pub page: i64,
@@ -60,3 +63,175 @@ where
         self.data.iter().map(|item| item.get_id()).collect()
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PaginationMode {
+    #[default]
+    Page,
+    Cursor,
+}
+
+/**
+ * Pagination requested by a list endpoint: page numbers (`LIMIT/OFFSET`) or
+ * a keyset cursor.
+ */
+#[derive(Debug, Clone)]
+pub enum PageRequest {
+    Page(Paginate),
+    Cursor(CursorPaginate),
+}
+
+impl PageRequest {
+    pub fn new(
+        mode: Option<PaginationMode>,
+        limit: i64,
+        page: Option<i64>,
+        cursor: Option<&str>,
+        codec: &CursorCodec,
+    ) -> Result<Self, AppError> {
+        match mode.unwrap_or_default() {
+            PaginationMode::Page => {
+                if cursor.is_some() {
+                    return Err(AppError::field(
+                        "cursor",
+                        "unexpected",
+                        "is only allowed with pagination=cursor",
+                    ));
+                }
+
+                let page =
+                    page.ok_or_else(|| AppError::field("page", "required", "is required"))?;
+
+                Ok(PageRequest::Page(Paginate::new(limit, page)))
+            }
+            PaginationMode::Cursor => {
+                if page.is_some() {
+                    return Err(AppError::field(
+                        "page",
+                        "unexpected",
+                        "is not allowed with pagination=cursor",
+                    ));
+                }
+
+                let cursor = cursor.map(|token| codec.decode(token)).transpose()?;
+
+                Ok(PageRequest::Cursor(CursorPaginate { limit, cursor }))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CursorPaginate {
+    pub limit: i64,
+    pub cursor: Option<Cursor>,
+}
+
+impl CursorPaginate {
+    pub fn direction(&self) -> CursorDirection {
+        self.cursor
+            .map(|cursor| cursor.direction)
+            .unwrap_or(CursorDirection::Next)
+    }
+
+    /** One extra row tells whether there is another page in that direction. */
+    pub fn fetch_limit(&self) -> i64 {
+        self.limit + 1
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CursorPaginatedDataCollection<T> {
+    pub data: Vec<T>,
+    pub limit: i64,
+    pub next: Option<Cursor>,
+    pub prev: Option<Cursor>,
+}
+
+impl<T> CursorPaginatedDataCollection<T>
+where
+    T: HasCursorKey,
+{
+    /**
+     * Builds a page from rows fetched with `fetch_limit`, in query order (rows
+     * read backwards are flipped back to newest first).
+     */
+    pub fn new(mut rows: Vec<T>, pagination: &CursorPaginate) -> Self {
+        let has_more = rows.len() as i64 > pagination.limit;
+        rows.truncate(pagination.limit as usize);
+
+        let (has_next, has_prev) = match pagination.cursor {
+            None => (has_more, false),
+            Some(cursor) if cursor.direction == CursorDirection::Next => (has_more, true),
+            Some(_) => {
+                rows.reverse();
+                (true, has_more)
+            }
+        };
+
+        let next = rows
+            .last()
+            .filter(|_| has_next)
+            .map(|row| Cursor::at(row, CursorDirection::Next));
+
+        let prev = rows
+            .first()
+            .filter(|_| has_prev)
+            .map(|row| Cursor::at(row, CursorDirection::Prev));
+
+        Self {
+            data: rows,
+            limit: pagination.limit,
+            next,
+            prev,
+        }
+    }
+}
+
+impl<T> CursorPaginatedDataCollection<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> CursorPaginatedDataCollection<U> {
+        let data = self.data.into_iter().map(f).collect();
+
+        CursorPaginatedDataCollection {
+            data,
+            limit: self.limit,
+            next: self.next,
+            prev: self.prev,
+        }
+    }
+
+    pub fn with_data<U>(self, data: Vec<U>) -> CursorPaginatedDataCollection<U> {
+        CursorPaginatedDataCollection {
+            data,
+            limit: self.limit,
+            next: self.next,
+            prev: self.prev,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CursorMeta {
+    pub limit: i64,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+impl CursorMeta {
+    pub fn new<T>(collection: &CursorPaginatedDataCollection<T>, codec: &CursorCodec) -> Self {
+        Self {
+            limit: collection.limit,
+            next_cursor: collection.next.as_ref().map(|cursor| codec.encode(cursor)),
+            prev_cursor: collection.prev.as_ref().map(|cursor| codec.encode(cursor)),
+        }
+    }
+}
+
+/** Response meta, shaped after the pagination mode that produced the page. */
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum PaginationMeta {
+    Page(Paginate),
+    Cursor(CursorMeta),
+}
@@ -70,6 +70,25 @@ impl UseStorage for LocalStorage {
             .map_err(|e| AppError::Internal(e.to_string()))
             .await
     }
+
+    async fn health_check(&self) -> Result<(), AppError> {
+        fs::create_dir_all(&self.base_path)
+            .map_err(|e| AppError::Internal(e.to_string()))
+            .await?;
+
+        let metadata = fs::metadata(&self.base_path)
+            .map_err(|e| AppError::Internal(e.to_string()))
+            .await?;
+
+        if !metadata.is_dir() || metadata.permissions().readonly() {
+            return Err(AppError::Internal(format!(
+                "storage path {} is not writable",
+                self.base_path
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 impl S3Storage {
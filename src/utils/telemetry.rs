@@ -0,0 +1,27 @@
+use crate::config::settings::{LogFormat, LoggingConfig};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::{SubscriberInitExt, TryInitError};
+use tracing_subscriber::{EnvFilter, fmt};
+
+/**
+ * Installs the global tracing subscriber. `RUST_LOG` takes precedence over `logging.level`,
+ * and records emitted through the `log` crate (sqlx, actix) are forwarded to it.
+ */
+pub fn init(config: &LoggingConfig) -> Result<(), TryInitError> {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&config.level));
+
+    let registry = tracing_subscriber::registry().with(filter);
+
+    match config.format {
+        LogFormat::Json => registry
+            .with(
+                fmt::layer()
+                    .json()
+                    .with_current_span(true)
+                    .with_span_list(true),
+            )
+            .try_init(),
+        LogFormat::Pretty => registry.with(fmt::layer()).try_init(),
+    }
+}
@@ -56,6 +56,8 @@ pub trait UseStorage {
 
     fn delete(&self, path: &str) -> impl Future<Output = Result<(), AppError>> + Send;
 
+    fn health_check(&self) -> impl Future<Output = Result<(), AppError>> + Send;
+
     fn mime_to_extension(&self, mime: &Mime) -> String {
         mime.subtype().to_string()
     }
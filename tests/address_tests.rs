@@ -0,0 +1,191 @@
+use actix_web::http::StatusCode;
+use ecomm::app::addresses::rules::{normalize_postcode, validate_for_country};
+use serde_json::{Value, json};
+
+mod utils;
+
+fn address(country: &str, region: Option<&str>, postcode: Option<&str>) -> Value {
+    json!({
+        "full_name": "Jane Doe",
+        "line1": "1 Main Street",
+        "city": "Springfield",
+        "country": country,
+        "region": region,
+        "postcode": postcode,
+    })
+}
+
+#[test]
+fn test_country_rules() {
+    assert!(validate_for_country("US", Some("NY"), Some("10001")).is_ok());
+    assert!(validate_for_country("US", Some("NY"), Some("10001-1234")).is_ok());
+    assert!(validate_for_country("US", None, Some("10001")).is_err());
+    assert!(validate_for_country("US", Some("NY"), Some("1000")).is_err());
+    assert!(validate_for_country("US", Some("NY"), None).is_err());
+
+    assert!(validate_for_country("GB", None, Some(&normalize_postcode("sw1a  1aa"))).is_ok());
+    assert!(validate_for_country("GB", None, Some("SW1A")).is_err());
+    assert!(validate_for_country("CA", Some("ON"), Some("K1A 0B1")).is_ok());
+    assert!(validate_for_country("NL", None, Some("1234AB")).is_ok());
+    assert!(validate_for_country("IE", None, Some("D6W XY12")).is_ok());
+
+    // no rules, anything goes
+    assert!(validate_for_country("ZZ", None, None).is_ok());
+}
+
+#[actix_rt::test]
+async fn test_address_book() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .post("/api/v1/me/addresses/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&address("us", Some("NY"), Some("10001")))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    // the first address becomes the default for both
+    let body: Value = res.json().await.unwrap();
+    let first = body["data"]["id"].as_i64().unwrap();
+    assert_eq!(body["data"]["country"], "US");
+    assert_eq!(body["data"]["is_default_shipping"], true);
+    assert_eq!(body["data"]["is_default_billing"], true);
+
+    let mut second = address("DE", None, Some("10115"));
+    second["is_default_shipping"] = json!(true);
+
+    let mut res = context
+        .srv
+        .post("/api/v1/me/addresses/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&second)
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let body: Value = res.json().await.unwrap();
+    let second = body["data"]["id"].as_i64().unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/me/addresses/list")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+
+    let addresses = body["data"].as_array().unwrap();
+    assert_eq!(addresses.len(), 2);
+    assert_eq!(addresses[0]["id"], second);
+    assert_eq!(addresses[0]["is_default_shipping"], true);
+    assert_eq!(addresses[0]["is_default_billing"], false);
+    assert_eq!(addresses[1]["id"], first);
+    assert_eq!(addresses[1]["is_default_shipping"], false);
+    assert_eq!(addresses[1]["is_default_billing"], true);
+
+    // another user can't see it
+    let other = utils::auto_login(&context.srv, "test2@test.com".to_string()).await;
+    let res = context
+        .srv
+        .get(format!("/api/v1/me/addresses/get/{}", first))
+        .insert_header(("Authorization", format!("Bearer {}", other)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = context
+        .srv
+        .delete(format!("/api/v1/me/addresses/delete/{}", first))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_address_validation() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+
+    for body in [
+        address("US", None, Some("10001")),
+        address("US", Some("NY"), Some("ABCDE")),
+        address("USA", Some("NY"), Some("10001")),
+        json!({ "line1": "1 Main Street", "city": "Springfield", "country": "FR", "postcode": "75001" }),
+    ] {
+        let res = context
+            .srv
+            .post("/api/v1/me/addresses/create")
+            .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+            .send_json(&body)
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    }
+
+    let res = context
+        .srv
+        .get("/api/v1/me/addresses/list")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_admin_reads_user_addresses() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    let res = context
+        .srv
+        .post("/api/v1/me/addresses/create")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send_json(&address("FR", None, Some("75001")))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let mut res = context
+        .srv
+        .get("/api/v1/admin/users/get/1/addresses")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["data"][0]["postcode"], "75001");
+
+    let res = context
+        .srv
+        .get("/api/v1/admin/users/get/999/addresses")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = context
+        .srv
+        .get("/api/v1/admin/users/get/1/addresses")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    context.database.cleanup().await;
+}
@@ -10,7 +10,7 @@ mod utils;
 
 fn get_index_url(payload: IndexCategoryDTO) -> String {
     format!(
-        "/admin/categories/list?{}",
+        "/api/v1/admin/categories/list?{}",
         serde_urlencoded::to_string(payload).unwrap()
     )
 }
@@ -20,7 +20,7 @@ async fn get_category(context: &utils::TestContext, category_id: i64) -> ClientR
 
     context
         .srv
-        .get(format!("/admin/categories/get/{}", category_id))
+        .get(format!("/api/v1/admin/categories/get/{}", category_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
@@ -35,7 +35,7 @@ async fn create_category(
 
     context
         .srv
-        .post("/admin/categories/create")
+        .post("/api/v1/admin/categories/create")
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -51,7 +51,7 @@ async fn update_category(
 
     context
         .srv
-        .put(format!("/admin/categories/update/{}", category_id))
+        .put(format!("/api/v1/admin/categories/update/{}", category_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -63,7 +63,7 @@ async fn delete_category(context: &utils::TestContext, category_id: i64) -> Clie
 
     context
         .srv
-        .delete(format!("/admin/categories/delete/{}", category_id))
+        .delete(format!("/api/v1/admin/categories/delete/{}", category_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
@@ -295,7 +295,7 @@ async fn test_admin_category_show_no_token() {
 
     let res = context
         .srv
-        .get("/admin/categories/get/1")
+        .get("/api/v1/admin/categories/get/1")
         .send()
         .await
         .unwrap();
@@ -359,7 +359,7 @@ async fn test_admin_category_create_no_token() {
 
     let res = context
         .srv
-        .post("/admin/categories/create")
+        .post("/api/v1/admin/categories/create")
         .send_json(&payload)
         .await
         .unwrap();
@@ -398,7 +398,7 @@ async fn test_admin_category_create_wrong_slug() {
 
     let res = create_category(&context, &payload).await;
 
-    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
 
     context.database.cleanup().await;
 }
@@ -417,7 +417,7 @@ async fn test_admin_category_create_category_already_exists() {
 
     let res = create_category(&context, &payload).await;
 
-    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(res.status(), StatusCode::CONFLICT);
 
     context.database.cleanup().await;
 }
@@ -495,7 +495,7 @@ async fn test_admin_category_update_wrong_slug() {
 
     let res = update_category(&context, &payload, 1).await;
 
-    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
 
     context.database.cleanup().await;
 }
@@ -514,7 +514,7 @@ async fn test_admin_category_update_category_already_exists() {
 
     let res = update_category(&context, &payload, 1).await;
 
-    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(res.status(), StatusCode::CONFLICT);
 
     context.database.cleanup().await;
 }
@@ -1,6 +1,8 @@
 use bigdecimal::BigDecimal;
 use ecomm::admin::products::images::dto::{CreateProductImageCommand, UpdateProductImageSortDTO};
 use ecomm::admin::products::images::service::AdminProductImageService;
+use ecomm::app::products::cache::ProductCache;
+use ecomm::config::settings::CacheConfig;
 use ecomm::responses::api_responses::LocalApiResponse;
 use ecomm::responses::error_responses::ErrorResponse;
 use ecomm::utils::storage::LocalStorage;
@@ -15,7 +17,10 @@ async fn test_admin_product_image_upload() {
 
     let temp_dir = TempDir::new(format!("test_dir_{}", Uuid::new_v4()).as_str()).unwrap();
 
-    let admin_product_image_service = AdminProductImageService::new(context.database.pool.clone());
+    let admin_product_image_service = AdminProductImageService::new(
+        context.database.pool.clone(),
+        ProductCache::new(&CacheConfig::default()),
+    );
 
     let command = CreateProductImageCommand {
         product_id: 1,
@@ -28,7 +33,7 @@ async fn test_admin_product_image_upload() {
     let storage = LocalStorage::new(temp_dir.path().to_str().unwrap().to_string());
 
     let result = admin_product_image_service
-        .upload(command, &storage, vec![1, 2, 3, 4, 5, 6], "png")
+        .upload(command, &storage, vec![1, 2, 3, 4, 5, 6], "png", 3)
         .await;
 
     assert!(result.is_ok(), "{:?}", result);
@@ -48,7 +53,7 @@ async fn test_admin_product_image_sort_update() {
 
     let mut res = context
         .srv
-        .put(format!("/admin/products/images/{}/update-sort", 1))
+        .put(format!("/api/v1/admin/products/images/{}/update-sort", 1))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -75,7 +80,10 @@ async fn test_admin_product_image_delete() {
 
     let temp_dir = TempDir::new(format!("test_dir_{}", Uuid::new_v4()).as_str()).unwrap();
 
-    let admin_product_image_service = AdminProductImageService::new(context.database.pool.clone());
+    let admin_product_image_service = AdminProductImageService::new(
+        context.database.pool.clone(),
+        ProductCache::new(&CacheConfig::default()),
+    );
 
     let command = CreateProductImageCommand {
         product_id: 1,
@@ -90,13 +98,13 @@ async fn test_admin_product_image_delete() {
     let storage = LocalStorage::new(path.clone());
 
     let image_id = admin_product_image_service
-        .upload(command, &storage, vec![1, 2, 3, 4, 5, 6], "png")
+        .upload(command, &storage, vec![1, 2, 3, 4, 5, 6], "png", 3)
         .await
         .unwrap();
 
     let mut res = context
         .srv
-        .delete(format!("/admin/products/images/delete/{}", image_id))
+        .delete(format!("/api/v1/admin/products/images/delete/{}", image_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
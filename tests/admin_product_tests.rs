@@ -1,5 +1,6 @@
 use actix_test::ClientResponse;
 use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
 use ecomm::admin::products::dto::{
     AdminPublicProduct, CreateProductDTO, IndexProductDTO, UpdateProductDTO,
 };
@@ -10,7 +11,7 @@ mod utils;
 
 fn get_index_url(payload: IndexProductDTO) -> String {
     format!(
-        "/admin/products/list?{}",
+        "/api/v1/admin/products/list?{}",
         serde_urlencoded::to_string(payload).unwrap()
     )
 }
@@ -20,7 +21,7 @@ async fn get_product(context: &utils::TestContext, product_id: i64) -> ClientRes
 
     context
         .srv
-        .get(format!("/admin/products/get/{}", product_id))
+        .get(format!("/api/v1/admin/products/get/{}", product_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
@@ -35,7 +36,7 @@ async fn create_product(
 
     context
         .srv
-        .post("/admin/products/create")
+        .post("/api/v1/admin/products/create")
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -51,7 +52,7 @@ async fn update_product(
 
     context
         .srv
-        .put(format!("/admin/products/update/{}", product_id))
+        .put(format!("/api/v1/admin/products/update/{}", product_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -63,7 +64,7 @@ async fn delete_product(context: &utils::TestContext, product_id: i64) -> Client
 
     context
         .srv
-        .delete(format!("/admin/products/delete/{}", product_id))
+        .delete(format!("/api/v1/admin/products/delete/{}", product_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
@@ -324,7 +325,7 @@ async fn test_admin_product_show_no_token() {
 
     let res = context
         .srv
-        .get("/admin/products/get/1")
+        .get("/api/v1/admin/products/get/1")
         .send()
         .await
         .unwrap();
@@ -342,10 +343,20 @@ async fn test_admin_product_create() {
         name: Some("Test Product New 1".to_string()),
         slug: Some("test-product-new-1".to_string()),
         categories: Some(vec![1, 2]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let mut res = create_product(&context, &payload).await;
@@ -371,10 +382,20 @@ async fn test_admin_product_create_unauthorized_user() {
         name: Some("Test Product New 1".to_string()),
         slug: Some("test-product-new-1".to_string()),
         categories: Some(vec![1, 2]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let res = create_product(&context, &payload).await;
@@ -392,15 +413,25 @@ async fn test_admin_product_create_no_token() {
         name: Some("Test Product New 1".to_string()),
         slug: Some("test-product-new-1".to_string()),
         categories: Some(vec![1, 2]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let res = context
         .srv
-        .post("/admin/products/create")
+        .post("/api/v1/admin/products/create")
         .send_json(&payload)
         .await
         .unwrap();
@@ -422,6 +453,16 @@ async fn test_admin_product_create_unprocessable_entity() {
         quantity: None,
         configurable: None,
         is_active: None,
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let res = create_product(&context, &payload).await;
@@ -439,17 +480,27 @@ async fn test_admin_product_create_product_already_exists() {
         name: Some("Test Product New 1".to_string()),
         slug: Some("test-product-new-1".to_string()),
         categories: Some(vec![1]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     create_product(&context, &payload).await;
 
     let res = create_product(&context, &payload).await;
 
-    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(res.status(), StatusCode::CONFLICT);
 
     context.database.cleanup().await;
 }
@@ -462,10 +513,20 @@ async fn test_admin_product_create_category_not_found() {
         name: Some("Test Product New 1".to_string()),
         slug: Some("test-product-new-1".to_string()),
         categories: Some(vec![1, 24]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let res = create_product(&context, &payload).await;
@@ -483,10 +544,20 @@ async fn test_admin_product_update() {
         name: Some("Test Product Edited 1".to_string()),
         slug: Some("test-product-edited-1".to_string()),
         categories: Some(vec![1, 2]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let mut res = update_product(&context, &payload, 1).await;
@@ -514,10 +585,20 @@ async fn test_admin_product_update_unauthorized_user() {
         name: Some("Test Product Edited 1".to_string()),
         slug: Some("test-product-edited-1".to_string()),
         categories: Some(vec![1, 2]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let res = update_product(&context, &payload, 1).await;
@@ -539,6 +620,16 @@ async fn test_admin_product_update_unprocessable_entity() {
         quantity: None,
         configurable: None,
         is_active: None,
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let res = update_product(&context, &payload, 1).await;
@@ -556,17 +647,27 @@ async fn test_admin_product_update_product_already_exists() {
         name: Some("Test Product Edited 1".to_string()),
         slug: Some("test-product-edited-1".to_string()),
         categories: Some(vec![1, 2]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     update_product(&context, &payload, 1).await;
 
     let res = update_product(&context, &payload, 1).await;
 
-    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(res.status(), StatusCode::CONFLICT);
 
     context.database.cleanup().await;
 }
@@ -579,10 +680,20 @@ async fn test_admin_product_update_category_not_found() {
         name: Some("Test Product Edited 1".to_string()),
         slug: Some("test-product-edited-1".to_string()),
         categories: Some(vec![1, 20]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let res = update_product(&context, &payload, 1).await;
@@ -600,10 +711,20 @@ async fn test_admin_product_update_product_not_found() {
         name: Some("Test Product Edited 1".to_string()),
         slug: Some("test-product-edited-1".to_string()),
         categories: Some(vec![1, 2]),
-        price: Some(100.0),
+        price: Some(BigDecimal::from(100)),
         quantity: Some(10),
         configurable: Some(false),
         is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
     };
 
     let res = update_product(&context, &payload, 270).await;
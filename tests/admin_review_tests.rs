@@ -10,7 +10,7 @@ mod utils;
 
 fn get_index_url(payload: IndexReviewDTO) -> String {
     format!(
-        "/admin/reviews/list?{}",
+        "/api/v1/admin/reviews/list?{}",
         serde_urlencoded::to_string(payload).unwrap()
     )
 }
@@ -20,7 +20,7 @@ async fn get_review(context: &utils::TestContext, review_id: i64) -> ClientRespo
 
     context
         .srv
-        .get(format!("/admin/reviews/{}/get", review_id))
+        .get(format!("/api/v1/admin/reviews/{}/get", review_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
@@ -36,7 +36,7 @@ async fn update_review_status(
 
     context
         .srv
-        .put(format!("/admin/reviews/{}/update-status", review_id))
+        .put(format!("/api/v1/admin/reviews/{}/update-status", review_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -48,7 +48,7 @@ async fn delete_review(context: &utils::TestContext, review_id: i64) -> ClientRe
 
     context
         .srv
-        .delete(format!("/admin/reviews/{}/delete", review_id))
+        .delete(format!("/api/v1/admin/reviews/{}/delete", review_id))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
@@ -64,6 +64,8 @@ async fn test_admin_review_index() {
     let query_payload = IndexReviewDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         user_id: None,
         product_id: None,
@@ -103,6 +105,8 @@ async fn test_admin_review_index_incomplete_query_params() {
     let query_payload = IndexReviewDTO {
         page: None,
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         user_id: None,
         product_id: None,
@@ -134,6 +138,8 @@ async fn test_admin_review_index_search() {
     let query_payload_title = IndexReviewDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: Some("product 1 review title 1".to_string()),
         user_id: None,
         product_id: None,
@@ -144,6 +150,8 @@ async fn test_admin_review_index_search() {
     let query_payload_content = IndexReviewDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: Some("product 1 review content 1".to_string()),
         user_id: None,
         product_id: None,
@@ -207,6 +215,8 @@ async fn test_admin_review_index_simple_filter() {
     let query_payload = IndexReviewDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         user_id: Some(2),
         product_id: None,
@@ -247,6 +257,8 @@ async fn test_admin_review_index_wrong_token() {
     let query_payload = IndexReviewDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         user_id: None,
         product_id: None,
@@ -276,6 +288,8 @@ async fn test_admin_review_index_no_token() {
     let query_payload = IndexReviewDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         user_id: None,
         product_id: None,
@@ -335,7 +349,7 @@ async fn test_admin_review_show_no_token() {
 
     let res = context
         .srv
-        .get("/admin/reviews/1/get")
+        .get("/api/v1/admin/reviews/1/get")
         .send()
         .await
         .unwrap();
@@ -0,0 +1,104 @@
+use actix_web::http::StatusCode;
+use ecomm::auth::dto::LoginDTO;
+use serde_json::Value;
+
+mod utils;
+
+fn credentials() -> LoginDTO {
+    LoginDTO {
+        email: Some("test1@test.com".to_string()),
+        password: Some("123456".to_string()),
+    }
+}
+
+#[actix_rt::test]
+async fn test_routes_are_mounted_under_v1() {
+    let context = utils::TestContext::new(None).await;
+
+    let res = context
+        .srv
+        .get("/api/v1/products/list?page=1&limit=10")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = context
+        .srv
+        .get("/products/list?page=1&limit=10")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_v1_login_is_deprecated() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context
+        .srv
+        .post("/api/v1/auth/login")
+        .send_json(&credentials())
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let headers = res.headers();
+    assert_eq!(headers.get("deprecation").unwrap(), "@1792368000");
+    assert_eq!(
+        headers.get("sunset").unwrap(),
+        "Mon, 19 Apr 2027 00:00:00 GMT"
+    );
+    assert_eq!(
+        headers.get("link").unwrap(),
+        "</api/v2/auth/login>; rel=\"successor-version\""
+    );
+
+    let body: Value = res.json().await.unwrap();
+    assert!(body["token"].is_string());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_v2_login_uses_response_envelope() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context
+        .srv
+        .post("/api/v2/auth/login")
+        .send_json(&credentials())
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(res.headers().get("deprecation").is_none());
+
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["message"], "success");
+    assert!(body["data"]["token"].is_string());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_v1_routes_without_v2_variant_are_not_served_under_v2() {
+    let context = utils::TestContext::new(None).await;
+
+    let res = context
+        .srv
+        .get("/api/v2/products/list?page=1&limit=10")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
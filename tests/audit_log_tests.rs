@@ -0,0 +1,141 @@
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use ecomm::admin::products::dto::UpdateProductDTO;
+use serde_json::Value;
+use std::str::FromStr;
+
+mod utils;
+
+async fn get_audit_log(context: &utils::TestContext, query: &str) -> (StatusCode, Value) {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get(format!(
+            "/api/v1/admin/audit/list?page=1&limit=10&{}",
+            query
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    (res.status(), res.json().await.unwrap())
+}
+
+#[actix_rt::test]
+async fn test_product_update_is_audited_with_diff() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = UpdateProductDTO {
+        name: Some("Test Product 1".to_string()),
+        slug: Some("test-product-1-renamed".to_string()),
+        categories: None,
+        price: Some(BigDecimal::from_str("42.50").unwrap()),
+        quantity: Some(7),
+        configurable: Some(false),
+        is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
+    };
+
+    let res = context
+        .srv
+        .put("/api/v1/admin/products/update/1")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let (status, body) = get_audit_log(&context, "entity=product&entity_id=1").await;
+
+    assert_eq!(status, StatusCode::OK);
+
+    let entries = body["data"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+
+    let entry = &entries[0];
+    assert_eq!(entry["action"], "update");
+    assert_eq!(entry["actor_id"], 3);
+    assert_eq!(entry["changes"]["price"]["after"], "42.50");
+    assert_eq!(entry["changes"]["quantity"]["after"], 7);
+    assert_eq!(entry["changes"]["slug"]["before"], "test-product-1");
+    assert_eq!(entry["changes"]["slug"]["after"], "test-product-1-renamed");
+
+    // unchanged and bookkeeping fields are left out
+    assert!(entry["changes"].get("name").is_none());
+    assert!(entry["changes"].get("updated_at").is_none());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_audit_log_filters() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let res = context
+        .srv
+        .delete("/api/v1/admin/reviews/1/delete")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert!(res.status().is_success());
+
+    let res = context
+        .srv
+        .delete("/api/v1/admin/categories/delete/2")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert!(res.status().is_success());
+
+    let (_, body) = get_audit_log(&context, "actor_id=3").await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 2);
+
+    let (_, body) = get_audit_log(&context, "entity=review").await;
+    let entries = body["data"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["entity_id"], 1);
+    assert_eq!(entries[0]["action"], "delete");
+    assert!(entries[0]["changes"]["title"]["after"].is_null());
+
+    let (_, body) = get_audit_log(&context, "actor_id=4").await;
+    assert!(body["data"].as_array().unwrap().is_empty());
+
+    let (_, body) = get_audit_log(&context, "from=2999-01-01T00:00:00Z").await;
+    assert!(body["data"].as_array().unwrap().is_empty());
+
+    let (status, _) = get_audit_log(&context, "entity=order").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_audit_log_requires_admin() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    let (status, _) = get_audit_log(&context, "entity=product").await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,151 @@
+use actix_test::{ClientResponse, TestServer};
+use actix_web::http::StatusCode;
+use ecomm::auth::dto::LoginDTO;
+
+mod utils;
+
+async fn login(srv: &TestServer, email: &str, password: &str) -> ClientResponse {
+    let payload = LoginDTO {
+        email: Some(email.to_string()),
+        password: Some(password.to_string()),
+    };
+
+    srv.post("/api/v1/auth/login")
+        .send_json(&payload)
+        .await
+        .unwrap()
+}
+
+async fn fail_logins(srv: &TestServer, email: &str, times: i64) -> StatusCode {
+    let mut status = StatusCode::OK;
+
+    for _ in 0..times {
+        status = login(srv, email, "wrong-password").await.status();
+    }
+
+    status
+}
+
+#[actix_rt::test]
+async fn test_account_is_locked_after_repeated_failures() {
+    let context = utils::TestContext::new(None).await;
+
+    let max_attempts = utils::test_config()
+        .auth
+        .login_protection
+        .max_failed_attempts;
+
+    let status = fail_logins(&context.srv, "test1@test.com", max_attempts - 1).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let status = fail_logins(&context.srv, "test1@test.com", 1).await;
+    assert_eq!(status, StatusCode::LOCKED);
+
+    // correct credentials are rejected while the lockout is active
+    let res = login(&context.srv, "test1@test.com", "123456").await;
+    assert_eq!(res.status(), StatusCode::LOCKED);
+
+    // other accounts are not affected
+    let res = login(&context.srv, "test2@test.com", "123456").await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_unknown_email_is_locked_like_an_account() {
+    let context = utils::TestContext::new(None).await;
+
+    let max_attempts = utils::test_config()
+        .auth
+        .login_protection
+        .max_failed_attempts;
+
+    let status = fail_logins(&context.srv, "nobody@test.com", max_attempts - 1).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let status = fail_logins(&context.srv, "nobody@test.com", 1).await;
+    assert_eq!(status, StatusCode::LOCKED);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_successful_login_resets_failures() {
+    let context = utils::TestContext::new(None).await;
+
+    let max_attempts = utils::test_config()
+        .auth
+        .login_protection
+        .max_failed_attempts;
+
+    fail_logins(&context.srv, "test1@test.com", max_attempts - 1).await;
+
+    let res = login(&context.srv, "test1@test.com", "123456").await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let status = fail_logins(&context.srv, "test1@test.com", max_attempts - 1).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_admin_can_unlock_account() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let max_attempts = utils::test_config()
+        .auth
+        .login_protection
+        .max_failed_attempts;
+
+    let status = fail_logins(&context.srv, "test1@test.com", max_attempts).await;
+    assert_eq!(status, StatusCode::LOCKED);
+
+    let res = context
+        .srv
+        .post("/api/v1/auth/lockouts/1/unlock")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK, "{:#?}", res);
+
+    let res = login(&context.srv, "test1@test.com", "123456").await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    // nothing left to unlock
+    let res = context
+        .srv
+        .post("/api/v1/auth/lockouts/1/unlock")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_user_cannot_unlock_account() {
+    let context = utils::TestContext::new(Some("test2@test.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let res = context
+        .srv
+        .post("/api/v1/auth/lockouts/1/unlock")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,167 @@
+use actix_web::http::StatusCode;
+use actix_web::http::header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH};
+use serde_json::Value;
+
+mod utils;
+
+#[actix_rt::test]
+async fn test_product_show_is_conditional() {
+    let context = utils::TestContext::new(None).await;
+
+    let res = context
+        .srv
+        .get("/api/v1/products/get/test-product-1")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get(CACHE_CONTROL).unwrap(),
+        "public, max-age=60"
+    );
+
+    let etag = res.headers().get(ETAG).unwrap().clone();
+    assert!(!etag.to_str().unwrap().starts_with("W/"));
+
+    let mut res = context
+        .srv
+        .get("/api/v1/products/get/test-product-1")
+        .insert_header((IF_NONE_MATCH, etag.clone()))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(res.headers().get(ETAG).unwrap(), &etag);
+    assert!(res.body().await.unwrap().is_empty());
+
+    // loading relations is a different representation
+    let res = context
+        .srv
+        .get("/api/v1/products/get/test-product-1?images=true")
+        .insert_header((IF_NONE_MATCH, etag.clone()))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_ne!(res.headers().get(ETAG).unwrap(), &etag);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_product_list_is_conditional() {
+    let context = utils::TestContext::new(None).await;
+
+    let res = context
+        .srv
+        .get("/api/v1/products/list?page=1&limit=10")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let etag = res.headers().get(ETAG).unwrap().clone();
+
+    let res = context
+        .srv
+        .get("/api/v1/products/list?page=1&limit=10")
+        .insert_header((IF_NONE_MATCH, etag.clone()))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+
+    let res = context
+        .srv
+        .get("/api/v1/products/list?page=1&limit=10")
+        .insert_header((IF_NONE_MATCH, "\"stale\""))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_admin_changes_invalidate_cached_product_page() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/products/get/test-product-1?reviews=true")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let etag = res.headers().get(ETAG).unwrap().clone();
+    let body: Value = res.json().await.unwrap();
+    let reviews_before = body["data"]["reviews"].as_array().unwrap().len();
+
+    let res = context
+        .srv
+        .delete("/api/v1/admin/reviews/1/delete")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert!(res.status().is_success());
+
+    let mut res = context
+        .srv
+        .get("/api/v1/products/get/test-product-1?reviews=true")
+        .insert_header((IF_NONE_MATCH, etag.clone()))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_ne!(res.headers().get(ETAG).unwrap(), &etag);
+
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(
+        body["data"]["reviews"].as_array().unwrap().len(),
+        reviews_before - 1
+    );
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_category_show_is_conditional() {
+    let context = utils::TestContext::new(None).await;
+
+    let res = context
+        .srv
+        .get("/api/v1/categories/get/test-category-1")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let etag = res.headers().get(ETAG).unwrap().clone();
+
+    let res = context
+        .srv
+        .get("/api/v1/categories/get/test-category-1")
+        .insert_header((IF_NONE_MATCH, etag))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+
+    context.database.cleanup().await;
+}
@@ -7,7 +7,7 @@ mod utils;
 
 fn get_index_url(payload: IndexCategoryDTO) -> String {
     format!(
-        "/categories/list?{}",
+        "/api/v1/categories/list?{}",
         serde_urlencoded::to_string(payload).unwrap()
     )
 }
@@ -92,7 +92,7 @@ async fn test_category_show() {
 
     let res = context
         .srv
-        .get("/categories/get/test-category-1")
+        .get("/api/v1/categories/get/test-category-1")
         .send()
         .await
         .unwrap();
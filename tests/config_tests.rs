@@ -0,0 +1,40 @@
+use ecomm::config::error::ConfigError;
+use ecomm::config::settings::{Config, Profile, StorageBackend};
+use std::str::FromStr;
+
+#[test]
+fn test_default_config_is_valid() {
+    let config = Config::default();
+
+    assert!(config.validate().is_ok());
+    assert_eq!(config.server.bind_address(), "127.0.0.1:8080");
+    assert_eq!(config.auth.access_token_ttl().num_days(), 2);
+}
+
+#[test]
+fn test_test_profile_loads() {
+    let config = Config::load_profile(Profile::Test).unwrap();
+
+    assert_eq!(config.profile, Profile::Test);
+    assert_eq!(config.storage.backend, StorageBackend::Local);
+}
+
+#[test]
+fn test_invalid_config_reports_every_error() {
+    let mut config = Config::default();
+    config.database.max_connections = 0;
+    config.auth.access_token_ttl_minutes = 0;
+    config.cors.allowed_origins = vec!["localhost".to_string()];
+
+    match config.validate() {
+        Err(ConfigError::Invalid(errors)) => assert_eq!(errors.len(), 3, "{:?}", errors),
+        other => panic!("expected invalid config, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_profile_from_str() {
+    assert_eq!(Profile::from_str("production").unwrap(), Profile::Prod);
+    assert_eq!(Profile::from_str("TEST").unwrap(), Profile::Test);
+    assert!(Profile::from_str("staging").is_err());
+}
@@ -0,0 +1,248 @@
+use actix_web::http::StatusCode;
+use ecomm::app::cart::cart_items::dto::AddItemDto;
+use ecomm::app::cart::coupons::dto::ApplyCouponDto;
+use serde_json::{Value, json};
+
+mod utils;
+
+async fn create_coupon(context: &utils::TestContext, payload: Value) -> (StatusCode, Value) {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .post("/api/v1/admin/coupons/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap();
+
+    (res.status(), res.json().await.unwrap())
+}
+
+async fn add_to_cart(context: &utils::TestContext, token: &str, quantity: i32) {
+    let payload = AddItemDto {
+        product_id: Some(1),
+        quantity: Some(quantity),
+    };
+
+    let res = context
+        .srv
+        .post("/api/v1/cart/user/add")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&payload)
+        .await
+        .unwrap();
+
+    assert!(res.status().is_success(), "{:#?}", res);
+}
+
+async fn apply_coupon(
+    context: &utils::TestContext,
+    token: &str,
+    code: &str,
+) -> (StatusCode, Value) {
+    let payload = ApplyCouponDto {
+        code: Some(code.to_string()),
+    };
+
+    let mut res = context
+        .srv
+        .post("/api/v1/cart/user/coupon")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .send_json(&payload)
+        .await
+        .unwrap();
+
+    (res.status(), res.json().await.unwrap())
+}
+
+#[actix_rt::test]
+async fn test_percentage_coupon_discounts_the_cart() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    let (status, body) = create_coupon(
+        &context,
+        json!({"code": "spring10", "kind": "percentage", "value": "10"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["data"]["code"], "SPRING10");
+
+    add_to_cart(&context, &customer, 2).await;
+
+    // codes match regardless of case
+    let (status, body) = apply_coupon(&context, &customer, "Spring10").await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    let cart = &body["data"];
+    assert_eq!(cart["coupon_code"], "SPRING10");
+    assert_eq!(cart["subtotal"]["amount"], "21.98");
+    assert_eq!(cart["discounts"][0]["kind"], "percentage");
+    assert_eq!(cart["discounts"][0]["amount"]["amount"], "2.20");
+    assert_eq!(cart["total"]["amount"], "19.78");
+
+    // the discount follows the cart contents
+    add_to_cart(&context, &customer, 1).await;
+
+    let mut res = context
+        .srv
+        .get("/api/v1/cart/user/get")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["discounts"][0]["amount"]["amount"], "3.30");
+
+    // removing it gives the full price back
+    let mut res = context
+        .srv
+        .delete("/api/v1/cart/user/coupon")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = res.json().await.unwrap();
+    assert!(body["data"]["coupon_code"].is_null());
+    assert!(body["data"]["discounts"].as_array().unwrap().is_empty());
+    assert_eq!(body["data"]["total"], body["data"]["subtotal"]);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_coupon_rejections() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    for payload in [
+        json!({"code": "BIGSPEND", "kind": "fixed_amount", "value": "5", "min_cart_value": "50"}),
+        json!({"code": "CATEGORY2", "kind": "fixed_amount", "value": "5", "categories": [2]}),
+        json!({"code": "LATER", "kind": "percentage", "value": "5", "starts_at": "2999-01-01T00:00:00Z"}),
+        json!({"code": "DISABLED", "kind": "percentage", "value": "5", "is_active": false}),
+    ] {
+        let (status, body) = create_coupon(&context, payload).await;
+        assert_eq!(status, StatusCode::CREATED, "{}", body);
+    }
+
+    add_to_cart(&context, &customer, 1).await;
+
+    for (code, error) in [
+        ("BIGSPEND", "min_cart_value"),
+        ("CATEGORY2", "not_applicable"),
+        ("LATER", "not_started"),
+        ("DISABLED", "invalid"),
+        ("NOPE", "invalid"),
+    ] {
+        let (status, body) = apply_coupon(&context, &customer, code).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", code);
+        assert_eq!(body["errors"][0]["field"], "code");
+        assert_eq!(body["errors"][0]["code"], error, "{}", code);
+    }
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_coupon_usage_limit() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let first = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+    let second = utils::auto_login(&context.srv, "test2@test.com".to_string()).await;
+
+    let (status, _) = create_coupon(
+        &context,
+        json!({"code": "ONCE", "kind": "fixed_amount", "value": "2.50", "usage_limit": 1}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    add_to_cart(&context, &first, 1).await;
+    add_to_cart(&context, &second, 1).await;
+
+    let (status, body) = apply_coupon(&context, &first, "ONCE").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["total"]["amount"], "8.49");
+
+    let (status, body) = apply_coupon(&context, &second, "ONCE").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["errors"][0]["code"], "usage_limit_reached");
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_free_item_coupon() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    let (status, body) = create_coupon(
+        &context,
+        json!({"code": "FREEONE", "kind": "free_item", "free_product_id": 1}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+
+    add_to_cart(&context, &customer, 3).await;
+
+    let (status, body) = apply_coupon(&context, &customer, "FREEONE").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let cart = &body["data"];
+    assert_eq!(cart["discounts"][0]["product_id"], 1);
+    assert_eq!(cart["discounts"][0]["amount"]["amount"], "10.99");
+    assert_eq!(cart["total"]["amount"], "21.98");
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_admin_coupon_validation() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let (status, _) = create_coupon(
+        &context,
+        json!({"code": "TAKEN", "kind": "percentage", "value": "10"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, _) = create_coupon(
+        &context,
+        json!({"code": "taken", "kind": "percentage", "value": "20"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    for (payload, field) in [
+        (
+            json!({"code": "TOOMUCH", "kind": "percentage", "value": "150"}),
+            "value",
+        ),
+        (json!({"code": "NOVALUE", "kind": "fixed_amount"}), "value"),
+        (
+            json!({"code": "NOPRODUCT", "kind": "free_item"}),
+            "free_product_id",
+        ),
+        (json!({"code": "WEIRD", "kind": "bogo"}), "kind"),
+        (
+            json!({"code": "has space", "kind": "percentage", "value": "5"}),
+            "code",
+        ),
+    ] {
+        let (status, body) = create_coupon(&context, payload).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+        assert_eq!(body["errors"][0]["field"], field, "{}", body);
+    }
+
+    let (status, _) = create_coupon(
+        &context,
+        json!({"code": "GHOST", "kind": "percentage", "value": "5", "products": [999]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,124 @@
+use actix_web::http::StatusCode;
+use actix_web::http::header::CONTENT_TYPE;
+use ecomm::admin::categories::dto::CreateCategoryDTO;
+use ecomm::responses::error_responses::{ErrorResponse, FieldError};
+use serde_json::json;
+
+mod utils;
+
+#[actix_rt::test]
+async fn test_validation_errors_are_problem_details() {
+    let context = utils::TestContext::new(None).await;
+
+    let payload = json!({
+        "username": "ab",
+        "email": "not-an-email",
+        "password_confirmation": "123456",
+    });
+
+    let mut res = context
+        .srv
+        .post("/api/v1/auth/register")
+        .send_json(&payload)
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        res.headers().get(CONTENT_TYPE).unwrap(),
+        "application/problem+json"
+    );
+
+    let body: ErrorResponse = res.json().await.unwrap();
+
+    assert_eq!(body.code, "validation_failed");
+    assert_eq!(body.status, 422);
+    assert_eq!(body.problem_type, "urn:ecomm:problem:validation_failed");
+    assert!(body.request_id.is_some());
+
+    assert!(body.errors.contains(&FieldError::new(
+        "email",
+        "email",
+        "must be a valid email address"
+    )));
+    assert!(
+        body.errors
+            .contains(&FieldError::new("password", "required", "is required"))
+    );
+    assert!(body.errors.contains(&FieldError::new(
+        "username",
+        "length",
+        "must be at least 3 characters"
+    )));
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_conflict_returns_409() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = CreateCategoryDTO {
+        name: Some("Conflicting Category".to_string()),
+        slug: Some("conflicting-category".to_string()),
+        is_active: Some(true),
+    };
+
+    for expected in [StatusCode::CREATED, StatusCode::CONFLICT] {
+        let res = context
+            .srv
+            .post("/api/v1/admin/categories/create")
+            .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+            .send_json(&payload)
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), expected);
+    }
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_malformed_json_is_bad_request() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context
+        .srv
+        .post("/api/v1/auth/login")
+        .insert_header((CONTENT_TYPE, "application/json"))
+        .send_body("{not json")
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let body: ErrorResponse = res.json().await.unwrap();
+
+    assert_eq!(body.code, "bad_request");
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_auth_errors_use_stable_codes() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context
+        .srv
+        .get("/api/v1/cart/user/get")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    let body: ErrorResponse = res.json().await.unwrap();
+
+    assert_eq!(body.code, "unauthorized");
+    assert_eq!(body.detail, "missing bearer");
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,75 @@
+use actix_web::http::StatusCode;
+use ecomm::app::cart::cart_items::dto::AddItemDto;
+use ecomm::health::dto::{HealthReport, HealthStatus};
+
+mod utils;
+
+#[actix_rt::test]
+async fn test_health_live() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context.srv.get("/health/live").send().await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: HealthReport = res.json().await.unwrap();
+
+    assert_eq!(body.status, HealthStatus::Ok);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_health_ready() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context.srv.get("/health/ready").send().await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: HealthReport = res.json().await.unwrap();
+
+    assert_eq!(body.status, HealthStatus::Ok);
+    assert_eq!(body.checks.get("database"), Some(&HealthStatus::Ok));
+    assert_eq!(body.checks.get("storage"), Some(&HealthStatus::Ok));
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_metrics_exposes_request_and_business_counters() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    let auth_token = context.auth_token.unwrap();
+
+    let payload = AddItemDto {
+        product_id: Some(1),
+        quantity: Some(1),
+    };
+
+    let res = context
+        .srv
+        .post("/api/v1/cart/user/add")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap();
+
+    assert!(res.status().is_success(), "{:#?}", res);
+
+    let mut res = context.srv.get("/metrics").send().await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = String::from_utf8(res.body().await.unwrap().to_vec()).unwrap();
+
+    assert!(body.contains(
+        "ecomm_http_requests_total{method=\"POST\",route=\"/api/v1/cart/user/add\",status=\"200\"} 1"
+    ));
+    assert!(body.contains("ecomm_http_request_duration_seconds_bucket"));
+    assert!(body.contains("ecomm_carts_created_total 1"));
+    assert!(body.contains("ecomm_cart_items_added_total 1"));
+    assert!(body.contains("ecomm_db_pool_connections"));
+
+    context.database.cleanup().await;
+}
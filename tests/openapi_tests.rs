@@ -0,0 +1,61 @@
+use actix_web::http::StatusCode;
+use serde_json::Value;
+
+mod utils;
+
+#[actix_rt::test]
+async fn test_openapi_spec_is_served() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context.srv.get("/openapi.json").send().await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let spec: Value = res.json().await.unwrap();
+
+    assert!(spec["openapi"].as_str().unwrap().starts_with("3."));
+
+    let paths = spec["paths"].as_object().unwrap();
+    assert!(paths.contains_key("/api/v1/auth/login"));
+    assert!(paths.contains_key("/api/v1/products/get/{slug}"));
+    assert!(paths.contains_key("/api/v1/cart/guest/add"));
+    assert!(paths.contains_key("/api/v1/admin/products/create"));
+
+    let schemes = &spec["components"]["securitySchemes"];
+    assert_eq!(schemes["bearer_auth"]["scheme"], "bearer");
+    assert_eq!(schemes["guest_token"]["in"], "header");
+    assert_eq!(schemes["guest_token"]["name"], "x-guest-token");
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_openapi_spec_lists_required_scopes_and_errors() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context.srv.get("/openapi.json").send().await.unwrap();
+    let spec: Value = res.json().await.unwrap();
+
+    let create = &spec["paths"]["/api/v1/admin/products/create"]["post"];
+    assert_eq!(create["security"][0]["bearer_auth"][0], "products:create");
+    assert_eq!(
+        create["responses"]["403"]["content"]["application/problem+json"]["schema"]["$ref"],
+        "#/components/schemas/ErrorResponse"
+    );
+
+    let guest_get = &spec["paths"]["/api/v1/cart/guest/get"]["get"];
+    assert!(guest_get["security"][0]["guest_token"].is_array());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_docs_ui_is_served() {
+    let context = utils::TestContext::new(None).await;
+
+    let res = context.srv.get("/docs/").send().await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    context.database.cleanup().await;
+}
@@ -8,7 +8,7 @@ mod utils;
 
 fn get_index_url(payload: IndexProductDTO) -> String {
     format!(
-        "/products/list?{}",
+        "/api/v1/products/list?{}",
         serde_urlencoded::to_string(payload).unwrap()
     )
 }
@@ -16,11 +16,11 @@ fn get_index_url(payload: IndexProductDTO) -> String {
 fn get_show_url(payload: Option<ShowProductDTO>, slug: String) -> String {
     match payload {
         Some(payload) => format!(
-            "/products/get/{}?{}",
+            "/api/v1/products/get/{}?{}",
             slug,
             serde_urlencoded::to_string(payload).unwrap()
         ),
-        None => format!("/products/get/{}", slug),
+        None => format!("/api/v1/products/get/{}", slug),
     }
 }
 
@@ -31,6 +31,8 @@ async fn test_product_index() {
     let query_payload = IndexProductDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         category: None,
         price_min: None,
@@ -67,6 +69,8 @@ async fn test_product_index_with_all_relations() {
     let query_payload = IndexProductDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         category: None,
         price_min: None,
@@ -112,6 +116,8 @@ async fn test_product_index_with_only_images_relation() {
     let query_payload = IndexProductDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         category: None,
         price_min: None,
@@ -151,6 +157,8 @@ async fn test_product_index_incomplete_query_params() {
     let query_payload = IndexProductDTO {
         page: None,
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: None,
         category: None,
         price_min: None,
@@ -176,6 +184,8 @@ async fn test_product_index_search() {
     let query_payload = IndexProductDTO {
         page: Some(1),
         limit: Some(10),
+        pagination: None,
+        cursor: None,
         search: Some("Test Product 1".to_string()),
         category: None,
         price_min: None,
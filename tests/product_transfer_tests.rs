@@ -0,0 +1,243 @@
+use actix_web::http::StatusCode;
+use ecomm::admin::products::transfer::dto::{ImportMode, TransferFormat};
+use ecomm::admin::products::transfer::service::AdminProductTransferService;
+use ecomm::app::products::cache::ProductCache;
+use ecomm::config::settings::CacheConfig;
+use ecomm::errors::error::AppError;
+use ecomm::notifications::notifier::LogNotifier;
+use serde_json::Value;
+use std::sync::Arc;
+
+mod utils;
+
+fn transfer_service(context: &utils::TestContextNoServer) -> AdminProductTransferService {
+    AdminProductTransferService::with_notifier(
+        context.database.pool.clone(),
+        ProductCache::new(&CacheConfig::default()),
+        Arc::new(LogNotifier),
+    )
+}
+
+async fn product_count(context: &utils::TestContextNoServer) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM products")
+        .fetch_one(&context.database.pool)
+        .await
+        .unwrap()
+}
+
+#[actix_rt::test]
+async fn test_csv_import_creates_products_with_categories() {
+    let context = utils::TestContextNoServer::new().await;
+    let service = transfer_service(&context);
+
+    let file = "name,slug,price,quantity,configurable,is_active,categories\n\
+        Imported One,imported-1,10.5,3,false,true,test-category-1|test-category-2\n\
+        Imported Two,imported-2,20,,,,\n";
+
+    let report = service
+        .import(
+            file.as_bytes(),
+            TransferFormat::Csv,
+            ImportMode::Create,
+            false,
+            3,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(report.created, 2);
+    assert_eq!(report.updated, 0);
+
+    let categories: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM product_has_categories JOIN products ON products.id = product_id WHERE products.slug = 'imported-1'",
+    )
+    .fetch_one(&context.database.pool)
+    .await
+    .unwrap();
+    assert_eq!(categories, 2);
+
+    // missing optional columns fall back to the create defaults
+    let (quantity, is_active): (i32, bool) =
+        sqlx::query_as("SELECT quantity, is_active FROM products WHERE slug = 'imported-2'")
+            .fetch_one(&context.database.pool)
+            .await
+            .unwrap();
+    assert_eq!((quantity, is_active), (0, true));
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_json_upsert_updates_by_slug() {
+    let context = utils::TestContextNoServer::new().await;
+    let service = transfer_service(&context);
+
+    let file = r#"[
+        {"name": "Test Product 1", "slug": "test-product-1", "price": 99.0, "quantity": 42},
+        {"name": "Brand New", "slug": "brand-new", "price": 5.0, "categories": ["test-category-1"]}
+    ]"#;
+
+    let report = service
+        .import(
+            file.as_bytes(),
+            TransferFormat::Json,
+            ImportMode::Upsert,
+            false,
+            3,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(report.created, 1);
+    assert_eq!(report.updated, 1);
+
+    let quantity: i32 = sqlx::query_scalar("SELECT quantity FROM products WHERE id = 1")
+        .fetch_one(&context.database.pool)
+        .await
+        .unwrap();
+    assert_eq!(quantity, 42);
+
+    let audited: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM audit_log WHERE entity = 'product'")
+            .fetch_one(&context.database.pool)
+            .await
+            .unwrap();
+    assert_eq!(audited, 2);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_import_reports_errors_per_row_and_writes_nothing() {
+    let context = utils::TestContextNoServer::new().await;
+    let service = transfer_service(&context);
+    let before = product_count(&context).await;
+
+    let file = r#"[
+        {"name": "Valid Row", "slug": "valid-row", "price": 1.0},
+        {"name": "No", "slug": "bad price", "price": -1.0},
+        {"name": "Existing", "slug": "test-product-1", "price": 1.0},
+        {"name": "Unknown Category", "slug": "unknown-category", "price": 1.0, "categories": ["nope"]},
+        {"name": "Valid Row Again", "slug": "valid-row", "price": 1.0},
+        "not a product"
+    ]"#;
+
+    let result = service
+        .import(
+            file.as_bytes(),
+            TransferFormat::Json,
+            ImportMode::Create,
+            false,
+            3,
+        )
+        .await;
+
+    let Err(AppError::ValidationSingle(errors)) = result else {
+        panic!("expected row errors, got {:?}", result);
+    };
+
+    let fields: Vec<&str> = errors.iter().map(|error| error.field.as_str()).collect();
+
+    assert!(fields.contains(&"rows[1].name"));
+    assert!(fields.contains(&"rows[1].price"));
+    assert!(fields.contains(&"rows[2].slug"));
+    assert!(fields.contains(&"rows[3].categories"));
+    assert!(fields.contains(&"rows[4].slug"));
+    assert!(fields.contains(&"rows[5]"));
+    assert!(!fields.iter().any(|field| field.starts_with("rows[0]")));
+
+    assert_eq!(product_count(&context).await, before);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_dry_run_validates_without_writing() {
+    let context = utils::TestContextNoServer::new().await;
+    let service = transfer_service(&context);
+    let before = product_count(&context).await;
+
+    let file = r#"[{"name": "Dry Run", "slug": "dry-run", "price": 1.0}]"#;
+
+    let report = service
+        .import(
+            file.as_bytes(),
+            TransferFormat::Json,
+            ImportMode::Create,
+            true,
+            3,
+        )
+        .await
+        .unwrap();
+
+    assert!(report.dry_run);
+    assert_eq!(report.created, 1);
+    assert_eq!(product_count(&context).await, before);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_export_streams_filtered_products() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/admin/products/export?format=json&is_active=true")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(
+        res.headers()
+            .get("content-disposition")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .contains("products.json")
+    );
+
+    let body: Value = serde_json::from_slice(&res.body().await.unwrap()).unwrap();
+    let slugs: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| row["slug"].as_str().unwrap())
+        .collect();
+    assert_eq!(slugs, vec!["test-product-1"]);
+
+    let mut res = context
+        .srv
+        .get("/api/v1/admin/products/export?format=csv")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = String::from_utf8(res.body().await.unwrap().to_vec()).unwrap();
+    let mut lines = body.lines();
+    assert_eq!(
+        lines.next(),
+        Some(
+            "name,slug,price,quantity,configurable,is_active,publish_at,unpublish_at,sale_price,sale_starts_at,sale_ends_at,tax_class,weight_grams,length_mm,width_mm,height_mm,categories"
+        )
+    );
+    assert_eq!(lines.count(), 2);
+
+    let res = context
+        .srv
+        .get("/api/v1/admin/products/export")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,304 @@
+use actix_web::http::StatusCode;
+use chrono::Utc;
+use ecomm::admin::promotions::dto::{PromotionKind, PromotionRule, VolumeTier};
+use ecomm::app::cart::cart_items::dto::AddItemDto;
+use ecomm::app::cart::cart_items::model::CartItemModel;
+use ecomm::app::cart::promotions::engine::evaluate;
+use ecomm::app::cart::promotions::model::PromotionModel;
+use serde_json::{Value, json};
+use sqlx::types::Json;
+use std::collections::HashMap;
+
+mod utils;
+
+use utils::decimal;
+
+fn item(product_id: i64, price: &str, quantity: i32) -> CartItemModel {
+    CartItemModel {
+        id: product_id,
+        cart_id: 1,
+        product_id,
+        price: decimal(price),
+        quantity,
+        created_at: Utc::now(),
+    }
+}
+
+fn promotion(id: i64, priority: i32, stackable: bool, rule: PromotionRule) -> PromotionModel {
+    PromotionModel {
+        id,
+        name: format!("promotion {}", id),
+        rule: Json(rule),
+        priority,
+        stackable,
+    }
+}
+
+fn category_percentage(category_id: i64, percent: &str) -> PromotionRule {
+    PromotionRule::CategoryPercentage {
+        category_id,
+        percent: decimal(percent),
+    }
+}
+
+#[test]
+fn test_buy_x_get_y() {
+    let same_product = promotion(
+        1,
+        0,
+        true,
+        PromotionRule::BuyXGetY {
+            product_id: 1,
+            buy_quantity: 2,
+            get_product_id: None,
+            get_quantity: 1,
+        },
+    );
+
+    // 7 units make two groups of 2 + 1
+    let applied = evaluate(&[same_product], &[item(1, "10.00", 7)], &HashMap::new());
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].kind, PromotionKind::BuyXGetY);
+    assert_eq!(applied[0].product_id, Some(1));
+    assert_eq!(applied[0].amount.amount, decimal("20.00"));
+
+    let other_product = promotion(
+        2,
+        0,
+        true,
+        PromotionRule::BuyXGetY {
+            product_id: 1,
+            buy_quantity: 2,
+            get_product_id: Some(2),
+            get_quantity: 1,
+        },
+    );
+
+    // two free units earned, only one in the cart
+    let applied = evaluate(
+        &[other_product],
+        &[item(1, "10.00", 4), item(2, "5.00", 1)],
+        &HashMap::new(),
+    );
+    assert_eq!(applied[0].product_id, Some(2));
+    assert_eq!(applied[0].amount.amount, decimal("5.00"));
+}
+
+#[test]
+fn test_tiered_volume_picks_highest_tier_reached() {
+    let tiered = || {
+        promotion(
+            1,
+            0,
+            true,
+            PromotionRule::TieredVolume {
+                product_id: 1,
+                tiers: vec![
+                    VolumeTier {
+                        min_quantity: 10,
+                        percent: decimal("10"),
+                    },
+                    VolumeTier {
+                        min_quantity: 3,
+                        percent: decimal("5"),
+                    },
+                ],
+            },
+        )
+    };
+
+    let applied = evaluate(&[tiered()], &[item(1, "2.00", 10)], &HashMap::new());
+    assert_eq!(applied[0].amount.amount, decimal("2.00"));
+
+    let applied = evaluate(&[tiered()], &[item(1, "2.00", 5)], &HashMap::new());
+    assert_eq!(applied[0].amount.amount, decimal("0.50"));
+
+    let applied = evaluate(&[tiered()], &[item(1, "2.00", 2)], &HashMap::new());
+    assert!(applied.is_empty());
+}
+
+#[test]
+fn test_category_percentage() {
+    let categories = HashMap::from([(1, vec![7]), (2, vec![8])]);
+
+    let applied = evaluate(
+        &[promotion(1, 0, true, category_percentage(7, "15"))],
+        &[item(1, "10.00", 2), item(2, "5.00", 1)],
+        &categories,
+    );
+
+    assert_eq!(applied[0].kind, PromotionKind::CategoryPercentage);
+    assert_eq!(applied[0].product_id, Some(1));
+    assert_eq!(applied[0].amount.amount, decimal("3.00"));
+}
+
+#[test]
+fn test_bundle_counts_complete_bundles() {
+    let bundle = || {
+        promotion(
+            1,
+            0,
+            true,
+            PromotionRule::Bundle {
+                product_ids: vec![1, 2],
+                price: decimal("12.00"),
+            },
+        )
+    };
+
+    let applied = evaluate(
+        &[bundle()],
+        &[item(1, "10.00", 2), item(2, "5.00", 3)],
+        &HashMap::new(),
+    );
+    assert_eq!(applied[0].product_id, None);
+    assert_eq!(applied[0].amount.amount, decimal("6.00"));
+
+    let applied = evaluate(&[bundle()], &[item(1, "10.00", 2)], &HashMap::new());
+    assert!(applied.is_empty());
+}
+
+#[test]
+fn test_stacking_and_priority() {
+    let categories = HashMap::from([(1, vec![7])]);
+    let items = [item(1, "10.00", 1)];
+
+    // both stack, the second one only gets what the first one left
+    let applied = evaluate(
+        &[
+            promotion(2, 5, true, category_percentage(7, "60")),
+            promotion(1, 10, true, category_percentage(7, "50")),
+        ],
+        &items,
+        &categories,
+    );
+    assert_eq!(applied.len(), 2);
+    assert_eq!(applied[0].promotion_id, 1);
+    assert_eq!(applied[0].amount.amount, decimal("5.00"));
+    assert_eq!(applied[1].promotion_id, 2);
+    assert_eq!(applied[1].amount.amount, decimal("5.00"));
+
+    // a non stackable promotion first keeps the others out
+    let applied = evaluate(
+        &[
+            promotion(1, 10, true, category_percentage(7, "50")),
+            promotion(2, 20, false, category_percentage(7, "10")),
+        ],
+        &items,
+        &categories,
+    );
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].promotion_id, 2);
+
+    // and is left out itself once another one applied
+    let applied = evaluate(
+        &[
+            promotion(1, 10, true, category_percentage(7, "50")),
+            promotion(2, 5, false, category_percentage(7, "10")),
+        ],
+        &items,
+        &categories,
+    );
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].promotion_id, 1);
+
+    // same priority goes by id
+    let applied = evaluate(
+        &[
+            promotion(4, 0, true, category_percentage(7, "10")),
+            promotion(3, 0, true, category_percentage(7, "20")),
+        ],
+        &items,
+        &categories,
+    );
+    assert_eq!(applied[0].promotion_id, 3);
+    assert_eq!(applied[1].promotion_id, 4);
+}
+
+#[actix_rt::test]
+async fn test_promotion_applies_to_cart() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    let mut res = context
+        .srv
+        .post("/api/v1/admin/promotions/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({
+            "name": "Two or more",
+            "rule": {
+                "type": "tiered_volume",
+                "product_id": 1,
+                "tiers": [{ "min_quantity": 2, "percent": "10" }]
+            }
+        }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["kind"], "tiered_volume");
+
+    let res = context
+        .srv
+        .post("/api/v1/cart/user/add")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send_json(&AddItemDto {
+            product_id: Some(1),
+            quantity: Some(2),
+        })
+        .await
+        .unwrap();
+    assert!(res.status().is_success());
+
+    let mut res = context
+        .srv
+        .get("/api/v1/cart/user/get")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+
+    let cart = &body["data"];
+    assert_eq!(cart["subtotal"]["amount"], "21.98");
+    assert_eq!(cart["promotions"][0]["name"], "Two or more");
+    assert_eq!(cart["promotions"][0]["amount"]["amount"], "2.20");
+    assert_eq!(cart["total"]["amount"], "19.78");
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_admin_promotion_validation() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+
+    for (rule, status) in [
+        (
+            json!({ "type": "bundle", "product_ids": [1], "price": "5" }),
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+        (
+            json!({ "type": "category_percentage", "category_id": 1, "percent": "120" }),
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+        (
+            json!({ "type": "buy_x_get_y", "product_id": 999, "buy_quantity": 1, "get_quantity": 1 }),
+            StatusCode::NOT_FOUND,
+        ),
+    ] {
+        let res = context
+            .srv
+            .post("/api/v1/admin/promotions/create")
+            .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+            .send_json(&json!({ "name": "Broken", "rule": rule }))
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), status, "{}", rule);
+    }
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,191 @@
+use actix_test::{ClientResponse, TestServer};
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, Utc};
+use ecomm::admin::products::dto::{AdminPublicProduct, CreateProductDTO, ProductStatus};
+use ecomm::responses::api_responses::LocalApiResponse;
+use serde_json::Value;
+
+mod utils;
+
+#[actix_rt::test]
+async fn test_product_status_without_window() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    assert_eq!(admin_status(&context, 1).await, ProductStatus::Live);
+    assert_eq!(admin_status(&context, 2).await, ProductStatus::Inactive);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_scheduled_product_is_hidden() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    set_window(&context, "now() + interval '1 day'", "NULL").await;
+
+    assert_eq!(
+        storefront_status(&context.srv, "test-product-1").await,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(admin_status(&context, 1).await, ProductStatus::Scheduled);
+
+    let mut res = context
+        .srv
+        .get("/api/v1/products/list?page=1&limit=10")
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+    assert!(body["data"].as_array().unwrap().is_empty());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_expired_product_is_hidden() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    set_window(
+        &context,
+        "now() - interval '2 days'",
+        "now() - interval '1 day'",
+    )
+    .await;
+
+    assert_eq!(
+        storefront_status(&context.srv, "test-product-1").await,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(admin_status(&context, 1).await, ProductStatus::Expired);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_product_is_live_inside_its_window() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    set_window(
+        &context,
+        "now() - interval '2 days'",
+        "now() + interval '1 day'",
+    )
+    .await;
+
+    assert_eq!(
+        storefront_status(&context.srv, "test-product-1").await,
+        StatusCode::OK
+    );
+    assert_eq!(admin_status(&context, 1).await, ProductStatus::Live);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_publish_window_must_end_after_it_starts() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+    let now = Utc::now();
+
+    let payload = seasonal_product(now + Duration::days(2), now + Duration::days(1));
+
+    let mut res = create_product(&context.srv, &auth_token, &payload).await;
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "unpublish_at");
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_product_created_with_future_window_is_hidden() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+    let now = Utc::now();
+
+    let payload = seasonal_product(now + Duration::days(2), now + Duration::days(3));
+
+    let res = create_product(&context.srv, &auth_token, &payload).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    assert_eq!(
+        storefront_status(&context.srv, "seasonal-product").await,
+        StatusCode::NOT_FOUND
+    );
+
+    context.database.cleanup().await;
+}
+
+fn seasonal_product(publish_at: DateTime<Utc>, unpublish_at: DateTime<Utc>) -> CreateProductDTO {
+    CreateProductDTO {
+        name: Some("Seasonal Product".to_string()),
+        slug: Some("seasonal-product".to_string()),
+        categories: None,
+        price: Some(BigDecimal::from(10)),
+        quantity: Some(1),
+        configurable: Some(false),
+        is_active: Some(true),
+        publish_at: Some(publish_at),
+        unpublish_at: Some(unpublish_at),
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
+    }
+}
+
+/** Moves the window of product 1, both ends given as SQL expressions. */
+async fn set_window(context: &utils::TestContext, publish_at: &str, unpublish_at: &str) {
+    sqlx::query(&format!(
+        "UPDATE products SET publish_at = {}, unpublish_at = {} WHERE id = 1",
+        publish_at, unpublish_at
+    ))
+    .execute(&context.database.pool)
+    .await
+    .unwrap();
+}
+
+async fn admin_status(context: &utils::TestContext, id: i64) -> ProductStatus {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get(format!("/api/v1/admin/products/get/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<AdminPublicProduct> = res.json().await.unwrap();
+    body.get_data().status
+}
+
+async fn storefront_status(srv: &TestServer, slug: &str) -> StatusCode {
+    srv.get(format!("/api/v1/products/get/{}", slug))
+        .send()
+        .await
+        .unwrap()
+        .status()
+}
+
+async fn create_product(
+    srv: &TestServer,
+    auth_token: &str,
+    payload: &CreateProductDTO,
+) -> ClientResponse {
+    srv.post("/api/v1/admin/products/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(payload)
+        .await
+        .unwrap()
+}
@@ -0,0 +1,83 @@
+use actix_web::http::StatusCode;
+use chrono::{Duration, Utc};
+use ecomm::auth::dto::LoginDTO;
+use ecomm::config::settings::RateLimitPolicyConfig;
+use ecomm::rate_limit::model::Bucket;
+use ecomm::responses::error_responses::ErrorResponse;
+
+mod utils;
+
+#[test]
+fn test_bucket_denies_when_empty_and_refills() {
+    let policy = RateLimitPolicyConfig::new(2, 60);
+    let now = Utc::now();
+    let mut bucket = Bucket::full(&policy, now);
+
+    assert!(bucket.take(&policy, now).allowed);
+    assert!(bucket.take(&policy, now).allowed);
+
+    let denied = bucket.take(&policy, now);
+    assert!(!denied.allowed);
+    assert_eq!(denied.remaining, 0);
+    assert_eq!(denied.retry_after_secs, 1);
+
+    // one token per second
+    let later = bucket.take(&policy, now + Duration::seconds(1));
+    assert!(later.allowed);
+}
+
+#[test]
+fn test_bucket_never_exceeds_capacity() {
+    let policy = RateLimitPolicyConfig::new(3, 60);
+    let now = Utc::now();
+    let mut bucket = Bucket::full(&policy, now);
+
+    let decision = bucket.take(&policy, now + Duration::hours(1));
+
+    assert!(decision.allowed);
+    assert_eq!(decision.limit, 3);
+    assert_eq!(decision.remaining, 2);
+}
+
+#[actix_rt::test]
+async fn test_login_is_rate_limited() {
+    let context = utils::TestContext::new(None).await;
+
+    let capacity = utils::test_config().rate_limit.login.capacity;
+
+    // a different account each time, so the lockout doesn't kick in first
+    let payload = |attempt: u32| LoginDTO {
+        email: Some(format!("unknown{}@test.com", attempt)),
+        password: Some("wrong-password".to_string()),
+    };
+
+    for attempt in 0..capacity {
+        let res = context
+            .srv
+            .post("/api/v1/auth/login")
+            .send_json(&payload(attempt))
+            .await
+            .unwrap();
+
+        assert_ne!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(res.headers().contains_key("ratelimit-remaining"));
+    }
+
+    let mut res = context
+        .srv
+        .post("/api/v1/auth/login")
+        .send_json(&payload(capacity))
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(res.headers().contains_key("retry-after"));
+    assert_eq!(res.headers().get("ratelimit-remaining").unwrap(), "0");
+
+    let body: ErrorResponse = res.json().await.unwrap();
+
+    assert_eq!(body.code, "rate_limited");
+    assert_eq!(body.status, 429);
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,63 @@
+use actix_web::http::StatusCode;
+use ecomm::responses::error_responses::ErrorResponse;
+
+mod utils;
+
+#[actix_rt::test]
+async fn test_request_id_is_generated() {
+    let context = utils::TestContext::new(None).await;
+
+    let res = context.srv.get("/health/live").send().await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let request_id = res.headers().get("x-request-id").unwrap().to_str().unwrap();
+
+    assert!(uuid::Uuid::parse_str(request_id).is_ok());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_incoming_request_id_is_echoed_in_error_response() {
+    let context = utils::TestContext::new(None).await;
+
+    let mut res = context
+        .srv
+        .get("/api/v1/products/get/not-existing-product")
+        .insert_header(("X-Request-Id", "support-trace-123"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        res.headers().get("x-request-id").unwrap(),
+        "support-trace-123"
+    );
+
+    let body: ErrorResponse = res.json().await.unwrap();
+
+    assert_eq!(body.request_id, Some("support-trace-123".to_string()));
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_invalid_request_id_is_replaced() {
+    let context = utils::TestContext::new(None).await;
+
+    let res = context
+        .srv
+        .get("/health/live")
+        .insert_header(("X-Request-Id", "not a valid id"))
+        .send()
+        .await
+        .unwrap();
+
+    let request_id = res.headers().get("x-request-id").unwrap().to_str().unwrap();
+
+    assert_ne!(request_id, "not a valid id");
+
+    context.database.cleanup().await;
+}
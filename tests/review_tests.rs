@@ -15,7 +15,10 @@ async fn create_review_user(
 
     context
         .srv
-        .post(format!("/products/{}/reviews/create-user", product_id))
+        .post(format!(
+            "/api/v1/products/{}/reviews/create-user",
+            product_id
+        ))
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -29,7 +32,10 @@ async fn create_review_guest(
 ) -> ClientResponse {
     context
         .srv
-        .post(format!("/products/{}/reviews/create-guest", product_id))
+        .post(format!(
+            "/api/v1/products/{}/reviews/create-guest",
+            product_id
+        ))
         .send_json(&payload)
         .await
         .unwrap()
@@ -92,7 +98,7 @@ async fn test_review_create_with_user_no_token() {
 
     let res = context
         .srv
-        .post("/products/1/reviews/create-user")
+        .post("/api/v1/products/1/reviews/create-user")
         .send_json(&payload)
         .await
         .unwrap();
@@ -0,0 +1,170 @@
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use ecomm::admin::products::dto::CreateProductDTO;
+use ecomm::app::cart::cart_items::dto::AddItemDto;
+use ecomm::utils::money::Money;
+use serde_json::{Value, json};
+use std::str::FromStr;
+
+mod utils;
+
+#[test]
+fn test_money_always_has_two_decimals() {
+    let amount = |money: Money| serde_json::to_value(money).unwrap()["amount"].clone();
+
+    assert_eq!(amount(Money::zero()), json!("0.00"));
+    assert_eq!(
+        amount(Money::new(BigDecimal::from_str("42.5000").unwrap())),
+        json!("42.50")
+    );
+    assert_eq!(amount(Money::new(BigDecimal::from(7))), json!("7.00"));
+}
+
+async fn start_sale(context: &utils::TestContext, starts: &str) {
+    sqlx::query(&format!(
+        "UPDATE products SET sale_price = 5.5, sale_starts_at = now() + interval '{}' WHERE id = 1",
+        starts
+    ))
+    .execute(&context.database.pool)
+    .await
+    .unwrap();
+}
+
+async fn list_slugs(context: &utils::TestContext, query: &str) -> Vec<String> {
+    let mut res = context
+        .srv
+        .get(format!("/api/v1/products/list?page=1&limit=10&{}", query))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = res.json().await.unwrap();
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|product| product["slug"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[actix_rt::test]
+async fn test_storefront_shows_regular_and_effective_price() {
+    let context = utils::TestContext::new(None).await;
+
+    start_sale(&context, "-1 hour").await;
+
+    let mut res = context
+        .srv
+        .get("/api/v1/products/get/test-product-1")
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+
+    assert_eq!(body["data"]["price"]["amount"], "10.99");
+    assert_eq!(body["data"]["effective_price"]["amount"], "5.50");
+
+    // price filters apply to what the product sells for
+    assert_eq!(
+        list_slugs(&context, "price_max=6").await,
+        vec!["test-product-1"]
+    );
+    assert!(list_slugs(&context, "price_min=10").await.is_empty());
+
+    // a sale that hasn't started yet leaves the regular price in place
+    start_sale(&context, "1 hour").await;
+
+    assert!(list_slugs(&context, "price_max=6").await.is_empty());
+    assert_eq!(
+        list_slugs(&context, "price_min=10").await,
+        vec!["test-product-1"]
+    );
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_cart_locks_in_effective_price_at_add() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    start_sale(&context, "-1 hour").await;
+
+    let payload = AddItemDto {
+        product_id: Some(1),
+        quantity: Some(1),
+    };
+
+    let res = context
+        .srv
+        .post("/api/v1/cart/user/add")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap();
+    assert!(res.status().is_success());
+
+    // ending the sale later doesn't reprice what is already in the cart
+    sqlx::query("UPDATE products SET sale_ends_at = now() - interval '1 minute' WHERE id = 1")
+        .execute(&context.database.pool)
+        .await
+        .unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/cart/user/get")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+
+    assert_eq!(body["data"]["items"][0]["price"]["amount"], "5.50");
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_sale_price_must_be_lower_than_price() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = CreateProductDTO {
+        name: Some("Discounted Product".to_string()),
+        slug: Some("discounted-product".to_string()),
+        categories: None,
+        price: Some(BigDecimal::from(10)),
+        quantity: Some(1),
+        configurable: Some(false),
+        is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: Some(BigDecimal::from(12)),
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
+    };
+
+    let mut res = context
+        .srv
+        .post("/api/v1/admin/products/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "sale_price");
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,340 @@
+use actix_web::http::StatusCode;
+use ecomm::admin::shipping_methods::dto::{ShippingRule, WeightTier};
+use ecomm::app::cart::cart_items::dto::AddItemDto;
+use ecomm::app::cart::destination::CartDestination;
+use ecomm::app::cart::shipping::dto::ShippableLine;
+use ecomm::app::cart::shipping::engine::{billable_weight, find_zone, options, quote};
+use ecomm::app::cart::shipping::model::{ShippingMethodModel, ShippingZoneModel};
+use serde_json::{Value, json};
+use sqlx::types::Json;
+
+mod utils;
+
+use utils::decimal;
+
+fn zone(id: i64, countries: &[&str], postcodes: &[&str], priority: i32) -> ShippingZoneModel {
+    ShippingZoneModel {
+        id,
+        name: format!("zone {}", id),
+        countries: countries.iter().map(|c| c.to_string()).collect(),
+        postcodes: postcodes.iter().map(|p| p.to_string()).collect(),
+        priority,
+    }
+}
+
+fn method(id: i64, zone_id: i64, rule: ShippingRule) -> ShippingMethodModel {
+    ShippingMethodModel {
+        id,
+        zone_id,
+        name: format!("method {}", id),
+        rule: Json(rule),
+    }
+}
+
+fn destination(country: &str, postcode: Option<&str>) -> CartDestination {
+    CartDestination {
+        country: country.to_string(),
+        region: None,
+        postcode: postcode.map(str::to_string),
+    }
+}
+
+fn tiers() -> ShippingRule {
+    ShippingRule::WeightTiers {
+        tiers: vec![
+            WeightTier {
+                max_weight_grams: 5000,
+                price: decimal("9.99"),
+            },
+            WeightTier {
+                max_weight_grams: 1000,
+                price: decimal("4.99"),
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_billable_weight() {
+    let lines = [
+        // heavier than it is big
+        ShippableLine {
+            quantity: 2,
+            weight_grams: Some(300),
+            length_mm: Some(100),
+            width_mm: Some(100),
+            height_mm: Some(100),
+        },
+        // a light but bulky box, 400 * 300 * 200 / 5000 = 4800g
+        ShippableLine {
+            quantity: 1,
+            weight_grams: Some(500),
+            length_mm: Some(400),
+            width_mm: Some(300),
+            height_mm: Some(200),
+        },
+        // dimensions only count when all of them are known
+        ShippableLine {
+            quantity: 3,
+            weight_grams: None,
+            length_mm: Some(400),
+            width_mm: None,
+            height_mm: Some(200),
+        },
+    ];
+
+    assert_eq!(billable_weight(&lines), 600 + 4800);
+    assert_eq!(billable_weight(&[]), 0);
+}
+
+#[test]
+fn test_most_specific_zone_wins() {
+    let zones = [
+        zone(1, &["DE", "AT"], &[], 0),
+        zone(2, &["DE"], &["80*", "81*"], 0),
+        zone(3, &["DE"], &[], 5),
+    ];
+
+    let found =
+        |country, postcode| find_zone(&zones, &destination(country, postcode)).map(|z| z.id);
+
+    assert_eq!(found("DE", Some("80331")), Some(2));
+    assert_eq!(found("DE", Some("10115")), Some(3));
+    assert_eq!(found("DE", None), Some(3));
+    assert_eq!(found("AT", Some("1010")), Some(1));
+    assert_eq!(found("FR", Some("75001")), None);
+}
+
+#[test]
+fn test_quotes() {
+    let value = decimal("30.00");
+
+    assert_eq!(
+        quote(
+            &ShippingRule::FlatRate {
+                price: decimal("5.00")
+            },
+            0,
+            &value
+        ),
+        Some(decimal("5.00"))
+    );
+
+    // the lightest tier the weight fits in, none past the heaviest
+    assert_eq!(quote(&tiers(), 0, &value), Some(decimal("4.99")));
+    assert_eq!(quote(&tiers(), 1000, &value), Some(decimal("4.99")));
+    assert_eq!(quote(&tiers(), 1001, &value), Some(decimal("9.99")));
+    assert_eq!(quote(&tiers(), 5001, &value), None);
+
+    let free_over = ShippingRule::FreeOver {
+        threshold: decimal("30.00"),
+        price: decimal("3.50"),
+    };
+    assert_eq!(quote(&free_over, 0, &value), Some(decimal("0")));
+    assert_eq!(
+        quote(&free_over, 0, &decimal("29.99")),
+        Some(decimal("3.50"))
+    );
+}
+
+#[test]
+fn test_options_cheapest_first() {
+    let zone = zone(1, &["DE"], &[], 0);
+    let methods = [
+        method(
+            1,
+            1,
+            ShippingRule::FlatRate {
+                price: decimal("12.00"),
+            },
+        ),
+        method(2, 1, tiers()),
+        method(
+            3,
+            2,
+            ShippingRule::FlatRate {
+                price: decimal("1.00"),
+            },
+        ),
+    ];
+
+    let lines = [ShippableLine {
+        quantity: 3,
+        weight_grams: Some(1000),
+        ..Default::default()
+    }];
+
+    let found = options(&zone, &methods, &lines, &decimal("10.00"));
+
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].method_id, 2);
+    assert_eq!(found[0].billable_weight_grams, 3000);
+    assert_eq!(found[0].cost.amount, decimal("9.99"));
+    assert_eq!(found[1].method_id, 1);
+
+    // too heavy for the tiers, only the flat rate is left
+    let lines = [ShippableLine {
+        quantity: 6,
+        weight_grams: Some(1000),
+        ..Default::default()
+    }];
+
+    let found = options(&zone, &methods, &lines, &decimal("10.00"));
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].method_id, 1);
+}
+
+#[actix_rt::test]
+async fn test_cart_shipping_options() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    let mut res = context
+        .srv
+        .post("/api/v1/admin/shipping-zones/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "name": "Germany", "countries": ["de", "DE"] }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let body: Value = res.json().await.unwrap();
+    let zone_id = body["data"]["id"].as_i64().unwrap();
+    assert_eq!(body["data"]["countries"], json!(["DE"]));
+
+    for rule in [
+        json!({ "type": "flat_rate", "price": "6.90" }),
+        json!({ "type": "free_over", "threshold": "20.00", "price": "4.90" }),
+    ] {
+        let res = context
+            .srv
+            .post("/api/v1/admin/shipping-methods/create")
+            .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+            .send_json(&json!({ "zone_id": zone_id, "name": rule["type"], "rule": rule }))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::CREATED);
+    }
+
+    let res = context
+        .srv
+        .post("/api/v1/cart/user/add")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send_json(&AddItemDto {
+            product_id: Some(1),
+            quantity: Some(2),
+        })
+        .await
+        .unwrap();
+    assert!(res.status().is_success());
+
+    // 2 x 10.99 is over the free shipping threshold
+    let mut res = context
+        .srv
+        .get("/api/v1/cart/user/shipping-options?country=DE&postcode=10115")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = res.json().await.unwrap();
+    let found = body["data"].as_array().unwrap();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0]["kind"], "free_over");
+    assert_eq!(found[0]["zone"], "Germany");
+    assert_eq!(found[0]["cost"]["amount"], "0.00");
+    assert_eq!(found[1]["kind"], "flat_rate");
+    assert_eq!(found[1]["cost"]["amount"], "6.90");
+
+    // nothing ships outside the zone
+    let mut res = context
+        .srv
+        .get("/api/v1/cart/user/shipping-options?country=FR")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["data"], json!([]));
+
+    let res = context
+        .srv
+        .get("/api/v1/cart/user/shipping-options")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_admin_shipping_validation() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+
+    for body in [
+        json!({ "name": "No countries", "countries": [] }),
+        json!({ "name": "Bad country", "countries": ["DEU"] }),
+        json!({ "name": "Bad postcode", "countries": ["DE"], "postcodes": ["80?"] }),
+    ] {
+        let res = context
+            .srv
+            .post("/api/v1/admin/shipping-zones/create")
+            .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+            .send_json(&body)
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    }
+
+    let mut res = context
+        .srv
+        .post("/api/v1/admin/shipping-zones/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "name": "Germany", "countries": ["DE"] }))
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+    let zone_id = body["data"]["id"].as_i64().unwrap();
+
+    for rule in [
+        json!({ "type": "flat_rate", "price": "-1" }),
+        json!({ "type": "weight_tiers", "tiers": [] }),
+        json!({ "type": "weight_tiers", "tiers": [
+            { "max_weight_grams": 1000, "price": "4.99" },
+            { "max_weight_grams": 1000, "price": "5.99" },
+        ] }),
+        json!({ "type": "free_over", "threshold": "0", "price": "4.99" }),
+    ] {
+        let res = context
+            .srv
+            .post("/api/v1/admin/shipping-methods/create")
+            .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+            .send_json(&json!({ "zone_id": zone_id, "name": "Standard", "rule": rule }))
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY, "{}", rule);
+    }
+
+    let res = context
+        .srv
+        .post("/api/v1/admin/shipping-methods/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({
+            "zone_id": 999,
+            "name": "Standard",
+            "rule": { "type": "flat_rate", "price": "4.99" },
+        }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
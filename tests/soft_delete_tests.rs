@@ -0,0 +1,223 @@
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use ecomm::admin::categories::dto::CreateCategoryDTO;
+use ecomm::admin::products::dto::CreateProductDTO;
+use serde_json::Value;
+
+mod utils;
+
+async fn send(
+    context: &utils::TestContext,
+    method: &str,
+    url: &str,
+    body: Option<Value>,
+) -> (StatusCode, Option<Value>) {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let request = match method {
+        "GET" => context.srv.get(url),
+        "PUT" => context.srv.put(url),
+        "POST" => context.srv.post(url),
+        "DELETE" => context.srv.delete(url),
+        _ => unreachable!(),
+    }
+    .insert_header(("Authorization", format!("Bearer {}", auth_token)));
+
+    let mut res = match body {
+        Some(body) => request.send_json(&body).await.unwrap(),
+        None => request.send().await.unwrap(),
+    };
+
+    let status = res.status();
+    let body = res.json::<Value>().await.ok();
+
+    (status, body)
+}
+
+fn ids(body: &Option<Value>) -> Vec<i64> {
+    body.as_ref().unwrap()["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["id"].as_i64().unwrap())
+        .collect()
+}
+
+#[actix_rt::test]
+async fn test_deleted_product_moves_to_trash_and_back() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let (status, _) = send(&context, "DELETE", "/api/v1/admin/products/delete/1", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // hidden from the storefront and the admin listing
+    let res = context
+        .srv
+        .get("/api/v1/products/get/test-product-1")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&context, "GET", "/api/v1/admin/products/get/1", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, list) = send(
+        &context,
+        "GET",
+        "/api/v1/admin/products/list?page=1&limit=10",
+        None,
+    )
+    .await;
+    assert!(!ids(&list).contains(&1));
+
+    let (status, trash) = send(
+        &context,
+        "GET",
+        "/api/v1/admin/products/trash?page=1&limit=10",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ids(&trash), vec![1]);
+    assert!(!trash.unwrap()["data"][0]["deleted_at"].is_null());
+
+    let (status, _) = send(&context, "PUT", "/api/v1/admin/products/restore/1", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let res = context
+        .srv
+        .get("/api/v1/products/get/test-product-1")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    // only trashed products can be restored
+    let (status, _) = send(&context, "PUT", "/api/v1/admin/products/restore/1", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_purge_removes_trashed_product_for_good() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    // live products have to be trashed first
+    let (status, _) = send(&context, "DELETE", "/api/v1/admin/products/purge/2", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    send(&context, "DELETE", "/api/v1/admin/products/delete/2", None).await;
+
+    let (status, _) = send(&context, "DELETE", "/api/v1/admin/products/purge/2", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM products WHERE id = 2)")
+        .fetch_one(&context.database.pool)
+        .await
+        .unwrap();
+    assert!(!exists);
+
+    let (status, _) = send(&context, "PUT", "/api/v1/admin/products/restore/2", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_trashed_product_frees_its_slug() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    send(&context, "DELETE", "/api/v1/admin/products/delete/1", None).await;
+
+    let payload = CreateProductDTO {
+        name: Some("Test Product 1 Again".to_string()),
+        slug: Some("test-product-1".to_string()),
+        categories: None,
+        price: Some(BigDecimal::from(100)),
+        quantity: Some(10),
+        configurable: Some(false),
+        is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
+    };
+
+    let (status, _) = send(
+        &context,
+        "POST",
+        "/api/v1/admin/products/create",
+        Some(serde_json::to_value(&payload).unwrap()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // the slug is live again, so the old product can't come back with it
+    let (status, _) = send(&context, "PUT", "/api/v1/admin/products/restore/1", None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_deleted_category_moves_to_trash_and_back() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let (status, _) = send(
+        &context,
+        "DELETE",
+        "/api/v1/admin/categories/delete/1",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let res = context
+        .srv
+        .get("/api/v1/categories/get/test-category-1")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let (status, trash) = send(
+        &context,
+        "GET",
+        "/api/v1/admin/categories/trash?page=1&limit=10",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ids(&trash), vec![1]);
+
+    let payload = CreateCategoryDTO {
+        name: Some("Test Category 1 Again".to_string()),
+        slug: Some("test-category-1".to_string()),
+        is_active: Some(true),
+    };
+
+    let (status, _) = send(
+        &context,
+        "POST",
+        "/api/v1/admin/categories/create",
+        Some(serde_json::to_value(&payload).unwrap()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, _) = send(&context, "PUT", "/api/v1/admin/categories/restore/1", None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = send(&context, "DELETE", "/api/v1/admin/categories/purge/1", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    context.database.cleanup().await;
+}
@@ -0,0 +1,687 @@
+use actix_test::ClientResponse;
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use ecomm::admin::products::dto::{
+    AdminPublicLowStockProduct, AdminPublicProduct, LowStockThresholdDTO,
+};
+use ecomm::admin::products::service::AdminProductService;
+use ecomm::admin::warehouses::dto::{
+    CreateWarehouseCommand, TransferStockCommand, UpdateWarehouseCommand,
+};
+use ecomm::admin::warehouses::service::AdminWarehouseService;
+use ecomm::app::products::cache::ProductCache;
+use ecomm::app::stock_subscriptions::alerts::{
+    StockSubscriptionAlerts, is_back_in_stock, notifications_for,
+};
+use ecomm::app::stock_subscriptions::dto::{
+    GuestStockSubscriptionDto, PublicStockSubscription, StockSubscriptionDto,
+};
+use ecomm::app::stock_subscriptions::model::StockSubscriberModel;
+use ecomm::app::stock_subscriptions::service::StockSubscriptionService;
+use ecomm::app::wishlists::alerts::ProductSnapshot;
+use ecomm::config::settings::CacheConfig;
+use ecomm::notifications::dto::Notification;
+use ecomm::notifications::notifier::{FileNotifier, Notifier};
+use ecomm::responses::api_responses::{LocalApiPaginatedResponse, LocalApiResponse};
+use serde_json::Value;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+mod utils;
+
+#[test]
+fn test_back_in_stock() {
+    assert!(is_back_in_stock(&snapshot(0), &snapshot(3)));
+    assert!(is_back_in_stock(&snapshot(-2), &snapshot(1)));
+
+    // still sold out, or it never was
+    assert!(!is_back_in_stock(&snapshot(0), &snapshot(0)));
+    assert!(!is_back_in_stock(&snapshot(2), &snapshot(5)));
+
+    let mut hidden = snapshot(3);
+    hidden.available = false;
+    assert!(!is_back_in_stock(&snapshot(0), &hidden));
+
+    // stock customers couldn't buy, e.g. of a trashed product, counts as sold out
+    assert!(is_back_in_stock(&hidden, &snapshot(3)));
+}
+
+#[test]
+fn test_notifications_for_each_subscriber() {
+    let found = notifications_for(
+        &snapshot(3),
+        vec![
+            StockSubscriberModel {
+                user_id: Some(1),
+                email: "test1@test.com".to_string(),
+            },
+            StockSubscriberModel {
+                user_id: None,
+                email: "guest@example.com".to_string(),
+            },
+        ],
+    );
+
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].user_id(), Some(1));
+    assert_eq!(
+        found[1],
+        Notification::Available {
+            user_id: None,
+            email: "guest@example.com".to_string(),
+            product_id: 1,
+            product_name: "Test Product 1".to_string(),
+        }
+    );
+}
+
+#[actix_rt::test]
+async fn test_restock_notifies_subscribers() {
+    let context = utils::TestContextNoServer::new().await;
+    let pool = context.database.pool.clone();
+    let service = StockSubscriptionService::new(pool.clone());
+
+    sell_out(&pool).await;
+    service.subscribe_user(1, 1).await.unwrap();
+    service
+        .subscribe_guest("guest@example.com", 1)
+        .await
+        .unwrap();
+
+    let notifier = Arc::new(RecordingNotifier::default());
+    let alerts = StockSubscriptionAlerts::with_notifier(pool.clone(), notifier.clone());
+
+    let sent = alerts
+        .product_changed(&snapshot(0), &snapshot(3))
+        .await
+        .unwrap();
+
+    assert_eq!(sent, 2);
+    assert_eq!(
+        available_emails(&notifier),
+        vec!["test1@test.com", "guest@example.com"]
+    );
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_still_sold_out_notifies_nobody() {
+    let context = utils::TestContextNoServer::new().await;
+    let pool = context.database.pool.clone();
+
+    sell_out(&pool).await;
+    StockSubscriptionService::new(pool.clone())
+        .subscribe_user(1, 1)
+        .await
+        .unwrap();
+
+    let notifier = Arc::new(RecordingNotifier::default());
+    let alerts = StockSubscriptionAlerts::with_notifier(pool.clone(), notifier.clone());
+
+    let sent = alerts
+        .product_changed(&snapshot(0), &snapshot(0))
+        .await
+        .unwrap();
+
+    assert_eq!(sent, 0);
+    assert!(notifier.sent.lock().unwrap().is_empty());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_subscriptions_are_used_up_by_the_restock() {
+    let context = utils::TestContextNoServer::new().await;
+    let pool = context.database.pool.clone();
+    let service = StockSubscriptionService::new(pool.clone());
+
+    sell_out(&pool).await;
+    service.subscribe_user(1, 1).await.unwrap();
+
+    let alerts = StockSubscriptionAlerts::with_notifier(
+        pool.clone(),
+        Arc::new(RecordingNotifier::default()),
+    );
+
+    let sent = alerts
+        .product_changed(&snapshot(0), &snapshot(3))
+        .await
+        .unwrap();
+    assert_eq!(sent, 1);
+
+    let sent = alerts
+        .product_changed(&snapshot(0), &snapshot(3))
+        .await
+        .unwrap();
+    assert_eq!(sent, 0);
+    assert!(service.get_all_public(1).await.unwrap().is_empty());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_same_guest_email_subscribes_once() {
+    let context = utils::TestContextNoServer::new().await;
+    let pool = context.database.pool.clone();
+    let service = StockSubscriptionService::new(pool.clone());
+
+    sell_out(&pool).await;
+
+    // the same address twice, whatever its case, is one subscription
+    for email in ["guest@example.com", "GUEST@example.com"] {
+        service.subscribe_guest(email, 1).await.unwrap();
+    }
+
+    let alerts = StockSubscriptionAlerts::with_notifier(
+        pool.clone(),
+        Arc::new(RecordingNotifier::default()),
+    );
+
+    let sent = alerts
+        .product_changed(&snapshot(0), &snapshot(3))
+        .await
+        .unwrap();
+    assert_eq!(sent, 1);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_warehouse_coming_online_notifies_subscribers() {
+    let context = utils::TestContextNoServer::new().await;
+    let pool = context.database.pool.clone();
+    let notifier = Arc::new(RecordingNotifier::default());
+    let warehouses = warehouse_service(&pool, notifier.clone());
+
+    sell_out(&pool).await;
+    StockSubscriptionService::new(pool.clone())
+        .subscribe_user(1, 1)
+        .await
+        .unwrap();
+
+    let store = warehouses.create(store(false), 3).await.unwrap();
+    stock_warehouse(&pool, store.id, 5).await;
+    assert!(notifier.sent.lock().unwrap().is_empty());
+
+    warehouses
+        .update(
+            UpdateWarehouseCommand {
+                name: store.name.clone(),
+                code: store.code.clone(),
+                priority: store.priority,
+                is_active: true,
+                is_default: false,
+            },
+            store.id,
+            3,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(available_emails(&notifier), vec!["test1@test.com"]);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_transfer_to_an_active_warehouse_notifies_subscribers() {
+    let context = utils::TestContextNoServer::new().await;
+    let pool = context.database.pool.clone();
+    let notifier = Arc::new(RecordingNotifier::default());
+    let warehouses = warehouse_service(&pool, notifier.clone());
+
+    sell_out(&pool).await;
+    StockSubscriptionService::new(pool.clone())
+        .subscribe_user(1, 1)
+        .await
+        .unwrap();
+
+    let store = warehouses.create(store(false), 3).await.unwrap();
+    stock_warehouse(&pool, store.id, 5).await;
+
+    let main_id: i64 = sqlx::query_scalar("SELECT id FROM warehouses WHERE is_default")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    warehouses
+        .transfer(
+            TransferStockCommand {
+                product_id: 1,
+                from_warehouse_id: store.id,
+                to_warehouse_id: main_id,
+                quantity: 2,
+                reason: None,
+            },
+            3,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(available_emails(&notifier), vec!["test1@test.com"]);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_restoring_a_stocked_product_notifies_subscribers() {
+    let context = utils::TestContextNoServer::new().await;
+    let pool = context.database.pool.clone();
+    let notifier = Arc::new(RecordingNotifier::default());
+
+    sell_out(&pool).await;
+    StockSubscriptionService::new(pool.clone())
+        .subscribe_user(1, 1)
+        .await
+        .unwrap();
+
+    // restocked while it sat in the trash
+    sqlx::query("UPDATE products SET deleted_at = now() WHERE id = 1")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE warehouse_stock SET quantity = 4 WHERE product_id = 1")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    AdminProductService::with_notifier(
+        pool.clone(),
+        ProductCache::new(&CacheConfig::default()),
+        notifier.clone(),
+    )
+    .restore(1, 3)
+    .await
+    .unwrap();
+
+    assert_eq!(available_emails(&notifier), vec!["test1@test.com"]);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_file_notifier() {
+    let path = std::env::temp_dir()
+        .join(format!("ecomm-notifications-{}", std::process::id()))
+        .join("notifications.jsonl");
+    let notifier = FileNotifier::new(path.clone());
+
+    for email in ["test1@test.com", "guest@example.com"] {
+        notifier.notify(Notification::Available {
+            user_id: None,
+            email: email.to_string(),
+            product_id: 1,
+            product_name: "Test Product 1".to_string(),
+        });
+    }
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<Value> = contents
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["type"], "available");
+    assert_eq!(lines[1]["email"], "guest@example.com");
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[actix_rt::test]
+async fn test_low_stock_report_skips_products_above_threshold() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    // 10 in stock is above the default threshold
+    let products = low_stock_report(&context, "").await;
+    assert!(products.iter().all(|p| p.id != 1));
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_low_stock_report_lists_products_at_threshold() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let res = set_threshold(&context, 1, 10).await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let products = low_stock_report(&context, "").await;
+    let product = products.iter().find(|p| p.id == 1).unwrap();
+    assert_eq!(product.quantity, 10);
+    assert_eq!(product.threshold, 10);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_low_stock_report_out_of_stock_only() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let res = set_threshold(&context, 1, 10).await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let products = low_stock_report(&context, "&out_of_stock=true").await;
+    assert!(products.iter().all(|p| p.id != 1));
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_low_stock_threshold_shows_on_the_product() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let res = set_threshold(&context, 1, 10).await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let mut res = context
+        .srv
+        .get("/api/v1/admin/products/get/1")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<AdminPublicProduct> = res.json().await.unwrap();
+    assert_eq!(body.get_data().low_stock_threshold, Some(10));
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_low_stock_threshold_cannot_be_negative() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let res = set_threshold(&context, 1, -1).await;
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_low_stock_threshold_for_non_existing_product() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let res = set_threshold(&context, 999, 1).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_low_stock_report_requires_scope() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let res = context
+        .srv
+        .get("/api/v1/admin/products/low-stock?page=1&limit=10")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_subscribe_to_a_product_in_stock() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    // nothing to wait for while it is in stock
+    let res = subscribe(&context, 1).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_subscribe_to_a_product_not_for_sale() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    // product 2 isn't for sale
+    let res = subscribe(&context, 2).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_subscribe_to_a_sold_out_product() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    sell_out(&context.database.pool).await;
+
+    let mut res = subscribe(&context, 1).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<Vec<PublicStockSubscription>> = res.json().await.unwrap();
+    assert_eq!(body.get_data().len(), 1);
+    assert_eq!(body.get_data()[0].product_id, 1);
+    assert_eq!(body.get_data()[0].slug, "test-product-1");
+
+    let subscriptions = list_subscriptions(&context).await;
+    assert_eq!(subscriptions.len(), 1);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_unsubscribe() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    sell_out(&context.database.pool).await;
+
+    let res = subscribe(&context, 1).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let mut res = unsubscribe(&context, 1).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<Vec<PublicStockSubscription>> = res.json().await.unwrap();
+    assert!(body.get_data().is_empty());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_unsubscribe_without_a_subscription() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    let res = unsubscribe(&context, 1).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_guest_subscribe_requires_a_guest_token() {
+    let context = utils::TestContext::new(None).await;
+
+    let payload = GuestStockSubscriptionDto {
+        product_id: Some(1),
+        email: Some("guest@example.com".to_string()),
+    };
+
+    let res = context
+        .srv
+        .post("/api/v1/stock-alerts/guest/subscribe")
+        .send_json(&payload)
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    context.database.cleanup().await;
+}
+
+#[derive(Default)]
+struct RecordingNotifier {
+    sent: Mutex<Vec<Notification>>,
+}
+
+impl Notifier for RecordingNotifier {
+    fn notify(&self, notification: Notification) {
+        self.sent.lock().unwrap().push(notification);
+    }
+}
+
+fn snapshot(quantity: i32) -> ProductSnapshot {
+    ProductSnapshot {
+        id: 1,
+        name: "Test Product 1".to_string(),
+        price: BigDecimal::from_str("10.99").unwrap(),
+        quantity,
+        available: true,
+    }
+}
+
+async fn sell_out(pool: &sqlx::PgPool) {
+    sqlx::query("UPDATE warehouse_stock SET quantity = 0 WHERE product_id = 1")
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE products SET quantity = 0 WHERE id = 1")
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+fn warehouse_service(
+    pool: &sqlx::PgPool,
+    notifier: Arc<RecordingNotifier>,
+) -> AdminWarehouseService {
+    AdminWarehouseService::with_notifier(
+        pool.clone(),
+        ProductCache::new(&CacheConfig::default()),
+        notifier,
+    )
+}
+
+fn store(is_active: bool) -> CreateWarehouseCommand {
+    CreateWarehouseCommand {
+        name: "Berlin store".to_string(),
+        code: "BER-1".to_string(),
+        priority: 1,
+        is_active,
+        is_default: false,
+    }
+}
+
+/** Puts stock of product 1 into a warehouse, around the ledger. */
+async fn stock_warehouse(pool: &sqlx::PgPool, warehouse_id: i64, quantity: i32) {
+    sqlx::query(
+        "INSERT INTO warehouse_stock (warehouse_id, product_id, quantity) VALUES ($1, 1, $2)",
+    )
+    .bind(warehouse_id)
+    .bind(quantity)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+fn available_emails(notifier: &RecordingNotifier) -> Vec<String> {
+    notifier
+        .sent
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|notification| match notification {
+            Notification::Available { email, .. } => Some(email.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+async fn set_threshold(
+    context: &utils::TestContext,
+    product_id: i64,
+    threshold: i32,
+) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = LowStockThresholdDTO {
+        threshold: Some(threshold),
+    };
+
+    context
+        .srv
+        .put(format!("/api/v1/admin/products/low-stock/{}", product_id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap()
+}
+
+async fn low_stock_report(
+    context: &utils::TestContext,
+    query: &str,
+) -> Vec<AdminPublicLowStockProduct> {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get(format!(
+            "/api/v1/admin/products/low-stock?page=1&limit=10{}",
+            query
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiPaginatedResponse<Vec<AdminPublicLowStockProduct>> =
+        res.json().await.unwrap();
+    body.get_data().clone()
+}
+
+async fn subscribe(context: &utils::TestContext, product_id: i64) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = StockSubscriptionDto {
+        product_id: Some(product_id),
+    };
+
+    context
+        .srv
+        .post("/api/v1/me/stock-alerts/subscribe")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap()
+}
+
+async fn unsubscribe(context: &utils::TestContext, product_id: i64) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = StockSubscriptionDto {
+        product_id: Some(product_id),
+    };
+
+    context
+        .srv
+        .delete("/api/v1/me/stock-alerts/unsubscribe")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap()
+}
+
+async fn list_subscriptions(context: &utils::TestContext) -> Vec<PublicStockSubscription> {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/me/stock-alerts/list")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<Vec<PublicStockSubscription>> = res.json().await.unwrap();
+    body.get_data().clone()
+}
@@ -0,0 +1,269 @@
+use actix_test::ClientResponse;
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use ecomm::admin::products::dto::UpdateProductDTO;
+use ecomm::admin::stock::dto::{
+    AdminPublicStockDrift, AdminPublicStockMovement, CreateStockMovementDTO,
+    RecordStockMovementCommand, StockMovementKind,
+};
+use ecomm::responses::api_responses::{LocalApiPaginatedResponse, LocalApiResponse};
+
+mod utils;
+
+#[test]
+fn test_movement_signs() {
+    assert!(StockMovementKind::Receipt.check_quantity(5).is_ok());
+    assert!(StockMovementKind::Receipt.check_quantity(-5).is_err());
+    assert!(StockMovementKind::Return.check_quantity(1).is_ok());
+    assert!(StockMovementKind::Return.check_quantity(0).is_err());
+    assert!(StockMovementKind::Sale.check_quantity(-2).is_ok());
+    assert!(StockMovementKind::Sale.check_quantity(2).is_err());
+    assert!(StockMovementKind::Adjustment.check_quantity(-3).is_ok());
+    assert!(StockMovementKind::Adjustment.check_quantity(3).is_ok());
+    assert!(StockMovementKind::Adjustment.check_quantity(0).is_err());
+
+    let adjustment = RecordStockMovementCommand::adjustment(1, 10, 4, "count", None).unwrap();
+    assert_eq!(adjustment.quantity, -6);
+    assert_eq!(adjustment.kind, StockMovementKind::Adjustment);
+
+    assert!(RecordStockMovementCommand::adjustment(1, 4, 4, "count", None).is_none());
+}
+
+#[actix_rt::test]
+async fn test_receipt_adds_to_stock() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    // product 1 starts with 10 in stock
+    let mut res = create_movement(&context, &movement(1, "receipt", 5)).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let body: LocalApiResponse<AdminPublicStockMovement> = res.json().await.unwrap();
+    assert_eq!(body.get_data().balance, 15);
+    assert_eq!(body.get_data().actor_id, Some(3));
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_movement_cannot_take_stock_below_zero() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let res = create_movement(&context, &movement(1, "sale", -20)).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let movements = list_movements(&context, 1).await;
+    assert_eq!(movements.len(), 1);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_movement_sign_must_match_its_kind() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    for payload in [
+        movement(1, "sale", 3),
+        movement(1, "adjustment", -1),
+        movement(1, "gift", 1),
+    ] {
+        let res = create_movement(&context, &payload).await;
+        assert_eq!(
+            res.status(),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "{:?}",
+            payload.kind
+        );
+    }
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_movement_for_non_existing_product() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let res = create_movement(&context, &movement(999, "receipt", 1)).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_product_update_records_the_difference() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let res = create_movement(&context, &movement(1, "sale", -5)).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let res = update_product_quantity(&context, 1, 8).await;
+    assert!(res.status().is_success());
+
+    let movements: Vec<(String, i32, i32)> = list_movements(&context, 1)
+        .await
+        .into_iter()
+        .map(|m| (m.kind, m.quantity, m.balance))
+        .collect();
+
+    assert_eq!(
+        movements,
+        vec![
+            ("adjustment".to_string(), 3, 8),
+            ("sale".to_string(), -5, 5),
+            ("adjustment".to_string(), 10, 10),
+        ]
+    );
+
+    assert!(reconcile(&context).await.is_empty());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_write_around_the_ledger_shows_up_as_drift() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    assert!(reconcile(&context).await.is_empty());
+
+    sqlx::query("UPDATE products SET quantity = 20 WHERE id = 1")
+        .execute(&context.database.pool)
+        .await
+        .unwrap();
+
+    let drift = reconcile(&context).await;
+    assert_eq!(drift.len(), 1);
+    assert_eq!(drift[0].product_id, 1);
+    assert_eq!(drift[0].ledger_quantity, 10);
+    assert_eq!(drift[0].drift, 10);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_movements_cannot_be_edited() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let updated = sqlx::query("UPDATE stock_movements SET quantity = 100")
+        .execute(&context.database.pool)
+        .await;
+    assert!(updated.is_err());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_stock_requires_scope() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let res = context
+        .srv
+        .get("/api/v1/admin/stock/reconcile")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    context.database.cleanup().await;
+}
+
+fn movement(product_id: i64, kind: &str, quantity: i32) -> CreateStockMovementDTO {
+    CreateStockMovementDTO {
+        product_id: Some(product_id),
+        warehouse_id: None,
+        kind: Some(kind.to_string()),
+        quantity: Some(quantity),
+        reason: None,
+    }
+}
+
+async fn create_movement(
+    context: &utils::TestContext,
+    payload: &CreateStockMovementDTO,
+) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    context
+        .srv
+        .post("/api/v1/admin/stock/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(payload)
+        .await
+        .unwrap()
+}
+
+/** Movements of a product, newest first. */
+async fn list_movements(
+    context: &utils::TestContext,
+    product_id: i64,
+) -> Vec<AdminPublicStockMovement> {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get(format!(
+            "/api/v1/admin/stock/list?page=1&limit=10&product_id={}",
+            product_id
+        ))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiPaginatedResponse<Vec<AdminPublicStockMovement>> =
+        res.json().await.unwrap();
+    body.get_data().clone()
+}
+
+async fn reconcile(context: &utils::TestContext) -> Vec<AdminPublicStockDrift> {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/admin/stock/reconcile")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<Vec<AdminPublicStockDrift>> = res.json().await.unwrap();
+    body.get_data().clone()
+}
+
+async fn update_product_quantity(
+    context: &utils::TestContext,
+    id: i64,
+    quantity: i32,
+) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = UpdateProductDTO {
+        name: Some("Test Product 1".to_string()),
+        slug: Some("test-product-1-recounted".to_string()),
+        categories: None,
+        price: Some(BigDecimal::from(10)),
+        quantity: Some(quantity),
+        configurable: Some(false),
+        is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
+    };
+
+    context
+        .srv
+        .put(format!("/api/v1/admin/products/update/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap()
+}
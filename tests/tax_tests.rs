@@ -0,0 +1,242 @@
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use ecomm::app::cart::cart_items::dto::AddItemDto;
+use ecomm::app::cart::destination::CartDestination;
+use ecomm::app::cart::tax::dto::TaxableLine;
+use ecomm::app::cart::tax::engine::{calculate, find_rate, matches_postcode};
+use ecomm::app::cart::tax::model::TaxRateModel;
+use serde_json::{Value, json};
+
+mod utils;
+
+use utils::decimal;
+
+fn rate(
+    id: i64,
+    tax_class: &str,
+    region: Option<&str>,
+    postcode: Option<&str>,
+    percent: &str,
+) -> TaxRateModel {
+    TaxRateModel {
+        id,
+        name: format!("rate {}", id),
+        tax_class: tax_class.to_string(),
+        country: "US".to_string(),
+        region: region.map(str::to_string),
+        postcode: postcode.map(str::to_string),
+        rate: decimal(percent),
+        priority: 0,
+    }
+}
+
+fn destination(region: Option<&str>, postcode: Option<&str>) -> CartDestination {
+    CartDestination {
+        country: "US".to_string(),
+        region: region.map(str::to_string),
+        postcode: postcode.map(str::to_string),
+    }
+}
+
+fn line(tax_class: &str, amount: &str) -> TaxableLine {
+    TaxableLine {
+        tax_class: tax_class.to_string(),
+        amount: decimal(amount),
+    }
+}
+
+#[test]
+fn test_postcode_patterns() {
+    assert!(matches_postcode("10001", "10001"));
+    assert!(matches_postcode("100*", "10001"));
+    assert!(matches_postcode("*", "10001"));
+    assert!(matches_postcode("1*1", "10001"));
+    assert!(matches_postcode("SW1*", "SW1A1AA"));
+    assert!(!matches_postcode("100*", "20001"));
+    assert!(!matches_postcode("1000", "10001"));
+    assert!(!matches_postcode("1*2", "10001"));
+}
+
+#[test]
+fn test_most_specific_rate_wins() {
+    let rates = [
+        rate(1, "standard", None, None, "5"),
+        rate(2, "standard", Some("NY"), None, "6"),
+        rate(3, "standard", Some("NY"), Some("10*"), "8"),
+        rate(4, "standard", Some("NY"), Some("1000*"), "8.875"),
+        rate(5, "reduced", None, None, "2"),
+    ];
+
+    let found = |tax_class, region, postcode| {
+        find_rate(&rates, tax_class, &destination(region, postcode)).map(|rate| rate.id)
+    };
+
+    assert_eq!(found("standard", Some("ny"), Some("10001")), Some(4));
+    assert_eq!(found("standard", Some("NY"), Some("10501")), Some(3));
+    assert_eq!(found("standard", Some("NY"), Some("14201")), Some(2));
+    assert_eq!(found("standard", Some("CA"), Some("10001")), Some(1));
+    assert_eq!(found("standard", None, None), Some(1));
+    assert_eq!(found("reduced", Some("NY"), Some("10001")), Some(5));
+    assert_eq!(found("zero", Some("NY"), None), None);
+
+    // a tie goes to the higher priority, then to the older rate
+    let mut tied = [
+        rate(7, "standard", None, None, "7"),
+        rate(6, "standard", None, None, "6"),
+    ];
+    assert_eq!(
+        find_rate(&tied, "standard", &destination(None, None)).map(|rate| rate.id),
+        Some(6)
+    );
+
+    tied[0].priority = 1;
+    assert_eq!(
+        find_rate(&tied, "standard", &destination(None, None)).map(|rate| rate.id),
+        Some(7)
+    );
+}
+
+#[test]
+fn test_exclusive_tax_after_discount() {
+    let rates = [
+        rate(1, "standard", None, None, "10"),
+        rate(2, "reduced", None, None, "5"),
+    ];
+
+    // 10.00 off a 100.00 cart comes off both lines by their value
+    let tax = calculate(
+        &[
+            line("standard", "60.00"),
+            line("reduced", "40.00"),
+            line("exempt", "0.00"),
+        ],
+        &decimal("10.00"),
+        &rates,
+        &destination(None, None),
+        false,
+    );
+
+    assert!(!tax.prices_include_tax);
+    assert_eq!(tax.lines.len(), 2);
+    assert_eq!(tax.lines[0].taxable.amount, decimal("54.00"));
+    assert_eq!(tax.lines[0].amount.amount, decimal("5.40"));
+    assert_eq!(tax.lines[1].taxable.amount, decimal("36.00"));
+    assert_eq!(tax.lines[1].amount.amount, decimal("1.80"));
+    assert_eq!(tax.total.amount, decimal("7.20"));
+}
+
+#[test]
+fn test_inclusive_tax_is_the_contained_share() {
+    let rates = [rate(1, "standard", None, None, "19")];
+
+    let tax = calculate(
+        &[line("standard", "119.00"), line("standard", "11.90")],
+        &BigDecimal::from(0),
+        &rates,
+        &destination(None, None),
+        true,
+    );
+
+    assert!(tax.prices_include_tax);
+    assert_eq!(tax.lines.len(), 1);
+    assert_eq!(tax.lines[0].taxable.amount, decimal("130.90"));
+    assert_eq!(tax.lines[0].amount.amount, decimal("20.90"));
+    assert_eq!(tax.total.amount, decimal("20.90"));
+}
+
+#[actix_rt::test]
+async fn test_cart_tax_breakdown() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    let mut res = context
+        .srv
+        .post("/api/v1/admin/tax-rates/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "name": "DE VAT", "country": "de", "rate": "19" }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["country"], "DE");
+    assert_eq!(body["data"]["tax_class"], "standard");
+
+    let res = context
+        .srv
+        .post("/api/v1/cart/user/add")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send_json(&AddItemDto {
+            product_id: Some(1),
+            quantity: Some(2),
+        })
+        .await
+        .unwrap();
+    assert!(res.status().is_success());
+
+    // no destination, no tax
+    let mut res = context
+        .srv
+        .get("/api/v1/cart/user/get")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+    assert!(body["data"]["tax"].is_null());
+    assert_eq!(body["data"]["total"]["amount"], "21.98");
+
+    let mut res = context
+        .srv
+        .get("/api/v1/cart/user/get?country=DE&postcode=10115")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    let body: Value = res.json().await.unwrap();
+
+    let cart = &body["data"];
+    assert_eq!(cart["tax"]["prices_include_tax"], false);
+    assert_eq!(cart["tax"]["lines"][0]["name"], "DE VAT");
+    assert_eq!(cart["tax"]["lines"][0]["amount"]["amount"], "4.18");
+    assert_eq!(cart["tax"]["total"]["amount"], "4.18");
+    assert_eq!(cart["total"]["amount"], "26.16");
+
+    let res = context
+        .srv
+        .get("/api/v1/cart/user/get?country=Germany")
+        .insert_header(("Authorization", format!("Bearer {}", customer)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_admin_tax_rate_validation() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+
+    for body in [
+        json!({ "name": "Too high", "country": "DE", "rate": "101" }),
+        json!({ "name": "Too precise", "country": "DE", "rate": "19.00001" }),
+        json!({ "name": "Bad country", "country": "DEU", "rate": "19" }),
+        json!({ "name": "Bad postcode", "country": "DE", "postcode": "10?15", "rate": "19" }),
+        json!({ "name": "Bad class", "country": "DE", "tax_class": "full rate", "rate": "19" }),
+    ] {
+        let res = context
+            .srv
+            .post("/api/v1/admin/tax-rates/create")
+            .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+            .send_json(&body)
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    }
+
+    context.database.cleanup().await;
+}
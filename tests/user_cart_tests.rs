@@ -28,7 +28,7 @@ async fn test_get_user_cart_with_wrong_auth_token() {
 
     let res = context
         .srv
-        .get("/cart/user/get")
+        .get("/api/v1/cart/user/get")
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
@@ -47,7 +47,6 @@ async fn test_get_user_cart_after_adding_an_item() {
 
     let payload = AddItemDto {
         product_id: Some(1),
-        price: Some(10.99),
         quantity: Some(1),
     };
 
@@ -69,7 +68,6 @@ async fn test_add_to_cart_non_existing_product() {
 
     let payload = AddItemDto {
         product_id: Some(100),
-        price: Some(10.99),
         quantity: Some(1),
     };
 
@@ -87,7 +85,6 @@ async fn test_add_to_cart_with_invalid_quantity() {
 
     let payload = AddItemDto {
         product_id: Some(1),
-        price: Some(10.99),
         quantity: Some(-1),
     };
 
@@ -98,6 +95,60 @@ async fn test_add_to_cart_with_invalid_quantity() {
     context.database.cleanup().await;
 }
 
+#[actix_rt::test]
+async fn test_add_same_product_twice_raises_one_line() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    let auth_token = context.auth_token.unwrap();
+
+    for _ in 0..2 {
+        let payload = AddItemDto {
+            product_id: Some(1),
+            quantity: Some(2),
+        };
+
+        let res = add_item_to_user_cart(&context.srv, &auth_token, payload).await;
+        assert!(res.status().is_success(), "{:#?}", res);
+    }
+
+    let cart = get_user_cart(&context.srv, &auth_token).await;
+
+    assert_eq!(cart.get_data().items.len(), 1);
+    assert_eq!(cart.get_data().items[0].quantity, 4);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_add_same_product_twice_past_sellable_stock() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+
+    let auth_token = context.auth_token.unwrap();
+
+    // product 1 has 10 in stock
+    let payload = AddItemDto {
+        product_id: Some(1),
+        quantity: Some(6),
+    };
+
+    let res = add_item_to_user_cart(&context.srv, &auth_token, payload).await;
+    assert!(res.status().is_success(), "{:#?}", res);
+
+    let payload = AddItemDto {
+        product_id: Some(1),
+        quantity: Some(6),
+    };
+
+    let res = add_item_to_user_cart(&context.srv, &auth_token, payload).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT, "{:#?}", res);
+
+    let cart = get_user_cart(&context.srv, &auth_token).await;
+
+    assert_eq!(cart.get_data().items[0].quantity, 6);
+
+    context.database.cleanup().await;
+}
+
 #[actix_rt::test]
 async fn test_update_item_on_user_cart() {
     let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
@@ -106,7 +157,6 @@ async fn test_update_item_on_user_cart() {
 
     let payload = AddItemDto {
         product_id: Some(1),
-        price: Some(10.99),
         quantity: Some(1),
     };
 
@@ -154,7 +204,6 @@ async fn test_update_item_on_user_cart_with_invalid_quantity() {
 
     let payload = AddItemDto {
         product_id: Some(1),
-        price: Some(10.99),
         quantity: Some(1),
     };
 
@@ -180,7 +229,6 @@ async fn test_remove_item_from_user_cart() {
 
     let payload = AddItemDto {
         product_id: Some(1),
-        price: Some(10.99),
         quantity: Some(1),
     };
 
@@ -220,7 +268,7 @@ async fn test_remove_non_existing_item_from_user_cart() {
 
 async fn get_user_cart(srv: &TestServer, auth_token: &str) -> LocalApiResponse<PublicUserCart> {
     let mut res = srv
-        .get("/cart/user/get")
+        .get("/api/v1/cart/user/get")
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send()
         .await
@@ -238,7 +286,7 @@ async fn add_item_to_user_cart(
     auth_token: &str,
     payload: AddItemDto,
 ) -> ClientResponse {
-    srv.post("/cart/user/add")
+    srv.post("/api/v1/cart/user/add")
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -250,7 +298,7 @@ async fn update_item_on_user_cart(
     auth_token: &str,
     payload: UpdateItemDto,
 ) -> ClientResponse {
-    srv.put("/cart/user/update")
+    srv.put("/api/v1/cart/user/update")
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
@@ -262,7 +310,7 @@ async fn remove_item_from_user_cart(
     auth_token: &str,
     payload: RemoveItemDto,
 ) -> ClientResponse {
-    srv.delete("/cart/user/remove")
+    srv.delete("/api/v1/cart/user/remove")
         .insert_header(("Authorization", format!("Bearer {}", auth_token)))
         .send_json(&payload)
         .await
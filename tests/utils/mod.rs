@@ -1,15 +1,20 @@
 use actix_test::TestServer;
 use actix_web::{App, web};
-use dotenvy::from_filename;
-use ecomm::admin::routes::routes as admin_routes;
-use ecomm::app::cart::routes::routes as cart_routes;
-use ecomm::app::categories::routes::routes as category_routes;
-use ecomm::app::products::routes::routes as products_routes;
+use bigdecimal::BigDecimal;
+use ecomm::api::routes as api_routes;
 use ecomm::auth::dto::LoginDTO;
-use ecomm::auth::routes::routes as auth_routes;
+use ecomm::config::settings::{Config, Profile};
+use ecomm::docs::routes::routes as docs_routes;
+use ecomm::errors::extractors;
+use ecomm::health::routes::routes as health_routes;
+use ecomm::metrics::routes::routes as metrics_routes;
+use ecomm::middlewares::metrics::MetricsMiddleware;
+use ecomm::middlewares::request_id::RequestIdMiddleware;
 use ecomm::state::AppState;
+use ecomm::utils::telemetry;
 use sqlx::{PgPool, postgres::PgPoolOptions};
 use std::env;
+use std::str::FromStr;
 use uuid::Uuid;
 
 pub struct TestDatabase {
@@ -20,8 +25,8 @@ pub struct TestDatabase {
 
 impl TestDatabase {
     pub async fn new() -> Self {
-        from_filename(".env.test").ok();
-        env_logger::try_init().ok();
+        let config = test_config();
+        telemetry::init(&config.logging).ok();
 
         let test_db_url = env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
         let admin_db_url = env::var("ADMIN_DATABASE_URL").expect("ADMIN_DATABASE_URL must be set");
@@ -40,8 +45,9 @@ impl TestDatabase {
             .await
             .expect("Failed to create test database");
 
-        let test_pool = PgPoolOptions::new()
-            .max_connections(5)
+        let test_pool = config
+            .database
+            .pool_options()
             .connect(&format!("{}{}", test_db_url, test_db_name))
             .await
             .expect("Failed to connect to test database");
@@ -129,31 +135,50 @@ impl TestContextNoServer {
     }
 }
 
+pub fn test_config() -> Config {
+    Config::load_profile(Profile::Test).expect("Failed to load test configuration")
+}
+
 pub fn create_test_server(pool: PgPool) -> TestServer {
+    let state = web::Data::new(AppState::new(pool, test_config()));
+
     actix_test::start(move || {
         App::new()
-            .app_data(web::Data::new(AppState::new(pool.clone())))
-            .configure(auth_routes)
-            .configure(admin_routes)
-            .configure(category_routes)
-            .configure(products_routes)
-            .configure(cart_routes)
+            .wrap(MetricsMiddleware::new(state.metrics.clone()))
+            .wrap(RequestIdMiddleware)
+            .app_data(state.clone())
+            .app_data(extractors::multipart_config(&state.config.uploads))
+            .configure(extractors::configure)
+            .configure(health_routes)
+            .configure(metrics_routes)
+            .configure(docs_routes)
+            .configure(api_routes)
     })
 }
 
+/** Parses a decimal literal, for amounts in assertions and fixtures. */
+#[allow(dead_code)] // only the suites dealing with amounts use it
+pub fn decimal(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
 pub async fn auto_login(srv: &TestServer, email: String) -> String {
     let payload = LoginDTO {
         email: Some(email),
         password: Some("123456".to_string()),
     };
 
-    let mut res = srv.post("/auth/login").send_json(&payload).await.unwrap();
+    let mut res = srv
+        .post("/api/v2/auth/login")
+        .send_json(&payload)
+        .await
+        .unwrap();
 
     let body = res.json::<serde_json::Value>().await.unwrap();
 
-    match body.get("token") {
+    match body.get("data").and_then(|data| data.get("token")) {
         Some(token) => token.as_str().unwrap().trim_matches('"').to_string(),
-        None => body.get("message").unwrap().to_string(),
+        None => body.get("detail").unwrap().to_string(),
     }
 }
 
@@ -216,6 +241,28 @@ pub async fn seed_products(pool: &PgPool) {
     .execute(pool)
     .await
     .expect("Failed to seed product test data");
+
+    sqlx::query!(
+        "INSERT INTO warehouse_stock (warehouse_id, product_id, quantity)
+         SELECT warehouses.id, products.id, products.quantity
+         FROM products CROSS JOIN warehouses
+         WHERE warehouses.is_default AND products.quantity > 0
+         ON CONFLICT DO NOTHING;"
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to seed warehouse stock test data");
+
+    sqlx::query!(
+        "INSERT INTO stock_movements (product_id, warehouse_id, kind, quantity, balance, reason)
+         SELECT products.id, warehouses.id, 'adjustment', products.quantity, products.quantity, 'opening balance'
+         FROM products CROSS JOIN warehouses
+         WHERE warehouses.is_default AND products.quantity <> 0
+            AND NOT EXISTS (SELECT 1 FROM stock_movements WHERE product_id = products.id);"
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to seed stock movement test data");
 }
 
 pub async fn seed_product_images(pool: &PgPool) {
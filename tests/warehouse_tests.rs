@@ -0,0 +1,506 @@
+use actix_test::{ClientResponse, TestServer};
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use ecomm::admin::products::dto::UpdateProductDTO;
+use ecomm::admin::stock::dto::{AdminPublicStockDrift, AdminPublicStockMovement};
+use ecomm::admin::warehouses::dto::{
+    AdminPublicWarehouse, AdminPublicWarehouseStock, CreateWarehouseDTO, TransferStockDTO,
+    UpdateWarehouseDTO,
+};
+use ecomm::app::cart::cart_items::dto::AddItemDto;
+use ecomm::app::cart::stock::engine::{Allocation, allocate, sellable};
+use ecomm::app::cart::stock::model::SellableStockModel;
+use ecomm::app::products::dto::PublicProduct;
+use ecomm::config::settings::AllocationStrategy;
+use ecomm::responses::api_responses::{LocalApiPaginatedResponse, LocalApiResponse};
+
+mod utils;
+
+#[test]
+fn test_priority_allocation_splits_in_priority_order() {
+    let levels = [level(1, 0, 3), level(2, 1, 5), level(3, 2, 0)];
+
+    assert_eq!(
+        allocate(&levels, 6, AllocationStrategy::Priority),
+        Some(vec![allocation(1, 3), allocation(2, 3)])
+    );
+    assert_eq!(
+        allocate(&levels, 2, AllocationStrategy::Priority),
+        Some(vec![allocation(1, 2)])
+    );
+    assert_eq!(allocate(&levels, 9, AllocationStrategy::Priority), None);
+    assert_eq!(sellable(&levels, AllocationStrategy::Priority), 8);
+
+    assert_eq!(allocate(&[], 1, AllocationStrategy::Priority), None);
+}
+
+#[test]
+fn test_most_stock_allocation_takes_the_biggest_holdings_first() {
+    let levels = [level(1, 0, 3), level(2, 1, 5), level(3, 2, 0)];
+
+    assert_eq!(
+        allocate(&levels, 6, AllocationStrategy::MostStock),
+        Some(vec![allocation(2, 5), allocation(1, 1)])
+    );
+    assert_eq!(sellable(&levels, AllocationStrategy::MostStock), 8);
+}
+
+#[test]
+fn test_single_allocation_never_splits() {
+    let levels = [level(1, 0, 3), level(2, 1, 5), level(3, 2, 0)];
+
+    // the first warehouse in priority order that fits
+    assert_eq!(
+        allocate(&levels, 2, AllocationStrategy::Single),
+        Some(vec![allocation(1, 2)])
+    );
+    assert_eq!(
+        allocate(&levels, 4, AllocationStrategy::Single),
+        Some(vec![allocation(2, 4)])
+    );
+    assert_eq!(allocate(&levels, 6, AllocationStrategy::Single), None);
+    assert_eq!(sellable(&levels, AllocationStrategy::Single), 5);
+
+    assert_eq!(sellable(&[], AllocationStrategy::Single), 0);
+}
+
+#[actix_rt::test]
+async fn test_default_warehouse_is_seeded() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let warehouses = list_warehouses(&context).await;
+
+    assert_eq!(warehouses.len(), 1);
+    assert_eq!(warehouses[0].code, "MAIN");
+    assert!(warehouses[0].is_default);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_create_warehouse() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let mut res = create_warehouse(&context, &warehouse("Berlin store", " ber-1 ")).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let body: LocalApiResponse<AdminPublicWarehouse> = res.json().await.unwrap();
+    assert_eq!(body.get_data().code, "BER-1");
+    assert!(!body.get_data().is_default);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_create_warehouse_with_invalid_code() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let res = create_warehouse(&context, &warehouse("Bad code", "BER 2")).await;
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_inactive_warehouse_cannot_be_the_default() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let payload = CreateWarehouseDTO {
+        is_active: Some(false),
+        is_default: Some(true),
+        ..warehouse("Closed", "CLOSED")
+    };
+
+    let res = create_warehouse(&context, &payload).await;
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_default_warehouse_can_only_hand_its_role_over() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let main_id = default_warehouse_id(&context).await;
+
+    let payload = UpdateWarehouseDTO {
+        name: Some("Main warehouse".to_string()),
+        code: Some("MAIN".to_string()),
+        priority: None,
+        is_active: None,
+        is_default: Some(false),
+    };
+
+    let res = update_warehouse(&context, main_id, &payload).await;
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_transfer_between_warehouses() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let main_id = default_warehouse_id(&context).await;
+    let store_id = create_store(&context).await;
+
+    // product 1 starts with 10 at the default warehouse
+    let mut res = transfer(&context, main_id, store_id, 4).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<Vec<AdminPublicWarehouseStock>> = res.json().await.unwrap();
+    let levels: Vec<(String, i32)> = body
+        .get_data()
+        .iter()
+        .map(|level| (level.code.clone(), level.quantity))
+        .collect();
+    assert_eq!(
+        levels,
+        vec![("MAIN".to_string(), 6), ("BER-1".to_string(), 4)]
+    );
+
+    // the product-wide balance stays put and the ledger still adds up
+    let movements = list_transfer_movements(&context).await;
+    assert_eq!(movements.len(), 2);
+    assert!(movements.iter().all(|movement| movement.balance == 10));
+
+    assert!(reconcile(&context).await.is_empty());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_transfer_to_the_same_warehouse() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let main_id = default_warehouse_id(&context).await;
+
+    let res = transfer(&context, main_id, main_id, 1).await;
+    assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_transfer_with_too_little_stock() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let main_id = default_warehouse_id(&context).await;
+    let store_id = create_store(&context).await;
+
+    let res = transfer(&context, main_id, store_id, 20).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    assert!(list_transfer_movements(&context).await.is_empty());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_transfer_to_non_existing_warehouse() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let main_id = default_warehouse_id(&context).await;
+
+    let res = transfer(&context, main_id, 999, 1).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_storefront_sells_what_active_warehouses_hold() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let main_id = default_warehouse_id(&context).await;
+    let store_id = create_store(&context).await;
+
+    let res = transfer(&context, main_id, store_id, 4).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_eq!(storefront_quantity(&context.srv).await, 10);
+
+    let res = deactivate_store(&context, store_id).await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    assert_eq!(storefront_quantity(&context.srv).await, 6);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_cart_allocation_across_sellable_warehouses() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    let main_id = default_warehouse_id(&context).await;
+    let store_id = create_store(&context).await;
+
+    let res = transfer(&context, main_id, store_id, 4).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    // neither warehouse holds 8 on its own
+    let res = add_to_cart(&context.srv, &customer, 8).await;
+    assert!(res.status().is_success());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_cart_skips_inactive_warehouses() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+    let customer = utils::auto_login(&context.srv, "test1@test.com".to_string()).await;
+
+    let main_id = default_warehouse_id(&context).await;
+    let store_id = create_store(&context).await;
+
+    let res = transfer(&context, main_id, store_id, 4).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = deactivate_store(&context, store_id).await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let res = add_to_cart(&context.srv, &customer, 8).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let res = add_to_cart(&context.srv, &customer, 6).await;
+    assert!(res.status().is_success());
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_product_stock_update_only_touches_the_default_warehouse() {
+    let context = utils::TestContext::new(Some("admin1@admin.com".to_string())).await;
+
+    let main_id = default_warehouse_id(&context).await;
+    let store_id = create_store(&context).await;
+
+    let res = transfer(&context, main_id, store_id, 4).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    // taking 10 down to 2 needs 8 from the default warehouse, which holds 6
+    let res = update_product_quantity(&context, 1, 2).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    context.database.cleanup().await;
+}
+
+fn level(warehouse_id: i64, priority: i32, quantity: i32) -> SellableStockModel {
+    SellableStockModel {
+        warehouse_id,
+        priority,
+        quantity,
+    }
+}
+
+fn allocation(warehouse_id: i64, quantity: i32) -> Allocation {
+    Allocation {
+        warehouse_id,
+        quantity,
+    }
+}
+
+fn warehouse(name: &str, code: &str) -> CreateWarehouseDTO {
+    CreateWarehouseDTO {
+        name: Some(name.to_string()),
+        code: Some(code.to_string()),
+        priority: Some(1),
+        is_active: None,
+        is_default: None,
+    }
+}
+
+async fn create_warehouse(
+    context: &utils::TestContext,
+    payload: &CreateWarehouseDTO,
+) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    context
+        .srv
+        .post("/api/v1/admin/warehouses/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(payload)
+        .await
+        .unwrap()
+}
+
+/** Creates the `BER-1` store behind the default warehouse, returns its id. */
+async fn create_store(context: &utils::TestContext) -> i64 {
+    let mut res = create_warehouse(context, &warehouse("Berlin store", "BER-1")).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let body: LocalApiResponse<AdminPublicWarehouse> = res.json().await.unwrap();
+    body.get_data().id
+}
+
+async fn update_warehouse(
+    context: &utils::TestContext,
+    id: i64,
+    payload: &UpdateWarehouseDTO,
+) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    context
+        .srv
+        .put(format!("/api/v1/admin/warehouses/update/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(payload)
+        .await
+        .unwrap()
+}
+
+async fn deactivate_store(context: &utils::TestContext, id: i64) -> ClientResponse {
+    let payload = UpdateWarehouseDTO {
+        name: Some("Berlin store".to_string()),
+        code: Some("BER-1".to_string()),
+        priority: Some(1),
+        is_active: Some(false),
+        is_default: None,
+    };
+
+    update_warehouse(context, id, &payload).await
+}
+
+async fn list_warehouses(context: &utils::TestContext) -> Vec<AdminPublicWarehouse> {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/admin/warehouses/list?page=1&limit=10")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiPaginatedResponse<Vec<AdminPublicWarehouse>> = res.json().await.unwrap();
+    body.get_data().clone()
+}
+
+async fn default_warehouse_id(context: &utils::TestContext) -> i64 {
+    list_warehouses(context)
+        .await
+        .into_iter()
+        .find(|warehouse| warehouse.is_default)
+        .unwrap()
+        .id
+}
+
+/** Moves `quantity` of product 1 between two warehouses. */
+async fn transfer(
+    context: &utils::TestContext,
+    from_warehouse_id: i64,
+    to_warehouse_id: i64,
+    quantity: i32,
+) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = TransferStockDTO {
+        product_id: Some(1),
+        from_warehouse_id: Some(from_warehouse_id),
+        to_warehouse_id: Some(to_warehouse_id),
+        quantity: Some(quantity),
+        reason: None,
+    };
+
+    context
+        .srv
+        .post("/api/v1/admin/warehouses/transfer")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap()
+}
+
+async fn list_transfer_movements(context: &utils::TestContext) -> Vec<AdminPublicStockMovement> {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/admin/stock/list?page=1&limit=10&kind=transfer")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiPaginatedResponse<Vec<AdminPublicStockMovement>> = res.json().await.unwrap();
+    body.get_data().clone()
+}
+
+async fn reconcile(context: &utils::TestContext) -> Vec<AdminPublicStockDrift> {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let mut res = context
+        .srv
+        .get("/api/v1/admin/stock/reconcile")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<Vec<AdminPublicStockDrift>> = res.json().await.unwrap();
+    body.get_data().clone()
+}
+
+async fn storefront_quantity(srv: &TestServer) -> i32 {
+    let mut res = srv
+        .get("/api/v1/products/get/test-product-1")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: LocalApiResponse<PublicProduct> = res.json().await.unwrap();
+    body.get_data().quantity
+}
+
+async fn add_to_cart(srv: &TestServer, auth_token: &str, quantity: i32) -> ClientResponse {
+    srv.post("/api/v1/cart/user/add")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&AddItemDto {
+            product_id: Some(1),
+            quantity: Some(quantity),
+        })
+        .await
+        .unwrap()
+}
+
+async fn update_product_quantity(
+    context: &utils::TestContext,
+    id: i64,
+    quantity: i32,
+) -> ClientResponse {
+    let auth_token = context.auth_token.clone().unwrap();
+
+    let payload = UpdateProductDTO {
+        name: Some("Test Product 1".to_string()),
+        slug: Some("test-product-1-recounted".to_string()),
+        categories: None,
+        price: Some(BigDecimal::from(10)),
+        quantity: Some(quantity),
+        configurable: Some(false),
+        is_active: Some(true),
+        publish_at: None,
+        unpublish_at: None,
+        sale_price: None,
+        sale_starts_at: None,
+        sale_ends_at: None,
+        tax_class: None,
+        weight_grams: None,
+        length_mm: None,
+        width_mm: None,
+        height_mm: None,
+    };
+
+    context
+        .srv
+        .put(format!("/api/v1/admin/products/update/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&payload)
+        .await
+        .unwrap()
+}
@@ -0,0 +1,268 @@
+use actix_web::http::StatusCode;
+use bigdecimal::BigDecimal;
+use ecomm::app::wishlists::alerts::{ProductSnapshot, WishlistAlerts, alerts_for};
+use ecomm::app::wishlists::dto::CreateWishlistCommand;
+use ecomm::app::wishlists::service::WishlistService;
+use ecomm::config::settings::InventoryConfig;
+use ecomm::notifications::dto::Notification;
+use ecomm::notifications::notifier::Notifier;
+use ecomm::utils::money::Money;
+use serde_json::{Value, json};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+mod utils;
+
+#[derive(Default)]
+struct RecordingNotifier {
+    sent: Mutex<Vec<Notification>>,
+}
+
+impl Notifier for RecordingNotifier {
+    fn notify(&self, notification: Notification) {
+        self.sent.lock().unwrap().push(notification);
+    }
+}
+
+fn snapshot(price: &str, quantity: i32) -> ProductSnapshot {
+    ProductSnapshot {
+        id: 1,
+        name: "Test Product 1".to_string(),
+        price: BigDecimal::from_str(price).unwrap(),
+        quantity,
+        available: true,
+    }
+}
+
+#[test]
+fn test_alerts_for() {
+    let watchers = [1, 2];
+
+    let found = alerts_for(&snapshot("10.99", 0), &snapshot("10.99", 5), &watchers);
+    assert_eq!(found.len(), 2);
+    assert!(matches!(
+        found[0],
+        Notification::BackInStock { user_id: 1, .. }
+    ));
+
+    let found = alerts_for(&snapshot("10.99", 5), &snapshot("8.99", 5), &[1]);
+    assert_eq!(
+        found,
+        vec![Notification::PriceDrop {
+            user_id: 1,
+            product_id: 1,
+            product_name: "Test Product 1".to_string(),
+            old_price: Money::new(BigDecimal::from_str("10.99").unwrap()),
+            new_price: Money::new(BigDecimal::from_str("8.99").unwrap()),
+        }]
+    );
+
+    // both at once
+    let found = alerts_for(&snapshot("10.99", 0), &snapshot("8.99", 5), &[1]);
+    assert_eq!(found.len(), 2);
+
+    // nothing to tell: pricier, still out of stock, or not for sale
+    assert!(alerts_for(&snapshot("10.99", 5), &snapshot("12.99", 5), &watchers).is_empty());
+    assert!(alerts_for(&snapshot("10.99", 0), &snapshot("8.99", 0), &watchers).is_empty());
+
+    let mut hidden = snapshot("8.99", 5);
+    hidden.available = false;
+    assert!(alerts_for(&snapshot("10.99", 0), &hidden, &watchers).is_empty());
+
+    // going on sale again with stock counts as back in stock
+    let found = alerts_for(&hidden, &snapshot("8.99", 5), &[1]);
+    assert!(matches!(found[..], [Notification::BackInStock { .. }]));
+}
+
+#[actix_rt::test]
+async fn test_product_changes_notify_watchers() {
+    let context = utils::TestContextNoServer::new().await;
+    let pool = context.database.pool.clone();
+    let service = WishlistService::new(pool.clone(), InventoryConfig::default());
+
+    // two lists of the same user holding the product count once
+    for (user_id, name) in [(1, "Birthday"), (1, "Christmas"), (2, "Birthday")] {
+        let wishlist = service
+            .create(
+                user_id,
+                CreateWishlistCommand {
+                    name: name.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        service.add_item(user_id, wishlist.id, 1).await.unwrap();
+    }
+
+    let notifier = Arc::new(RecordingNotifier::default());
+    let alerts = WishlistAlerts::with_notifier(pool, notifier.clone());
+
+    let sent = alerts
+        .product_changed(&snapshot("10.99", 0), &snapshot("10.99", 3))
+        .await
+        .unwrap();
+    assert_eq!(sent, 2);
+
+    let sent = alerts
+        .product_changed(&snapshot("10.99", 3), &snapshot("10.99", 2))
+        .await
+        .unwrap();
+    assert_eq!(sent, 0);
+
+    let users: Vec<i64> = notifier
+        .sent
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(Notification::user_id)
+        .collect();
+    assert_eq!(users, vec![1, 2]);
+
+    context.database.cleanup().await;
+}
+
+#[actix_rt::test]
+async fn test_wishlists() {
+    let context = utils::TestContext::new(Some("test1@test.com".to_string())).await;
+    let auth_token = context.auth_token.clone().unwrap();
+    let other = utils::auto_login(&context.srv, "test2@test.com".to_string()).await;
+
+    let mut res = context
+        .srv
+        .post("/api/v1/me/wishlists/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "name": " Birthday " }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let body: Value = res.json().await.unwrap();
+    let id = body["data"]["id"].as_i64().unwrap();
+    assert_eq!(body["data"]["name"], "Birthday");
+    assert_eq!(body["data"]["share_token"], Value::Null);
+
+    // names are unique per user, ignoring case
+    let res = context
+        .srv
+        .post("/api/v1/me/wishlists/create")
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "name": "birthday" }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let mut res = context
+        .srv
+        .post(format!("/api/v1/me/wishlists/items/add/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "product_id": 1 }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = res.json().await.unwrap();
+    let items = body["data"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["product_id"], 1);
+    assert_eq!(items[0]["added_price"]["amount"], "10.99");
+    assert_eq!(items[0]["in_stock"], true);
+
+    // inactive products can't be added
+    let res = context
+        .srv
+        .post(format!("/api/v1/me/wishlists/items/add/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "product_id": 2 }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    // someone else's list is not found
+    let res = context
+        .srv
+        .get(format!("/api/v1/me/wishlists/get/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", other)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let mut res = context
+        .srv
+        .post(format!("/api/v1/me/wishlists/share/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = res.json().await.unwrap();
+    let token = body["data"]["share_token"].as_str().unwrap().to_string();
+    assert_eq!(token.len(), 32);
+
+    let mut res = context
+        .srv
+        .get(format!("/api/v1/wishlists/shared/{}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["name"], "Birthday");
+    assert_eq!(body["data"]["items"].as_array().unwrap().len(), 1);
+    assert!(body["data"].get("share_token").is_none());
+
+    let mut res = context
+        .srv
+        .post(format!("/api/v1/me/wishlists/items/move/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "product_id": 1, "quantity": 2 }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = res.json().await.unwrap();
+    let cart_items = body["data"]["items"].as_array().unwrap();
+    assert_eq!(cart_items.len(), 1);
+    assert_eq!(cart_items[0]["quantity"], 2);
+
+    // moved items leave the list
+    let res = context
+        .srv
+        .post(format!("/api/v1/me/wishlists/items/move/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send_json(&json!({ "product_id": 1 }))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = context
+        .srv
+        .delete(format!("/api/v1/me/wishlists/share/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = context
+        .srv
+        .get(format!("/api/v1/wishlists/shared/{}", token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = context
+        .srv
+        .delete(format!("/api/v1/me/wishlists/delete/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", auth_token)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    context.database.cleanup().await;
+}